@@ -0,0 +1,679 @@
+# OEIS TUI - Deutsche Übersetzung
+
+# Application
+app-title = OEIS TUI
+app-subtitle = On-Line Encyclopedia of Integer Sequences
+
+# Greeting Screen
+greeting-title = Willkommen bei OEIS TUI
+greeting-line1 = Eine schöne Terminal-Oberfläche zum Erkunden von Ganzzahlfolgen
+greeting-line2 = 'i' oder '/' drücken, um die Suche zu starten
+greeting-line3 = 'r' drücken für eine zufällige Folge
+greeting-line4 = 'w' drücken für den Webcam-Modus
+greeting-line5 = 'Strg+H' drücken für die Hilfe
+greeting-copyright = © OEIS Foundation Inc. - Alle Folgendaten sind Eigentum der OEIS
+greeting-version = Version 0.1.0
+
+# Welcome / empty states
+welcome-title = Willkommen bei OEIS TUI
+welcome-subtitle = The On-Line Encyclopedia of Integer Sequences (inoffizielle TUI)
+welcome-prompt = Zum Start eine Folge, ein Wort oder eine A-Nummer eingeben.
+welcome-search-label = OEIS durchsuchen
+welcome-enter-hint = Enter zum Suchen
+welcome-esc-hint = Esc zum Schließen
+welcome-hero-subtitle = Bekannte Ganzzahlfolgen finden, Referenzen entdecken und Zusammenhänge erkunden.
+welcome-hero-tips = Beispiele: 1,2,3,4,5,6  •  keyword:prime  •  id:A000045
+welcome-hero-search-hint = Mit 'i' oder '/' jederzeit zur Suche springen.
+welcome-daily = Folge des Tages
+welcome-daily-open = zum Öffnen
+search-empty-title = Noch keine Suchergebnisse
+search-tips-title = Suchtipps:
+search-tip-terms = • Glieder der Folge eingeben: 1,1,2,3,5,8,13
+search-tip-anumber = • Nach A-Nummer suchen: id:A000045
+search-tip-keyword = • Nach Stichwort suchen: fibonacci
+search-tip-prefixes = • Präfixe verwenden: keyword:nice author:Sloane
+search-start-hint = 'i' oder '/' drücken, um die Suche zu starten
+search-recently-viewed = Zuletzt angesehen
+search-history-empty = Noch kein Verlauf
+search-bookmarks-title = Lesezeichen
+search-bookmarks-empty = Noch keine Lesezeichen. In der Detailansicht 'b' drücken, um Folgen zu merken.
+search-bookmarks-loading = Wird geladen...
+search-bookmarks-notes = Notizen
+search-results-title = Ergebnisse
+
+# Search Screen
+search-title = OEIS durchsuchen
+search-input-label = Suche
+search-input-placeholder = Glieder der Folge (z. B. 1,2,3,5,8,13), A-Nummer oder Stichwort eingeben...
+search-status-results = { $count ->
+    [0] Keine Ergebnisse gefunden
+    [one] 1 Ergebnis gefunden
+    *[other] { $count } Ergebnisse gefunden
+}
+search-status-page = Seite { $current } von { $total }
+search-status-loading = Suche läuft...
+search-status-fetching = Bitte warten, die Ergebnisse werden von der OEIS abgerufen
+search-status-error = Fehler: { $message }
+search-no-results = Keine Ergebnisse gefunden
+search-result-one = 1 Ergebnis gefunden
+search-result-many = { $count } Ergebnisse gefunden
+search-result-many-plus = { $count }+ Ergebnisse gefunden
+search-table-anumber = A-Nummer
+search-table-name = Name
+search-table-data = Datenvorschau
+search-table-views = Aufrufe
+search-block-results = Ergebnisse
+search-block-preview = Vorschau
+search-block-details = Details
+search-preview-empty = Keine Vorschau verfügbar
+search-invalid-tab = Ungültiger Tab
+search-view-count = { $count ->
+    [one] 1 Aufruf
+    *[other] { $count } Aufrufe
+}
+search-help = i,/ Suchen | ↑↓ Bewegen | ←→ Seite | Enter Öffnen | p Vorschau | r Zufall | w Webcam | s Einstellungen | Strg+H Hilfe | q Beenden
+search-help-search = Suchen
+search-help-navigate = Bewegen
+search-help-page = Seite
+search-help-view = Öffnen
+search-help-preview = Vorschau
+search-help-bookmarks = Lesezeichen
+search-help-random = Zufall
+search-help-webcam = Webcam
+search-help-settings = Einstellungen
+search-help-export = Exportieren
+search-help-help = Hilfe
+search-help-quit = Beenden
+search-help-click = Auswählen
+search-help-click-x2 = Öffnen
+search-help-scroll = Bewegen
+
+# Detail View
+detail-tab-overview = Übersicht
+detail-tab-formulas = Formeln
+detail-tab-code = Code
+detail-tab-references = Referenzen
+detail-tab-crossrefs = Querverweise
+detail-tab-metadata = Metadaten
+detail-tab-graph = Diagramm
+detail-tab-export = Export
+detail-offset = Offset
+detail-keywords = Stichwörter
+detail-author = Autor
+detail-created = Erstellt
+detail-modified = Zuletzt geändert
+detail-comments = Kommentare
+detail-data = Folgendaten
+detail-formulas = Formeln
+detail-examples = Beispiele
+detail-maple = Maple-Code
+detail-mathematica = Mathematica-Code
+detail-programs = Weitere Programme
+detail-references = Referenzen
+detail-links = Links
+detail-crossrefs = Querverweise
+detail-extensions = Erweiterungen
+detail-no-data = Keine Daten verfügbar
+detail-no-sequence = Keine Folge geladen
+detail-block-sequence = Folge
+detail-block-details = Details
+detail-section-data = Daten
+detail-section-comments = Kommentare
+detail-section-examples = Beispiele
+detail-help = Tab Wechseln | ↑↓ Scrollen | g Diagramm | e Export | o Browser | b Lesezeichen | Esc Zurück
+detail-help-prev-link = Vorheriger Link
+detail-help-switch-tab = Tab wechseln
+detail-help-scroll = Scrollen
+detail-help-graph = Diagramm
+detail-help-export = Exportieren
+detail-help-browser = Im Browser öffnen
+detail-bookmarked = Gemerkt
+detail-not-bookmarked = Nicht gemerkt
+detail-help-more = Mehr
+detail-help-modal-title = Detailansicht - Tastenkürzel
+detail-bfile-available = Erweiterte Daten verfügbar
+detail-bfile-fetch = 'f' drücken, um die b-file abzurufen
+detail-bfile-loading = b-file wird geladen...
+detail-bfile-loaded = ✓ {$count} Glieder geladen
+detail-bfile-error = b-file nicht verfügbar
+detail-bfile-not-found = Keine b-file für diese Folge gefunden
+
+# Graph View
+graph-title = Diagrammansicht
+graph-line = Liniendiagramm
+graph-scatter = Streudiagramm
+graph-log = Logarithmisches Streudiagramm
+graph-pin = Stabdiagramm
+graph-histogram = Histogramm der Werte
+graph-modk = Reste mod { $k }
+graph-ulam = Ulam-Spirale
+graph-differences = Erste Differenzen
+graph-ratio = Quotient aufeinanderfolgender Glieder
+graph-ulam-summary = { $members } Glieder zwischen 1 und { $limit }
+graph-no-data = Keine numerischen Daten zum Darstellen
+graph-no-positive = Keine positiven Werte für die logarithmische Skala
+graph-current = Aktuell
+graph-help = 1 Linie | 2 Streuung | 3 Log-Streuung | 4 Stäbe | Esc Zurück
+graph-help-line = Linie
+graph-help-scatter = Streuung
+graph-help-log = Log-Streuung
+graph-help-pin = Stäbe
+graph-help-save = Als SVG/PNG speichern
+graph-help-next-type = Nächster Typ
+graph-help-choose-type = Typ wählen
+graph-help-modulus = k ändern
+graph-help-zoom = Zoomen
+graph-help-pan = Verschieben
+graph-help-reset = Ansicht zurücksetzen
+graph-help-listen = Anhören
+graph-points-terms = { $count } Glieder
+graph-points-bfile = { $count } Glieder aus der b-file
+graph-help-back = Zurück zur Detailansicht
+
+# Table View
+table-title = Tabellenansicht
+table-shape-triangle = Dreieck, zeilenweise gelesen
+table-shape-array-down = Quadratisches Feld, nach Antidiagonalen gelesen (abwärts)
+table-shape-array-up = Quadratisches Feld, nach Antidiagonalen gelesen (aufwärts)
+table-source-data = { $rows } Zeilen · { $terms } Glieder
+table-source-bfile = { $rows } Zeilen · { $terms } Glieder aus der b-file
+table-row-sum = Summe der Zeile { $index }
+table-antidiagonal-sum = Summe der Antidiagonale n + k = { $index }
+table-sum-incomplete = unvollständig
+table-shape-chosen = Von Hand gewählte Anordnung
+table-unknown-shape = Der Name dieses unregelmäßigen Dreiecks nennt keine Zeilenlängen. Mit s eine Anordnung wählen.
+table-not-available = Diese Folge ist kein Dreieck und kein Feld (kein Stichwort tabl/tabf). Mit s trotzdem anordnen.
+table-help-move = Bewegen
+table-help-shape = Anordnung
+table-help-save = Als CSV speichern
+table-preview = Als Tabelle ({ $shape }):
+table-preview-hint = a drücken für die Tabellenansicht mit Zeilensummen und CSV-Export
+
+# Listen Dialog
+listen-title = Anhören
+listen-instrument = Instrument
+listen-tempo = Tempo
+listen-tempo-value = { $tempo } bpm
+listen-range = Tonumfang
+listen-range-value = { $count } Töne
+listen-lowest = Tiefster Ton
+listen-instrument-piano = Klavier
+listen-instrument-marimba = Marimba
+listen-instrument-organ = Orgel
+listen-instrument-guitar = Gitarre
+listen-instrument-strings = Streicher
+listen-instrument-flute = Flöte
+listen-instrument-synth = Synth-Lead
+listen-summary = { $count } Noten · { $duration } · { $pitches }
+listen-summary-bfile = { $count } Noten aus der b-file · { $duration } · { $pitches }
+listen-formats = MIDI (.mid) oder WAV (.wav), je nach Dateiname
+listen-help-save = Speichern…
+listen-unsupported-format = Nicht unterstütztes Audioformat; einen Dateinamen auf .mid oder .wav verwenden
+listen-no-terms = Keine ganzzahligen Glieder zum Abspielen
+constant-value = Wert auf { $places } Nachkommastellen:
+constant-convergents = Näherungsbrüche:
+constant-hint = +/- Nachkommastellen (bis { $max }) | y Wert kopieren
+constant-copy-success = Konstante in die Zwischenablage kopiert ({ $places } Nachkommastellen)
+
+# Export Screen
+export-title = Folge exportieren
+export-format = Format wählen
+export-json = JSON
+export-json-desc = Vollständige Folgendaten mit allen Metadaten
+export-csv = CSV
+export-csv-desc = Werte der Folge, durch Kommas getrennt
+export-txt = TXT
+export-txt-desc = Gut lesbarer Klartext
+export-markdown = Markdown
+export-markdown-desc = Formatierte Dokumentation
+export-preview = Vorschau
+export-no-sequence = Keine Folge zum Exportieren
+export-success = Erfolgreich in die Zwischenablage exportiert
+export-file-success = In Datei gespeichert: { $path }
+bulk-export-title = Sammelexport
+bulk-export-source = Quelle
+bulk-export-format = Format
+bulk-export-layout = Aufteilung
+bulk-export-directory = Verzeichnis
+bulk-export-source-results = Suchergebnisse (diese Seite)
+bulk-export-source-bookmarks = Lesezeichen
+bulk-export-source-history = Zuletzt angesehen
+bulk-export-layout-combined = Eine gemeinsame Datei
+bulk-export-layout-files = Eine Datei pro Folge
+bulk-export-help-field = Feld
+bulk-export-help-change = Ändern
+bulk-export-help-export = Exportieren
+bulk-export-help-cancel = Abbrechen
+bulk-export-combined-unsupported = { $format } lässt sich nicht in einer Datei zusammenfassen; eine Datei pro Folge wählen
+bulk-export-fetching = { $count } nicht zwischengespeicherte Folge(n) werden abgerufen...
+bulk-export-empty = Nichts zu exportieren
+bulk-export-success = { $count } Folge(n) nach { $path } exportiert
+bulk-export-skipped = ({ $count } konnten nicht abgerufen werden)
+save-as-title = Speichern unter
+save-as-path = Pfad
+save-as-more = +{ $count } weitere
+save-as-help-complete = Vervollständigen
+save-as-help-save = Speichern
+save-as-help-cancel = Abbrechen
+save-as-overwrite-title = Datei vorhanden
+save-as-overwrite-question = { $path } ist bereits vorhanden. Überschreiben?
+save-as-overwrite-yes = Überschreiben
+save-as-overwrite-no = Zurück
+plot-unsupported-format = Diagramme können als .svg oder .png gespeichert werden
+plot-no-positive-values = Keine positiven Werte für eine logarithmische Skala
+plot-no-spiral-terms = Keine Glieder zwischen 1 und { $limit } für die Spirale
+plot-no-data = Keine numerischen Daten zum Zeichnen
+code-help-select = Programm wählen
+code-help-copy = Kopieren
+code-help-save = Speichern
+code-help-verify = Prüfen
+code-copy-success = { $language }-Programm in die Zwischenablage kopiert
+code-no-snippets = Für diese Folge sind keine Programme verfügbar
+verify-confirm = Erneut v drücken, um dieses { $language }-Programm lokal auszuführen (Limit { $seconds } s). Nur vertrauenswürdigen Code ausführen.
+verify-unsupported = Nur PARI/GP- und Python-Programme können geprüft werden
+verify-missing-interpreter = Interpreter { $interpreter } nicht im PATH gefunden
+verify-running = Programm wird lokal ausgeführt...
+verify-match = ✓ { $interpreter }: { $count } Glieder stimmen überein ({ $seconds } s)
+verify-mismatch = ✗ { $interpreter }: a({ $index }) = { $actual }, erwartet { $expected } ({ $count } Glieder stimmten)
+verify-match-timed-out = ! { $interpreter }: { $count } Glieder stimmen überein, aber das Programm wurde nicht fertig
+verify-partial = ! { $interpreter }: nur { $count } von { $expected } Gliedern ausgegeben, alle stimmen ({ $seconds } s)
+verify-no-output = ! { $interpreter }: keine Glieder ausgegeben
+verify-timed-out = (Zeitlimit überschritten)
+verify-failed = ! Prüfung fehlgeschlagen: { $message }
+export-error = Export fehlgeschlagen: { $message }
+export-help-select = Auswählen
+export-help-quick = Schnellauswahl
+export-help-clipboard = Zwischenablage
+export-help-save = Speichern
+export-bfile = b-file
+export-bfile-desc = Erweiterte Folgendaten (Paare aus Index und Wert)
+export-latex = LaTeX
+export-latex-desc = Glieder und gesetzte Formeln für Artikel
+export-bibtex = BibTeX
+export-bibtex-desc = Zitiereintrag für Literaturverwaltungen
+export-notebook = Jupyter
+export-notebook-desc = Notebook mit Gliedern, Python-Programmen und einem Diagramm
+export-python = Python
+export-python-desc = Skript mit denselben Zellen wie das Notebook
+export-bfile-not-loaded = b-file nicht geladen - in der Detailansicht 'f' drücken
+export-select-format = Format wählen
+export-cancel = Abbrechen
+
+# Export Content Labels
+export-label-offset = Offset
+export-label-keywords = Stichwörter
+export-label-data = Daten
+export-label-author = Autor
+export-label-created = Erstellt
+export-label-modified = Zuletzt geändert
+export-label-references = Referenzen
+export-label-revision = Revision
+
+# Export Section Headers
+export-section-sequence-data = Folgendaten
+export-section-metadata = Metadaten
+export-section-comments = Kommentare
+export-section-formulas = Formeln
+export-section-examples = Beispiele
+export-section-code = Code
+export-section-references = Referenzen
+export-section-links = Links
+export-section-crossrefs = Querverweise
+
+# Export Subsection Headers
+export-subsection-maple = Maple
+export-subsection-mathematica = Mathematica
+export-subsection-programs = Weitere Programme
+
+# Export Format Specific
+export-csv-header = A-Nummer,Name,Werte
+export-markdown-source = Quelle
+export-markdown-oeis-credit = Daten aus der On-Line Encyclopedia of Integer Sequences (OEIS)
+
+# Webcam Mode
+webcam-title = OEIS-Webcam - Folgen-Browser
+webcam-category = Kategorie
+webcam-category-all = Alle Folgen
+webcam-category-all-desc = Alle OEIS-Folgen durchstöbern
+webcam-category-best = Beste Folgen
+webcam-category-best-desc = Interessante und bemerkenswerte Folgen (keyword:nice)
+webcam-category-needing = Glieder gesucht
+webcam-category-needing-desc = Folgen, für die weitere Glieder gesucht werden (keyword:more)
+webcam-category-recent = Neu hinzugefügt
+webcam-category-recent-desc = Kürzlich hinzugefügte oder geänderte Folgen (recent.txt)
+webcam-category-unedited = Unbearbeitet
+webcam-category-unedited-desc = Noch nicht redigierte Einsendungen (keyword:uned)
+webcam-category-core = Kernfolgen
+webcam-category-core-desc = Die grundlegendsten Folgen (keyword:core)
+webcam-category-easy = Einfache Folgen
+webcam-category-easy-desc = Glieder, die leicht zu berechnen sind (keyword:easy)
+webcam-category-hard = Schwierige Folgen
+webcam-category-hard-desc = Glieder, die schwer zu berechnen sind (keyword:hard)
+webcam-category-unknown = Unbekannte Glieder
+webcam-category-unknown-desc = Über sie ist wenig bekannt (keyword:unkn)
+webcam-category-bookmarks = Meine Lesezeichen
+webcam-category-bookmarks-desc = Zufällige Auswahl aus den Lesezeichen
+webcam-category-empty = Keine Folgen in dieser Kategorie gefunden
+webcam-no-bookmarks = Noch keine Lesezeichen. In der Detailansicht 'b' drücken, um Folgen zu merken.
+webcam-load-error = Folge konnte nicht geladen werden: { $message }
+webcam-interval = Aktualisierungsintervall
+webcam-interval-manual = Manuell
+webcam-interval-manual-desc = Mit Leertaste weiter
+webcam-interval-5s = 5 Sekunden
+webcam-interval-5s-desc = Alle 5 s aktualisieren
+webcam-interval-10s = 10 Sekunden
+webcam-interval-10s-desc = Alle 10 s aktualisieren
+webcam-interval-20s = 20 Sekunden
+webcam-interval-20s-desc = Alle 20 s aktualisieren
+webcam-interval-30s = 30 Sekunden
+webcam-interval-30s-desc = Alle 30 s aktualisieren
+webcam-interval-1m = 1 Minute
+webcam-interval-1m-desc = Alle 60 s aktualisieren
+webcam-current-sequence = Aktuelle Folge
+webcam-no-sequence = Keine Folge geladen
+webcam-load-first = Leertaste oder Enter drücken, um die erste Folge zu laden
+webcam-refresh-in = Nächste Aktualisierung in { $seconds } Sekunden...
+webcam-retry-in = neuer Versuch in { $seconds } s
+webcam-loading = Erste Folge wird abgerufen…
+webcam-more-comments = ... und { $count } weitere Kommentare
+webcam-sequence-offset = Offset
+webcam-sequence-keywords = Stichwörter
+webcam-sequence-data-title = Folgendaten
+webcam-sequence-comments-title = Kommentare
+webcam-help = Leertaste/Enter Weiter | ←→ Kategorie | ↑↓ Intervall | 0-5 Schnell | d Details | Esc Zurück
+webcam-help-next = Weiter
+webcam-help-category = Kategorie
+webcam-help-interval = Intervall
+webcam-help-quick = Schnellintervall
+webcam-help-detail = Detailansicht
+webcam-help-fullscreen = Vollbild
+webcam-help-back = Zurück
+
+# Settings Screen
+settings-title = Einstellungen
+settings-block-settings = Einstellungen
+settings-language = Sprache
+settings-language-desc = Sprache der Oberfläche wählen
+settings-theme = Farbschema
+settings-theme-desc = Farbschema (in Kürze)
+settings-cache = Cache
+settings-cache-desc = Lokalen Cache verwalten
+settings-cache-clear = Cache leeren
+settings-cache-size = Cache-Größe: { $size }
+settings-block-themes = Farbschemata
+themes-reloaded = Farbschemata neu geladen
+theme-file-error = Farbschema-Datei { $file }: { $error }
+settings-block-animation = Willkommensanimation
+settings-help = ↑↓ Bewegen | Enter Auswählen | Esc Zurück
+settings-help-switch = Bereich wechseln
+settings-help-navigate = Bewegen
+settings-help-apply = Übernehmen
+settings-help-cycle-theme = Farbschema wechseln
+settings-help-back = Zurück
+
+# About Screen
+about-title = Über OEIS TUI
+about-version = Version
+about-author = Erstellt von
+about-license = Lizenz
+about-built-with = Erstellt mit
+about-links = Links
+about-repository = Repository
+about-oeis-link = OEIS-Website
+about-disclaimer = Dies ist ein inoffizieller Client, der nicht mit The OEIS Foundation Inc. verbunden ist und nicht von ihr unterstützt wird.
+
+# Help Screen
+help-title = Hilfe - Tastenkürzel
+help-global = Allgemein
+help-global-quit = Programm beenden
+help-global-help = Hilfe ein-/ausblenden
+help-global-back = Zurück / Abbrechen
+help-global-about = Fenster „Über“ ein-/ausblenden
+help-global-theme = Farbschema wechseln
+help-global-preset = Tastenbelegung wechseln (default, vim, emacs, helix)
+help-global-palette = Befehlspalette: alle Aktionen durchsuchen
+help-search = Suchbildschirm
+help-search-input = Suche starten
+help-search-navigate = Durch die Ergebnisse bewegen
+help-search-page = Vorherige/nächste Seite
+help-search-view = Gewählte Folge öffnen
+help-search-random = Zufällige Folge
+help-search-preview = Vorschaubereich ein-/ausblenden
+help-search-preview-tabs = Vorschau-Tab wechseln
+help-search-preview-scroll = Vorschaubereich scrollen
+help-search-history = Suchverlauf fokussieren
+help-search-focus = Nächster Bereich
+help-search-bookmarks = Lesezeichen anzeigen
+help-search-settings = Einstellungen
+help-search-mouse-select = Klicken, um ein Ergebnis auszuwählen
+help-search-mouse-open = Doppelklicken, um ein Ergebnis zu öffnen
+help-search-mouse-scroll = Mausrad bewegt Vorschau/Ergebnisse
+help-search-webcam = Webcam-Modus
+help-search-export = Ergebnisse, Lesezeichen oder Verlauf exportieren
+help-welcome = Willkommensfenster
+help-welcome-search = Suche starten
+help-welcome-close = Willkommensfenster schließen
+help-welcome-daily = Folge des Tages öffnen
+help-detail = Detailansicht
+help-detail-links = Hervorgehobenen Link wechseln
+help-detail-tabs = Tabs wechseln
+help-detail-open-link = Hervorgehobenen Link öffnen
+help-detail-scroll = Inhalt scrollen
+help-detail-scroll-fast = Schneller scrollen
+help-detail-graph = Diagramm anzeigen
+help-detail-export = Folge exportieren
+help-detail-browser = Im Browser öffnen
+help-detail-bookmark = Lesezeichen setzen/entfernen
+help-detail-table = Dreiecks- oder Feldansicht (tabl/tabf)
+help-detail-bfile = b-file abrufen
+help-detail-notebook = Als Jupyter-Notebook speichern
+help-detail-help = Dieses Fenster ein-/ausblenden
+help-overview = Tab Übersicht
+help-overview-places = Mehr / weniger Nachkommastellen einer Konstante (cons/cofr)
+help-overview-copy = Konstante als Dezimalzahl kopieren
+help-code = Tab Code
+help-code-select = Programm wählen
+help-code-copy = Programm kopieren
+help-code-verify = Programm lokal ausführen und die Glieder prüfen
+help-code-save = Programm als Quelldatei speichern
+help-graph = Diagrammansicht
+help-graph-types = Diagrammtyp wählen (Linie, Streuung, Log, Stäbe, Histogramm, mod k, Ulam-Spirale, Differenzen, Quotient)
+help-graph-cycle = Diagrammtypen durchschalten
+help-graph-modulus = k für das mod-k-Diagramm verringern / erhöhen
+help-graph-save = Diagramm als SVG- oder PNG-Grafik speichern
+help-graph-zoom = Hinein- / herauszoomen (Mausrad zoomt am Zeiger)
+help-graph-pan = Gezoomtes Diagramm verschieben (oder mit der Maus ziehen)
+help-graph-reset = Wieder alle Glieder zeigen
+help-graph-listen = Folge als Musik speichern (MIDI oder WAV), eine Note pro Glied
+help-table = Tabellenansicht
+help-table-move = Zwischen Zellen bewegen
+help-table-rows = Seitenweise / zur ersten oder letzten Zeile springen
+help-table-row-ends = Anfang / Ende der Zeile
+help-table-shape = Zwischen Dreieck und Antidiagonalen-Feld wechseln
+help-table-save = Tabelle als CSV speichern
+help-export = Tab Export
+help-export-select = Format wählen
+help-export-quick = Schnelle Formatwahl
+help-export-clipboard = In die Zwischenablage exportieren
+help-export-file = In Datei speichern
+help-webcam = Webcam-Modus
+help-webcam-next = Nächste Folge laden
+help-webcam-quick = Schnelle Intervallwahl
+help-webcam-detail = Zur Detailansicht springen
+help-webcam-fullscreen = Vollbildansicht umschalten (nur Folge und Diagramm)
+help-webcam-panel = Zwischen Kategorie- und Intervallliste wechseln
+help-webcam-select = Kategorie oder Intervall wählen
+help-settings = Einstellungen
+help-settings-panel = Bereich wechseln
+help-settings-select = In der Liste bewegen
+help-settings-apply = Gewählte Option übernehmen
+help-settings-language = Schnelle Sprachwahl
+help-modal = Hilfefenster
+help-modal-ends = Zum Anfang / Ende springen
+help-keybinding-warnings = Probleme mit der Tastenbelegung (settings.json)
+keybindings-conflict = Taste { $key } ist sowohl { $first } als auch { $second } zugewiesen
+keybindings-unknown-context = Unbekannter Abschnitt der Tastenbelegung „{ $context }“
+keybindings-bad-section = Der Abschnitt „{ $context }“ sollte Aktionen Listen von Tasten zuordnen
+keybindings-unknown-action = Unbekannte Aktion „{ $action }“ im Abschnitt „{ $context }“
+keybindings-bad-key = Taste „{ $key }“ für { $context }.{ $action } ist nicht lesbar
+keybindings-preset-changed = Tastenbelegung: { $preset }
+keys-pending-title = Tastenfolge
+keys-pending-next = weiter: { $keys }
+palette-title = Befehlspalette
+palette-placeholder = Tippen, um Befehle zu suchen, oder eine A-Nummer, um dorthin zu springen
+palette-no-matches = Keine passenden Befehle
+palette-jump = Gehe zu { $number }
+announce-palette = Befehlspalette: { $label }, { $position } von { $count }
+announce-searching = Suche läuft…
+announce-search-empty = Suchfeld, leer
+announce-search-input = Suchfeld: { $query }
+announce-result = Ergebnis { $position } von { $count }: { $anumber }, { $name }
+announce-history = Zuletzt angesehen { $position } von { $count }: { $anumber }, { $name }
+announce-bookmark = Lesezeichen { $position } von { $count }: { $anumber } { $name }
+announce-no-results = Keine Ergebnisse
+announce-sequence = { $anumber }, { $name }, Tab { $tab }
+announce-reference = Verweis { $position } von { $count }: { $anumber }
+announce-graph = { $graph } von { $anumber }
+announce-settings = { $section }: { $item }
+announce-current = (aktuell)
+command-screen = Gehe zu
+command-theme = Farbschema
+command-export-format = Exportformat
+command-graph-type = Diagramm
+command-bookmark = Lesezeichen
+action-quit = Beenden
+action-help = Hilfe ein-/ausblenden
+action-about = Fenster „Über“ ein-/ausblenden
+action-cycle-theme = Nächstes Farbschema
+action-toggle-preset = Nächste Tastenbelegung
+action-back = Zurück
+action-next-panel = Nächster Bereich
+action-previous-panel = Vorheriger Bereich
+action-save = In Datei speichern
+action-copy = In die Zwischenablage kopieren
+action-focus-input = Suchfeld fokussieren
+action-focus-history = Suchverlauf fokussieren
+action-previous-page = Vorherige Ergebnisseite
+action-next-page = Nächste Ergebnisseite
+action-random = Zufällige Folge
+action-toggle-preview = Vorschaubereich ein-/ausblenden
+action-toggle-bookmarks = Lesezeichen ein-/ausblenden
+action-open-webcam = Webcam-Modus
+action-open-settings = Einstellungen
+action-bulk-export = Ergebnisse, Lesezeichen oder Verlauf exportieren
+action-open-daily = Folge des Tages
+action-detail-help = Tastenkürzel der Detailansicht
+action-next-tab = Nächster Tab
+action-previous-tab = Vorheriger Tab
+action-open-graph = Diagramm anzeigen
+action-export-tab = Folge exportieren
+action-open-browser = Im Browser öffnen
+action-toggle-bookmark = Lesezeichen setzen/entfernen
+action-fetch-bfile = b-file abrufen
+action-open-table = Dreiecks- oder Feldansicht
+action-notebook = Als Jupyter-Notebook speichern
+action-more-places = Mehr Nachkommastellen
+action-fewer-places = Weniger Nachkommastellen
+action-next-snippet = Nächstes Programm
+action-previous-snippet = Vorheriges Programm
+action-verify = Code ausführen und mit den Gliedern vergleichen
+action-next-graph-type = Nächster Diagrammtyp
+action-previous-graph-type = Vorheriger Diagrammtyp
+action-modulus-up = k erhöhen (mod-k-Diagramm)
+action-modulus-down = k verringern (mod-k-Diagramm)
+action-zoom-in = Hineinzoomen
+action-zoom-out = Herauszoomen
+action-reset-view = Wieder alle Glieder zeigen
+action-listen = Als Musik speichern (MIDI oder WAV)
+action-cycle-shape = Anordnung der Tabelle ändern
+action-next-sequence = Nächste Folge
+action-open-detail = In der Detailansicht öffnen
+action-toggle-fullscreen = Vollbild umschalten
+
+# Common
+common-loading = Wird geladen...
+common-error = Fehler
+common-success = Erfolg
+common-cancel = Abbrechen
+common-ok = OK
+common-yes = Ja
+common-no = Nein
+common-back = Zurück
+common-next = Weiter
+common-previous = Zurück
+common-page = Seite
+common-of = von
+
+# Errors
+error-network = Netzwerkfehler: Keine Verbindung zur OEIS
+error-api = API-Fehler: { $message }
+error-parse = Verarbeitungsfehler: Ungültiges Datenformat
+error-cache = Cache-Fehler: { $message }
+error-clipboard = Fehler der Zwischenablage: { $message }
+error-file = Dateifehler: { $message }
+error-unknown = Unbekannter Fehler
+
+# Command Line
+cli-empty-query = Die Suchanfrage darf nicht leer sein
+cli-results-for = Ergebnisse für „{ $query }“: { $count } gefunden
+cli-too-many-results = Keine Ergebnisse verfügbar (OEIS meldet zu viele Treffer).
+cli-no-completion = Keine Vervollständigung für { $shell }
+cli-man-written = Manpages nach { $path } geschrieben
+cli-not-found = Folge { $id } nicht gefunden
+cli-random-title = Zufällige Folge
+cli-random-unavailable = Im Moment ist keine zufällige Folge verfügbar.
+cli-random-no-match = Keine zufällige Folge passt zu diesen Filtern.
+cli-daily-no-match = Keine Folge passt zu { $query }.
+cli-no-programs = { $anumber } enthält keine Programme
+cli-no-language-program = Kein { $language }-Programm in { $anumber } (verfügbar: { $available })
+cli-saved = { $path } gespeichert
+cli-more-programs = { $count } weitere(s) { $language }-Programm(e) verfügbar; mit --save werden alle geschrieben
+cli-plot-saved = { $description } ({ $count } Glieder) nach { $path } gespeichert
+cli-listen-saved = { $count } Noten ({ $duration } bei { $tempo } bpm, { $pitches }) nach { $path } gespeichert
+cli-interval-invalid = `{ $interval }` ist kein Intervall wie 30s, 2m oder 1h
+cli-interval-unit = unbekannte Einheit `{ $unit }` (s, m oder h verwenden)
+cli-interval-too-short = das Intervall muss mindestens { $seconds } s betragen
+cli-export-not-combined = { $format } lässt sich nicht in einer Datei zusammenfassen; mit --split wird pro Folge eine Datei geschrieben
+cli-skipped = { $count } Folge(n) übersprungen, die nicht abgerufen werden konnten
+cli-verify-any-language = PARI/GP- oder Python
+cli-nothing-to-verify = { $anumber } enthält keine { $language }-Programme zum Prüfen
+cli-verify-failures = { $failures } von { $count } Programmen konnten nicht bestätigt werden
+cli-verify-timed-out = (Zeitlimit nach { $seconds } s)
+cli-verify-match = { $count } Glieder stimmen überein
+cli-verify-match-timed-out = { $count } Glieder stimmen überein, aber das Programm wurde nicht fertig
+cli-verify-mismatch = a({ $index }) = { $actual }, erwartet { $expected } ({ $count } Glieder stimmten)
+cli-verify-partial = nur { $count } von { $expected } Gliedern ausgegeben, alle stimmen
+cli-verify-no-output = keine Glieder ausgegeben
+shell-welcome = OEIS-Shell. help zeigt die Befehle, quit beendet.
+shell-help-title = Befehle (eine Folge ist eine A-Nummer oder die zuletzt gezeigte bzw. per | übergebene):
+shell-help-search = In der OEIS suchen (auch: alles, was kein Befehl ist)
+shell-help-more = Nächste Ergebnisseite
+shell-help-show = Ergebnis n der letzten Suche oder eine Folge zeigen (auch: nur die A-Nummer)
+shell-help-terms = Die im Eintrag aufgeführten Glieder
+shell-help-bfile = Die ersten Einträge der b-file (20, wenn keine Anzahl angegeben ist)
+shell-help-plot = ASCII-Diagramm: line, scatter, log, pin, histogram, mod, ulam, diff, ratio
+shell-help-bookmark = Lesezeichen setzen
+shell-help-unbookmark = Lesezeichen entfernen
+shell-help-bookmarks = Lesezeichen auflisten
+shell-help-diff = Zwei Folgen Glied für Glied vergleichen
+shell-help-help = Diese Liste
+shell-help-quit = Beenden (auch: exit oder Strg+D)
+shell-help-pipe = `|` reicht die Folgen eines Befehls an den nächsten weiter: search prime | terms
+shell-search-usage = Aufruf: search <Suchbegriffe>
+shell-nothing-to-page = Noch nichts zum Blättern; zuerst suchen
+shell-no-such-result = Auf dieser Seite gibt es kein Ergebnis { $index }
+shell-bad-count = „{ $count }“ ist keine Anzahl von Einträgen
+shell-bfile-entries = { $count } Einträge
+shell-unknown-graph = Unbekannter Diagrammtyp „{ $name }“; siehe help
+shell-bookmarked = Lesezeichen für { $anumber } gesetzt
+shell-unbookmarked = Lesezeichen für { $anumber } entfernt
+shell-no-bookmarks = Noch keine Lesezeichen.
+shell-diff-usage = Aufruf: diff <A-Nummer> <A-Nummer>
+shell-diff-count = diff vergleicht zwei Folgen, nicht { $count }
+shell-no-results = Keine Ergebnisse für { $query }.
+shell-results = Ergebnisse { $first } bis { $last } von { $count } für { $query }:
+shell-more-results = more zeigt die nächsten { $count } Ergebnisse.
+shell-which-sequence = Welche Folge? Eine A-Nummer angeben oder zuerst eine mit show zeigen
+shell-diff-disjoint = Die aufgeführten Glieder haben kein n gemeinsam.
+shell-diff-same = Die { $count } Glieder für n = { $first }..{ $last } sind gleich.
+shell-diff-different = { $agreeing } von { $count } Gliedern für n = { $first }..{ $last } stimmen überein; der erste Unterschied liegt bei n = { $n }: { $a } gegenüber { $b }
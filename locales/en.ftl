@@ -24,6 +24,8 @@ welcome-esc-hint = Esc to dismiss
 welcome-hero-subtitle = Find known integer sequences, discover references, and explore relationships.
 welcome-hero-tips = Try: 1,2,3,4,5,6  •  keyword:prime  •  id:A000045
 welcome-hero-search-hint = Press 'i' or '/' at any time to jump into search.
+welcome-daily = Sequence of the day
+welcome-daily-open = to open
 search-empty-title = No search results yet
 search-tips-title = Search tips:
 search-tip-terms = • Enter sequence terms: 1,1,2,3,5,8,13
@@ -79,6 +81,7 @@ search-help-bookmarks = Bookmarks
 search-help-random = Random
 search-help-webcam = Webcam
 search-help-settings = Settings
+search-help-export = Export
 search-help-help = Help
 search-help-quit = Quit
 search-help-click = Select
@@ -118,18 +121,14 @@ detail-section-data = Data
 detail-section-comments = Comments
 detail-section-examples = Examples
 detail-help = Tab Switch | ↑↓ Scroll | g Graph | e Export | o Browser | b Bookmark | Esc Back
-detail-help-next-link = Next link
 detail-help-prev-link = Previous link
 detail-help-switch-tab = Switch tab
-detail-help-follow-link = Follow link
 detail-help-scroll = Scroll
 detail-help-graph = Graph
 detail-help-export = Export
 detail-help-browser = Open in browser
-detail-help-bookmark = Bookmark
 detail-bookmarked = Bookmarked
 detail-not-bookmarked = Not Bookmarked
-detail-help-bfile = Fetch B-file
 detail-help-more = More
 detail-help-modal-title = Detail View - Keyboard Shortcuts
 detail-bfile-available = Extended data available
@@ -145,6 +144,12 @@ graph-line = Line Chart
 graph-scatter = Scatter Plot
 graph-log = Logarithmic Scatter Plot
 graph-pin = Pin Plot
+graph-histogram = Histogram of Values
+graph-modk = Residues mod { $k }
+graph-ulam = Ulam Spiral
+graph-differences = First Differences
+graph-ratio = Ratio of Successive Terms
+graph-ulam-summary = { $members } members among 1–{ $limit }
 graph-no-data = No numeric data to plot
 graph-no-positive = No positive values to plot on logarithmic scale
 graph-current = Current
@@ -153,8 +158,63 @@ graph-help-line = Line
 graph-help-scatter = Scatter
 graph-help-log = Log Scatter
 graph-help-pin = Pin Plot
+graph-help-save = Save SVG/PNG
+graph-help-next-type = Next type
+graph-help-choose-type = Choose type
+graph-help-modulus = Change k
+graph-help-zoom = Zoom
+graph-help-pan = Pan
+graph-help-reset = Reset view
+graph-help-listen = Listen
+graph-points-terms = { $count } terms
+graph-points-bfile = { $count } b-file terms
 graph-help-back = Back to detail view
 
+# Table View
+table-title = Table View
+table-shape-triangle = Triangle read by rows
+table-shape-array-down = Square array read by antidiagonals (downwards)
+table-shape-array-up = Square array read by antidiagonals (upwards)
+table-source-data = { $rows } rows · { $terms } terms
+table-source-bfile = { $rows } rows · { $terms } b-file terms
+table-row-sum = Sum of row { $index }
+table-antidiagonal-sum = Sum of antidiagonal n + k = { $index }
+table-sum-incomplete = incomplete
+table-shape-chosen = Layout chosen by hand
+table-unknown-shape = The name of this irregular triangle does not give its row lengths. Press s to choose a layout.
+table-not-available = This sequence is not a triangle or array (no tabl/tabf keyword). Press s to lay it out anyway.
+table-help-move = Move
+table-help-shape = Layout
+table-help-save = Save CSV
+table-preview = As a table ({ $shape }):
+table-preview-hint = Press a for the table view with row sums and CSV export
+
+# Listen Dialog
+listen-title = Listen
+listen-instrument = Instrument
+listen-tempo = Tempo
+listen-tempo-value = { $tempo } bpm
+listen-range = Pitch range
+listen-range-value = { $count } notes
+listen-lowest = Lowest note
+listen-instrument-piano = Piano
+listen-instrument-marimba = Marimba
+listen-instrument-organ = Organ
+listen-instrument-guitar = Guitar
+listen-instrument-strings = Strings
+listen-instrument-flute = Flute
+listen-instrument-synth = Synth lead
+listen-summary = { $count } notes · { $duration } · { $pitches }
+listen-summary-bfile = { $count } b-file notes · { $duration } · { $pitches }
+listen-formats = MIDI (.mid) or WAV (.wav), picked by the file name
+listen-help-save = Save…
+listen-unsupported-format = Unsupported audio format; use a .mid or .wav file name
+listen-no-terms = No integer terms to play
+constant-value = Value to { $places } decimal places:
+constant-convergents = Convergents:
+constant-hint = +/- Decimal places (up to { $max }) | y Copy value
+constant-copy-success = Constant copied to clipboard ({ $places } decimal places)
+
 # Export Screen
 export-title = Export Sequence
 export-format = Select Format
@@ -170,10 +230,71 @@ export-preview = Preview
 export-no-sequence = No sequence to export
 export-success = Exported to clipboard successfully
 export-file-success = Saved to file: { $path }
+bulk-export-title = Bulk Export
+bulk-export-source = Source
+bulk-export-format = Format
+bulk-export-layout = Layout
+bulk-export-directory = Directory
+bulk-export-source-results = Search results (this page)
+bulk-export-source-bookmarks = Bookmarks
+bulk-export-source-history = Recently viewed
+bulk-export-layout-combined = One combined file
+bulk-export-layout-files = One file per sequence
+bulk-export-help-field = Field
+bulk-export-help-change = Change
+bulk-export-help-export = Export
+bulk-export-help-cancel = Cancel
+bulk-export-combined-unsupported = { $format } cannot be combined into one file; choose one file per sequence
+bulk-export-fetching = Fetching { $count } uncached sequence(s)...
+bulk-export-empty = Nothing to export
+bulk-export-success = Exported { $count } sequence(s) to { $path }
+bulk-export-skipped = ({ $count } could not be fetched)
+save-as-title = Save As
+save-as-path = Path
+save-as-more = +{ $count } more
+save-as-help-complete = Complete
+save-as-help-save = Save
+save-as-help-cancel = Cancel
+save-as-overwrite-title = File Exists
+save-as-overwrite-question = { $path } already exists. Overwrite it?
+save-as-overwrite-yes = Overwrite
+save-as-overwrite-no = Back
+plot-unsupported-format = Plots can be saved as .svg or .png
+plot-no-positive-values = No positive values to plot on a logarithmic scale
+plot-no-spiral-terms = No terms between 1 and { $limit } to place on the spiral
+plot-no-data = No numeric data to plot
+code-help-select = Select Program
+code-help-copy = Copy
+code-help-save = Save
+code-help-verify = Verify
+code-copy-success = { $language } program copied to clipboard
+code-no-snippets = No programs available for this sequence
+verify-confirm = Press v again to run this { $language } program locally (limit { $seconds }s). Only run code you trust.
+verify-unsupported = Only PARI/GP and Python programs can be verified
+verify-missing-interpreter = { $interpreter } interpreter not found on PATH
+verify-running = Running program locally...
+verify-match = ✓ { $interpreter }: { $count } terms match ({ $seconds }s)
+verify-mismatch = ✗ { $interpreter }: a({ $index }) = { $actual }, expected { $expected } ({ $count } terms matched)
+verify-match-timed-out = ! { $interpreter }: { $count } terms match, but the program did not finish
+verify-partial = ! { $interpreter }: only { $count } of { $expected } terms printed, all matching ({ $seconds }s)
+verify-no-output = ! { $interpreter }: no terms printed
+verify-timed-out = (timed out)
+verify-failed = ! Verification failed: { $message }
 export-error = Export failed: { $message }
-export-help = ↑↓ Select | 1-5 Quick Select | Enter Clipboard | Ctrl+S Save | Esc Cancel
+export-help-select = Select
+export-help-quick = Quick Select
+export-help-clipboard = Clipboard
+export-help-save = Save
 export-bfile = B-file
 export-bfile-desc = Extended sequence data (index value pairs)
+export-latex = LaTeX
+export-latex-desc = Terms and typeset formulas for papers
+export-bibtex = BibTeX
+export-bibtex-desc = Citation entry for reference managers
+export-notebook = Jupyter
+export-notebook-desc = Notebook with terms, Python programs and a plot
+export-python = Python
+export-python-desc = Script with the same cells as the notebook
 export-bfile-not-loaded = B-file not loaded - press 'f' in detail view
 export-select-format = Select Format
 export-cancel = Cancel
@@ -219,7 +340,22 @@ webcam-category-best-desc = Interesting and notable sequences (keyword:nice)
 webcam-category-needing = Needing Terms
 webcam-category-needing-desc = Sequences requesting more terms (keyword:more)
 webcam-category-recent = Recent Additions
-webcam-category-recent-desc = Recently added sequences (keyword:new)
+webcam-category-recent-desc = Recently added or changed sequences (recent.txt)
+webcam-category-unedited = Unedited
+webcam-category-unedited-desc = Submissions not yet edited (keyword:uned)
+webcam-category-core = Core Sequences
+webcam-category-core-desc = The most fundamental sequences (keyword:core)
+webcam-category-easy = Easy Sequences
+webcam-category-easy-desc = Terms that are easy to compute (keyword:easy)
+webcam-category-hard = Hard Sequences
+webcam-category-hard-desc = Terms that are hard to compute (keyword:hard)
+webcam-category-unknown = Unknown Terms
+webcam-category-unknown-desc = Little is known about them (keyword:unkn)
+webcam-category-bookmarks = My Bookmarks
+webcam-category-bookmarks-desc = Random picks from your bookmarks
+webcam-category-empty = No sequences found in this category
+webcam-no-bookmarks = No bookmarks yet. Press 'b' in detail view to bookmark sequences.
+webcam-load-error = Could not load a sequence: { $message }
 webcam-interval = Refresh Interval
 webcam-interval-manual = Manual
 webcam-interval-manual-desc = Press Space to advance
@@ -237,6 +373,8 @@ webcam-current-sequence = Current Sequence
 webcam-no-sequence = No sequence loaded
 webcam-load-first = Press Space or Enter to load first sequence
 webcam-refresh-in = Next refresh in { $seconds } seconds...
+webcam-retry-in = retrying in { $seconds }s
+webcam-loading = Fetching the first sequence…
 webcam-more-comments = ... and { $count } more comments
 webcam-sequence-offset = Offset
 webcam-sequence-keywords = Keywords
@@ -248,6 +386,7 @@ webcam-help-category = Category
 webcam-help-interval = Interval
 webcam-help-quick = Quick Interval
 webcam-help-detail = Detail View
+webcam-help-fullscreen = Full screen
 webcam-help-back = Back
 
 # Settings Screen
@@ -262,6 +401,8 @@ settings-cache-desc = Manage local cache
 settings-cache-clear = Clear Cache
 settings-cache-size = Cache size: { $size }
 settings-block-themes = Themes
+themes-reloaded = Themes reloaded
+theme-file-error = Theme file { $file }: { $error }
 settings-block-animation = Welcome Animation
 settings-help = ↑↓ Navigate | Enter Select | Esc Back
 settings-help-switch = Switch Section
@@ -287,6 +428,10 @@ help-global = Global Controls
 help-global-quit = Quit application
 help-global-help = Show/hide help
 help-global-back = Go back / Cancel
+help-global-about = Show/hide the About window
+help-global-theme = Cycle the color theme
+help-global-preset = Cycle the keybinding preset (default, vim, emacs, helix)
+help-global-palette = Command palette: search every action
 help-search = Search Screen
 help-search-input = Start searching
 help-search-navigate = Navigate results
@@ -295,10 +440,20 @@ help-search-view = View selected sequence
 help-search-random = Random sequence
 help-search-preview = Toggle preview pane
 help-search-preview-tabs = Switch preview tab
+help-search-preview-scroll = Scroll the preview pane
+help-search-history = Focus the search history
+help-search-focus = Next panel
+help-search-bookmarks = Show bookmarks
+help-search-settings = Settings
 help-search-mouse-select = Click to select result
 help-search-mouse-open = Double-click to open result
 help-search-mouse-scroll = Scroll wheel to move preview/results
 help-search-webcam = Webcam mode
+help-search-export = Export results, bookmarks or history
+help-welcome = Welcome Window
+help-welcome-search = Start searching
+help-welcome-close = Close the welcome window
+help-welcome-daily = Open the sequence of the day
 help-detail = Detail View
 help-detail-links = Cycle highlighted link
 help-detail-tabs = Switch tabs
@@ -309,19 +464,131 @@ help-detail-graph = View graph
 help-detail-export = Export sequence
 help-detail-browser = Open in browser
 help-detail-bookmark = Toggle bookmark
+help-detail-table = Triangle or array view (tabl/tabf)
+help-detail-bfile = Fetch the b-file
+help-detail-notebook = Save as a Jupyter notebook
+help-detail-help = Show/hide this window
+help-overview = Overview Tab
+help-overview-places = More / fewer decimal places of a constant (cons/cofr)
+help-overview-copy = Copy the constant as a decimal
+help-code = Code Tab
+help-code-select = Select program
+help-code-copy = Copy program
+help-code-verify = Run the program locally and check its terms
+help-code-save = Save program as a source file
 help-graph = Graph View
-help-graph-types = Switch graph type
-help-export = Export Screen
+help-graph-types = Choose graph type (line, scatter, log, pin, histogram, mod k, Ulam spiral, differences, ratio)
+help-graph-cycle = Cycle through graph types
+help-graph-modulus = Decrease / increase k for the mod k plot
+help-graph-save = Save the graph as an SVG or PNG figure
+help-graph-zoom = Zoom in / out (mouse wheel zooms at the pointer)
+help-graph-pan = Pan the zoomed graph (or drag with the mouse)
+help-graph-reset = Show all terms again
+help-graph-listen = Save the sequence as music (MIDI or WAV), one note per term
+help-table = Table View
+help-table-move = Move between cells
+help-table-rows = Jump by pages / to the first or last row
+help-table-row-ends = Start / end of the row
+help-table-shape = Switch between triangle and antidiagonal array layouts
+help-table-save = Save the table as CSV
+help-export = Export Tab
 help-export-select = Select format
 help-export-quick = Quick format selection
 help-export-clipboard = Export to clipboard
 help-export-file = Save to file
 help-webcam = Webcam Mode
 help-webcam-next = Load next sequence
-help-webcam-category = Switch category
-help-webcam-interval = Change refresh interval
 help-webcam-quick = Quick interval selection
 help-webcam-detail = Jump to detail view
+help-webcam-fullscreen = Toggle the full-screen layout (sequence and chart only)
+help-webcam-panel = Switch between the category and interval lists
+help-webcam-select = Choose a category or interval
+help-settings = Settings Screen
+help-settings-panel = Switch section
+help-settings-select = Move through the list
+help-settings-apply = Apply the selected option
+help-settings-language = Quick language selection
+help-modal = Help Windows
+help-modal-ends = Jump to the top / bottom
+help-keybinding-warnings = Keybinding Problems (settings.json)
+keybindings-conflict = Key { $key } is bound to both { $first } and { $second }
+keybindings-unknown-context = Unknown keybinding section "{ $context }"
+keybindings-bad-section = Keybinding section "{ $context }" should map actions to lists of keys
+keybindings-unknown-action = Unknown action "{ $action }" in keybinding section "{ $context }"
+keybindings-bad-key = Cannot read key "{ $key }" for { $context }.{ $action }
+keybindings-preset-changed = Keybindings: { $preset }
+keys-pending-title = Key sequence
+keys-pending-next = next: { $keys }
+palette-title = Command Palette
+palette-placeholder = Type to search commands, or an A-number to jump to it
+palette-no-matches = No matching commands
+palette-jump = Go to { $number }
+announce-palette = Command palette: { $label }, { $position } of { $count }
+announce-searching = Searching…
+announce-search-empty = Search box, empty
+announce-search-input = Search box: { $query }
+announce-result = Result { $position } of { $count }: { $anumber }, { $name }
+announce-history = Recently viewed { $position } of { $count }: { $anumber }, { $name }
+announce-bookmark = Bookmark { $position } of { $count }: { $anumber } { $name }
+announce-no-results = No results
+announce-sequence = { $anumber }, { $name }, { $tab } tab
+announce-reference = Reference { $position } of { $count }: { $anumber }
+announce-graph = { $graph } of { $anumber }
+announce-settings = { $section }: { $item }
+announce-current = (current)
+command-screen = Go to
+command-theme = Theme
+command-export-format = Export format
+command-graph-type = Graph
+command-bookmark = Bookmark
+action-quit = Quit
+action-help = Show/hide help
+action-about = Show/hide the About window
+action-cycle-theme = Next color theme
+action-toggle-preset = Next keybinding preset
+action-back = Go back
+action-next-panel = Next panel
+action-previous-panel = Previous panel
+action-save = Save to a file
+action-copy = Copy to the clipboard
+action-focus-input = Focus the search box
+action-focus-history = Focus the search history
+action-previous-page = Previous results page
+action-next-page = Next results page
+action-random = Random sequence
+action-toggle-preview = Show/hide the preview pane
+action-toggle-bookmarks = Show/hide bookmarks
+action-open-webcam = Webcam mode
+action-open-settings = Settings
+action-bulk-export = Export results, bookmarks or history
+action-open-daily = Sequence of the day
+action-detail-help = Detail view shortcuts
+action-next-tab = Next tab
+action-previous-tab = Previous tab
+action-open-graph = View graph
+action-export-tab = Export sequence
+action-open-browser = Open in browser
+action-toggle-bookmark = Toggle bookmark
+action-fetch-bfile = Fetch the b-file
+action-open-table = Triangle or array view
+action-notebook = Save as a Jupyter notebook
+action-more-places = More decimal places
+action-fewer-places = Fewer decimal places
+action-next-snippet = Next code snippet
+action-previous-snippet = Previous code snippet
+action-verify = Run the code and compare with the terms
+action-next-graph-type = Next graph type
+action-previous-graph-type = Previous graph type
+action-modulus-up = Increase k (mod k plot)
+action-modulus-down = Decrease k (mod k plot)
+action-zoom-in = Zoom in
+action-zoom-out = Zoom out
+action-reset-view = Show all terms again
+action-listen = Save as music (MIDI or WAV)
+action-cycle-shape = Change the table layout
+action-next-sequence = Next sequence
+action-open-detail = Open in the detail view
+action-toggle-fullscreen = Toggle full screen
 
 # Common
 common-loading = Loading...
@@ -345,3 +612,68 @@ error-cache = Cache error: { $message }
 error-clipboard = Clipboard error: { $message }
 error-file = File error: { $message }
 error-unknown = Unknown error occurred
+
+# Command Line
+cli-empty-query = Query cannot be empty
+cli-results-for = Results for '{ $query }': { $count } found
+cli-too-many-results = No results available (OEIS returned too many matches).
+cli-no-completion = No completion support for { $shell }
+cli-man-written = Man pages written to { $path }
+cli-not-found = Sequence { $id } not found
+cli-random-title = Random sequence
+cli-random-unavailable = No random sequence available right now.
+cli-random-no-match = No random sequence matches these filters.
+cli-daily-no-match = No sequence matches { $query }.
+cli-no-programs = { $anumber } has no programs
+cli-no-language-program = No { $language } program in { $anumber } (available: { $available })
+cli-saved = Saved { $path }
+cli-more-programs = { $count } more { $language } program(s) available; use --save to write them all
+cli-plot-saved = Saved { $description } ({ $count } terms) to { $path }
+cli-listen-saved = Saved { $count } notes ({ $duration } at { $tempo } bpm, { $pitches }) to { $path }
+cli-interval-invalid = `{ $interval }` is not an interval like 30s, 2m or 1h
+cli-interval-unit = unknown unit `{ $unit }` (use s, m or h)
+cli-interval-too-short = the interval must be at least { $seconds }s
+cli-export-not-combined = { $format } cannot be combined into one file; add --split to write one file per sequence
+cli-skipped = Skipped { $count } sequence(s) that could not be fetched
+cli-verify-any-language = PARI/GP or Python
+cli-nothing-to-verify = { $anumber } has no { $language } programs to verify
+cli-verify-failures = { $failures } of { $count } programs could not be verified
+cli-verify-timed-out = (timed out after { $seconds }s)
+cli-verify-match = { $count } terms match
+cli-verify-match-timed-out = { $count } terms match, but the program did not finish
+cli-verify-mismatch = a({ $index }) = { $actual }, expected { $expected } ({ $count } terms matched)
+cli-verify-partial = only { $count } of { $expected } terms printed, all matching
+cli-verify-no-output = no terms printed
+shell-welcome = OEIS shell. Type help for the commands, quit to leave.
+shell-help-title = Commands (a sequence is an A-number, or the one shown last or piped in):
+shell-help-search = Search the OEIS (also: anything that is not a command)
+shell-help-more = Next page of results
+shell-help-show = Show result n of the last search, or a sequence (also: just the A-number)
+shell-help-terms = The terms listed in the entry
+shell-help-bfile = The first entries of the b-file (20 unless a count is given)
+shell-help-plot = ASCII plot: line, scatter, log, pin, histogram, mod, ulam, diff, ratio
+shell-help-bookmark = Bookmark
+shell-help-unbookmark = Remove a bookmark
+shell-help-bookmarks = List the bookmarks
+shell-help-diff = Compare two sequences term by term
+shell-help-help = This list
+shell-help-quit = Leave (also: exit, or Ctrl+D)
+shell-help-pipe = `|` passes the sequences of one command to the next: search prime | terms
+shell-search-usage = Usage: search <terms>
+shell-nothing-to-page = Nothing to page through yet; search first
+shell-no-such-result = There is no result { $index } on this page
+shell-bad-count = "{ $count }" is not a number of entries
+shell-bfile-entries = { $count } entries
+shell-unknown-graph = Unknown graph type "{ $name }"; see help
+shell-bookmarked = Bookmarked { $anumber }
+shell-unbookmarked = Removed the bookmark of { $anumber }
+shell-no-bookmarks = No bookmarks yet.
+shell-diff-usage = Usage: diff <A-number> <A-number>
+shell-diff-count = diff compares two sequences, not { $count }
+shell-no-results = No results for { $query }.
+shell-results = Results { $first } to { $last } of { $count } for { $query }:
+shell-more-results = Type more for the next { $count } results.
+shell-which-sequence = Which sequence? Give an A-number, or show one first
+shell-diff-disjoint = No n in common between the listed terms.
+shell-diff-same = The { $count } terms for n = { $first }..{ $last } are the same.
+shell-diff-different = { $agreeing } of the { $count } terms for n = { $first }..{ $last } agree; the first difference is at n = { $n }: { $a } vs { $b }
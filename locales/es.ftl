@@ -24,6 +24,8 @@ welcome-esc-hint = Esc para cerrar
 welcome-hero-subtitle = Encuentra secuencias conocidas, descubre referencias y explora relaciones.
 welcome-hero-tips = Prueba: 1,2,3,4,5,6  •  keyword:prime  •  id:A000045
 welcome-hero-search-hint = Pulsa 'i' o '/' en cualquier momento para buscar.
+welcome-daily = Secuencia del día
+welcome-daily-open = para abrirla
 search-empty-title = Aún no hay resultados
 search-tips-title = Consejos de búsqueda:
 search-tip-terms = • Ingresa términos de secuencia: 1,1,2,3,5,8,13
@@ -78,6 +80,7 @@ search-help-bookmarks = Marcadores
 search-help-random = Aleatorio
 search-help-webcam = Webcam
 search-help-settings = Ajustes
+search-help-export = Exportar
 search-help-help = Ayuda
 search-help-quit = Salir
 search-help-click = Seleccionar
@@ -111,18 +114,14 @@ detail-crossrefs = Referencias cruzadas
 detail-extensions = Extensiones
 detail-no-data = No hay datos disponibles
 detail-help = Tab Cambiar | ↑↓ Desplazar | g Gráfico | e Exportar | o Navegador | b Marcador | Esc Volver
-detail-help-next-link = Siguiente enlace
 detail-help-prev-link = Enlace anterior
 detail-help-switch-tab = Cambiar pestaña
-detail-help-follow-link = Seguir enlace
 detail-help-scroll = Desplazar
 detail-help-graph = Gráfico
 detail-help-export = Exportar
 detail-help-browser = Abrir en navegador
-detail-help-bookmark = Marcador
 detail-bookmarked = Marcado
 detail-not-bookmarked = No marcado
-detail-help-bfile = Obtener B-file
 detail-help-more = Más
 detail-help-modal-title = Vista Detallada - Atajos de Teclado
 detail-bfile-available = Datos extendidos disponibles
@@ -138,6 +137,12 @@ graph-line = Gráfico de Líneas
 graph-scatter = Diagrama de Dispersión
 graph-log = Diagrama de Dispersión Logarítmico
 graph-pin = Gráfico de Pines
+graph-histogram = Histograma de valores
+graph-modk = Residuos módulo { $k }
+graph-ulam = Espiral de Ulam
+graph-differences = Primeras diferencias
+graph-ratio = Cociente de términos sucesivos
+graph-ulam-summary = { $members } miembros entre 1–{ $limit }
 graph-no-data = No hay datos numéricos para graficar
 graph-no-positive = No hay valores positivos para escala logarítmica
 graph-current = Actual
@@ -158,10 +163,71 @@ export-preview = Vista Previa
 export-no-sequence = No hay secuencia para exportar
 export-success = Exportado al portapapeles exitosamente
 export-file-success = Guardado en archivo: { $path }
+bulk-export-title = Exportación masiva
+bulk-export-source = Origen
+bulk-export-format = Formato
+bulk-export-layout = Disposición
+bulk-export-directory = Directorio
+bulk-export-source-results = Resultados (esta página)
+bulk-export-source-bookmarks = Marcadores
+bulk-export-source-history = Vistos recientemente
+bulk-export-layout-combined = Un único archivo
+bulk-export-layout-files = Un archivo por secuencia
+bulk-export-help-field = Campo
+bulk-export-help-change = Cambiar
+bulk-export-help-export = Exportar
+bulk-export-help-cancel = Cancelar
+bulk-export-combined-unsupported = { $format } no se puede combinar en un archivo; elige un archivo por secuencia
+bulk-export-fetching = Descargando { $count } secuencia(s) no almacenada(s)...
+bulk-export-empty = Nada que exportar
+bulk-export-success = { $count } secuencia(s) exportada(s) a { $path }
+bulk-export-skipped = ({ $count } no se pudieron descargar)
+save-as-title = Guardar como
+save-as-path = Ruta
+save-as-more = +{ $count } más
+save-as-help-complete = Completar
+save-as-help-save = Guardar
+save-as-help-cancel = Cancelar
+save-as-overwrite-title = El archivo existe
+save-as-overwrite-question = { $path } ya existe. ¿Sobrescribirlo?
+save-as-overwrite-yes = Sobrescribir
+save-as-overwrite-no = Volver
+plot-unsupported-format = Los gráficos se pueden guardar como .svg o .png
+plot-no-positive-values = No hay valores positivos para graficar en escala logarítmica
+plot-no-spiral-terms = No hay términos entre 1 y { $limit } para colocar en la espiral
+plot-no-data = No hay datos numéricos para graficar
+code-help-select = Elegir programa
+code-help-copy = Copiar
+code-help-save = Guardar
+code-help-verify = Verificar
+code-copy-success = Programa { $language } copiado al portapapeles
+code-no-snippets = No hay programas disponibles para esta secuencia
+verify-confirm = Pulsa v otra vez para ejecutar este programa { $language } localmente (límite { $seconds } s). Ejecuta solo código de confianza.
+verify-unsupported = Solo se pueden verificar programas PARI/GP y Python
+verify-missing-interpreter = No se encontró el intérprete { $interpreter } en el PATH
+verify-running = Ejecutando el programa localmente...
+verify-match = ✓ { $interpreter }: { $count } términos coinciden ({ $seconds } s)
+verify-mismatch = ✗ { $interpreter }: a({ $index }) = { $actual }, se esperaba { $expected } ({ $count } términos coincidieron)
+verify-match-timed-out = ! { $interpreter }: { $count } términos coinciden, pero el programa no terminó
+verify-partial = ! { $interpreter }: solo se imprimieron { $count } de { $expected } términos, todos coinciden ({ $seconds } s)
+verify-no-output = ! { $interpreter }: no se imprimió ningún término
+verify-timed-out = (tiempo agotado)
+verify-failed = ! La verificación falló: { $message }
 export-error = Fallo al exportar: { $message }
-export-help = ↑↓ Seleccionar | 1-5 Selección rápida | Enter Portapapeles | Ctrl+S Guardar | Esc Cancelar
+export-help-select = Seleccionar
+export-help-quick = Selección rápida
+export-help-clipboard = Portapapeles
+export-help-save = Guardar
 export-bfile = B-file
 export-bfile-desc = Datos extendidos de secuencia (pares índice valor)
+export-latex = LaTeX
+export-latex-desc = Términos y fórmulas tipografiadas para artículos
+export-bibtex = BibTeX
+export-bibtex-desc = Entrada de cita para gestores de referencias
+export-notebook = Jupyter
+export-notebook-desc = Notebook con términos, programas Python y un gráfico
+export-python = Python
+export-python-desc = Script con las mismas celdas que el notebook
 export-bfile-not-loaded = B-file no cargado - presiona 'f' en vista detallada
 export-select-format = Seleccionar Formato
 export-cancel = Cancelar
@@ -207,7 +273,22 @@ webcam-category-best-desc = Secuencias interesantes y notables (palabra clave:ni
 webcam-category-needing = Necesitan Términos
 webcam-category-needing-desc = Secuencias que solicitan más términos (palabra clave:more)
 webcam-category-recent = Adiciones Recientes
-webcam-category-recent-desc = Secuencias añadidas recientemente (palabra clave:new)
+webcam-category-recent-desc = Secuencias añadidas o modificadas recientemente (recent.txt)
+webcam-category-unedited = Sin Editar
+webcam-category-unedited-desc = Envíos aún no editados (palabra clave:uned)
+webcam-category-core = Secuencias Básicas
+webcam-category-core-desc = Las secuencias más fundamentales (palabra clave:core)
+webcam-category-easy = Secuencias Fáciles
+webcam-category-easy-desc = Términos fáciles de calcular (palabra clave:easy)
+webcam-category-hard = Secuencias Difíciles
+webcam-category-hard-desc = Términos difíciles de calcular (palabra clave:hard)
+webcam-category-unknown = Términos Desconocidos
+webcam-category-unknown-desc = Se sabe poco de ellas (palabra clave:unkn)
+webcam-category-bookmarks = Mis Marcadores
+webcam-category-bookmarks-desc = Elecciones al azar entre tus marcadores
+webcam-category-empty = No se encontraron secuencias en esta categoría
+webcam-no-bookmarks = Aún no hay marcadores. Presiona 'b' en la vista de detalle para añadirlos.
+webcam-load-error = No se pudo cargar una secuencia: { $message }
 webcam-interval = Intervalo de Actualización
 webcam-interval-manual = Manual
 webcam-interval-manual-desc = Presiona Espacio para avanzar
@@ -225,11 +306,14 @@ webcam-current-sequence = Secuencia Actual
 webcam-no-sequence = No hay secuencia cargada
 webcam-load-first = Presiona Espacio o Enter para cargar la primera secuencia
 webcam-refresh-in = Próxima actualización en { $seconds } segundos...
+webcam-retry-in = reintento en { $seconds } s
+webcam-loading = Obteniendo la primera secuencia…
 webcam-more-comments = ... y { $count } comentarios más
 webcam-help = Espacio/Enter Siguiente | ←→ Categoría | ↑↓ Intervalo | 0-5 Rápido | d Detalle | Esc Volver
 
 # Settings Screen
 settings-title = Configuración
+settings-block-settings = Configuración
 settings-language = Idioma
 settings-language-desc = Seleccionar idioma de la interfaz
 settings-theme = Tema
@@ -257,6 +341,10 @@ help-global = Controles Globales
 help-global-quit = Salir de la aplicación
 help-global-help = Mostrar/ocultar ayuda
 help-global-back = Volver / Cancelar
+help-global-about = Mostrar/ocultar la ventana Acerca de
+help-global-theme = Cambiar el tema de colores
+help-global-preset = Cambiar el conjunto de atajos (default, vim, emacs, helix)
+help-global-palette = Paleta de comandos: buscar entre todas las acciones
 help-search = Pantalla de Búsqueda
 help-search-input = Comenzar búsqueda
 help-search-navigate = Navegar resultados
@@ -265,10 +353,20 @@ help-search-view = Ver secuencia seleccionada
 help-search-random = Secuencia aleatoria
 help-search-preview = Alternar panel de vista previa
 help-search-preview-tabs = Cambiar pestaña de vista previa
+help-search-preview-scroll = Desplazar la vista previa
+help-search-history = Ir al historial de búsqueda
+help-search-focus = Panel siguiente
+help-search-bookmarks = Mostrar marcadores
+help-search-settings = Configuración
 help-search-mouse-select = Clic para seleccionar resultado
 help-search-mouse-open = Doble clic para abrir resultado
 help-search-mouse-scroll = Desplazar rueda para mover vista previa/resultados
 help-search-webcam = Modo webcam
+help-search-export = Exportar resultados, marcadores o historial
+help-welcome = Ventana de bienvenida
+help-welcome-search = Comenzar búsqueda
+help-welcome-close = Cerrar la ventana de bienvenida
+help-welcome-daily = Abrir la sucesión del día
 help-detail = Vista Detallada
 help-detail-links = Ciclar enlace resaltado
 help-detail-tabs = Cambiar pestañas
@@ -279,19 +377,131 @@ help-detail-graph = Ver gráfico
 help-detail-export = Exportar secuencia
 help-detail-browser = Abrir en navegador
 help-detail-bookmark = Alternar marcador
+help-detail-table = Vista de triángulo o matriz (tabl/tabf)
+help-detail-bfile = Obtener el b-file
+help-detail-notebook = Guardar como notebook de Jupyter
+help-detail-help = Mostrar/ocultar esta ventana
+help-overview = Pestaña Resumen
+help-overview-places = Más / menos decimales de una constante (cons/cofr)
+help-overview-copy = Copiar la constante como decimal
+help-code = Pestaña Código
+help-code-select = Seleccionar programa
+help-code-copy = Copiar programa
+help-code-verify = Ejecutar el programa localmente y comprobar sus términos
+help-code-save = Guardar programa como archivo fuente
 help-graph = Vista de Gráfico
-help-graph-types = Cambiar tipo de gráfico
-help-export = Pantalla de Exportación
+help-graph-types = Elegir tipo de gráfico (línea, dispersión, log, pines, histograma, mod k, espiral de Ulam, diferencias, cociente)
+help-graph-cycle = Recorrer los tipos de gráfico
+help-graph-modulus = Reducir / aumentar k en el gráfico mod k
+help-graph-save = Guardar el gráfico como SVG o PNG
+help-graph-zoom = Acercar / alejar (la rueda del ratón hace zoom bajo el puntero)
+help-graph-pan = Desplazar el gráfico ampliado (o arrastrar con el ratón)
+help-graph-reset = Mostrar de nuevo todos los términos
+help-graph-listen = Guardar la secuencia como música (MIDI o WAV), una nota por término
+help-table = Vista de tabla
+help-table-move = Moverse entre celdas
+help-table-rows = Saltar por páginas / a la primera o última fila
+help-table-row-ends = Inicio / final de la fila
+help-table-shape = Alternar entre triángulo y matriz por antidiagonales
+help-table-save = Guardar la tabla como CSV
+help-export = Pestaña Exportar
 help-export-select = Seleccionar formato
 help-export-quick = Selección rápida de formato
 help-export-clipboard = Exportar al portapapeles
 help-export-file = Guardar en archivo
 help-webcam = Modo Webcam
 help-webcam-next = Cargar siguiente secuencia
-help-webcam-category = Cambiar categoría
-help-webcam-interval = Cambiar intervalo de actualización
 help-webcam-quick = Selección rápida de intervalo
 help-webcam-detail = Ir a vista detallada
+help-webcam-fullscreen = Alternar la vista a pantalla completa (solo secuencia y gráfico)
+help-webcam-panel = Alternar entre las listas de categorías e intervalos
+help-webcam-select = Elegir una categoría o un intervalo
+help-settings = Pantalla de configuración
+help-settings-panel = Cambiar de sección
+help-settings-select = Moverse por la lista
+help-settings-apply = Aplicar la opción seleccionada
+help-settings-language = Selección rápida de idioma
+help-modal = Ventanas de ayuda
+help-modal-ends = Ir al principio / al final
+help-keybinding-warnings = Problemas de atajos (settings.json)
+keybindings-conflict = La tecla { $key } está asignada a { $first } y a { $second }
+keybindings-unknown-context = Sección de atajos desconocida «{ $context }»
+keybindings-bad-section = La sección «{ $context }» debe asociar acciones a listas de teclas
+keybindings-unknown-action = Acción desconocida «{ $action }» en la sección «{ $context }»
+keybindings-bad-key = No se puede leer la tecla «{ $key }» de { $context }.{ $action }
+keybindings-preset-changed = Atajos: { $preset }
+keys-pending-title = Secuencia de teclas
+keys-pending-next = siguiente: { $keys }
+palette-title = Paleta de comandos
+palette-placeholder = Escriba para buscar comandos, o un número A para ir a él
+palette-no-matches = Ningún comando coincide
+palette-jump = Ir a { $number }
+announce-palette = Paleta de comandos: { $label }, { $position } de { $count }
+announce-searching = Buscando…
+announce-search-empty = Cuadro de búsqueda, vacío
+announce-search-input = Cuadro de búsqueda: { $query }
+announce-result = Resultado { $position } de { $count }: { $anumber }, { $name }
+announce-history = Visto recientemente { $position } de { $count }: { $anumber }, { $name }
+announce-bookmark = Marcador { $position } de { $count }: { $anumber } { $name }
+announce-no-results = Sin resultados
+announce-sequence = { $anumber }, { $name }, pestaña { $tab }
+announce-reference = Referencia { $position } de { $count }: { $anumber }
+announce-graph = { $graph } de { $anumber }
+announce-settings = { $section }: { $item }
+announce-current = (actual)
+command-screen = Ir a
+command-theme = Tema
+command-export-format = Formato de exportación
+command-graph-type = Gráfico
+command-bookmark = Marcador
+action-quit = Salir
+action-help = Mostrar/ocultar la ayuda
+action-about = Mostrar/ocultar la ventana Acerca de
+action-cycle-theme = Siguiente tema de color
+action-toggle-preset = Siguiente conjunto de atajos
+action-back = Volver
+action-next-panel = Panel siguiente
+action-previous-panel = Panel anterior
+action-save = Guardar en un archivo
+action-copy = Copiar al portapapeles
+action-focus-input = Ir al cuadro de búsqueda
+action-focus-history = Ir al historial
+action-previous-page = Página de resultados anterior
+action-next-page = Página de resultados siguiente
+action-random = Secuencia aleatoria
+action-toggle-preview = Mostrar/ocultar la vista previa
+action-toggle-bookmarks = Mostrar/ocultar marcadores
+action-open-webcam = Modo webcam
+action-open-settings = Configuración
+action-bulk-export = Exportar resultados, marcadores o historial
+action-open-daily = Secuencia del día
+action-detail-help = Atajos de la vista de detalle
+action-next-tab = Pestaña siguiente
+action-previous-tab = Pestaña anterior
+action-open-graph = Ver gráfico
+action-export-tab = Exportar secuencia
+action-open-browser = Abrir en el navegador
+action-toggle-bookmark = Alternar marcador
+action-fetch-bfile = Descargar el b-file
+action-open-table = Vista de triángulo o tabla
+action-notebook = Guardar como cuaderno Jupyter
+action-more-places = Más decimales
+action-fewer-places = Menos decimales
+action-next-snippet = Siguiente fragmento de código
+action-previous-snippet = Fragmento de código anterior
+action-verify = Ejecutar el código y comparar con los términos
+action-next-graph-type = Siguiente tipo de gráfico
+action-previous-graph-type = Tipo de gráfico anterior
+action-modulus-up = Aumentar k (gráfico mod k)
+action-modulus-down = Disminuir k (gráfico mod k)
+action-zoom-in = Acercar
+action-zoom-out = Alejar
+action-reset-view = Mostrar de nuevo todos los términos
+action-listen = Guardar como música (MIDI o WAV)
+action-cycle-shape = Cambiar la disposición de la tabla
+action-next-sequence = Siguiente secuencia
+action-open-detail = Abrir en la vista de detalle
+action-toggle-fullscreen = Alternar pantalla completa
 
 # Common
 common-loading = Cargando...
@@ -325,7 +535,62 @@ graph-help-line = Línea
 graph-help-scatter = Dispersión
 graph-help-log = Log
 graph-help-pin = Pines
+graph-help-save = Guardar SVG/PNG
+graph-help-next-type = Tipo siguiente
+graph-help-choose-type = Elegir tipo
+graph-help-modulus = Cambiar k
+graph-help-zoom = Zoom
+graph-help-pan = Desplazar
+graph-help-reset = Restablecer vista
+graph-help-listen = Escuchar
+graph-points-terms = { $count } términos
+graph-points-bfile = { $count } términos del b-file
 graph-help-back = Volver a vista detallada
+
+# Table View
+table-title = Vista de tabla
+table-shape-triangle = Triángulo leído por filas
+table-shape-array-down = Matriz cuadrada leída por antidiagonales (descendentes)
+table-shape-array-up = Matriz cuadrada leída por antidiagonales (ascendentes)
+table-source-data = { $rows } filas · { $terms } términos
+table-source-bfile = { $rows } filas · { $terms } términos del b-file
+table-row-sum = Suma de la fila { $index }
+table-antidiagonal-sum = Suma de la antidiagonal n + k = { $index }
+table-sum-incomplete = incompleta
+table-shape-chosen = Disposición elegida a mano
+table-unknown-shape = El nombre de este triángulo irregular no indica la longitud de sus filas. Pulse s para elegir una disposición.
+table-not-available = Esta secuencia no es un triángulo ni una matriz (sin palabra clave tabl/tabf). Pulse s para disponerla de todos modos.
+table-help-move = Mover
+table-help-shape = Disposición
+table-help-save = Guardar CSV
+table-preview = Como tabla ({ $shape }):
+table-preview-hint = Pulse a para la vista de tabla con sumas de filas y exportación CSV
+
+# Listen Dialog
+listen-title = Escuchar
+listen-instrument = Instrumento
+listen-tempo = Tempo
+listen-tempo-value = { $tempo } bpm
+listen-range = Registro
+listen-range-value = { $count } notas
+listen-lowest = Nota más grave
+listen-instrument-piano = Piano
+listen-instrument-marimba = Marimba
+listen-instrument-organ = Órgano
+listen-instrument-guitar = Guitarra
+listen-instrument-strings = Cuerdas
+listen-instrument-flute = Flauta
+listen-instrument-synth = Sintetizador
+listen-summary = { $count } notas · { $duration } · { $pitches }
+listen-summary-bfile = { $count } notas del b-file · { $duration } · { $pitches }
+listen-formats = MIDI (.mid) o WAV (.wav) según el nombre del archivo
+listen-help-save = Guardar…
+listen-unsupported-format = Formato de audio no compatible; use un nombre de archivo .mid o .wav
+listen-no-terms = No hay términos enteros para reproducir
+constant-value = Valor con { $places } decimales:
+constant-convergents = Convergentes:
+constant-hint = +/- Decimales (hasta { $max }) | y Copiar valor
+constant-copy-success = Constante copiada al portapapeles ({ $places } decimales)
 webcam-sequence-offset = Desplazamiento
 webcam-sequence-keywords = Palabras clave
 webcam-sequence-data-title = Datos de Secuencia
@@ -335,11 +600,79 @@ webcam-help-category = Categoría
 webcam-help-interval = Intervalo
 webcam-help-quick = Rápido
 webcam-help-detail = Detalles
+webcam-help-fullscreen = Pantalla completa
 webcam-help-back = Volver
 settings-block-themes = Temas
+themes-reloaded = Temas recargados
+theme-file-error = Archivo de tema { $file }: { $error }
 settings-block-animation = Animación de Bienvenida
 settings-help-switch = Cambiar Sección
 settings-help-navigate = Navegar
 settings-help-apply = Aplicar
 settings-help-cycle-theme = Cambiar Tema
 settings-help-back = Volver
+
+# Command Line
+cli-empty-query = La consulta no puede estar vacía
+cli-results-for = Resultados para '{ $query }': { $count } encontrados
+cli-too-many-results = No hay resultados disponibles (OEIS devolvió demasiadas coincidencias).
+cli-no-completion = No hay autocompletado para { $shell }
+cli-man-written = Páginas de manual escritas en { $path }
+cli-not-found = Secuencia { $id } no encontrada
+cli-random-title = Secuencia aleatoria
+cli-random-unavailable = No hay ninguna secuencia aleatoria disponible ahora mismo.
+cli-random-no-match = Ninguna secuencia aleatoria coincide con estos filtros.
+cli-daily-no-match = Ninguna secuencia coincide con { $query }.
+cli-no-programs = { $anumber } no tiene programas
+cli-no-language-program = No hay ningún programa en { $language } en { $anumber } (disponibles: { $available })
+cli-saved = Guardado: { $path }
+cli-more-programs = Hay { $count } programa(s) más en { $language }; usa --save para guardarlos todos
+cli-plot-saved = { $description } ({ $count } términos) guardado en { $path }
+cli-listen-saved = { $count } notas ({ $duration } a { $tempo } bpm, { $pitches }) guardadas en { $path }
+cli-interval-invalid = `{ $interval }` no es un intervalo como 30s, 2m o 1h
+cli-interval-unit = unidad desconocida `{ $unit }` (use s, m o h)
+cli-interval-too-short = el intervalo debe ser de al menos { $seconds } s
+cli-export-not-combined = { $format } no se puede combinar en un solo archivo; añade --split para escribir un archivo por secuencia
+cli-skipped = Se omitieron { $count } secuencia(s) que no se pudieron obtener
+cli-verify-any-language = PARI/GP o Python
+cli-nothing-to-verify = { $anumber } no tiene programas en { $language } que verificar
+cli-verify-failures = No se pudieron verificar { $failures } de { $count } programas
+cli-verify-timed-out = (tiempo agotado tras { $seconds } s)
+cli-verify-match = { $count } términos coinciden
+cli-verify-match-timed-out = { $count } términos coinciden, pero el programa no terminó
+cli-verify-mismatch = a({ $index }) = { $actual }, se esperaba { $expected } ({ $count } términos coincidieron)
+cli-verify-partial = solo se imprimieron { $count } de { $expected } términos, todos coinciden
+cli-verify-no-output = no se imprimió ningún término
+shell-welcome = Shell de OEIS. Escribe help para ver los comandos y quit para salir.
+shell-help-title = Comandos (una secuencia es un número A, o la última mostrada o recibida por |):
+shell-help-search = Buscar en OEIS (también: cualquier texto que no sea un comando)
+shell-help-more = Siguiente página de resultados
+shell-help-show = Mostrar el resultado n de la última búsqueda, o una secuencia (también: solo el número A)
+shell-help-terms = Los términos que figuran en la entrada
+shell-help-bfile = Las primeras entradas del b-file (20 si no se indica otra cantidad)
+shell-help-plot = Gráfico ASCII: line, scatter, log, pin, histogram, mod, ulam, diff, ratio
+shell-help-bookmark = Añadir a marcadores
+shell-help-unbookmark = Quitar un marcador
+shell-help-bookmarks = Listar los marcadores
+shell-help-diff = Comparar dos secuencias término a término
+shell-help-help = Esta lista
+shell-help-quit = Salir (también: exit o Ctrl+D)
+shell-help-pipe = `|` pasa las secuencias de un comando al siguiente: search prime | terms
+shell-search-usage = Uso: search <términos>
+shell-nothing-to-page = Todavía no hay nada que paginar; busca primero
+shell-no-such-result = No hay resultado { $index } en esta página
+shell-bad-count = "{ $count }" no es un número de entradas
+shell-bfile-entries = { $count } entradas
+shell-unknown-graph = Tipo de gráfico desconocido "{ $name }"; consulta help
+shell-bookmarked = { $anumber } añadido a marcadores
+shell-unbookmarked = Se quitó el marcador de { $anumber }
+shell-no-bookmarks = Aún no hay marcadores.
+shell-diff-usage = Uso: diff <número A> <número A>
+shell-diff-count = diff compara dos secuencias, no { $count }
+shell-no-results = No hay resultados para { $query }.
+shell-results = Resultados { $first } a { $last } de { $count } para { $query }:
+shell-more-results = Escribe more para ver los { $count } resultados siguientes.
+shell-which-sequence = ¿Qué secuencia? Indica un número A o muestra una primero
+shell-diff-disjoint = No hay ningún n en común entre los términos listados.
+shell-diff-same = Los { $count } términos para n = { $first }..{ $last } son iguales.
+shell-diff-different = Coinciden { $agreeing } de los { $count } términos para n = { $first }..{ $last }; la primera diferencia está en n = { $n }: { $a } frente a { $b }
@@ -24,6 +24,8 @@ welcome-esc-hint = Échap pour fermer
 welcome-hero-subtitle = Trouvez des suites connues, découvrez des références et explorez des relations.
 welcome-hero-tips = Essayez : 1,2,3,4,5,6  •  keyword:prime  •  id:A000045
 welcome-hero-search-hint = Appuyez sur 'i' ou '/' à tout moment pour rechercher.
+welcome-daily = Suite du jour
+welcome-daily-open = pour l'ouvrir
 search-empty-title = Aucun résultat pour l’instant
 search-tips-title = Conseils de recherche :
 search-tip-terms = • Entrez les termes de la suite : 1,1,2,3,5,8,13
@@ -78,6 +80,7 @@ search-help-bookmarks = Favoris
 search-help-random = Aléatoire
 search-help-webcam = Webcam
 search-help-settings = Réglages
+search-help-export = Exporter
 search-help-help = Aide
 search-help-quit = Quitter
 search-help-click = Sélectionner
@@ -117,18 +120,14 @@ detail-section-data = Données
 detail-section-comments = Commentaires
 detail-section-examples = Exemples
 detail-help = Tab Changer | ↑↓ Défiler | g Graphique | e Exporter | o Navigateur | b Favori | Échap Retour
-detail-help-next-link = Lien suivant
 detail-help-prev-link = Lien précédent
 detail-help-switch-tab = Changer d'onglet
-detail-help-follow-link = Suivre le lien
 detail-help-scroll = Défiler
 detail-help-graph = Graphique
 detail-help-export = Exporter
 detail-help-browser = Ouvrir dans le navigateur
-detail-help-bookmark = Favori
 detail-bookmarked = Marqué en favori
 detail-not-bookmarked = Non marqué
-detail-help-bfile = Récupérer B-file
 detail-help-more = Plus
 detail-help-modal-title = Vue Détaillée - Raccourcis Clavier
 detail-bfile-available = Données étendues disponibles
@@ -144,6 +143,12 @@ graph-line = Graphique Linéaire
 graph-scatter = Nuage de Points
 graph-log = Nuage de Points Logarithmique
 graph-pin = Graphique à Épingles
+graph-histogram = Histogramme des valeurs
+graph-modk = Résidus modulo { $k }
+graph-ulam = Spirale d'Ulam
+graph-differences = Différences premières
+graph-ratio = Rapport des termes successifs
+graph-ulam-summary = { $members } membres parmi 1–{ $limit }
 graph-no-data = Aucune donnée numérique à tracer
 graph-no-positive = Aucune valeur positive pour l'échelle logarithmique
 graph-current = Actuel
@@ -152,8 +157,63 @@ graph-help-line = Ligne
 graph-help-scatter = Nuage
 graph-help-log = Log
 graph-help-pin = Épingles
+graph-help-save = Enregistrer SVG/PNG
+graph-help-next-type = Type suivant
+graph-help-choose-type = Choisir le type
+graph-help-modulus = Changer k
+graph-help-zoom = Zoom
+graph-help-pan = Défiler
+graph-help-reset = Réinitialiser la vue
+graph-help-listen = Écouter
+graph-points-terms = { $count } termes
+graph-points-bfile = { $count } termes du b-file
 graph-help-back = Retour à la vue détaillée
 
+# Table View
+table-title = Vue tableau
+table-shape-triangle = Triangle lu par lignes
+table-shape-array-down = Tableau carré lu par antidiagonales (descendantes)
+table-shape-array-up = Tableau carré lu par antidiagonales (montantes)
+table-source-data = { $rows } lignes · { $terms } termes
+table-source-bfile = { $rows } lignes · { $terms } termes du b-file
+table-row-sum = Somme de la ligne { $index }
+table-antidiagonal-sum = Somme de l'antidiagonale n + k = { $index }
+table-sum-incomplete = incomplète
+table-shape-chosen = Disposition choisie manuellement
+table-unknown-shape = Le nom de ce triangle irrégulier ne donne pas la longueur de ses lignes. Appuyez sur s pour choisir une disposition.
+table-not-available = Cette suite n'est ni un triangle ni un tableau (pas de mot-clé tabl/tabf). Appuyez sur s pour la disposer quand même.
+table-help-move = Déplacer
+table-help-shape = Disposition
+table-help-save = Enregistrer en CSV
+table-preview = En tableau ({ $shape }) :
+table-preview-hint = Appuyez sur a pour la vue tableau avec sommes des lignes et export CSV
+
+# Listen Dialog
+listen-title = Écouter
+listen-instrument = Instrument
+listen-tempo = Tempo
+listen-tempo-value = { $tempo } bpm
+listen-range = Tessiture
+listen-range-value = { $count } notes
+listen-lowest = Note la plus grave
+listen-instrument-piano = Piano
+listen-instrument-marimba = Marimba
+listen-instrument-organ = Orgue
+listen-instrument-guitar = Guitare
+listen-instrument-strings = Cordes
+listen-instrument-flute = Flûte
+listen-instrument-synth = Synthé lead
+listen-summary = { $count } notes · { $duration } · { $pitches }
+listen-summary-bfile = { $count } notes du b-file · { $duration } · { $pitches }
+listen-formats = MIDI (.mid) ou WAV (.wav) selon le nom du fichier
+listen-help-save = Enregistrer…
+listen-unsupported-format = Format audio non pris en charge ; utilisez un nom de fichier .mid ou .wav
+listen-no-terms = Aucun terme entier à jouer
+constant-value = Valeur avec { $places } décimales :
+constant-convergents = Réduites :
+constant-hint = +/- Décimales (jusqu'à { $max }) | y Copier la valeur
+constant-copy-success = Constante copiée dans le presse-papiers ({ $places } décimales)
+
 # Export Screen
 export-title = Exporter la Suite
 export-format = Sélectionner le Format
@@ -169,10 +229,71 @@ export-preview = Aperçu
 export-no-sequence = Aucune suite à exporter
 export-success = Exporté dans le presse-papiers
 export-file-success = Enregistré dans: { $path }
+bulk-export-title = Export groupé
+bulk-export-source = Source
+bulk-export-format = Format
+bulk-export-layout = Disposition
+bulk-export-directory = Dossier
+bulk-export-source-results = Résultats (cette page)
+bulk-export-source-bookmarks = Favoris
+bulk-export-source-history = Consultés récemment
+bulk-export-layout-combined = Un seul fichier
+bulk-export-layout-files = Un fichier par séquence
+bulk-export-help-field = Champ
+bulk-export-help-change = Modifier
+bulk-export-help-export = Exporter
+bulk-export-help-cancel = Annuler
+bulk-export-combined-unsupported = { $format } ne peut pas être regroupé en un seul fichier ; choisissez un fichier par séquence
+bulk-export-fetching = Récupération de { $count } séquence(s) hors cache...
+bulk-export-empty = Rien à exporter
+bulk-export-success = { $count } séquence(s) exportée(s) vers { $path }
+bulk-export-skipped = ({ $count } non récupérée(s))
+save-as-title = Enregistrer sous
+save-as-path = Chemin
+save-as-more = +{ $count } de plus
+save-as-help-complete = Compléter
+save-as-help-save = Enregistrer
+save-as-help-cancel = Annuler
+save-as-overwrite-title = Fichier existant
+save-as-overwrite-question = { $path } existe déjà. L'écraser ?
+save-as-overwrite-yes = Écraser
+save-as-overwrite-no = Retour
+plot-unsupported-format = Les graphiques s'enregistrent en .svg ou .png
+plot-no-positive-values = Aucune valeur positive à tracer en échelle logarithmique
+plot-no-spiral-terms = Aucun terme entre 1 et { $limit } à placer sur la spirale
+plot-no-data = Aucune donnée numérique à tracer
+code-help-select = Choisir le programme
+code-help-copy = Copier
+code-help-save = Sauvegarder
+code-help-verify = Vérifier
+code-copy-success = Programme { $language } copié dans le presse-papiers
+code-no-snippets = Aucun programme disponible pour cette suite
+verify-confirm = Appuyez de nouveau sur v pour exécuter ce programme { $language } localement (limite { $seconds } s). N'exécutez que du code de confiance.
+verify-unsupported = Seuls les programmes PARI/GP et Python peuvent être vérifiés
+verify-missing-interpreter = Interpréteur { $interpreter } introuvable dans le PATH
+verify-running = Exécution locale du programme...
+verify-match = ✓ { $interpreter } : { $count } termes concordent ({ $seconds } s)
+verify-mismatch = ✗ { $interpreter } : a({ $index }) = { $actual }, attendu { $expected } ({ $count } termes concordants)
+verify-match-timed-out = ! { $interpreter } : { $count } termes concordent, mais le programme ne s’est pas terminé
+verify-partial = ! { $interpreter } : seulement { $count } termes sur { $expected } affichés, tous concordants ({ $seconds } s)
+verify-no-output = ! { $interpreter } : aucun terme affiché
+verify-timed-out = (délai dépassé)
+verify-failed = ! Échec de la vérification : { $message }
 export-error = Échec de l'export: { $message }
-export-help = ↑↓ Sélectionner | 1-5 Sélection rapide | Entrée Presse-papiers | Ctrl+S Sauvegarder | Échap Annuler
+export-help-select = Sélectionner
+export-help-quick = Sélection rapide
+export-help-clipboard = Presse-papiers
+export-help-save = Sauvegarder
 export-bfile = B-file
 export-bfile-desc = Données de suite étendues (paires indice valeur)
+export-latex = LaTeX
+export-latex-desc = Termes et formules composées pour articles
+export-bibtex = BibTeX
+export-bibtex-desc = Entrée de citation pour gestionnaires de références
+export-notebook = Jupyter
+export-notebook-desc = Notebook avec les termes, les programmes Python et un graphique
+export-python = Python
+export-python-desc = Script avec les mêmes cellules que le notebook
 export-bfile-not-loaded = B-file non chargé - appuyez sur 'f' dans la vue détaillée
 export-select-format = Sélectionner le Format
 export-cancel = Annuler
@@ -218,7 +339,22 @@ webcam-category-best-desc = Suites intéressantes et notables (mot-clé:nice)
 webcam-category-needing = Termes Manquants
 webcam-category-needing-desc = Suites demandant plus de termes (mot-clé:more)
 webcam-category-recent = Ajouts Récents
-webcam-category-recent-desc = Suites récemment ajoutées (mot-clé:new)
+webcam-category-recent-desc = Suites ajoutées ou modifiées récemment (recent.txt)
+webcam-category-unedited = Non Relues
+webcam-category-unedited-desc = Soumissions pas encore relues (mot-clé:uned)
+webcam-category-core = Suites Fondamentales
+webcam-category-core-desc = Les suites les plus fondamentales (mot-clé:core)
+webcam-category-easy = Suites Faciles
+webcam-category-easy-desc = Termes faciles à calculer (mot-clé:easy)
+webcam-category-hard = Suites Difficiles
+webcam-category-hard-desc = Termes difficiles à calculer (mot-clé:hard)
+webcam-category-unknown = Termes Inconnus
+webcam-category-unknown-desc = On en sait peu de chose (mot-clé:unkn)
+webcam-category-bookmarks = Mes Favoris
+webcam-category-bookmarks-desc = Tirages au hasard parmi vos favoris
+webcam-category-empty = Aucune suite trouvée dans cette catégorie
+webcam-no-bookmarks = Aucun favori. Appuyez sur 'b' dans la vue détaillée pour en ajouter.
+webcam-load-error = Impossible de charger une suite : { $message }
 webcam-interval = Intervalle de Rafraîchissement
 webcam-interval-manual = Manuel
 webcam-interval-manual-desc = Appuyez sur Espace pour avancer
@@ -236,6 +372,8 @@ webcam-current-sequence = Suite Actuelle
 webcam-no-sequence = Aucune suite chargée
 webcam-load-first = Appuyez sur Espace ou Entrée pour charger la première suite
 webcam-refresh-in = Prochain rafraîchissement dans { $seconds } secondes...
+webcam-retry-in = nouvel essai dans { $seconds } s
+webcam-loading = Chargement de la première suite…
 webcam-more-comments = ... et { $count } commentaires supplémentaires
 webcam-sequence-offset = Décalage
 webcam-sequence-keywords = Mots-clés
@@ -247,10 +385,12 @@ webcam-help-category = Catégorie
 webcam-help-interval = Intervalle
 webcam-help-quick = Rapide
 webcam-help-detail = Détails
+webcam-help-fullscreen = Plein écran
 webcam-help-back = Retour
 
 # Settings Screen
 settings-title = Paramètres
+settings-block-settings = Paramètres
 settings-language = Langue
 settings-language-desc = Sélectionner la langue de l'interface
 settings-theme = Thème
@@ -260,6 +400,8 @@ settings-cache-desc = Gérer le cache local
 settings-cache-clear = Vider le Cache
 settings-cache-size = Taille du cache: { $size }
 settings-block-themes = Thèmes
+themes-reloaded = Thèmes rechargés
+theme-file-error = Fichier de thème { $file } : { $error }
 settings-block-animation = Animation de Bienvenue
 settings-help = ↑↓ Naviguer | Entrée Sélectionner | Échap Retour
 settings-help-switch = Changer de Section
@@ -285,6 +427,10 @@ help-global = Contrôles Globaux
 help-global-quit = Quitter l'application
 help-global-help = Afficher/masquer l'aide
 help-global-back = Retour / Annuler
+help-global-about = Afficher/masquer la fenêtre À propos
+help-global-theme = Changer de thème de couleurs
+help-global-preset = Changer de jeu de raccourcis (default, vim, emacs, helix)
+help-global-palette = Palette de commandes : chercher parmi toutes les actions
 help-search = Écran de Recherche
 help-search-input = Commencer la recherche
 help-search-navigate = Naviguer dans les résultats
@@ -293,10 +439,20 @@ help-search-view = Voir la suite sélectionnée
 help-search-random = Suite aléatoire
 help-search-preview = Activer/désactiver le panneau d'aperçu
 help-search-preview-tabs = Changer d'onglet d'aperçu
+help-search-preview-scroll = Faire défiler l'aperçu
+help-search-history = Aller à l'historique de recherche
+help-search-focus = Panneau suivant
+help-search-bookmarks = Afficher les favoris
+help-search-settings = Paramètres
 help-search-mouse-select = Clic pour sélectionner le résultat
 help-search-mouse-open = Double clic pour ouvrir le résultat
 help-search-mouse-scroll = Molette pour faire défiler aperçu/résultats
 help-search-webcam = Mode webcam
+help-search-export = Exporter les résultats, favoris ou l'historique
+help-welcome = Fenêtre d'accueil
+help-welcome-search = Commencer la recherche
+help-welcome-close = Fermer la fenêtre d'accueil
+help-welcome-daily = Ouvrir la suite du jour
 help-detail = Vue Détaillée
 help-detail-links = Parcourir le lien en surbrillance
 help-detail-tabs = Changer d'onglet
@@ -307,19 +463,131 @@ help-detail-graph = Voir le graphique
 help-detail-export = Exporter la suite
 help-detail-browser = Ouvrir dans le navigateur
 help-detail-bookmark = Basculer favori
+help-detail-table = Vue triangle ou tableau (tabl/tabf)
+help-detail-bfile = Récupérer le b-file
+help-detail-notebook = Enregistrer comme notebook Jupyter
+help-detail-help = Afficher/masquer cette fenêtre
+help-overview = Onglet Vue d'ensemble
+help-overview-places = Plus / moins de décimales d'une constante (cons/cofr)
+help-overview-copy = Copier la constante en écriture décimale
+help-code = Onglet Code
+help-code-select = Sélectionner un programme
+help-code-copy = Copier le programme
+help-code-verify = Exécuter le programme localement et vérifier ses termes
+help-code-save = Enregistrer le programme comme fichier source
 help-graph = Vue Graphique
-help-graph-types = Changer le type de graphique
-help-export = Écran d'Export
+help-graph-types = Choisir le type de graphique (ligne, nuage, log, épingles, histogramme, mod k, spirale d'Ulam, différences, rapport)
+help-graph-cycle = Parcourir les types de graphique
+help-graph-modulus = Diminuer / augmenter k pour le graphique mod k
+help-graph-save = Enregistrer le graphique en SVG ou PNG
+help-graph-zoom = Zoomer / dézoomer (la molette zoome sous le pointeur)
+help-graph-pan = Déplacer le graphique zoomé (ou glisser avec la souris)
+help-graph-reset = Afficher à nouveau tous les termes
+help-graph-listen = Enregistrer la suite en musique (MIDI ou WAV), une note par terme
+help-table = Vue tableau
+help-table-move = Se déplacer entre les cellules
+help-table-rows = Sauter d'une page / à la première ou dernière ligne
+help-table-row-ends = Début / fin de la ligne
+help-table-shape = Basculer entre triangle et tableau par antidiagonales
+help-table-save = Enregistrer le tableau en CSV
+help-export = Onglet Export
 help-export-select = Sélectionner le format
 help-export-quick = Sélection rapide du format
 help-export-clipboard = Exporter vers le presse-papiers
 help-export-file = Sauvegarder dans un fichier
 help-webcam = Mode Webcam
 help-webcam-next = Charger la suite suivante
-help-webcam-category = Changer de catégorie
-help-webcam-interval = Changer l'intervalle de rafraîchissement
 help-webcam-quick = Sélection rapide de l'intervalle
 help-webcam-detail = Aller à la vue détaillée
+help-webcam-fullscreen = Basculer la mise en page plein écran (suite et graphique seulement)
+help-webcam-panel = Passer de la liste des catégories à celle des intervalles
+help-webcam-select = Choisir une catégorie ou un intervalle
+help-settings = Écran des paramètres
+help-settings-panel = Changer de section
+help-settings-select = Parcourir la liste
+help-settings-apply = Appliquer l'option choisie
+help-settings-language = Choix rapide de la langue
+help-modal = Fenêtres d'aide
+help-modal-ends = Aller au début / à la fin
+help-keybinding-warnings = Problèmes de raccourcis (settings.json)
+keybindings-conflict = La touche { $key } est associée à { $first } et à { $second }
+keybindings-unknown-context = Section de raccourcis inconnue « { $context } »
+keybindings-bad-section = La section « { $context } » doit associer des actions à des listes de touches
+keybindings-unknown-action = Action inconnue « { $action } » dans la section « { $context } »
+keybindings-bad-key = Touche illisible « { $key } » pour { $context }.{ $action }
+keybindings-preset-changed = Raccourcis : { $preset }
+keys-pending-title = Séquence de touches
+keys-pending-next = suite : { $keys }
+palette-title = Palette de commandes
+palette-placeholder = Tapez pour chercher une commande, ou un numéro A pour y aller
+palette-no-matches = Aucune commande correspondante
+palette-jump = Aller à { $number }
+announce-palette = Palette de commandes : { $label }, { $position } sur { $count }
+announce-searching = Recherche…
+announce-search-empty = Champ de recherche, vide
+announce-search-input = Champ de recherche : { $query }
+announce-result = Résultat { $position } sur { $count } : { $anumber }, { $name }
+announce-history = Consulté récemment { $position } sur { $count } : { $anumber }, { $name }
+announce-bookmark = Favori { $position } sur { $count } : { $anumber } { $name }
+announce-no-results = Aucun résultat
+announce-sequence = { $anumber }, { $name }, onglet { $tab }
+announce-reference = Référence { $position } sur { $count } : { $anumber }
+announce-graph = { $graph } de { $anumber }
+announce-settings = { $section } : { $item }
+announce-current = (actuel)
+command-screen = Aller à
+command-theme = Thème
+command-export-format = Format d'export
+command-graph-type = Graphique
+command-bookmark = Favori
+action-quit = Quitter
+action-help = Afficher/masquer l'aide
+action-about = Afficher/masquer la fenêtre À propos
+action-cycle-theme = Thème de couleurs suivant
+action-toggle-preset = Jeu de raccourcis suivant
+action-back = Retour
+action-next-panel = Panneau suivant
+action-previous-panel = Panneau précédent
+action-save = Enregistrer dans un fichier
+action-copy = Copier dans le presse-papiers
+action-focus-input = Aller au champ de recherche
+action-focus-history = Aller à l'historique
+action-previous-page = Page de résultats précédente
+action-next-page = Page de résultats suivante
+action-random = Suite aléatoire
+action-toggle-preview = Afficher/masquer l'aperçu
+action-toggle-bookmarks = Afficher/masquer les favoris
+action-open-webcam = Mode webcam
+action-open-settings = Paramètres
+action-bulk-export = Exporter résultats, favoris ou historique
+action-open-daily = Suite du jour
+action-detail-help = Raccourcis de la vue détaillée
+action-next-tab = Onglet suivant
+action-previous-tab = Onglet précédent
+action-open-graph = Voir le graphique
+action-export-tab = Exporter la suite
+action-open-browser = Ouvrir dans le navigateur
+action-toggle-bookmark = Ajouter/retirer des favoris
+action-fetch-bfile = Télécharger le b-file
+action-open-table = Vue en triangle ou tableau
+action-notebook = Enregistrer en notebook Jupyter
+action-more-places = Plus de décimales
+action-fewer-places = Moins de décimales
+action-next-snippet = Extrait de code suivant
+action-previous-snippet = Extrait de code précédent
+action-verify = Exécuter le code et comparer aux termes
+action-next-graph-type = Type de graphique suivant
+action-previous-graph-type = Type de graphique précédent
+action-modulus-up = Augmenter k (graphique mod k)
+action-modulus-down = Diminuer k (graphique mod k)
+action-zoom-in = Zoom avant
+action-zoom-out = Zoom arrière
+action-reset-view = Réafficher tous les termes
+action-listen = Enregistrer en musique (MIDI ou WAV)
+action-cycle-shape = Changer la disposition du tableau
+action-next-sequence = Suite suivante
+action-open-detail = Ouvrir dans la vue détaillée
+action-toggle-fullscreen = Plein écran
 
 # Common
 common-loading = Chargement...
@@ -343,3 +611,68 @@ error-cache = Erreur de cache: { $message }
 error-clipboard = Erreur de presse-papiers: { $message }
 error-file = Erreur de fichier: { $message }
 error-unknown = Une erreur inconnue s'est produite
+
+# Command Line
+cli-empty-query = La requête ne peut pas être vide
+cli-results-for = Résultats pour « { $query } » : { $count } trouvés
+cli-too-many-results = Aucun résultat disponible (l'OEIS a renvoyé trop de correspondances).
+cli-no-completion = Pas de complétion pour { $shell }
+cli-man-written = Pages de manuel écrites dans { $path }
+cli-not-found = Suite { $id } introuvable
+cli-random-title = Suite aléatoire
+cli-random-unavailable = Aucune suite aléatoire disponible pour le moment.
+cli-random-no-match = Aucune suite aléatoire ne correspond à ces filtres.
+cli-daily-no-match = Aucune suite ne correspond à { $query }.
+cli-no-programs = { $anumber } ne contient aucun programme
+cli-no-language-program = Aucun programme { $language } dans { $anumber } (disponibles : { $available })
+cli-saved = Enregistré : { $path }
+cli-more-programs = { $count } autre(s) programme(s) { $language } disponible(s) ; utilisez --save pour tous les écrire
+cli-plot-saved = { $description } ({ $count } termes) enregistré dans { $path }
+cli-listen-saved = { $count } notes ({ $duration } à { $tempo } bpm, { $pitches }) enregistrées dans { $path }
+cli-interval-invalid = `{ $interval }` n’est pas un intervalle comme 30s, 2m ou 1h
+cli-interval-unit = unité inconnue `{ $unit }` (utilisez s, m ou h)
+cli-interval-too-short = l’intervalle doit être d’au moins { $seconds } s
+cli-export-not-combined = { $format } ne peut pas être regroupé dans un seul fichier ; ajoutez --split pour écrire un fichier par suite
+cli-skipped = { $count } suite(s) ignorée(s) car impossible(s) à récupérer
+cli-verify-any-language = PARI/GP ou Python
+cli-nothing-to-verify = { $anumber } n'a aucun programme { $language } à vérifier
+cli-verify-failures = { $failures } programme(s) sur { $count } n'ont pas pu être vérifiés
+cli-verify-timed-out = (délai dépassé après { $seconds } s)
+cli-verify-match = { $count } termes concordent
+cli-verify-match-timed-out = { $count } termes concordent, mais le programme ne s’est pas terminé
+cli-verify-mismatch = a({ $index }) = { $actual }, attendu { $expected } ({ $count } termes concordants)
+cli-verify-partial = seulement { $count } termes sur { $expected } affichés, tous concordants
+cli-verify-no-output = aucun terme affiché
+shell-welcome = Shell OEIS. Tapez help pour les commandes, quit pour quitter.
+shell-help-title = Commandes (une suite est un numéro A, ou la dernière affichée ou transmise par |) :
+shell-help-search = Rechercher dans l'OEIS (aussi : tout ce qui n'est pas une commande)
+shell-help-more = Page de résultats suivante
+shell-help-show = Afficher le résultat n de la dernière recherche, ou une suite (aussi : le numéro A seul)
+shell-help-terms = Les termes listés dans la fiche
+shell-help-bfile = Les premières entrées du b-file (20 sauf nombre indiqué)
+shell-help-plot = Graphique ASCII : line, scatter, log, pin, histogram, mod, ulam, diff, ratio
+shell-help-bookmark = Ajouter aux favoris
+shell-help-unbookmark = Retirer des favoris
+shell-help-bookmarks = Lister les favoris
+shell-help-diff = Comparer deux suites terme à terme
+shell-help-help = Cette liste
+shell-help-quit = Quitter (aussi : exit, ou Ctrl+D)
+shell-help-pipe = `|` transmet les suites d'une commande à la suivante : search prime | terms
+shell-search-usage = Usage : search <termes>
+shell-nothing-to-page = Rien à parcourir pour l'instant ; lancez d'abord une recherche
+shell-no-such-result = Il n'y a pas de résultat { $index } sur cette page
+shell-bad-count = « { $count } » n'est pas un nombre d'entrées
+shell-bfile-entries = { $count } entrées
+shell-unknown-graph = Type de graphique inconnu « { $name } » ; voir help
+shell-bookmarked = { $anumber } ajouté aux favoris
+shell-unbookmarked = { $anumber } retiré des favoris
+shell-no-bookmarks = Aucun favori pour l'instant.
+shell-diff-usage = Usage : diff <numéro A> <numéro A>
+shell-diff-count = diff compare deux suites, pas { $count }
+shell-no-results = Aucun résultat pour { $query }.
+shell-results = Résultats { $first } à { $last } sur { $count } pour { $query } :
+shell-more-results = Tapez more pour les { $count } résultats suivants.
+shell-which-sequence = Quelle suite ? Indiquez un numéro A, ou affichez-en une d'abord
+shell-diff-disjoint = Aucun n en commun entre les termes listés.
+shell-diff-same = Les { $count } termes pour n = { $first }..{ $last } sont identiques.
+shell-diff-different = { $agreeing } des { $count } termes pour n = { $first }..{ $last } concordent ; la première différence est en n = { $n } : { $a } contre { $b }
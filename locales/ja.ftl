@@ -24,6 +24,8 @@ welcome-esc-hint = Esc で閉じる
 welcome-hero-subtitle = 既知の整数列を見つけ、参考文献を調べ、関係性を探ります。
 welcome-hero-tips = 例: 1,2,3,4,5,6  •  keyword:prime  •  id:A000045
 welcome-hero-search-hint = いつでも 'i' または '/' を押して検索に移動できます。
+welcome-daily = 今日の数列
+welcome-daily-open = で開く
 search-empty-title = まだ検索結果がありません
 search-tips-title = 検索のヒント:
 search-tip-terms = • 数列の項を入力: 1,1,2,3,5,8,13
@@ -82,18 +84,14 @@ detail-crossrefs = 相互参照
 detail-extensions = 拡張
 detail-no-data = データがありません
 detail-help = Tab 切替 | ↑↓ スクロール | g グラフ | e エクスポート | o ブラウザ | b ブックマーク | Esc 戻る
-detail-help-next-link = 次のリンク
 detail-help-prev-link = 前のリンク
 detail-help-switch-tab = タブ切替
-detail-help-follow-link = リンクを開く
 detail-help-scroll = スクロール
 detail-help-graph = グラフ
 detail-help-export = エクスポート
 detail-help-browser = ブラウザで開く
-detail-help-bookmark = ブックマーク
 detail-bookmarked = ブックマーク済み
 detail-not-bookmarked = 未ブックマーク
-detail-help-bfile = B-fileを取得
 detail-help-more = 詳細
 detail-help-modal-title = 詳細ビュー - キーボードショートカット
 detail-bfile-available = 拡張データが利用可能
@@ -109,6 +107,12 @@ graph-line = 折れ線グラフ
 graph-scatter = 散布図
 graph-log = 対数散布図
 graph-pin = ピングラフ
+graph-histogram = 値のヒストグラム
+graph-modk = { $k } を法とする剰余
+graph-ulam = ウラムの螺旋
+graph-differences = 階差
+graph-ratio = 隣接項の比
+graph-ulam-summary = 1–{ $limit } のうち { $members } 個
 graph-no-data = プロットする数値データがありません
 graph-no-positive = 対数スケールの正の値がありません
 graph-current = 現在
@@ -129,10 +133,71 @@ export-preview = プレビュー
 export-no-sequence = エクスポートする数列がありません
 export-success = クリップボードにエクスポートしました
 export-file-success = ファイルに保存しました: { $path }
+bulk-export-title = 一括エクスポート
+bulk-export-source = 対象
+bulk-export-format = 形式
+bulk-export-layout = 出力方法
+bulk-export-directory = 保存先
+bulk-export-source-results = 検索結果（このページ）
+bulk-export-source-bookmarks = ブックマーク
+bulk-export-source-history = 最近表示した数列
+bulk-export-layout-combined = 1つのファイルにまとめる
+bulk-export-layout-files = 数列ごとに1ファイル
+bulk-export-help-field = 項目
+bulk-export-help-change = 変更
+bulk-export-help-export = エクスポート
+bulk-export-help-cancel = キャンセル
+bulk-export-combined-unsupported = { $format } は1つのファイルにまとめられません。数列ごとに1ファイルを選択してください
+bulk-export-fetching = キャッシュにない数列を { $count } 件取得中...
+bulk-export-empty = エクスポートするものがありません
+bulk-export-success = { $count } 件の数列を { $path } にエクスポートしました
+bulk-export-skipped = （{ $count } 件は取得できませんでした）
+save-as-title = 名前を付けて保存
+save-as-path = パス
+save-as-more = 他 { $count } 件
+save-as-help-complete = 補完
+save-as-help-save = 保存
+save-as-help-cancel = キャンセル
+save-as-overwrite-title = ファイルが存在します
+save-as-overwrite-question = { $path } は既に存在します。上書きしますか？
+save-as-overwrite-yes = 上書き
+save-as-overwrite-no = 戻る
+plot-unsupported-format = グラフは .svg または .png で保存できます
+plot-no-positive-values = 対数スケールで描ける正の値がありません
+plot-no-spiral-terms = スパイラルに置ける 1 から { $limit } までの項がありません
+plot-no-data = 描画できる数値データがありません
+code-help-select = プログラム選択
+code-help-copy = コピー
+code-help-save = 保存
+code-help-verify = 検証
+code-copy-success = { $language } のプログラムをクリップボードにコピーしました
+code-no-snippets = この数列にはプログラムがありません
+verify-confirm = もう一度 v を押すとこの { $language } プログラムをローカルで実行します（制限 { $seconds } 秒）。信頼できるコードのみ実行してください。
+verify-unsupported = 検証できるのは PARI/GP と Python のプログラムのみです
+verify-missing-interpreter = PATH に { $interpreter } インタプリタが見つかりません
+verify-running = プログラムをローカルで実行中...
+verify-match = ✓ { $interpreter }: { $count } 項が一致（{ $seconds } 秒）
+verify-mismatch = ✗ { $interpreter }: a({ $index }) = { $actual }、期待値 { $expected }（{ $count } 項一致）
+verify-match-timed-out = ! { $interpreter }: { $count } 項が一致しましたが、プログラムが終了しませんでした
+verify-partial = ! { $interpreter }: { $expected } 項中 { $count } 項しか出力されず、すべて一致（{ $seconds } 秒）
+verify-no-output = ! { $interpreter }: 項が出力されませんでした
+verify-timed-out = （タイムアウト）
+verify-failed = ! 検証に失敗しました: { $message }
 export-error = エクスポートに失敗しました: { $message }
-export-help = ↑↓ 選択 | 1-5 クイック選択 | Enter クリップボード | Ctrl+S 保存 | Esc キャンセル
+export-help-select = 選択
+export-help-quick = クイック選択
+export-help-clipboard = クリップボード
+export-help-save = 保存
 export-bfile = B-file
 export-bfile-desc = 拡張数列データ（インデックス 値 ペア）
+export-latex = LaTeX
+export-latex-desc = 論文用の項と組版済み数式
+export-bibtex = BibTeX
+export-bibtex-desc = 文献管理ソフト用の引用エントリ
+export-notebook = Jupyter
+export-notebook-desc = 項・Python プログラム・グラフ入りのノートブック
+export-python = Python
+export-python-desc = ノートブックと同じセル構成のスクリプト
 export-bfile-not-loaded = B-fileが読み込まれていません - 詳細ビューで'f'を押してください
 export-select-format = フォーマットを選択
 export-cancel = キャンセル
@@ -178,7 +243,22 @@ webcam-category-best-desc = 興味深く注目すべき数列（キーワード:
 webcam-category-needing = 項が必要
 webcam-category-needing-desc = より多くの項を要求している数列（キーワード:more）
 webcam-category-recent = 最近の追加
-webcam-category-recent-desc = 最近追加された数列（キーワード:new）
+webcam-category-recent-desc = 最近追加・変更された数列（recent.txt）
+webcam-category-unedited = 未編集
+webcam-category-unedited-desc = まだ編集されていない投稿（キーワード:uned）
+webcam-category-core = 基本数列
+webcam-category-core-desc = 最も基本的な数列（キーワード:core）
+webcam-category-easy = 易しい数列
+webcam-category-easy-desc = 項の計算が易しい（キーワード:easy）
+webcam-category-hard = 難しい数列
+webcam-category-hard-desc = 項の計算が難しい（キーワード:hard）
+webcam-category-unknown = 未知の項
+webcam-category-unknown-desc = ほとんど分かっていない（キーワード:unkn）
+webcam-category-bookmarks = ブックマーク
+webcam-category-bookmarks-desc = ブックマークからランダムに選択
+webcam-category-empty = このカテゴリに数列が見つかりません
+webcam-no-bookmarks = ブックマークがありません。詳細ビューで 'b' を押して追加してください。
+webcam-load-error = 数列を読み込めません: { $message }
 webcam-interval = 更新間隔
 webcam-interval-manual = 手動
 webcam-interval-manual-desc = スペースキーで進む
@@ -196,11 +276,14 @@ webcam-current-sequence = 現在の数列
 webcam-no-sequence = 数列が読み込まれていません
 webcam-load-first = スペースキーまたはEnterキーを押して最初の数列を読み込む
 webcam-refresh-in = 次の更新まで { $seconds } 秒...
+webcam-retry-in = { $seconds } 秒後に再試行
+webcam-loading = 最初の数列を取得中…
 webcam-more-comments = ... および { $count } 件のコメント
 webcam-help = Space/Enter 次へ | ←→ カテゴリ | ↑↓ 間隔 | 0-5 クイック | d 詳細 | Esc 戻る
 
 # Settings Screen
 settings-title = 設定
+settings-block-settings = 設定
 settings-language = 言語
 settings-language-desc = インターフェース言語を選択
 settings-theme = テーマ
@@ -228,6 +311,10 @@ help-global = グローバルコントロール
 help-global-quit = アプリケーションを終了
 help-global-help = ヘルプの表示/非表示
 help-global-back = 戻る / キャンセル
+help-global-about = 「このアプリについて」の表示/非表示
+help-global-theme = カラーテーマを切り替え
+help-global-preset = キー割り当てのプリセットを切り替え (default, vim, emacs, helix)
+help-global-palette = コマンドパレット: すべての操作を検索
 help-search = 検索画面
 help-search-input = 検索を開始
 help-search-navigate = 結果をナビゲート
@@ -236,10 +323,20 @@ help-search-view = 選択した数列を表示
 help-search-random = ランダム数列
 help-search-preview = プレビュー パネルの切り替え
 help-search-preview-tabs = プレビュータブを切り替え
+help-search-preview-scroll = プレビューをスクロール
+help-search-history = 検索履歴にフォーカス
+help-search-focus = 次のパネル
+help-search-bookmarks = ブックマークを表示
+help-search-settings = 設定
 help-search-mouse-select = クリックで結果を選択
 help-search-mouse-open = ダブルクリックで結果を開く
 help-search-mouse-scroll = ホイールでプレビュー/結果をスクロール
 help-search-webcam = ウェブカムモード
+help-search-export = 検索結果・ブックマーク・履歴を一括エクスポート
+help-welcome = ウェルカム画面
+help-welcome-search = 検索を開始
+help-welcome-close = ウェルカム画面を閉じる
+help-welcome-daily = 今日の数列を開く
 help-detail = 詳細ビュー
 help-detail-links = ハイライトされたリンクを巡回
 help-detail-tabs = タブを切り替え
@@ -250,19 +347,131 @@ help-detail-graph = グラフを表示
 help-detail-export = 数列をエクスポート
 help-detail-browser = ブラウザで開く
 help-detail-bookmark = ブックマークを切り替え
+help-detail-table = 三角形・配列ビュー（tabl/tabf）
+help-detail-bfile = b-file を取得
+help-detail-notebook = Jupyter ノートブックとして保存
+help-detail-help = このウィンドウの表示/非表示
+help-overview = 概要タブ
+help-overview-places = 定数の小数桁数を増減 (cons/cofr)
+help-overview-copy = 定数を小数としてコピー
+help-code = コードタブ
+help-code-select = プログラムを選択
+help-code-copy = プログラムをコピー
+help-code-verify = プログラムをローカルで実行して項を検証
+help-code-save = プログラムをソースファイルとして保存
 help-graph = グラフビュー
-help-graph-types = グラフタイプを切り替え
-help-export = エクスポート画面
+help-graph-types = グラフの種類を選択（折れ線、散布、対数、ピン、ヒストグラム、mod k、ウラムの螺旋、階差、比）
+help-graph-cycle = グラフの種類を順に切り替え
+help-graph-modulus = mod k グラフの k を減らす / 増やす
+help-graph-save = グラフをSVGまたはPNGで保存
+help-graph-zoom = 拡大 / 縮小（マウスホイールはポインタ位置で拡大）
+help-graph-pan = 拡大したグラフを移動（マウスのドラッグでも可）
+help-graph-reset = すべての項を再表示
+help-graph-listen = 数列を音楽（MIDI または WAV）として保存、1 項につき 1 音
+help-table = 表ビュー
+help-table-move = セル間を移動
+help-table-rows = ページ単位で移動 / 最初・最後の行へ
+help-table-row-ends = 行の先頭 / 末尾
+help-table-shape = 三角形と反対角線配列のレイアウトを切り替え
+help-table-save = 表を CSV で保存
+help-export = エクスポートタブ
 help-export-select = フォーマットを選択
 help-export-quick = クイックフォーマット選択
 help-export-clipboard = クリップボードにエクスポート
 help-export-file = ファイルに保存
 help-webcam = ウェブカムモード
 help-webcam-next = 次の数列を読み込む
-help-webcam-category = カテゴリを切り替え
-help-webcam-interval = 更新間隔を変更
 help-webcam-quick = クイック間隔選択
 help-webcam-detail = 詳細ビューにジャンプ
+help-webcam-fullscreen = 全画面レイアウトの切り替え（数列とグラフのみ）
+help-webcam-panel = カテゴリと間隔のリストを切り替え
+help-webcam-select = カテゴリまたは間隔を選択
+help-settings = 設定画面
+help-settings-panel = セクションを切り替え
+help-settings-select = リスト内を移動
+help-settings-apply = 選択した項目を適用
+help-settings-language = 言語のクイック選択
+help-modal = ヘルプウィンドウ
+help-modal-ends = 先頭 / 末尾へ移動
+help-keybinding-warnings = キー割り当ての問題 (settings.json)
+keybindings-conflict = キー { $key } が { $first } と { $second } の両方に割り当てられています
+keybindings-unknown-context = 不明なキー割り当てセクション「{ $context }」
+keybindings-bad-section = セクション「{ $context }」はアクションとキーのリストの対応にしてください
+keybindings-unknown-action = セクション「{ $context }」に不明なアクション「{ $action }」があります
+keybindings-bad-key = { $context }.{ $action } のキー「{ $key }」を読み取れません
+keybindings-preset-changed = キー割り当て: { $preset }
+keys-pending-title = キーシーケンス
+keys-pending-next = 次: { $keys }
+palette-title = コマンドパレット
+palette-placeholder = コマンドを検索、または A 番号を入力して移動
+palette-no-matches = 一致するコマンドはありません
+palette-jump = { $number } を開く
+announce-palette = コマンドパレット: { $label }、{ $count } 件中 { $position } 件目
+announce-searching = 検索中…
+announce-search-empty = 検索ボックス、空
+announce-search-input = 検索ボックス: { $query }
+announce-result = 結果 { $count } 件中 { $position } 件目: { $anumber }、{ $name }
+announce-history = 最近表示 { $count } 件中 { $position } 件目: { $anumber }、{ $name }
+announce-bookmark = ブックマーク { $count } 件中 { $position } 件目: { $anumber } { $name }
+announce-no-results = 結果なし
+announce-sequence = { $anumber }、{ $name }、{ $tab } タブ
+announce-reference = 参照 { $count } 件中 { $position } 件目: { $anumber }
+announce-graph = { $anumber } の { $graph }
+announce-settings = { $section }: { $item }
+announce-current = (現在)
+command-screen = 移動
+command-theme = テーマ
+command-export-format = エクスポート形式
+command-graph-type = グラフ
+command-bookmark = ブックマーク
+action-quit = 終了
+action-help = ヘルプの表示/非表示
+action-about = 情報ウィンドウの表示/非表示
+action-cycle-theme = 次のカラーテーマ
+action-toggle-preset = 次のキー割り当てプリセット
+action-back = 戻る
+action-next-panel = 次のパネル
+action-previous-panel = 前のパネル
+action-save = ファイルに保存
+action-copy = クリップボードにコピー
+action-focus-input = 検索ボックスへ移動
+action-focus-history = 検索履歴へ移動
+action-previous-page = 前の結果ページ
+action-next-page = 次の結果ページ
+action-random = ランダムな数列
+action-toggle-preview = プレビューの表示/非表示
+action-toggle-bookmarks = ブックマークの表示/非表示
+action-open-webcam = ウェブカメラモード
+action-open-settings = 設定
+action-bulk-export = 結果・ブックマーク・履歴をエクスポート
+action-open-daily = 今日の数列
+action-detail-help = 詳細ビューのショートカット
+action-next-tab = 次のタブ
+action-previous-tab = 前のタブ
+action-open-graph = グラフを表示
+action-export-tab = 数列をエクスポート
+action-open-browser = ブラウザで開く
+action-toggle-bookmark = ブックマークの切り替え
+action-fetch-bfile = b-file を取得
+action-open-table = 三角形/配列ビュー
+action-notebook = Jupyter ノートブックとして保存
+action-more-places = 小数桁を増やす
+action-fewer-places = 小数桁を減らす
+action-next-snippet = 次のコード
+action-previous-snippet = 前のコード
+action-verify = コードを実行して項と比較
+action-next-graph-type = 次のグラフ種類
+action-previous-graph-type = 前のグラフ種類
+action-modulus-up = k を増やす (mod k)
+action-modulus-down = k を減らす (mod k)
+action-zoom-in = ズームイン
+action-zoom-out = ズームアウト
+action-reset-view = すべての項を再表示
+action-listen = 音楽として保存 (MIDI/WAV)
+action-cycle-shape = 表のレイアウトを変更
+action-next-sequence = 次の数列
+action-open-detail = 詳細ビューで開く
+action-toggle-fullscreen = 全画面表示の切り替え
 
 # Common
 common-loading = 読み込み中...
@@ -309,6 +518,7 @@ search-help-bookmarks = ブックマーク
 search-help-random = ランダム
 search-help-webcam = ウェブカム
 search-help-settings = 設定
+search-help-export = エクスポート
 search-help-help = ヘルプ
 search-help-quit = 終了
 search-help-click = 選択
@@ -324,7 +534,62 @@ graph-help-line = ライン
 graph-help-scatter = 散布図
 graph-help-log = 対数
 graph-help-pin = ピン
+graph-help-save = SVG/PNG保存
+graph-help-next-type = 次の種類
+graph-help-choose-type = 種類を選択
+graph-help-modulus = k を変更
+graph-help-zoom = ズーム
+graph-help-pan = 移動
+graph-help-reset = 表示をリセット
+graph-help-listen = 音で聴く
+graph-points-terms = { $count } 項
+graph-points-bfile = b-file の { $count } 項
 graph-help-back = 詳細ビューに戻る
+
+# Table View
+table-title = 表ビュー
+table-shape-triangle = 行ごとに読む三角形
+table-shape-array-down = 反対角線ごとに読む正方配列（下向き）
+table-shape-array-up = 反対角線ごとに読む正方配列（上向き）
+table-source-data = { $rows } 行 · { $terms } 項
+table-source-bfile = { $rows } 行 · B-file の { $terms } 項
+table-row-sum = 第 { $index } 行の和
+table-antidiagonal-sum = 反対角線 n + k = { $index } の和
+table-sum-incomplete = 不完全
+table-shape-chosen = 手動で選んだレイアウト
+table-unknown-shape = この不規則な三角形の名前からは行の長さがわかりません。s キーでレイアウトを選んでください。
+table-not-available = この数列は三角形でも配列でもありません（tabl/tabf キーワードなし）。s キーで表として並べることもできます。
+table-help-move = 移動
+table-help-shape = レイアウト
+table-help-save = CSV で保存
+table-preview = 表として（{ $shape }）：
+table-preview-hint = a キーで行の和と CSV エクスポート付きの表ビューを開きます
+
+# Listen Dialog
+listen-title = 音で聴く
+listen-instrument = 楽器
+listen-tempo = テンポ
+listen-tempo-value = { $tempo } bpm
+listen-range = 音域
+listen-range-value = { $count } 音
+listen-lowest = 最低音
+listen-instrument-piano = ピアノ
+listen-instrument-marimba = マリンバ
+listen-instrument-organ = オルガン
+listen-instrument-guitar = ギター
+listen-instrument-strings = ストリングス
+listen-instrument-flute = フルート
+listen-instrument-synth = シンセリード
+listen-summary = { $count } 音 · { $duration } · { $pitches }
+listen-summary-bfile = B-file の { $count } 音 · { $duration } · { $pitches }
+listen-formats = ファイル名に応じて MIDI (.mid) または WAV (.wav) で保存
+listen-help-save = 保存…
+listen-unsupported-format = 対応していない音声形式です。.mid か .wav のファイル名を使ってください
+listen-no-terms = 再生できる整数の項がありません
+constant-value = 小数点以下 { $places } 桁の値：
+constant-convergents = 近似分数（収束子）：
+constant-hint = +/- 小数点以下の桁数（最大 { $max }） | y 値をコピー
+constant-copy-success = 定数をクリップボードにコピーしました（小数点以下 { $places } 桁）
 webcam-sequence-offset = オフセット
 webcam-sequence-keywords = キーワード
 webcam-sequence-data-title = シーケンスデータ
@@ -334,11 +599,79 @@ webcam-help-category = カテゴリ
 webcam-help-interval = 間隔
 webcam-help-quick = クイック
 webcam-help-detail = 詳細表示
+webcam-help-fullscreen = 全画面
 webcam-help-back = 戻る
 settings-block-themes = テーマ
+themes-reloaded = テーマを再読み込みしました
+theme-file-error = テーマファイル { $file }: { $error }
 settings-block-animation = ウェルカムアニメーション
 settings-help-switch = セクション切替
 settings-help-navigate = ナビゲート
 settings-help-apply = 適用
 settings-help-cycle-theme = テーマ切替
 settings-help-back = 戻る
+
+# Command Line
+cli-empty-query = 検索語を入力してください
+cli-results-for = 「{ $query }」の検索結果: { $count } 件
+cli-too-many-results = 結果を表示できません (OEIS の一致件数が多すぎます)。
+cli-no-completion = { $shell } の補完には対応していません
+cli-man-written = man ページを { $path } に書き出しました
+cli-not-found = 数列 { $id } が見つかりません
+cli-random-title = ランダムな数列
+cli-random-unavailable = 現在ランダムな数列を取得できません。
+cli-random-no-match = これらの条件に合うランダムな数列はありません。
+cli-daily-no-match = { $query } に一致する数列はありません。
+cli-no-programs = { $anumber } にはプログラムがありません
+cli-no-language-program = { $anumber } に { $language } のプログラムはありません (利用可能: { $available })
+cli-saved = { $path } に保存しました
+cli-more-programs = { $language } のプログラムがあと { $count } 件あります。すべて書き出すには --save を使ってください
+cli-plot-saved = { $description } ({ $count } 項) を { $path } に保存しました
+cli-listen-saved = { $count } 音 ({ $duration }、{ $tempo } bpm、{ $pitches }) を { $path } に保存しました
+cli-interval-invalid = `{ $interval }` は 30s、2m、1h のような間隔ではありません
+cli-interval-unit = 不明な単位 `{ $unit }` です（s、m、h を使ってください）
+cli-interval-too-short = 間隔は { $seconds } 秒以上にしてください
+cli-export-not-combined = { $format } は 1 つのファイルにまとめられません。数列ごとに書き出すには --split を付けてください
+cli-skipped = 取得できなかった { $count } 件の数列をスキップしました
+cli-verify-any-language = PARI/GP または Python
+cli-nothing-to-verify = { $anumber } には検証できる { $language } のプログラムがありません
+cli-verify-failures = { $count } 件中 { $failures } 件のプログラムを検証できませんでした
+cli-verify-timed-out = ({ $seconds } 秒でタイムアウト)
+cli-verify-match = { $count } 項が一致
+cli-verify-match-timed-out = { $count } 項が一致しましたが、プログラムが終了しませんでした
+cli-verify-mismatch = a({ $index }) = { $actual }、期待値 { $expected } ({ $count } 項一致)
+cli-verify-partial = { $expected } 項中 { $count } 項しか出力されず、すべて一致
+cli-verify-no-output = 項が出力されませんでした
+shell-welcome = OEIS シェル。help でコマンド一覧、quit で終了します。
+shell-help-title = コマンド (数列は A 番号、または最後に表示した数列、| で渡された数列):
+shell-help-search = OEIS を検索 (コマンド以外の入力も検索します)
+shell-help-more = 結果の次のページ
+shell-help-show = 直前の検索の n 番目の結果、または数列を表示 (A 番号だけでも可)
+shell-help-terms = エントリに載っている項
+shell-help-bfile = b-file の最初の項目 (件数を指定しなければ 20)
+shell-help-plot = ASCII グラフ: line, scatter, log, pin, histogram, mod, ulam, diff, ratio
+shell-help-bookmark = ブックマーク
+shell-help-unbookmark = ブックマークを削除
+shell-help-bookmarks = ブックマークの一覧
+shell-help-diff = 2 つの数列を項ごとに比較
+shell-help-help = この一覧
+shell-help-quit = 終了 (exit または Ctrl+D でも可)
+shell-help-pipe = `|` はコマンドの数列を次のコマンドに渡します: search prime | terms
+shell-search-usage = 使い方: search <検索語>
+shell-nothing-to-page = まだページがありません。先に検索してください
+shell-no-such-result = このページに { $index } 番目の結果はありません
+shell-bad-count = 「{ $count }」は項目数ではありません
+shell-bfile-entries = { $count } 項目
+shell-unknown-graph = 不明なグラフの種類「{ $name }」です。help を参照してください
+shell-bookmarked = { $anumber } をブックマークしました
+shell-unbookmarked = { $anumber } のブックマークを削除しました
+shell-no-bookmarks = ブックマークはまだありません。
+shell-diff-usage = 使い方: diff <A 番号> <A 番号>
+shell-diff-count = diff で比較できるのは 2 つの数列です ({ $count } ではなく)
+shell-no-results = { $query } の結果はありません。
+shell-results = { $query } の結果 { $count } 件中 { $first }〜{ $last } 件目:
+shell-more-results = 次の { $count } 件は more で表示します。
+shell-which-sequence = どの数列ですか? A 番号を指定するか、先に show で表示してください
+shell-diff-disjoint = 掲載されている項に共通の n がありません。
+shell-diff-same = n = { $first }..{ $last } の { $count } 項はすべて同じです。
+shell-diff-different = n = { $first }..{ $last } の { $count } 項中 { $agreeing } 項が一致。最初の違いは n = { $n }: { $a } と { $b }
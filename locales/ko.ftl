@@ -24,6 +24,8 @@ welcome-esc-hint = Esc로 닫기
 welcome-hero-subtitle = 알려진 수열을 찾고, 참고문헌을 살펴보고, 관계를 탐색하세요.
 welcome-hero-tips = 예: 1,2,3,4,5,6  •  keyword:prime  •  id:A000045
 welcome-hero-search-hint = 언제든 'i' 또는 '/'를 눌러 검색으로 이동하세요.
+welcome-daily = 오늘의 수열
+welcome-daily-open = 로 열기
 search-empty-title = 아직 검색 결과가 없습니다
 search-tips-title = 검색 팁:
 search-tip-terms = • 수열 항 입력: 1,1,2,3,5,8,13
@@ -82,18 +84,14 @@ detail-crossrefs = 상호참조
 detail-extensions = 확장
 detail-no-data = 사용 가능한 데이터가 없습니다
 detail-help = Tab 전환 | ↑↓ 스크롤 | g 그래프 | e 내보내기 | o 브라우저 | b 북마크 | Esc 뒤로
-detail-help-next-link = 다음 링크
 detail-help-prev-link = 이전 링크
 detail-help-switch-tab = 탭 전환
-detail-help-follow-link = 링크 열기
 detail-help-scroll = 스크롤
 detail-help-graph = 그래프
 detail-help-export = 내보내기
 detail-help-browser = 브라우저에서 열기
-detail-help-bookmark = 북마크
 detail-bookmarked = 북마크됨
 detail-not-bookmarked = 북마크 안됨
-detail-help-bfile = B-file 가져오기
 detail-help-more = 더보기
 detail-help-modal-title = 상세 보기 - 키보드 단축키
 detail-bfile-available = 확장 데이터 사용 가능
@@ -109,6 +107,12 @@ graph-line = 꺾은선 그래프
 graph-scatter = 산점도
 graph-log = 로그 산점도
 graph-pin = 핀 그래프
+graph-histogram = 값 히스토그램
+graph-modk = { $k }로 나눈 나머지
+graph-ulam = 울람 나선
+graph-differences = 1계 차분
+graph-ratio = 연속 항의 비
+graph-ulam-summary = 1–{ $limit } 중 { $members }개
 graph-no-data = 그릴 수치 데이터가 없습니다
 graph-no-positive = 로그 스케일에 양수 값이 없습니다
 graph-current = 현재
@@ -129,10 +133,71 @@ export-preview = 미리보기
 export-no-sequence = 내보낼 수열이 없습니다
 export-success = 클립보드로 내보내기 성공
 export-file-success = 파일에 저장됨: { $path }
+bulk-export-title = 일괄 내보내기
+bulk-export-source = 대상
+bulk-export-format = 형식
+bulk-export-layout = 구성
+bulk-export-directory = 디렉터리
+bulk-export-source-results = 검색 결과 (현재 페이지)
+bulk-export-source-bookmarks = 북마크
+bulk-export-source-history = 최근 본 수열
+bulk-export-layout-combined = 하나의 파일로 합치기
+bulk-export-layout-files = 수열마다 파일 하나
+bulk-export-help-field = 항목
+bulk-export-help-change = 변경
+bulk-export-help-export = 내보내기
+bulk-export-help-cancel = 취소
+bulk-export-combined-unsupported = { $format } 형식은 하나의 파일로 합칠 수 없습니다. 수열마다 파일 하나를 선택하세요
+bulk-export-fetching = 캐시에 없는 수열 { $count }개 가져오는 중...
+bulk-export-empty = 내보낼 항목이 없습니다
+bulk-export-success = 수열 { $count }개를 { $path }(으)로 내보냈습니다
+bulk-export-skipped = ({ $count }개는 가져오지 못했습니다)
+save-as-title = 다른 이름으로 저장
+save-as-path = 경로
+save-as-more = { $count }개 더
+save-as-help-complete = 자동 완성
+save-as-help-save = 저장
+save-as-help-cancel = 취소
+save-as-overwrite-title = 파일이 이미 있음
+save-as-overwrite-question = { $path } 파일이 이미 있습니다. 덮어쓸까요?
+save-as-overwrite-yes = 덮어쓰기
+save-as-overwrite-no = 돌아가기
+plot-unsupported-format = 그래프는 .svg 또는 .png로 저장할 수 있습니다
+plot-no-positive-values = 로그 눈금에 그릴 양수 값이 없습니다
+plot-no-spiral-terms = 나선에 놓을 1부터 { $limit } 사이의 항이 없습니다
+plot-no-data = 그릴 숫자 데이터가 없습니다
+code-help-select = 프로그램 선택
+code-help-copy = 복사
+code-help-save = 저장
+code-help-verify = 검증
+code-copy-success = { $language } 프로그램을 클립보드에 복사했습니다
+code-no-snippets = 이 수열에는 프로그램이 없습니다
+verify-confirm = 이 { $language } 프로그램을 로컬에서 실행하려면 v를 한 번 더 누르세요 (제한 { $seconds }초). 신뢰할 수 있는 코드만 실행하세요.
+verify-unsupported = PARI/GP 및 Python 프로그램만 검증할 수 있습니다
+verify-missing-interpreter = PATH에서 { $interpreter } 인터프리터를 찾을 수 없습니다
+verify-running = 프로그램을 로컬에서 실행 중...
+verify-match = ✓ { $interpreter }: { $count }개 항 일치 ({ $seconds }초)
+verify-mismatch = ✗ { $interpreter }: a({ $index }) = { $actual }, 예상값 { $expected } ({ $count }개 항 일치)
+verify-match-timed-out = ! { $interpreter }: { $count }개 항이 일치하지만 프로그램이 끝나지 않았습니다
+verify-partial = ! { $interpreter }: { $expected }개 중 { $count }개 항만 출력됨, 모두 일치 ({ $seconds }초)
+verify-no-output = ! { $interpreter }: 출력된 항이 없습니다
+verify-timed-out = (시간 초과)
+verify-failed = ! 검증 실패: { $message }
 export-error = 내보내기 실패: { $message }
-export-help = ↑↓ 선택 | 1-5 빠른 선택 | Enter 클립보드 | Ctrl+S 저장 | Esc 취소
+export-help-select = 선택
+export-help-quick = 빠른 선택
+export-help-clipboard = 클립보드
+export-help-save = 저장
 export-bfile = B-file
 export-bfile-desc = 확장 수열 데이터 (인덱스 값 쌍)
+export-latex = LaTeX
+export-latex-desc = 논문용 항과 조판된 공식
+export-bibtex = BibTeX
+export-bibtex-desc = 참고문헌 관리자용 인용 항목
+export-notebook = Jupyter
+export-notebook-desc = 항, Python 프로그램, 그래프가 담긴 노트북
+export-python = Python
+export-python-desc = 노트북과 같은 셀로 구성된 스크립트
 export-bfile-not-loaded = B-file이 로드되지 않음 - 상세 보기에서 'f'를 누르세요
 export-select-format = 형식 선택
 export-cancel = 취소
@@ -178,7 +243,22 @@ webcam-category-best-desc = 흥미롭고 주목할 만한 수열 (키워드:nice
 webcam-category-needing = 항이 필요함
 webcam-category-needing-desc = 더 많은 항을 요청하는 수열 (키워드:more)
 webcam-category-recent = 최근 추가
-webcam-category-recent-desc = 최근에 추가된 수열 (키워드:new)
+webcam-category-recent-desc = 최근에 추가되거나 변경된 수열 (recent.txt)
+webcam-category-unedited = 미편집
+webcam-category-unedited-desc = 아직 편집되지 않은 제출 (키워드:uned)
+webcam-category-core = 핵심 수열
+webcam-category-core-desc = 가장 기본적인 수열 (키워드:core)
+webcam-category-easy = 쉬운 수열
+webcam-category-easy-desc = 항을 계산하기 쉬움 (키워드:easy)
+webcam-category-hard = 어려운 수열
+webcam-category-hard-desc = 항을 계산하기 어려움 (키워드:hard)
+webcam-category-unknown = 알려지지 않은 항
+webcam-category-unknown-desc = 알려진 것이 거의 없음 (키워드:unkn)
+webcam-category-bookmarks = 내 북마크
+webcam-category-bookmarks-desc = 북마크에서 무작위로 선택
+webcam-category-empty = 이 카테고리에서 수열을 찾을 수 없습니다
+webcam-no-bookmarks = 북마크가 없습니다. 상세 보기에서 'b'를 눌러 추가하세요.
+webcam-load-error = 수열을 불러올 수 없습니다: { $message }
 webcam-interval = 새로고침 간격
 webcam-interval-manual = 수동
 webcam-interval-manual-desc = 스페이스 키를 눌러 진행
@@ -196,11 +276,14 @@ webcam-current-sequence = 현재 수열
 webcam-no-sequence = 로드된 수열이 없습니다
 webcam-load-first = 스페이스 또는 Enter를 눌러 첫 번째 수열 로드
 webcam-refresh-in = 다음 새로고침까지 { $seconds }초...
+webcam-retry-in = { $seconds }초 후 다시 시도
+webcam-loading = 첫 번째 수열을 가져오는 중…
 webcam-more-comments = ... 그리고 { $count }개의 댓글 더
 webcam-help = Space/Enter 다음 | ←→ 카테고리 | ↑↓ 간격 | 0-5 빠른 선택 | d 상세 | Esc 뒤로
 
 # Settings Screen
 settings-title = 설정
+settings-block-settings = 설정
 settings-language = 언어
 settings-language-desc = 인터페이스 언어 선택
 settings-theme = 테마
@@ -228,6 +311,10 @@ help-global = 전역 컨트롤
 help-global-quit = 애플리케이션 종료
 help-global-help = 도움말 표시/숨기기
 help-global-back = 뒤로 / 취소
+help-global-about = 정보 창 표시/숨기기
+help-global-theme = 색상 테마 전환
+help-global-preset = 단축키 프리셋 전환 (default, vim, emacs, helix)
+help-global-palette = 명령 팔레트: 모든 동작 검색
 help-search = 검색 화면
 help-search-input = 검색 시작
 help-search-navigate = 결과 탐색
@@ -236,10 +323,20 @@ help-search-view = 선택한 수열 보기
 help-search-random = 무작위 수열
 help-search-preview = 미리보기 패널 토글
 help-search-preview-tabs = 미리보기 탭 전환
+help-search-preview-scroll = 미리보기 스크롤
+help-search-history = 검색 기록으로 이동
+help-search-focus = 다음 패널
+help-search-bookmarks = 북마크 보기
+help-search-settings = 설정
 help-search-mouse-select = 클릭하여 결과 선택
 help-search-mouse-open = 더블 클릭하여 결과 열기
 help-search-mouse-scroll = 휠로 미리보기/결과 스크롤
 help-search-webcam = 웹캠 모드
+help-search-export = 검색 결과, 북마크 또는 기록 내보내기
+help-welcome = 시작 창
+help-welcome-search = 검색 시작
+help-welcome-close = 시작 창 닫기
+help-welcome-daily = 오늘의 수열 열기
 help-detail = 상세 보기
 help-detail-links = 강조된 링크 순환
 help-detail-tabs = 탭 전환
@@ -250,19 +347,131 @@ help-detail-graph = 그래프 보기
 help-detail-export = 수열 내보내기
 help-detail-browser = 브라우저에서 열기
 help-detail-bookmark = 북마크 전환
+help-detail-table = 삼각형 또는 배열 보기 (tabl/tabf)
+help-detail-bfile = b-file 가져오기
+help-detail-notebook = Jupyter 노트북으로 저장
+help-detail-help = 이 창 표시/숨기기
+help-overview = 개요 탭
+help-overview-places = 상수의 소수 자릿수 늘리기 / 줄이기 (cons/cofr)
+help-overview-copy = 상수를 소수로 복사
+help-code = 코드 탭
+help-code-select = 프로그램 선택
+help-code-copy = 프로그램 복사
+help-code-verify = 프로그램을 로컬에서 실행해 항 검증
+help-code-save = 프로그램을 소스 파일로 저장
 help-graph = 그래프 보기
-help-graph-types = 그래프 유형 전환
-help-export = 내보내기 화면
+help-graph-types = 그래프 종류 선택 (선, 산점도, 로그, 핀, 히스토그램, mod k, 울람 나선, 차분, 비)
+help-graph-cycle = 그래프 종류 순환
+help-graph-modulus = mod k 그래프의 k 감소 / 증가
+help-graph-save = 그래프를 SVG 또는 PNG로 저장
+help-graph-zoom = 확대 / 축소 (마우스 휠은 포인터 위치에서 확대)
+help-graph-pan = 확대된 그래프 이동 (마우스 드래그도 가능)
+help-graph-reset = 모든 항 다시 보기
+help-graph-listen = 수열을 음악(MIDI 또는 WAV)으로 저장, 항마다 음 하나
+help-table = 표 보기
+help-table-move = 셀 사이 이동
+help-table-rows = 페이지 단위 이동 / 첫 행 또는 마지막 행으로
+help-table-row-ends = 행의 시작 / 끝
+help-table-shape = 삼각형과 반대각선 배열 배치 전환
+help-table-save = 표를 CSV로 저장
+help-export = 내보내기 탭
 help-export-select = 형식 선택
 help-export-quick = 빠른 형식 선택
 help-export-clipboard = 클립보드로 내보내기
 help-export-file = 파일로 저장
 help-webcam = 웹캠 모드
 help-webcam-next = 다음 수열 로드
-help-webcam-category = 카테고리 전환
-help-webcam-interval = 새로고침 간격 변경
 help-webcam-quick = 빠른 간격 선택
 help-webcam-detail = 상세 보기로 이동
+help-webcam-fullscreen = 전체 화면 레이아웃 전환 (수열과 그래프만)
+help-webcam-panel = 카테고리 / 간격 목록 전환
+help-webcam-select = 카테고리 또는 간격 선택
+help-settings = 설정 화면
+help-settings-panel = 섹션 전환
+help-settings-select = 목록 이동
+help-settings-apply = 선택한 항목 적용
+help-settings-language = 빠른 언어 선택
+help-modal = 도움말 창
+help-modal-ends = 맨 위 / 맨 아래로 이동
+help-keybinding-warnings = 단축키 문제 (settings.json)
+keybindings-conflict = 키 { $key }가 { $first }와 { $second }에 모두 지정되어 있습니다
+keybindings-unknown-context = 알 수 없는 단축키 섹션 "{ $context }"
+keybindings-bad-section = 섹션 "{ $context }"은(는) 동작을 키 목록에 매핑해야 합니다
+keybindings-unknown-action = 섹션 "{ $context }"에 알 수 없는 동작 "{ $action }"
+keybindings-bad-key = { $context }.{ $action }의 키 "{ $key }"를 읽을 수 없습니다
+keybindings-preset-changed = 단축키: { $preset }
+keys-pending-title = 키 시퀀스
+keys-pending-next = 다음: { $keys }
+palette-title = 명령 팔레트
+palette-placeholder = 명령을 검색하거나 A 번호를 입력해 이동
+palette-no-matches = 일치하는 명령이 없습니다
+palette-jump = { $number }(으)로 이동
+announce-palette = 명령 팔레트: { $label }, { $count }개 중 { $position }번째
+announce-searching = 검색 중…
+announce-search-empty = 검색창, 비어 있음
+announce-search-input = 검색창: { $query }
+announce-result = 결과 { $count }개 중 { $position }번째: { $anumber }, { $name }
+announce-history = 최근 본 항목 { $count }개 중 { $position }번째: { $anumber }, { $name }
+announce-bookmark = 북마크 { $count }개 중 { $position }번째: { $anumber } { $name }
+announce-no-results = 결과 없음
+announce-sequence = { $anumber }, { $name }, { $tab } 탭
+announce-reference = 참조 { $count }개 중 { $position }번째: { $anumber }
+announce-graph = { $anumber }의 { $graph }
+announce-settings = { $section }: { $item }
+announce-current = (현재)
+command-screen = 이동
+command-theme = 테마
+command-export-format = 내보내기 형식
+command-graph-type = 그래프
+command-bookmark = 북마크
+action-quit = 종료
+action-help = 도움말 표시/숨기기
+action-about = 정보 창 표시/숨기기
+action-cycle-theme = 다음 색상 테마
+action-toggle-preset = 다음 단축키 프리셋
+action-back = 뒤로
+action-next-panel = 다음 패널
+action-previous-panel = 이전 패널
+action-save = 파일로 저장
+action-copy = 클립보드에 복사
+action-focus-input = 검색창으로 이동
+action-focus-history = 검색 기록으로 이동
+action-previous-page = 이전 결과 페이지
+action-next-page = 다음 결과 페이지
+action-random = 무작위 수열
+action-toggle-preview = 미리보기 표시/숨기기
+action-toggle-bookmarks = 북마크 표시/숨기기
+action-open-webcam = 웹캠 모드
+action-open-settings = 설정
+action-bulk-export = 결과, 북마크 또는 기록 내보내기
+action-open-daily = 오늘의 수열
+action-detail-help = 상세 보기 단축키
+action-next-tab = 다음 탭
+action-previous-tab = 이전 탭
+action-open-graph = 그래프 보기
+action-export-tab = 수열 내보내기
+action-open-browser = 브라우저에서 열기
+action-toggle-bookmark = 북마크 전환
+action-fetch-bfile = b-file 가져오기
+action-open-table = 삼각형/배열 보기
+action-notebook = Jupyter 노트북으로 저장
+action-more-places = 소수 자릿수 늘리기
+action-fewer-places = 소수 자릿수 줄이기
+action-next-snippet = 다음 코드 조각
+action-previous-snippet = 이전 코드 조각
+action-verify = 코드를 실행해 항과 비교
+action-next-graph-type = 다음 그래프 종류
+action-previous-graph-type = 이전 그래프 종류
+action-modulus-up = k 늘리기 (mod k)
+action-modulus-down = k 줄이기 (mod k)
+action-zoom-in = 확대
+action-zoom-out = 축소
+action-reset-view = 모든 항 다시 보기
+action-listen = 음악으로 저장 (MIDI 또는 WAV)
+action-cycle-shape = 표 배치 바꾸기
+action-next-sequence = 다음 수열
+action-open-detail = 상세 보기에서 열기
+action-toggle-fullscreen = 전체 화면 전환
 
 # Common
 common-loading = 로딩 중...
@@ -309,6 +518,7 @@ search-help-bookmarks = 북마크
 search-help-random = 임의
 search-help-webcam = 웹캠
 search-help-settings = 설정
+search-help-export = 내보내기
 search-help-help = 도움말
 search-help-quit = 종료
 search-help-click = 선택
@@ -324,7 +534,62 @@ graph-help-line = 선
 graph-help-scatter = 산점도
 graph-help-log = 로그
 graph-help-pin = 핀
+graph-help-save = SVG/PNG 저장
+graph-help-next-type = 다음 종류
+graph-help-choose-type = 종류 선택
+graph-help-modulus = k 변경
+graph-help-zoom = 확대/축소
+graph-help-pan = 이동
+graph-help-reset = 보기 초기화
+graph-help-listen = 듣기
+graph-points-terms = { $count }개 항
+graph-points-bfile = b-file { $count }개 항
 graph-help-back = 상세 보기로 돌아가기
+
+# Table View
+table-title = 표 보기
+table-shape-triangle = 행 단위로 읽는 삼각형
+table-shape-array-down = 반대각선 단위로 읽는 정사각 배열 (아래 방향)
+table-shape-array-up = 반대각선 단위로 읽는 정사각 배열 (위 방향)
+table-source-data = { $rows }행 · { $terms }개 항
+table-source-bfile = { $rows }행 · B-file 항 { $terms }개
+table-row-sum = { $index }행의 합
+table-antidiagonal-sum = 반대각선 n + k = { $index }의 합
+table-sum-incomplete = 불완전
+table-shape-chosen = 직접 선택한 배치
+table-unknown-shape = 이 불규칙 삼각형의 이름에 행 길이가 나와 있지 않습니다. s 키로 배치를 선택하세요.
+table-not-available = 이 수열은 삼각형이나 배열이 아닙니다 (tabl/tabf 키워드 없음). s 키로 그래도 배치할 수 있습니다.
+table-help-move = 이동
+table-help-shape = 배치
+table-help-save = CSV 저장
+table-preview = 표로 보기 ({ $shape }):
+table-preview-hint = a 키를 누르면 행 합계와 CSV 내보내기가 있는 표 보기가 열립니다
+
+# Listen Dialog
+listen-title = 듣기
+listen-instrument = 악기
+listen-tempo = 템포
+listen-tempo-value = { $tempo } bpm
+listen-range = 음역
+listen-range-value = { $count }음
+listen-lowest = 최저음
+listen-instrument-piano = 피아노
+listen-instrument-marimba = 마림바
+listen-instrument-organ = 오르간
+listen-instrument-guitar = 기타
+listen-instrument-strings = 현악기
+listen-instrument-flute = 플루트
+listen-instrument-synth = 신스 리드
+listen-summary = { $count }음 · { $duration } · { $pitches }
+listen-summary-bfile = B-file { $count }음 · { $duration } · { $pitches }
+listen-formats = 파일 이름에 따라 MIDI (.mid) 또는 WAV (.wav)로 저장
+listen-help-save = 저장…
+listen-unsupported-format = 지원하지 않는 오디오 형식입니다. .mid 또는 .wav 파일 이름을 사용하세요
+listen-no-terms = 재생할 정수 항이 없습니다
+constant-value = 소수점 이하 { $places }자리 값:
+constant-convergents = 수렴 분수:
+constant-hint = +/- 소수 자릿수 (최대 { $max }) | y 값 복사
+constant-copy-success = 상수를 클립보드에 복사했습니다 (소수점 이하 { $places }자리)
 webcam-sequence-offset = 오프셋
 webcam-sequence-keywords = 키워드
 webcam-sequence-data-title = 수열 데이터
@@ -334,11 +599,79 @@ webcam-help-category = 카테고리
 webcam-help-interval = 간격
 webcam-help-quick = 빠른 선택
 webcam-help-detail = 상세 보기
+webcam-help-fullscreen = 전체 화면
 webcam-help-back = 돌아가기
 settings-block-themes = 테마
+themes-reloaded = 테마를 다시 불러왔습니다
+theme-file-error = 테마 파일 { $file }: { $error }
 settings-block-animation = 환영 애니메이션
 settings-help-switch = 섹션 전환
 settings-help-navigate = 탐색
 settings-help-apply = 적용
 settings-help-cycle-theme = 테마 순환
 settings-help-back = 돌아가기
+
+# Command Line
+cli-empty-query = 검색어를 입력하세요
+cli-results-for = '{ $query }' 검색 결과: { $count }개
+cli-too-many-results = 결과를 표시할 수 없습니다 (OEIS 일치 항목이 너무 많습니다).
+cli-no-completion = { $shell }의 자동 완성은 지원되지 않습니다
+cli-man-written = 매뉴얼 페이지를 { $path }에 저장했습니다
+cli-not-found = 수열 { $id }을(를) 찾을 수 없습니다
+cli-random-title = 무작위 수열
+cli-random-unavailable = 지금은 무작위 수열을 가져올 수 없습니다.
+cli-random-no-match = 이 조건에 맞는 무작위 수열이 없습니다.
+cli-daily-no-match = { $query }에 맞는 수열이 없습니다.
+cli-no-programs = { $anumber }에는 프로그램이 없습니다
+cli-no-language-program = { $anumber }에 { $language } 프로그램이 없습니다 (사용 가능: { $available })
+cli-saved = { $path }에 저장했습니다
+cli-more-programs = { $language } 프로그램이 { $count }개 더 있습니다. 모두 저장하려면 --save를 사용하세요
+cli-plot-saved = { $description } ({ $count }개 항)을(를) { $path }에 저장했습니다
+cli-listen-saved = 음 { $count }개 ({ $duration }, { $tempo } bpm, { $pitches })를 { $path }에 저장했습니다
+cli-interval-invalid = `{ $interval }`은(는) 30s, 2m, 1h 같은 간격이 아닙니다
+cli-interval-unit = 알 수 없는 단위 `{ $unit }` (s, m, h를 사용하세요)
+cli-interval-too-short = 간격은 최소 { $seconds }초여야 합니다
+cli-export-not-combined = { $format }은(는) 한 파일로 합칠 수 없습니다. 수열마다 파일을 만들려면 --split을 추가하세요
+cli-skipped = 가져오지 못한 수열 { $count }개를 건너뛰었습니다
+cli-verify-any-language = PARI/GP 또는 Python
+cli-nothing-to-verify = { $anumber }에는 검증할 { $language } 프로그램이 없습니다
+cli-verify-failures = 프로그램 { $count }개 중 { $failures }개를 검증하지 못했습니다
+cli-verify-timed-out = ({ $seconds }초 후 시간 초과)
+cli-verify-match = { $count }개 항 일치
+cli-verify-match-timed-out = { $count }개 항이 일치하지만 프로그램이 끝나지 않았습니다
+cli-verify-mismatch = a({ $index }) = { $actual }, 예상값 { $expected } ({ $count }개 항 일치)
+cli-verify-partial = { $expected }개 중 { $count }개 항만 출력됨, 모두 일치
+cli-verify-no-output = 출력된 항이 없습니다
+shell-welcome = OEIS 셸입니다. 명령은 help, 종료는 quit을 입력하세요.
+shell-help-title = 명령 (수열은 A 번호, 또는 마지막으로 본 수열이나 |로 전달된 수열):
+shell-help-search = OEIS 검색 (명령이 아닌 입력도 검색합니다)
+shell-help-more = 다음 결과 페이지
+shell-help-show = 마지막 검색의 n번째 결과나 수열 표시 (A 번호만 입력해도 됨)
+shell-help-terms = 항목에 실린 항
+shell-help-bfile = b-file의 처음 항목 (개수를 지정하지 않으면 20개)
+shell-help-plot = ASCII 그래프: line, scatter, log, pin, histogram, mod, ulam, diff, ratio
+shell-help-bookmark = 북마크
+shell-help-unbookmark = 북마크 삭제
+shell-help-bookmarks = 북마크 목록
+shell-help-diff = 두 수열을 항별로 비교
+shell-help-help = 이 목록
+shell-help-quit = 종료 (exit 또는 Ctrl+D도 가능)
+shell-help-pipe = `|`는 한 명령의 수열을 다음 명령에 넘깁니다: search prime | terms
+shell-search-usage = 사용법: search <검색어>
+shell-nothing-to-page = 아직 넘길 페이지가 없습니다. 먼저 검색하세요
+shell-no-such-result = 이 페이지에 { $index }번째 결과가 없습니다
+shell-bad-count = "{ $count }"은(는) 항목 수가 아닙니다
+shell-bfile-entries = { $count }개 항목
+shell-unknown-graph = 알 수 없는 그래프 종류 "{ $name }"입니다. help를 참고하세요
+shell-bookmarked = { $anumber }을(를) 북마크했습니다
+shell-unbookmarked = { $anumber }의 북마크를 삭제했습니다
+shell-no-bookmarks = 아직 북마크가 없습니다.
+shell-diff-usage = 사용법: diff <A 번호> <A 번호>
+shell-diff-count = diff는 수열 두 개를 비교합니다 ({ $count }개가 아님)
+shell-no-results = { $query }에 대한 결과가 없습니다.
+shell-results = { $query } 결과 { $count }개 중 { $first }–{ $last }번째:
+shell-more-results = 다음 결과 { $count }개를 보려면 more를 입력하세요.
+shell-which-sequence = 어떤 수열인가요? A 번호를 입력하거나 먼저 show로 표시하세요
+shell-diff-disjoint = 실린 항 사이에 공통된 n이 없습니다.
+shell-diff-same = n = { $first }..{ $last }의 항 { $count }개가 모두 같습니다.
+shell-diff-different = n = { $first }..{ $last }의 항 { $count }개 중 { $agreeing }개가 일치합니다. 처음 다른 곳은 n = { $n }: { $a } 대 { $b }
@@ -0,0 +1,679 @@
+# OEIS TUI - Traduções em Português
+
+# Application
+app-title = OEIS TUI
+app-subtitle = On-Line Encyclopedia of Integer Sequences
+
+# Greeting Screen
+greeting-title = Bem-vindo ao OEIS TUI
+greeting-line1 = Uma bela interface de terminal para explorar sequências de inteiros
+greeting-line2 = Pressione 'i' ou '/' para começar a pesquisar
+greeting-line3 = Pressione 'r' para uma sequência aleatória
+greeting-line4 = Pressione 'w' para o modo webcam
+greeting-line5 = Pressione 'Ctrl+H' para ajuda
+greeting-copyright = © OEIS Foundation Inc. - Todos os dados das sequências pertencem à OEIS
+greeting-version = Versão 0.1.0
+
+# Welcome / empty states
+welcome-title = Bem-vindo ao OEIS TUI
+welcome-subtitle = The On-Line Encyclopedia of Integer Sequences (TUI não oficial)
+welcome-prompt = Digite uma sequência, palavra ou número A para começar.
+welcome-search-label = Pesquisar no OEIS
+welcome-enter-hint = Enter para pesquisar
+welcome-esc-hint = Esc para fechar
+welcome-hero-subtitle = Encontre sequências de inteiros conhecidas, descubra referências e explore relações.
+welcome-hero-tips = Experimente: 1,2,3,4,5,6  •  keyword:prime  •  id:A000045
+welcome-hero-search-hint = Pressione 'i' ou '/' a qualquer momento para ir à pesquisa.
+welcome-daily = Sequência do dia
+welcome-daily-open = para abrir
+search-empty-title = Ainda não há resultados
+search-tips-title = Dicas de pesquisa:
+search-tip-terms = • Digite termos da sequência: 1,1,2,3,5,8,13
+search-tip-anumber = • Pesquise pelo número A: id:A000045
+search-tip-keyword = • Pesquise por palavra-chave: fibonacci
+search-tip-prefixes = • Use prefixos: keyword:nice author:Sloane
+search-start-hint = Pressione 'i' ou '/' para começar a pesquisar
+search-recently-viewed = Vistas recentemente
+search-history-empty = Ainda não há histórico
+search-bookmarks-title = Favoritos
+search-bookmarks-empty = Ainda não há favoritos. Pressione 'b' na visualização de detalhes para salvar sequências.
+search-bookmarks-loading = Carregando...
+search-bookmarks-notes = Notas
+search-results-title = Resultados
+
+# Search Screen
+search-title = Pesquisar no OEIS
+search-input-label = Pesquisa
+search-input-placeholder = Digite termos da sequência (ex.: 1,2,3,5,8,13), número A ou palavra-chave...
+search-status-results = { $count ->
+    [0] Nenhum resultado encontrado
+    [one] 1 resultado encontrado
+    *[other] { $count } resultados encontrados
+}
+search-status-page = Página { $current } de { $total }
+search-status-loading = Pesquisando...
+search-status-fetching = Aguarde enquanto os resultados são obtidos do OEIS
+search-status-error = Erro: { $message }
+search-no-results = Nenhum resultado encontrado
+search-result-one = 1 resultado encontrado
+search-result-many = { $count } resultados encontrados
+search-result-many-plus = { $count }+ resultados encontrados
+search-table-anumber = Número A
+search-table-name = Nome
+search-table-data = Prévia dos dados
+search-table-views = Visualizações
+search-block-results = Resultados
+search-block-preview = Prévia
+search-block-details = Detalhes
+search-preview-empty = Nenhuma prévia disponível
+search-invalid-tab = Aba inválida
+search-view-count = { $count ->
+    [one] 1 visualização
+    *[other] { $count } visualizações
+}
+search-help = i,/ Pesquisar | ↑↓ Navegar | ←→ Página | Enter Ver | p Prévia | r Aleatória | w Webcam | s Configurações | Ctrl+H Ajuda | q Sair
+search-help-search = Pesquisar
+search-help-navigate = Navegar
+search-help-page = Página
+search-help-view = Ver
+search-help-preview = Prévia
+search-help-bookmarks = Favoritos
+search-help-random = Aleatória
+search-help-webcam = Webcam
+search-help-settings = Configurações
+search-help-export = Exportar
+search-help-help = Ajuda
+search-help-quit = Sair
+search-help-click = Selecionar
+search-help-click-x2 = Abrir
+search-help-scroll = Mover
+
+# Detail View
+detail-tab-overview = Visão geral
+detail-tab-formulas = Fórmulas
+detail-tab-code = Código
+detail-tab-references = Referências
+detail-tab-crossrefs = Referências cruzadas
+detail-tab-metadata = Metadados
+detail-tab-graph = Gráfico
+detail-tab-export = Exportar
+detail-offset = Offset
+detail-keywords = Palavras-chave
+detail-author = Autor
+detail-created = Criada
+detail-modified = Última modificação
+detail-comments = Comentários
+detail-data = Dados da sequência
+detail-formulas = Fórmulas
+detail-examples = Exemplos
+detail-maple = Código Maple
+detail-mathematica = Código Mathematica
+detail-programs = Outros programas
+detail-references = Referências
+detail-links = Links
+detail-crossrefs = Referências cruzadas
+detail-extensions = Extensões
+detail-no-data = Nenhum dado disponível
+detail-no-sequence = Nenhuma sequência carregada
+detail-block-sequence = Sequência
+detail-block-details = Detalhes
+detail-section-data = Dados
+detail-section-comments = Comentários
+detail-section-examples = Exemplos
+detail-help = Tab Trocar | ↑↓ Rolar | g Gráfico | e Exportar | o Navegador | b Favorito | Esc Voltar
+detail-help-prev-link = Link anterior
+detail-help-switch-tab = Trocar aba
+detail-help-scroll = Rolar
+detail-help-graph = Gráfico
+detail-help-export = Exportar
+detail-help-browser = Abrir no navegador
+detail-bookmarked = Nos favoritos
+detail-not-bookmarked = Fora dos favoritos
+detail-help-more = Mais
+detail-help-modal-title = Visualização de detalhes - Atalhos de teclado
+detail-bfile-available = Dados estendidos disponíveis
+detail-bfile-fetch = Pressione 'f' para obter o b-file
+detail-bfile-loading = Carregando b-file...
+detail-bfile-loaded = ✓ {$count} termos carregados
+detail-bfile-error = b-file indisponível
+detail-bfile-not-found = b-file não encontrado para esta sequência
+
+# Graph View
+graph-title = Visualização de gráfico
+graph-line = Gráfico de linhas
+graph-scatter = Gráfico de dispersão
+graph-log = Dispersão logarítmica
+graph-pin = Gráfico de hastes
+graph-histogram = Histograma dos valores
+graph-modk = Restos mod { $k }
+graph-ulam = Espiral de Ulam
+graph-differences = Primeiras diferenças
+graph-ratio = Razão entre termos consecutivos
+graph-ulam-summary = { $members } membros entre 1 e { $limit }
+graph-no-data = Nenhum dado numérico para exibir
+graph-no-positive = Nenhum valor positivo para a escala logarítmica
+graph-current = Atual
+graph-help = 1 Linhas | 2 Dispersão | 3 Dispersão log | 4 Hastes | Esc Voltar
+graph-help-line = Linhas
+graph-help-scatter = Dispersão
+graph-help-log = Dispersão log
+graph-help-pin = Hastes
+graph-help-save = Salvar SVG/PNG
+graph-help-next-type = Próximo tipo
+graph-help-choose-type = Escolher tipo
+graph-help-modulus = Mudar k
+graph-help-zoom = Zoom
+graph-help-pan = Deslocar
+graph-help-reset = Redefinir visualização
+graph-help-listen = Ouvir
+graph-points-terms = { $count } termos
+graph-points-bfile = { $count } termos do b-file
+graph-help-back = Voltar aos detalhes
+
+# Table View
+table-title = Visualização de tabela
+table-shape-triangle = Triângulo lido por linhas
+table-shape-array-down = Matriz quadrada lida por antidiagonais (para baixo)
+table-shape-array-up = Matriz quadrada lida por antidiagonais (para cima)
+table-source-data = { $rows } linhas · { $terms } termos
+table-source-bfile = { $rows } linhas · { $terms } termos do b-file
+table-row-sum = Soma da linha { $index }
+table-antidiagonal-sum = Soma da antidiagonal n + k = { $index }
+table-sum-incomplete = incompleta
+table-shape-chosen = Disposição escolhida manualmente
+table-unknown-shape = O nome deste triângulo irregular não indica o tamanho das linhas. Pressione s para escolher uma disposição.
+table-not-available = Esta sequência não é um triângulo nem uma matriz (sem a palavra-chave tabl/tabf). Pressione s para organizá-la mesmo assim.
+table-help-move = Mover
+table-help-shape = Disposição
+table-help-save = Salvar CSV
+table-preview = Como tabela ({ $shape }):
+table-preview-hint = Pressione a para a visualização de tabela com somas das linhas e exportação CSV
+
+# Listen Dialog
+listen-title = Ouvir
+listen-instrument = Instrumento
+listen-tempo = Andamento
+listen-tempo-value = { $tempo } bpm
+listen-range = Extensão
+listen-range-value = { $count } notas
+listen-lowest = Nota mais grave
+listen-instrument-piano = Piano
+listen-instrument-marimba = Marimba
+listen-instrument-organ = Órgão
+listen-instrument-guitar = Violão
+listen-instrument-strings = Cordas
+listen-instrument-flute = Flauta
+listen-instrument-synth = Sintetizador
+listen-summary = { $count } notas · { $duration } · { $pitches }
+listen-summary-bfile = { $count } notas do b-file · { $duration } · { $pitches }
+listen-formats = MIDI (.mid) ou WAV (.wav), conforme o nome do arquivo
+listen-help-save = Salvar…
+listen-unsupported-format = Formato de áudio não suportado; use um nome de arquivo .mid ou .wav
+listen-no-terms = Nenhum termo inteiro para tocar
+constant-value = Valor com { $places } casas decimais:
+constant-convergents = Convergentes:
+constant-hint = +/- Casas decimais (até { $max }) | y Copiar valor
+constant-copy-success = Constante copiada para a área de transferência ({ $places } casas decimais)
+
+# Export Screen
+export-title = Exportar sequência
+export-format = Selecionar formato
+export-json = JSON
+export-json-desc = Dados completos da sequência com todos os metadados
+export-csv = CSV
+export-csv-desc = Valores da sequência separados por vírgulas
+export-txt = TXT
+export-txt-desc = Texto simples legível
+export-markdown = Markdown
+export-markdown-desc = Documentação formatada
+export-preview = Prévia
+export-no-sequence = Nenhuma sequência para exportar
+export-success = Exportado para a área de transferência
+export-file-success = Salvo no arquivo: { $path }
+bulk-export-title = Exportação em lote
+bulk-export-source = Origem
+bulk-export-format = Formato
+bulk-export-layout = Organização
+bulk-export-directory = Diretório
+bulk-export-source-results = Resultados da pesquisa (esta página)
+bulk-export-source-bookmarks = Favoritos
+bulk-export-source-history = Vistas recentemente
+bulk-export-layout-combined = Um único arquivo
+bulk-export-layout-files = Um arquivo por sequência
+bulk-export-help-field = Campo
+bulk-export-help-change = Mudar
+bulk-export-help-export = Exportar
+bulk-export-help-cancel = Cancelar
+bulk-export-combined-unsupported = { $format } não pode ser combinado em um único arquivo; escolha um arquivo por sequência
+bulk-export-fetching = Obtendo { $count } sequência(s) fora do cache...
+bulk-export-empty = Nada para exportar
+bulk-export-success = { $count } sequência(s) exportada(s) para { $path }
+bulk-export-skipped = ({ $count } não puderam ser obtidas)
+save-as-title = Salvar como
+save-as-path = Caminho
+save-as-more = +{ $count } mais
+save-as-help-complete = Completar
+save-as-help-save = Salvar
+save-as-help-cancel = Cancelar
+save-as-overwrite-title = O arquivo existe
+save-as-overwrite-question = { $path } já existe. Substituir?
+save-as-overwrite-yes = Substituir
+save-as-overwrite-no = Voltar
+plot-unsupported-format = Os gráficos podem ser salvos como .svg ou .png
+plot-no-positive-values = Nenhum valor positivo para plotar em escala logarítmica
+plot-no-spiral-terms = Nenhum termo entre 1 e { $limit } para colocar na espiral
+plot-no-data = Nenhum dado numérico para plotar
+code-help-select = Selecionar programa
+code-help-copy = Copiar
+code-help-save = Salvar
+code-help-verify = Verificar
+code-copy-success = Programa em { $language } copiado para a área de transferência
+code-no-snippets = Nenhum programa disponível para esta sequência
+verify-confirm = Pressione v de novo para executar este programa em { $language } localmente (limite de { $seconds } s). Execute apenas código confiável.
+verify-unsupported = Apenas programas em PARI/GP e Python podem ser verificados
+verify-missing-interpreter = Interpretador { $interpreter } não encontrado no PATH
+verify-running = Executando o programa localmente...
+verify-match = ✓ { $interpreter }: { $count } termos coincidem ({ $seconds } s)
+verify-mismatch = ✗ { $interpreter }: a({ $index }) = { $actual }, esperado { $expected } ({ $count } termos coincidiram)
+verify-match-timed-out = ! { $interpreter }: { $count } termos coincidem, mas o programa não terminou
+verify-partial = ! { $interpreter }: apenas { $count } de { $expected } termos impressos, todos coincidem ({ $seconds } s)
+verify-no-output = ! { $interpreter }: nenhum termo impresso
+verify-timed-out = (tempo esgotado)
+verify-failed = ! Falha na verificação: { $message }
+export-error = Falha na exportação: { $message }
+export-help-select = Selecionar
+export-help-quick = Seleção rápida
+export-help-clipboard = Área de transferência
+export-help-save = Salvar
+export-bfile = b-file
+export-bfile-desc = Dados estendidos da sequência (pares índice valor)
+export-latex = LaTeX
+export-latex-desc = Termos e fórmulas tipografadas para artigos
+export-bibtex = BibTeX
+export-bibtex-desc = Entrada de citação para gerenciadores de referências
+export-notebook = Jupyter
+export-notebook-desc = Notebook com os termos, programas em Python e um gráfico
+export-python = Python
+export-python-desc = Script com as mesmas células do notebook
+export-bfile-not-loaded = b-file não carregado - pressione 'f' na visualização de detalhes
+export-select-format = Selecionar formato
+export-cancel = Cancelar
+
+# Export Content Labels
+export-label-offset = Offset
+export-label-keywords = Palavras-chave
+export-label-data = Dados
+export-label-author = Autor
+export-label-created = Criada
+export-label-modified = Última modificação
+export-label-references = Referências
+export-label-revision = Revisão
+
+# Export Section Headers
+export-section-sequence-data = Dados da sequência
+export-section-metadata = Metadados
+export-section-comments = Comentários
+export-section-formulas = Fórmulas
+export-section-examples = Exemplos
+export-section-code = Código
+export-section-references = Referências
+export-section-links = Links
+export-section-crossrefs = Referências cruzadas
+
+# Export Subsection Headers
+export-subsection-maple = Maple
+export-subsection-mathematica = Mathematica
+export-subsection-programs = Outros programas
+
+# Export Format Specific
+export-csv-header = Número A,Nome,Valores
+export-markdown-source = Fonte
+export-markdown-oeis-credit = Dados da On-Line Encyclopedia of Integer Sequences (OEIS)
+
+# Webcam Mode
+webcam-title = Webcam OEIS - Navegador de sequências
+webcam-category = Categoria
+webcam-category-all = Todas as sequências
+webcam-category-all-desc = Navegar por todas as sequências do OEIS
+webcam-category-best = Melhores sequências
+webcam-category-best-desc = Sequências interessantes e notáveis (keyword:nice)
+webcam-category-needing = Precisam de termos
+webcam-category-needing-desc = Sequências que pedem mais termos (keyword:more)
+webcam-category-recent = Adições recentes
+webcam-category-recent-desc = Sequências adicionadas ou alteradas recentemente (recent.txt)
+webcam-category-unedited = Não editadas
+webcam-category-unedited-desc = Submissões ainda não editadas (keyword:uned)
+webcam-category-core = Sequências essenciais
+webcam-category-core-desc = As sequências mais fundamentais (keyword:core)
+webcam-category-easy = Sequências fáceis
+webcam-category-easy-desc = Termos fáceis de calcular (keyword:easy)
+webcam-category-hard = Sequências difíceis
+webcam-category-hard-desc = Termos difíceis de calcular (keyword:hard)
+webcam-category-unknown = Termos desconhecidos
+webcam-category-unknown-desc = Pouco se sabe sobre elas (keyword:unkn)
+webcam-category-bookmarks = Meus favoritos
+webcam-category-bookmarks-desc = Escolhas aleatórias dos seus favoritos
+webcam-category-empty = Nenhuma sequência encontrada nesta categoria
+webcam-no-bookmarks = Ainda não há favoritos. Pressione 'b' na visualização de detalhes para salvar sequências.
+webcam-load-error = Não foi possível carregar uma sequência: { $message }
+webcam-interval = Intervalo de atualização
+webcam-interval-manual = Manual
+webcam-interval-manual-desc = Pressione Espaço para avançar
+webcam-interval-5s = 5 segundos
+webcam-interval-5s-desc = Atualiza a cada 5 s
+webcam-interval-10s = 10 segundos
+webcam-interval-10s-desc = Atualiza a cada 10 s
+webcam-interval-20s = 20 segundos
+webcam-interval-20s-desc = Atualiza a cada 20 s
+webcam-interval-30s = 30 segundos
+webcam-interval-30s-desc = Atualiza a cada 30 s
+webcam-interval-1m = 1 minuto
+webcam-interval-1m-desc = Atualiza a cada 60 s
+webcam-current-sequence = Sequência atual
+webcam-no-sequence = Nenhuma sequência carregada
+webcam-load-first = Pressione Espaço ou Enter para carregar a primeira sequência
+webcam-refresh-in = Próxima atualização em { $seconds } segundos...
+webcam-retry-in = nova tentativa em { $seconds } s
+webcam-loading = Obtendo a primeira sequência…
+webcam-more-comments = ... e mais { $count } comentários
+webcam-sequence-offset = Offset
+webcam-sequence-keywords = Palavras-chave
+webcam-sequence-data-title = Dados da sequência
+webcam-sequence-comments-title = Comentários
+webcam-help = Espaço/Enter Próxima | ←→ Categoria | ↑↓ Intervalo | 0-5 Rápido | d Detalhes | Esc Voltar
+webcam-help-next = Próxima
+webcam-help-category = Categoria
+webcam-help-interval = Intervalo
+webcam-help-quick = Intervalo rápido
+webcam-help-detail = Detalhes
+webcam-help-fullscreen = Tela cheia
+webcam-help-back = Voltar
+
+# Settings Screen
+settings-title = Configurações
+settings-block-settings = Configurações
+settings-language = Idioma
+settings-language-desc = Selecione o idioma da interface
+settings-theme = Tema
+settings-theme-desc = Esquema de cores (em breve)
+settings-cache = Cache
+settings-cache-desc = Gerenciar o cache local
+settings-cache-clear = Limpar cache
+settings-cache-size = Tamanho do cache: { $size }
+settings-block-themes = Temas
+themes-reloaded = Temas recarregados
+theme-file-error = Arquivo de tema { $file }: { $error }
+settings-block-animation = Animação de boas-vindas
+settings-help = ↑↓ Navegar | Enter Selecionar | Esc Voltar
+settings-help-switch = Trocar seção
+settings-help-navigate = Navegar
+settings-help-apply = Aplicar
+settings-help-cycle-theme = Alternar tema
+settings-help-back = Voltar
+
+# About Screen
+about-title = Sobre o OEIS TUI
+about-version = Versão
+about-author = Criado por
+about-license = Licença
+about-built-with = Feito com
+about-links = Links
+about-repository = Repositório
+about-oeis-link = Site do OEIS
+about-disclaimer = Este é um cliente não oficial, sem vínculo ou endosso da The OEIS Foundation Inc.
+
+# Help Screen
+help-title = Ajuda - Atalhos de teclado
+help-global = Controles gerais
+help-global-quit = Sair do aplicativo
+help-global-help = Mostrar/ocultar ajuda
+help-global-back = Voltar / Cancelar
+help-global-about = Mostrar/ocultar a janela Sobre
+help-global-theme = Alternar o tema de cores
+help-global-preset = Alternar o conjunto de atalhos (default, vim, emacs, helix)
+help-global-palette = Paleta de comandos: pesquisar todas as ações
+help-search = Tela de pesquisa
+help-search-input = Começar a pesquisar
+help-search-navigate = Navegar pelos resultados
+help-search-page = Página anterior/seguinte
+help-search-view = Ver a sequência selecionada
+help-search-random = Sequência aleatória
+help-search-preview = Mostrar/ocultar o painel de prévia
+help-search-preview-tabs = Trocar a aba da prévia
+help-search-preview-scroll = Rolar o painel de prévia
+help-search-history = Focar o histórico de pesquisa
+help-search-focus = Próximo painel
+help-search-bookmarks = Mostrar favoritos
+help-search-settings = Configurações
+help-search-mouse-select = Clique para selecionar um resultado
+help-search-mouse-open = Clique duas vezes para abrir um resultado
+help-search-mouse-scroll = Roda do mouse move a prévia/os resultados
+help-search-webcam = Modo webcam
+help-search-export = Exportar resultados, favoritos ou histórico
+help-welcome = Janela de boas-vindas
+help-welcome-search = Começar a pesquisar
+help-welcome-close = Fechar a janela de boas-vindas
+help-welcome-daily = Abrir a sequência do dia
+help-detail = Visualização de detalhes
+help-detail-links = Alternar o link destacado
+help-detail-tabs = Trocar de aba
+help-detail-open-link = Abrir o link destacado
+help-detail-scroll = Rolar o conteúdo
+help-detail-scroll-fast = Rolar mais rápido
+help-detail-graph = Ver gráfico
+help-detail-export = Exportar sequência
+help-detail-browser = Abrir no navegador
+help-detail-bookmark = Adicionar/remover favorito
+help-detail-table = Visualização de triângulo ou matriz (tabl/tabf)
+help-detail-bfile = Obter o b-file
+help-detail-notebook = Salvar como notebook Jupyter
+help-detail-help = Mostrar/ocultar esta janela
+help-overview = Aba Visão geral
+help-overview-places = Mais / menos casas decimais de uma constante (cons/cofr)
+help-overview-copy = Copiar a constante como decimal
+help-code = Aba Código
+help-code-select = Selecionar programa
+help-code-copy = Copiar programa
+help-code-verify = Executar o programa localmente e conferir os termos
+help-code-save = Salvar o programa como arquivo-fonte
+help-graph = Visualização de gráfico
+help-graph-types = Escolher o tipo de gráfico (linhas, dispersão, log, hastes, histograma, mod k, espiral de Ulam, diferenças, razão)
+help-graph-cycle = Alternar entre os tipos de gráfico
+help-graph-modulus = Diminuir / aumentar k no gráfico mod k
+help-graph-save = Salvar o gráfico como figura SVG ou PNG
+help-graph-zoom = Aproximar / afastar (a roda do mouse aproxima no ponteiro)
+help-graph-pan = Deslocar o gráfico ampliado (ou arrastar com o mouse)
+help-graph-reset = Mostrar todos os termos de novo
+help-graph-listen = Salvar a sequência como música (MIDI ou WAV), uma nota por termo
+help-table = Visualização de tabela
+help-table-move = Mover entre células
+help-table-rows = Pular por páginas / para a primeira ou a última linha
+help-table-row-ends = Início / fim da linha
+help-table-shape = Alternar entre triângulo e matriz por antidiagonais
+help-table-save = Salvar a tabela como CSV
+help-export = Aba Exportar
+help-export-select = Selecionar formato
+help-export-quick = Seleção rápida de formato
+help-export-clipboard = Exportar para a área de transferência
+help-export-file = Salvar em arquivo
+help-webcam = Modo webcam
+help-webcam-next = Carregar a próxima sequência
+help-webcam-quick = Seleção rápida de intervalo
+help-webcam-detail = Ir para a visualização de detalhes
+help-webcam-fullscreen = Alternar a tela cheia (apenas sequência e gráfico)
+help-webcam-panel = Alternar entre as listas de categorias e intervalos
+help-webcam-select = Escolher uma categoria ou intervalo
+help-settings = Tela de configurações
+help-settings-panel = Trocar seção
+help-settings-select = Mover pela lista
+help-settings-apply = Aplicar a opção selecionada
+help-settings-language = Seleção rápida de idioma
+help-modal = Janelas de ajuda
+help-modal-ends = Ir para o início / fim
+help-keybinding-warnings = Problemas nos atalhos (settings.json)
+keybindings-conflict = A tecla { $key } está associada a { $first } e a { $second }
+keybindings-unknown-context = Seção de atalhos desconhecida "{ $context }"
+keybindings-bad-section = A seção de atalhos "{ $context }" deve associar ações a listas de teclas
+keybindings-unknown-action = Ação desconhecida "{ $action }" na seção de atalhos "{ $context }"
+keybindings-bad-key = Não foi possível ler a tecla "{ $key }" em { $context }.{ $action }
+keybindings-preset-changed = Atalhos: { $preset }
+keys-pending-title = Sequência de teclas
+keys-pending-next = próxima: { $keys }
+palette-title = Paleta de comandos
+palette-placeholder = Digite para pesquisar comandos, ou um número A para ir até ele
+palette-no-matches = Nenhum comando encontrado
+palette-jump = Ir para { $number }
+announce-palette = Paleta de comandos: { $label }, { $position } de { $count }
+announce-searching = Pesquisando…
+announce-search-empty = Caixa de pesquisa, vazia
+announce-search-input = Caixa de pesquisa: { $query }
+announce-result = Resultado { $position } de { $count }: { $anumber }, { $name }
+announce-history = Vista recentemente { $position } de { $count }: { $anumber }, { $name }
+announce-bookmark = Favorito { $position } de { $count }: { $anumber } { $name }
+announce-no-results = Nenhum resultado
+announce-sequence = { $anumber }, { $name }, aba { $tab }
+announce-reference = Referência { $position } de { $count }: { $anumber }
+announce-graph = { $graph } de { $anumber }
+announce-settings = { $section }: { $item }
+announce-current = (atual)
+command-screen = Ir para
+command-theme = Tema
+command-export-format = Formato de exportação
+command-graph-type = Gráfico
+command-bookmark = Favorito
+action-quit = Sair
+action-help = Mostrar/ocultar ajuda
+action-about = Mostrar/ocultar a janela Sobre
+action-cycle-theme = Próximo tema de cores
+action-toggle-preset = Próximo conjunto de atalhos
+action-back = Voltar
+action-next-panel = Próximo painel
+action-previous-panel = Painel anterior
+action-save = Salvar em arquivo
+action-copy = Copiar para a área de transferência
+action-focus-input = Focar a caixa de pesquisa
+action-focus-history = Focar o histórico de pesquisa
+action-previous-page = Página de resultados anterior
+action-next-page = Próxima página de resultados
+action-random = Sequência aleatória
+action-toggle-preview = Mostrar/ocultar o painel de prévia
+action-toggle-bookmarks = Mostrar/ocultar favoritos
+action-open-webcam = Modo webcam
+action-open-settings = Configurações
+action-bulk-export = Exportar resultados, favoritos ou histórico
+action-open-daily = Sequência do dia
+action-detail-help = Atalhos da visualização de detalhes
+action-next-tab = Próxima aba
+action-previous-tab = Aba anterior
+action-open-graph = Ver gráfico
+action-export-tab = Exportar sequência
+action-open-browser = Abrir no navegador
+action-toggle-bookmark = Adicionar/remover favorito
+action-fetch-bfile = Obter o b-file
+action-open-table = Visualização de triângulo ou matriz
+action-notebook = Salvar como notebook Jupyter
+action-more-places = Mais casas decimais
+action-fewer-places = Menos casas decimais
+action-next-snippet = Próximo programa
+action-previous-snippet = Programa anterior
+action-verify = Executar o código e comparar com os termos
+action-next-graph-type = Próximo tipo de gráfico
+action-previous-graph-type = Tipo de gráfico anterior
+action-modulus-up = Aumentar k (gráfico mod k)
+action-modulus-down = Diminuir k (gráfico mod k)
+action-zoom-in = Aproximar
+action-zoom-out = Afastar
+action-reset-view = Mostrar todos os termos de novo
+action-listen = Salvar como música (MIDI ou WAV)
+action-cycle-shape = Mudar a disposição da tabela
+action-next-sequence = Próxima sequência
+action-open-detail = Abrir na visualização de detalhes
+action-toggle-fullscreen = Alternar tela cheia
+
+# Common
+common-loading = Carregando...
+common-error = Erro
+common-success = Sucesso
+common-cancel = Cancelar
+common-ok = OK
+common-yes = Sim
+common-no = Não
+common-back = Voltar
+common-next = Próximo
+common-previous = Anterior
+common-page = Página
+common-of = de
+
+# Errors
+error-network = Erro de rede: não foi possível conectar ao OEIS
+error-api = Erro da API: { $message }
+error-parse = Erro de análise: formato de dados inválido
+error-cache = Erro de cache: { $message }
+error-clipboard = Erro da área de transferência: { $message }
+error-file = Erro de arquivo: { $message }
+error-unknown = Ocorreu um erro desconhecido
+
+# Command Line
+cli-empty-query = A consulta não pode estar vazia
+cli-results-for = Resultados para '{ $query }': { $count } encontrados
+cli-too-many-results = Nenhum resultado disponível (o OEIS retornou correspondências demais).
+cli-no-completion = Sem suporte a autocompletar para { $shell }
+cli-man-written = Páginas de manual gravadas em { $path }
+cli-not-found = Sequência { $id } não encontrada
+cli-random-title = Sequência aleatória
+cli-random-unavailable = Nenhuma sequência aleatória disponível no momento.
+cli-random-no-match = Nenhuma sequência aleatória corresponde a esses filtros.
+cli-daily-no-match = Nenhuma sequência corresponde a { $query }.
+cli-no-programs = { $anumber } não tem programas
+cli-no-language-program = Nenhum programa em { $language } em { $anumber } (disponíveis: { $available })
+cli-saved = Salvo: { $path }
+cli-more-programs = Há mais { $count } programa(s) em { $language }; use --save para gravar todos
+cli-plot-saved = { $description } ({ $count } termos) salvo em { $path }
+cli-listen-saved = { $count } notas ({ $duration } a { $tempo } bpm, { $pitches }) salvas em { $path }
+cli-interval-invalid = `{ $interval }` não é um intervalo como 30s, 2m ou 1h
+cli-interval-unit = unidade desconhecida `{ $unit }` (use s, m ou h)
+cli-interval-too-short = o intervalo deve ser de pelo menos { $seconds } s
+cli-export-not-combined = { $format } não pode ser combinado em um único arquivo; adicione --split para gravar um arquivo por sequência
+cli-skipped = { $count } sequência(s) ignorada(s) por não poderem ser obtidas
+cli-verify-any-language = PARI/GP ou Python
+cli-nothing-to-verify = { $anumber } não tem programas em { $language } para verificar
+cli-verify-failures = { $failures } de { $count } programas não puderam ser verificados
+cli-verify-timed-out = (tempo esgotado após { $seconds } s)
+cli-verify-match = { $count } termos coincidem
+cli-verify-match-timed-out = { $count } termos coincidem, mas o programa não terminou
+cli-verify-mismatch = a({ $index }) = { $actual }, esperado { $expected } ({ $count } termos coincidiram)
+cli-verify-partial = apenas { $count } de { $expected } termos impressos, todos coincidem
+cli-verify-no-output = nenhum termo impresso
+shell-welcome = Shell do OEIS. Digite help para ver os comandos e quit para sair.
+shell-help-title = Comandos (uma sequência é um número A, ou a última exibida ou recebida por |):
+shell-help-search = Pesquisar no OEIS (também: qualquer texto que não seja um comando)
+shell-help-more = Próxima página de resultados
+shell-help-show = Mostrar o resultado n da última pesquisa, ou uma sequência (também: só o número A)
+shell-help-terms = Os termos listados na entrada
+shell-help-bfile = As primeiras entradas do b-file (20, a menos que se indique a quantidade)
+shell-help-plot = Gráfico ASCII: line, scatter, log, pin, histogram, mod, ulam, diff, ratio
+shell-help-bookmark = Adicionar aos favoritos
+shell-help-unbookmark = Remover dos favoritos
+shell-help-bookmarks = Listar os favoritos
+shell-help-diff = Comparar duas sequências termo a termo
+shell-help-help = Esta lista
+shell-help-quit = Sair (também: exit ou Ctrl+D)
+shell-help-pipe = `|` passa as sequências de um comando para o próximo: search prime | terms
+shell-search-usage = Uso: search <termos>
+shell-nothing-to-page = Ainda não há nada para paginar; pesquise primeiro
+shell-no-such-result = Não há resultado { $index } nesta página
+shell-bad-count = "{ $count }" não é um número de entradas
+shell-bfile-entries = { $count } entradas
+shell-unknown-graph = Tipo de gráfico desconhecido "{ $name }"; veja help
+shell-bookmarked = { $anumber } adicionado aos favoritos
+shell-unbookmarked = { $anumber } removido dos favoritos
+shell-no-bookmarks = Ainda não há favoritos.
+shell-diff-usage = Uso: diff <número A> <número A>
+shell-diff-count = diff compara duas sequências, não { $count }
+shell-no-results = Nenhum resultado para { $query }.
+shell-results = Resultados { $first } a { $last } de { $count } para { $query }:
+shell-more-results = Digite more para ver os próximos { $count } resultados.
+shell-which-sequence = Qual sequência? Informe um número A ou mostre uma primeiro
+shell-diff-disjoint = Não há nenhum n em comum entre os termos listados.
+shell-diff-same = Os { $count } termos para n = { $first }..{ $last } são iguais.
+shell-diff-different = { $agreeing } dos { $count } termos para n = { $first }..{ $last } coincidem; a primeira diferença está em n = { $n }: { $a } contra { $b }
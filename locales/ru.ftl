@@ -0,0 +1,683 @@
+# OEIS TUI - Русский перевод
+
+# Application
+app-title = OEIS TUI
+app-subtitle = On-Line Encyclopedia of Integer Sequences
+
+# Greeting Screen
+greeting-title = Добро пожаловать в OEIS TUI
+greeting-line1 = Удобный терминальный интерфейс для изучения целочисленных последовательностей
+greeting-line2 = Нажмите 'i' или '/', чтобы начать поиск
+greeting-line3 = Нажмите 'r' для случайной последовательности
+greeting-line4 = Нажмите 'w' для режима веб-камеры
+greeting-line5 = Нажмите 'Ctrl+H' для справки
+greeting-copyright = © OEIS Foundation Inc. - Все данные последовательностей принадлежат OEIS
+greeting-version = Версия 0.1.0
+
+# Welcome / empty states
+welcome-title = Добро пожаловать в OEIS TUI
+welcome-subtitle = The On-Line Encyclopedia of Integer Sequences (неофициальный TUI)
+welcome-prompt = Введите последовательность, слово или A-номер, чтобы начать.
+welcome-search-label = Поиск в OEIS
+welcome-enter-hint = Enter для поиска
+welcome-esc-hint = Esc чтобы закрыть
+welcome-hero-subtitle = Находите известные целочисленные последовательности, ссылки и связи между ними.
+welcome-hero-tips = Попробуйте: 1,2,3,4,5,6  •  keyword:prime  •  id:A000045
+welcome-hero-search-hint = Нажмите 'i' или '/' в любой момент, чтобы перейти к поиску.
+welcome-daily = Последовательность дня
+welcome-daily-open = чтобы открыть
+search-empty-title = Результатов пока нет
+search-tips-title = Советы по поиску:
+search-tip-terms = • Введите члены последовательности: 1,1,2,3,5,8,13
+search-tip-anumber = • Поиск по A-номеру: id:A000045
+search-tip-keyword = • Поиск по ключевому слову: fibonacci
+search-tip-prefixes = • Используйте префиксы: keyword:nice author:Sloane
+search-start-hint = Нажмите 'i' или '/', чтобы начать поиск
+search-recently-viewed = Недавно просмотренные
+search-history-empty = История пока пуста
+search-bookmarks-title = Закладки
+search-bookmarks-empty = Закладок пока нет. Нажмите 'b' в подробном просмотре, чтобы сохранить последовательность.
+search-bookmarks-loading = Загрузка...
+search-bookmarks-notes = Заметки
+search-results-title = Результаты
+
+# Search Screen
+search-title = Поиск в OEIS
+search-input-label = Поиск
+search-input-placeholder = Введите члены последовательности (например, 1,2,3,5,8,13), A-номер или ключевое слово...
+search-status-results = { $count ->
+    [0] Ничего не найдено
+    [one] Найден { $count } результат
+    [few] Найдено { $count } результата
+    [many] Найдено { $count } результатов
+    *[other] Найдено { $count } результатов
+}
+search-status-page = Страница { $current } из { $total }
+search-status-loading = Поиск...
+search-status-fetching = Подождите, результаты загружаются из OEIS
+search-status-error = Ошибка: { $message }
+search-no-results = Ничего не найдено
+search-result-one = Найден 1 результат
+search-result-many = Найдено результатов: { $count }
+search-result-many-plus = Найдено результатов: { $count }+
+search-table-anumber = A-номер
+search-table-name = Название
+search-table-data = Начало данных
+search-table-views = Просмотры
+search-block-results = Результаты
+search-block-preview = Предпросмотр
+search-block-details = Подробности
+search-preview-empty = Предпросмотр недоступен
+search-invalid-tab = Неверная вкладка
+search-view-count = { $count ->
+    [one] { $count } просмотр
+    [few] { $count } просмотра
+    [many] { $count } просмотров
+    *[other] { $count } просмотров
+}
+search-help = i,/ Поиск | ↑↓ Навигация | ←→ Страница | Enter Открыть | p Предпросмотр | r Случайная | w Веб-камера | s Настройки | Ctrl+H Справка | q Выход
+search-help-search = Поиск
+search-help-navigate = Навигация
+search-help-page = Страница
+search-help-view = Открыть
+search-help-preview = Предпросмотр
+search-help-bookmarks = Закладки
+search-help-random = Случайная
+search-help-webcam = Веб-камера
+search-help-settings = Настройки
+search-help-export = Экспорт
+search-help-help = Справка
+search-help-quit = Выход
+search-help-click = Выбрать
+search-help-click-x2 = Открыть
+search-help-scroll = Прокрутка
+
+# Detail View
+detail-tab-overview = Обзор
+detail-tab-formulas = Формулы
+detail-tab-code = Код
+detail-tab-references = Литература
+detail-tab-crossrefs = Перекрёстные ссылки
+detail-tab-metadata = Метаданные
+detail-tab-graph = График
+detail-tab-export = Экспорт
+detail-offset = Смещение
+detail-keywords = Ключевые слова
+detail-author = Автор
+detail-created = Создана
+detail-modified = Последнее изменение
+detail-comments = Комментарии
+detail-data = Данные последовательности
+detail-formulas = Формулы
+detail-examples = Примеры
+detail-maple = Код Maple
+detail-mathematica = Код Mathematica
+detail-programs = Другие программы
+detail-references = Литература
+detail-links = Ссылки
+detail-crossrefs = Перекрёстные ссылки
+detail-extensions = Дополнения
+detail-no-data = Нет данных
+detail-no-sequence = Последовательность не загружена
+detail-block-sequence = Последовательность
+detail-block-details = Подробности
+detail-section-data = Данные
+detail-section-comments = Комментарии
+detail-section-examples = Примеры
+detail-help = Tab Вкладка | ↑↓ Прокрутка | g График | e Экспорт | o Браузер | b Закладка | Esc Назад
+detail-help-prev-link = Предыдущая ссылка
+detail-help-switch-tab = Сменить вкладку
+detail-help-scroll = Прокрутка
+detail-help-graph = График
+detail-help-export = Экспорт
+detail-help-browser = Открыть в браузере
+detail-bookmarked = В закладках
+detail-not-bookmarked = Не в закладках
+detail-help-more = Ещё
+detail-help-modal-title = Подробный просмотр - Сочетания клавиш
+detail-bfile-available = Доступны расширенные данные
+detail-bfile-fetch = Нажмите 'f', чтобы загрузить b-файл
+detail-bfile-loading = Загрузка b-файла...
+detail-bfile-loaded = ✓ Загружено членов: {$count}
+detail-bfile-error = b-файл недоступен
+detail-bfile-not-found = Для этой последовательности нет b-файла
+
+# Graph View
+graph-title = График
+graph-line = Линейный график
+graph-scatter = Точечный график
+graph-log = Логарифмический точечный график
+graph-pin = Стержневой график
+graph-histogram = Гистограмма значений
+graph-modk = Остатки по модулю { $k }
+graph-ulam = Спираль Улама
+graph-differences = Первые разности
+graph-ratio = Отношение соседних членов
+graph-ulam-summary = { $members } членов от 1 до { $limit }
+graph-no-data = Нет числовых данных для отображения
+graph-no-positive = Нет положительных значений для логарифмической шкалы
+graph-current = Текущий
+graph-help = 1 Линии | 2 Точки | 3 Лог. точки | 4 Стержни | Esc Назад
+graph-help-line = Линии
+graph-help-scatter = Точки
+graph-help-log = Лог. точки
+graph-help-pin = Стержни
+graph-help-save = Сохранить SVG/PNG
+graph-help-next-type = Следующий тип
+graph-help-choose-type = Выбрать тип
+graph-help-modulus = Изменить k
+graph-help-zoom = Масштаб
+graph-help-pan = Сдвиг
+graph-help-reset = Сбросить вид
+graph-help-listen = Прослушать
+graph-points-terms = Членов: { $count }
+graph-points-bfile = Членов из b-файла: { $count }
+graph-help-back = Назад к подробностям
+
+# Table View
+table-title = Таблица
+table-shape-triangle = Треугольник, читаемый по строкам
+table-shape-array-down = Квадратный массив, читаемый по антидиагоналям (вниз)
+table-shape-array-up = Квадратный массив, читаемый по антидиагоналям (вверх)
+table-source-data = Строк: { $rows } · членов: { $terms }
+table-source-bfile = Строк: { $rows } · членов из b-файла: { $terms }
+table-row-sum = Сумма строки { $index }
+table-antidiagonal-sum = Сумма антидиагонали n + k = { $index }
+table-sum-incomplete = неполная
+table-shape-chosen = Форма выбрана вручную
+table-unknown-shape = По названию этого нерегулярного треугольника нельзя определить длины строк. Нажмите s, чтобы выбрать форму.
+table-not-available = Эта последовательность не является треугольником или массивом (нет ключевого слова tabl/tabf). Нажмите s, чтобы всё равно разложить её.
+table-help-move = Перемещение
+table-help-shape = Форма
+table-help-save = Сохранить CSV
+table-preview = Как таблица ({ $shape }):
+table-preview-hint = Нажмите a для табличного вида с суммами строк и экспортом в CSV
+
+# Listen Dialog
+listen-title = Прослушать
+listen-instrument = Инструмент
+listen-tempo = Темп
+listen-tempo-value = { $tempo } уд/мин
+listen-range = Диапазон
+listen-range-value = Нот: { $count }
+listen-lowest = Самая низкая нота
+listen-instrument-piano = Фортепиано
+listen-instrument-marimba = Маримба
+listen-instrument-organ = Орган
+listen-instrument-guitar = Гитара
+listen-instrument-strings = Струнные
+listen-instrument-flute = Флейта
+listen-instrument-synth = Синтезатор
+listen-summary = Нот: { $count } · { $duration } · { $pitches }
+listen-summary-bfile = Нот из b-файла: { $count } · { $duration } · { $pitches }
+listen-formats = MIDI (.mid) или WAV (.wav) в зависимости от имени файла
+listen-help-save = Сохранить…
+listen-unsupported-format = Неподдерживаемый аудиоформат; используйте имя файла .mid или .wav
+listen-no-terms = Нет целых членов для воспроизведения
+constant-value = Значение с { $places } знаками после запятой:
+constant-convergents = Подходящие дроби:
+constant-hint = +/- Знаки после запятой (до { $max }) | y Копировать значение
+constant-copy-success = Константа скопирована в буфер обмена (знаков после запятой: { $places })
+
+# Export Screen
+export-title = Экспорт последовательности
+export-format = Выберите формат
+export-json = JSON
+export-json-desc = Полные данные последовательности со всеми метаданными
+export-csv = CSV
+export-csv-desc = Значения последовательности через запятую
+export-txt = TXT
+export-txt-desc = Простой читаемый текст
+export-markdown = Markdown
+export-markdown-desc = Форматированная документация
+export-preview = Предпросмотр
+export-no-sequence = Нет последовательности для экспорта
+export-success = Экспортировано в буфер обмена
+export-file-success = Сохранено в файл: { $path }
+bulk-export-title = Массовый экспорт
+bulk-export-source = Источник
+bulk-export-format = Формат
+bulk-export-layout = Раскладка
+bulk-export-directory = Каталог
+bulk-export-source-results = Результаты поиска (эта страница)
+bulk-export-source-bookmarks = Закладки
+bulk-export-source-history = Недавно просмотренные
+bulk-export-layout-combined = Один файл
+bulk-export-layout-files = Файл на каждую последовательность
+bulk-export-help-field = Поле
+bulk-export-help-change = Изменить
+bulk-export-help-export = Экспорт
+bulk-export-help-cancel = Отмена
+bulk-export-combined-unsupported = { $format } нельзя объединить в один файл; выберите файл на каждую последовательность
+bulk-export-fetching = Загрузка последовательностей, которых нет в кэше: { $count }...
+bulk-export-empty = Нечего экспортировать
+bulk-export-success = Экспортировано последовательностей: { $count } в { $path }
+bulk-export-skipped = (не удалось загрузить: { $count })
+save-as-title = Сохранить как
+save-as-path = Путь
+save-as-more = ещё { $count }
+save-as-help-complete = Дополнить
+save-as-help-save = Сохранить
+save-as-help-cancel = Отмена
+save-as-overwrite-title = Файл существует
+save-as-overwrite-question = { $path } уже существует. Перезаписать?
+save-as-overwrite-yes = Перезаписать
+save-as-overwrite-no = Назад
+plot-unsupported-format = Графики можно сохранять как .svg или .png
+plot-no-positive-values = Нет положительных значений для логарифмической шкалы
+plot-no-spiral-terms = Нет членов от 1 до { $limit } для спирали
+plot-no-data = Нет числовых данных для графика
+code-help-select = Выбрать программу
+code-help-copy = Копировать
+code-help-save = Сохранить
+code-help-verify = Проверить
+code-copy-success = Программа на { $language } скопирована в буфер обмена
+code-no-snippets = Для этой последовательности нет программ
+verify-confirm = Нажмите v ещё раз, чтобы запустить эту программу на { $language } локально (лимит { $seconds } с). Запускайте только код, которому доверяете.
+verify-unsupported = Проверить можно только программы на PARI/GP и Python
+verify-missing-interpreter = Интерпретатор { $interpreter } не найден в PATH
+verify-running = Программа выполняется локально...
+verify-match = ✓ { $interpreter }: совпало членов: { $count } ({ $seconds } с)
+verify-mismatch = ✗ { $interpreter }: a({ $index }) = { $actual }, ожидалось { $expected } (совпало членов: { $count })
+verify-match-timed-out = ! { $interpreter }: совпало членов: { $count }, но программа не завершилась
+verify-partial = ! { $interpreter }: выведено только { $count } из { $expected } членов, все совпадают ({ $seconds } с)
+verify-no-output = ! { $interpreter }: не выведено ни одного члена
+verify-timed-out = (превышено время)
+verify-failed = ! Ошибка проверки: { $message }
+export-error = Ошибка экспорта: { $message }
+export-help-select = Выбрать
+export-help-quick = Быстрый выбор
+export-help-clipboard = Буфер обмена
+export-help-save = Сохранить
+export-bfile = b-файл
+export-bfile-desc = Расширенные данные последовательности (пары индекс значение)
+export-latex = LaTeX
+export-latex-desc = Члены и формулы, свёрстанные для статей
+export-bibtex = BibTeX
+export-bibtex-desc = Запись для менеджеров библиографии
+export-notebook = Jupyter
+export-notebook-desc = Блокнот с членами, программами на Python и графиком
+export-python = Python
+export-python-desc = Скрипт с теми же ячейками, что и блокнот
+export-bfile-not-loaded = b-файл не загружен - нажмите 'f' в подробном просмотре
+export-select-format = Выберите формат
+export-cancel = Отмена
+
+# Export Content Labels
+export-label-offset = Смещение
+export-label-keywords = Ключевые слова
+export-label-data = Данные
+export-label-author = Автор
+export-label-created = Создана
+export-label-modified = Последнее изменение
+export-label-references = Литература
+export-label-revision = Редакция
+
+# Export Section Headers
+export-section-sequence-data = Данные последовательности
+export-section-metadata = Метаданные
+export-section-comments = Комментарии
+export-section-formulas = Формулы
+export-section-examples = Примеры
+export-section-code = Код
+export-section-references = Литература
+export-section-links = Ссылки
+export-section-crossrefs = Перекрёстные ссылки
+
+# Export Subsection Headers
+export-subsection-maple = Maple
+export-subsection-mathematica = Mathematica
+export-subsection-programs = Другие программы
+
+# Export Format Specific
+export-csv-header = A-номер,Название,Значения
+export-markdown-source = Источник
+export-markdown-oeis-credit = Данные из On-Line Encyclopedia of Integer Sequences (OEIS)
+
+# Webcam Mode
+webcam-title = Веб-камера OEIS - Обозреватель последовательностей
+webcam-category = Категория
+webcam-category-all = Все последовательности
+webcam-category-all-desc = Просмотр всех последовательностей OEIS
+webcam-category-best = Лучшие последовательности
+webcam-category-best-desc = Интересные и примечательные последовательности (keyword:nice)
+webcam-category-needing = Нужны члены
+webcam-category-needing-desc = Последовательности, которым нужны новые члены (keyword:more)
+webcam-category-recent = Недавние добавления
+webcam-category-recent-desc = Недавно добавленные или изменённые последовательности (recent.txt)
+webcam-category-unedited = Неотредактированные
+webcam-category-unedited-desc = Ещё не отредактированные поступления (keyword:uned)
+webcam-category-core = Основные последовательности
+webcam-category-core-desc = Самые фундаментальные последовательности (keyword:core)
+webcam-category-easy = Простые последовательности
+webcam-category-easy-desc = Члены легко вычисляются (keyword:easy)
+webcam-category-hard = Сложные последовательности
+webcam-category-hard-desc = Члены трудно вычислить (keyword:hard)
+webcam-category-unknown = Неизвестные члены
+webcam-category-unknown-desc = О них мало что известно (keyword:unkn)
+webcam-category-bookmarks = Мои закладки
+webcam-category-bookmarks-desc = Случайный выбор из ваших закладок
+webcam-category-empty = В этой категории последовательностей не найдено
+webcam-no-bookmarks = Закладок пока нет. Нажмите 'b' в подробном просмотре, чтобы сохранить последовательность.
+webcam-load-error = Не удалось загрузить последовательность: { $message }
+webcam-interval = Интервал обновления
+webcam-interval-manual = Вручную
+webcam-interval-manual-desc = Нажмите Пробел для перехода
+webcam-interval-5s = 5 секунд
+webcam-interval-5s-desc = Обновление каждые 5 с
+webcam-interval-10s = 10 секунд
+webcam-interval-10s-desc = Обновление каждые 10 с
+webcam-interval-20s = 20 секунд
+webcam-interval-20s-desc = Обновление каждые 20 с
+webcam-interval-30s = 30 секунд
+webcam-interval-30s-desc = Обновление каждые 30 с
+webcam-interval-1m = 1 минута
+webcam-interval-1m-desc = Обновление каждые 60 с
+webcam-current-sequence = Текущая последовательность
+webcam-no-sequence = Последовательность не загружена
+webcam-load-first = Нажмите Пробел или Enter, чтобы загрузить первую последовательность
+webcam-refresh-in = Следующее обновление через { $seconds } с...
+webcam-retry-in = повтор через { $seconds } с
+webcam-loading = Загрузка первой последовательности…
+webcam-more-comments = ... и ещё комментариев: { $count }
+webcam-sequence-offset = Смещение
+webcam-sequence-keywords = Ключевые слова
+webcam-sequence-data-title = Данные последовательности
+webcam-sequence-comments-title = Комментарии
+webcam-help = Пробел/Enter Далее | ←→ Категория | ↑↓ Интервал | 0-5 Быстро | d Подробно | Esc Назад
+webcam-help-next = Далее
+webcam-help-category = Категория
+webcam-help-interval = Интервал
+webcam-help-quick = Быстрый интервал
+webcam-help-detail = Подробно
+webcam-help-fullscreen = Полный экран
+webcam-help-back = Назад
+
+# Settings Screen
+settings-title = Настройки
+settings-block-settings = Настройки
+settings-language = Язык
+settings-language-desc = Выберите язык интерфейса
+settings-theme = Тема
+settings-theme-desc = Цветовая схема (скоро)
+settings-cache = Кэш
+settings-cache-desc = Управление локальным кэшем
+settings-cache-clear = Очистить кэш
+settings-cache-size = Размер кэша: { $size }
+settings-block-themes = Темы
+themes-reloaded = Темы перезагружены
+theme-file-error = Файл темы { $file }: { $error }
+settings-block-animation = Приветственная анимация
+settings-help = ↑↓ Навигация | Enter Выбрать | Esc Назад
+settings-help-switch = Сменить раздел
+settings-help-navigate = Навигация
+settings-help-apply = Применить
+settings-help-cycle-theme = Сменить тему
+settings-help-back = Назад
+
+# About Screen
+about-title = О программе OEIS TUI
+about-version = Версия
+about-author = Автор
+about-license = Лицензия
+about-built-with = Создано с помощью
+about-links = Ссылки
+about-repository = Репозиторий
+about-oeis-link = Сайт OEIS
+about-disclaimer = Это неофициальный клиент, не связанный с The OEIS Foundation Inc. и не одобренный ею.
+
+# Help Screen
+help-title = Справка - Сочетания клавиш
+help-global = Общие клавиши
+help-global-quit = Выйти из приложения
+help-global-help = Показать/скрыть справку
+help-global-back = Назад / Отмена
+help-global-about = Показать/скрыть окно «О программе»
+help-global-theme = Сменить цветовую тему
+help-global-preset = Сменить набор клавиш (default, vim, emacs, helix)
+help-global-palette = Палитра команд: поиск по всем действиям
+help-search = Экран поиска
+help-search-input = Начать поиск
+help-search-navigate = Перемещение по результатам
+help-search-page = Предыдущая/следующая страница
+help-search-view = Открыть выбранную последовательность
+help-search-random = Случайная последовательность
+help-search-preview = Показать/скрыть панель предпросмотра
+help-search-preview-tabs = Сменить вкладку предпросмотра
+help-search-preview-scroll = Прокрутить панель предпросмотра
+help-search-history = Перейти к истории поиска
+help-search-focus = Следующая панель
+help-search-bookmarks = Показать закладки
+help-search-settings = Настройки
+help-search-mouse-select = Щелчок выбирает результат
+help-search-mouse-open = Двойной щелчок открывает результат
+help-search-mouse-scroll = Колесо мыши прокручивает предпросмотр/результаты
+help-search-webcam = Режим веб-камеры
+help-search-export = Экспорт результатов, закладок или истории
+help-welcome = Приветственное окно
+help-welcome-search = Начать поиск
+help-welcome-close = Закрыть приветственное окно
+help-welcome-daily = Открыть последовательность дня
+help-detail = Подробный просмотр
+help-detail-links = Переключить выделенную ссылку
+help-detail-tabs = Сменить вкладку
+help-detail-open-link = Открыть выделенную ссылку
+help-detail-scroll = Прокрутить содержимое
+help-detail-scroll-fast = Быстрая прокрутка
+help-detail-graph = Показать график
+help-detail-export = Экспортировать последовательность
+help-detail-browser = Открыть в браузере
+help-detail-bookmark = Добавить/удалить закладку
+help-detail-table = Вид треугольника или массива (tabl/tabf)
+help-detail-bfile = Загрузить b-файл
+help-detail-notebook = Сохранить как блокнот Jupyter
+help-detail-help = Показать/скрыть это окно
+help-overview = Вкладка «Обзор»
+help-overview-places = Больше / меньше знаков константы (cons/cofr)
+help-overview-copy = Копировать константу как десятичную дробь
+help-code = Вкладка «Код»
+help-code-select = Выбрать программу
+help-code-copy = Копировать программу
+help-code-verify = Запустить программу локально и сверить члены
+help-code-save = Сохранить программу как исходный файл
+help-graph = График
+help-graph-types = Выбрать тип графика (линии, точки, лог., стержни, гистограмма, mod k, спираль Улама, разности, отношение)
+help-graph-cycle = Переключать типы графика
+help-graph-modulus = Уменьшить / увеличить k на графике mod k
+help-graph-save = Сохранить график как изображение SVG или PNG
+help-graph-zoom = Приблизить / отдалить (колесо мыши масштабирует у указателя)
+help-graph-pan = Сдвинуть увеличенный график (или тянуть мышью)
+help-graph-reset = Снова показать все члены
+help-graph-listen = Сохранить последовательность как музыку (MIDI или WAV), одна нота на член
+help-table = Таблица
+help-table-move = Перемещение между ячейками
+help-table-rows = Перейти на страницу / к первой или последней строке
+help-table-row-ends = Начало / конец строки
+help-table-shape = Переключить треугольник и массив по антидиагоналям
+help-table-save = Сохранить таблицу как CSV
+help-export = Вкладка «Экспорт»
+help-export-select = Выбрать формат
+help-export-quick = Быстрый выбор формата
+help-export-clipboard = Экспорт в буфер обмена
+help-export-file = Сохранить в файл
+help-webcam = Режим веб-камеры
+help-webcam-next = Загрузить следующую последовательность
+help-webcam-quick = Быстрый выбор интервала
+help-webcam-detail = Перейти к подробному просмотру
+help-webcam-fullscreen = Полноэкранный режим (только последовательность и график)
+help-webcam-panel = Переключить списки категорий и интервалов
+help-webcam-select = Выбрать категорию или интервал
+help-settings = Экран настроек
+help-settings-panel = Сменить раздел
+help-settings-select = Перемещение по списку
+help-settings-apply = Применить выбранный вариант
+help-settings-language = Быстрый выбор языка
+help-modal = Окна справки
+help-modal-ends = Перейти в начало / конец
+help-keybinding-warnings = Проблемы с сочетаниями клавиш (settings.json)
+keybindings-conflict = Клавиша { $key } назначена и на { $first }, и на { $second }
+keybindings-unknown-context = Неизвестный раздел сочетаний клавиш "{ $context }"
+keybindings-bad-section = Раздел сочетаний клавиш "{ $context }" должен сопоставлять действиям списки клавиш
+keybindings-unknown-action = Неизвестное действие "{ $action }" в разделе сочетаний клавиш "{ $context }"
+keybindings-bad-key = Не удалось разобрать клавишу "{ $key }" в { $context }.{ $action }
+keybindings-preset-changed = Набор клавиш: { $preset }
+keys-pending-title = Последовательность клавиш
+keys-pending-next = далее: { $keys }
+palette-title = Палитра команд
+palette-placeholder = Введите текст для поиска команд или A-номер для перехода
+palette-no-matches = Подходящих команд нет
+palette-jump = Перейти к { $number }
+announce-palette = Палитра команд: { $label }, { $position } из { $count }
+announce-searching = Поиск…
+announce-search-empty = Поле поиска, пусто
+announce-search-input = Поле поиска: { $query }
+announce-result = Результат { $position } из { $count }: { $anumber }, { $name }
+announce-history = Недавно просмотренная { $position } из { $count }: { $anumber }, { $name }
+announce-bookmark = Закладка { $position } из { $count }: { $anumber } { $name }
+announce-no-results = Нет результатов
+announce-sequence = { $anumber }, { $name }, вкладка { $tab }
+announce-reference = Ссылка { $position } из { $count }: { $anumber }
+announce-graph = { $graph } для { $anumber }
+announce-settings = { $section }: { $item }
+announce-current = (текущий)
+command-screen = Перейти
+command-theme = Тема
+command-export-format = Формат экспорта
+command-graph-type = График
+command-bookmark = Закладка
+action-quit = Выход
+action-help = Показать/скрыть справку
+action-about = Показать/скрыть окно «О программе»
+action-cycle-theme = Следующая цветовая тема
+action-toggle-preset = Следующий набор клавиш
+action-back = Назад
+action-next-panel = Следующая панель
+action-previous-panel = Предыдущая панель
+action-save = Сохранить в файл
+action-copy = Копировать в буфер обмена
+action-focus-input = Перейти к полю поиска
+action-focus-history = Перейти к истории поиска
+action-previous-page = Предыдущая страница результатов
+action-next-page = Следующая страница результатов
+action-random = Случайная последовательность
+action-toggle-preview = Показать/скрыть панель предпросмотра
+action-toggle-bookmarks = Показать/скрыть закладки
+action-open-webcam = Режим веб-камеры
+action-open-settings = Настройки
+action-bulk-export = Экспорт результатов, закладок или истории
+action-open-daily = Последовательность дня
+action-detail-help = Сочетания клавиш подробного просмотра
+action-next-tab = Следующая вкладка
+action-previous-tab = Предыдущая вкладка
+action-open-graph = Показать график
+action-export-tab = Экспортировать последовательность
+action-open-browser = Открыть в браузере
+action-toggle-bookmark = Добавить/удалить закладку
+action-fetch-bfile = Загрузить b-файл
+action-open-table = Вид треугольника или массива
+action-notebook = Сохранить как блокнот Jupyter
+action-more-places = Больше знаков после запятой
+action-fewer-places = Меньше знаков после запятой
+action-next-snippet = Следующая программа
+action-previous-snippet = Предыдущая программа
+action-verify = Запустить код и сверить с членами
+action-next-graph-type = Следующий тип графика
+action-previous-graph-type = Предыдущий тип графика
+action-modulus-up = Увеличить k (график mod k)
+action-modulus-down = Уменьшить k (график mod k)
+action-zoom-in = Приблизить
+action-zoom-out = Отдалить
+action-reset-view = Снова показать все члены
+action-listen = Сохранить как музыку (MIDI или WAV)
+action-cycle-shape = Сменить форму таблицы
+action-next-sequence = Следующая последовательность
+action-open-detail = Открыть в подробном просмотре
+action-toggle-fullscreen = Полноэкранный режим
+
+# Common
+common-loading = Загрузка...
+common-error = Ошибка
+common-success = Готово
+common-cancel = Отмена
+common-ok = OK
+common-yes = Да
+common-no = Нет
+common-back = Назад
+common-next = Далее
+common-previous = Назад
+common-page = Страница
+common-of = из
+
+# Errors
+error-network = Ошибка сети: не удалось подключиться к OEIS
+error-api = Ошибка API: { $message }
+error-parse = Ошибка разбора: неверный формат данных
+error-cache = Ошибка кэша: { $message }
+error-clipboard = Ошибка буфера обмена: { $message }
+error-file = Ошибка файла: { $message }
+error-unknown = Произошла неизвестная ошибка
+
+# Command Line
+cli-empty-query = Запрос не может быть пустым
+cli-results-for = Результаты по запросу «{ $query }»: найдено { $count }
+cli-too-many-results = Нет результатов (OEIS вернула слишком много совпадений).
+cli-no-completion = Дополнение для { $shell } не поддерживается
+cli-man-written = Man-страницы записаны в { $path }
+cli-not-found = Последовательность { $id } не найдена
+cli-random-title = Случайная последовательность
+cli-random-unavailable = Сейчас случайная последовательность недоступна.
+cli-random-no-match = Нет случайной последовательности, подходящей под эти фильтры.
+cli-daily-no-match = Нет последовательностей, подходящих под { $query }.
+cli-no-programs = В { $anumber } нет программ
+cli-no-language-program = В { $anumber } нет программы на { $language } (есть: { $available })
+cli-saved = Сохранено: { $path }
+cli-more-programs = Ещё программ на { $language }: { $count }; чтобы записать все, используйте --save
+cli-plot-saved = { $description } ({ $count } членов) сохранено в { $path }
+cli-listen-saved = Нот: { $count } ({ $duration }, { $tempo } bpm, { $pitches }) сохранено в { $path }
+cli-interval-invalid = `{ $interval }` — не интервал вида 30s, 2m или 1h
+cli-interval-unit = неизвестная единица `{ $unit }` (используйте s, m или h)
+cli-interval-too-short = интервал должен быть не меньше { $seconds } с
+cli-export-not-combined = { $format } нельзя объединить в один файл; добавьте --split, чтобы записать по файлу на последовательность
+cli-skipped = Пропущено последовательностей, которые не удалось загрузить: { $count }
+cli-verify-any-language = PARI/GP или Python
+cli-nothing-to-verify = В { $anumber } нет программ на { $language } для проверки
+cli-verify-failures = Не удалось проверить программ: { $failures } из { $count }
+cli-verify-timed-out = (превышено время, { $seconds } с)
+cli-verify-match = совпадает членов: { $count }
+cli-verify-match-timed-out = совпало членов: { $count }, но программа не завершилась
+cli-verify-mismatch = a({ $index }) = { $actual }, ожидалось { $expected } (совпало членов: { $count })
+cli-verify-partial = выведено только { $count } из { $expected } членов, все совпадают
+cli-verify-no-output = члены не выведены
+shell-welcome = Оболочка OEIS. Введите help для списка команд, quit для выхода.
+shell-help-title = Команды (последовательность — это A-номер либо последняя показанная или переданная через |):
+shell-help-search = Поиск в OEIS (также: любой текст, который не является командой)
+shell-help-more = Следующая страница результатов
+shell-help-show = Показать результат n последнего поиска или последовательность (можно просто A-номер)
+shell-help-terms = Члены, перечисленные в статье
+shell-help-bfile = Первые записи b-file (20, если не указано количество)
+shell-help-plot = ASCII-график: line, scatter, log, pin, histogram, mod, ulam, diff, ratio
+shell-help-bookmark = Добавить в закладки
+shell-help-unbookmark = Удалить закладку
+shell-help-bookmarks = Список закладок
+shell-help-diff = Сравнить две последовательности почленно
+shell-help-help = Этот список
+shell-help-quit = Выход (также exit или Ctrl+D)
+shell-help-pipe = `|` передаёт последовательности одной команды следующей: search prime | terms
+shell-search-usage = Использование: search <запрос>
+shell-nothing-to-page = Листать пока нечего; сначала выполните поиск
+shell-no-such-result = На этой странице нет результата { $index }
+shell-bad-count = «{ $count }» — не количество записей
+shell-bfile-entries = записей: { $count }
+shell-unknown-graph = Неизвестный тип графика «{ $name }»; см. help
+shell-bookmarked = { $anumber } добавлена в закладки
+shell-unbookmarked = Закладка { $anumber } удалена
+shell-no-bookmarks = Закладок пока нет.
+shell-diff-usage = Использование: diff <A-номер> <A-номер>
+shell-diff-count = diff сравнивает две последовательности, а не { $count }
+shell-no-results = По запросу { $query } ничего не найдено.
+shell-results = Результаты { $first }–{ $last } из { $count } по запросу { $query }:
+shell-more-results = Введите more, чтобы увидеть следующие { $count } результатов.
+shell-which-sequence = Какая последовательность? Укажите A-номер или сначала откройте её через show
+shell-diff-disjoint = У перечисленных членов нет общих n.
+shell-diff-same = Все { $count } членов для n = { $first }..{ $last } совпадают.
+shell-diff-different = Совпадает { $agreeing } из { $count } членов для n = { $first }..{ $last }; первое различие при n = { $n }: { $a } и { $b }
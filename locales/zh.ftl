@@ -24,6 +24,8 @@ welcome-esc-hint = Esc 关闭
 welcome-hero-subtitle = 查找已知数列，发现参考文献并探索关系。
 welcome-hero-tips = 示例：1,2,3,4,5,6  •  keyword:prime  •  id:A000045
 welcome-hero-search-hint = 随时按 'i' 或 '/' 跳转到搜索。
+welcome-daily = 每日数列
+welcome-daily-open = 打开
 search-empty-title = 暂无搜索结果
 search-tips-title = 搜索技巧：
 search-tip-terms = • 输入数列项：1,1,2,3,5,8,13
@@ -82,18 +84,14 @@ detail-crossrefs = 交叉引用
 detail-extensions = 扩展
 detail-no-data = 无可用数据
 detail-help = Tab 切换 | ↑↓ 滚动 | g 图表 | e 导出 | o 浏览器 | b 书签 | Esc 返回
-detail-help-next-link = 下一个链接
 detail-help-prev-link = 上一个链接
 detail-help-switch-tab = 切换标签
-detail-help-follow-link = 打开链接
 detail-help-scroll = 滚动
 detail-help-graph = 图表
 detail-help-export = 导出
 detail-help-browser = 在浏览器中打开
-detail-help-bookmark = 书签
 detail-bookmarked = 已添加书签
 detail-not-bookmarked = 未添加书签
-detail-help-bfile = 获取B-file
 detail-help-more = 更多
 detail-help-modal-title = 详细视图 - 键盘快捷键
 detail-bfile-available = 扩展数据可用
@@ -109,6 +107,12 @@ graph-line = 折线图
 graph-scatter = 散点图
 graph-log = 对数散点图
 graph-pin = 针状图
+graph-histogram = 数值直方图
+graph-modk = 模 { $k } 余数
+graph-ulam = 乌拉姆螺旋
+graph-differences = 一阶差分
+graph-ratio = 相邻项之比
+graph-ulam-summary = 1–{ $limit } 中的 { $members } 个成员
 graph-no-data = 没有可绘制的数值数据
 graph-no-positive = 对数刻度没有正值
 graph-current = 当前
@@ -129,10 +133,71 @@ export-preview = 预览
 export-no-sequence = 没有要导出的数列
 export-success = 已成功导出到剪贴板
 export-file-success = 已保存到文件: { $path }
+bulk-export-title = 批量导出
+bulk-export-source = 来源
+bulk-export-format = 格式
+bulk-export-layout = 布局
+bulk-export-directory = 目录
+bulk-export-source-results = 搜索结果（当前页）
+bulk-export-source-bookmarks = 书签
+bulk-export-source-history = 最近查看
+bulk-export-layout-combined = 合并为一个文件
+bulk-export-layout-files = 每个序列一个文件
+bulk-export-help-field = 字段
+bulk-export-help-change = 更改
+bulk-export-help-export = 导出
+bulk-export-help-cancel = 取消
+bulk-export-combined-unsupported = { $format } 无法合并为一个文件，请选择每个序列一个文件
+bulk-export-fetching = 正在获取 { $count } 个未缓存的序列...
+bulk-export-empty = 没有可导出的内容
+bulk-export-success = 已将 { $count } 个序列导出到 { $path }
+bulk-export-skipped = （{ $count } 个无法获取）
+save-as-title = 另存为
+save-as-path = 路径
+save-as-more = 还有 { $count } 项
+save-as-help-complete = 补全
+save-as-help-save = 保存
+save-as-help-cancel = 取消
+save-as-overwrite-title = 文件已存在
+save-as-overwrite-question = { $path } 已存在。要覆盖吗？
+save-as-overwrite-yes = 覆盖
+save-as-overwrite-no = 返回
+plot-unsupported-format = 图表只能保存为 .svg 或 .png
+plot-no-positive-values = 没有可在对数坐标上绘制的正值
+plot-no-spiral-terms = 没有介于 1 和 { $limit } 之间、可放在螺旋上的项
+plot-no-data = 没有可绘制的数值数据
+code-help-select = 选择程序
+code-help-copy = 复制
+code-help-save = 保存
+code-help-verify = 校验
+code-copy-success = 已将 { $language } 程序复制到剪贴板
+code-no-snippets = 此数列没有可用的程序
+verify-confirm = 再按一次 v 在本地运行此 { $language } 程序（限时 { $seconds } 秒）。请只运行可信的代码。
+verify-unsupported = 只能校验 PARI/GP 和 Python 程序
+verify-missing-interpreter = 在 PATH 中找不到 { $interpreter } 解释器
+verify-running = 正在本地运行程序...
+verify-match = ✓ { $interpreter }：{ $count } 项一致（{ $seconds } 秒）
+verify-mismatch = ✗ { $interpreter }：a({ $index }) = { $actual }，应为 { $expected }（{ $count } 项一致）
+verify-match-timed-out = ! { $interpreter }：{ $count } 项一致，但程序未结束
+verify-partial = ! { $interpreter }：仅输出 { $expected } 项中的 { $count } 项，全部一致（{ $seconds } 秒）
+verify-no-output = ! { $interpreter }：没有输出任何项
+verify-timed-out = （超时）
+verify-failed = ! 校验失败：{ $message }
 export-error = 导出失败: { $message }
-export-help = ↑↓ 选择 | 1-5 快速选择 | Enter 剪贴板 | Ctrl+S 保存 | Esc 取消
+export-help-select = 选择
+export-help-quick = 快速选择
+export-help-clipboard = 剪贴板
+export-help-save = 保存
 export-bfile = B-file
 export-bfile-desc = 扩展数列数据（索引 值 对）
+export-latex = LaTeX
+export-latex-desc = 用于论文的项与排版公式
+export-bibtex = BibTeX
+export-bibtex-desc = 文献管理器的引用条目
+export-notebook = Jupyter
+export-notebook-desc = 包含数列项、Python 程序和图表的笔记本
+export-python = Python
+export-python-desc = 与笔记本单元相同的脚本
 export-bfile-not-loaded = B-file未加载 - 在详细视图中按'f'
 export-select-format = 选择格式
 export-cancel = 取消
@@ -178,7 +243,22 @@ webcam-category-best-desc = 有趣且值得注意的数列（关键词:nice）
 webcam-category-needing = 需要项
 webcam-category-needing-desc = 请求更多项的数列（关键词:more）
 webcam-category-recent = 最近添加
-webcam-category-recent-desc = 最近添加的数列（关键词:new）
+webcam-category-recent-desc = 最近添加或修改的数列（recent.txt）
+webcam-category-unedited = 未编辑
+webcam-category-unedited-desc = 尚未编辑的投稿（关键词:uned）
+webcam-category-core = 核心数列
+webcam-category-core-desc = 最基本的数列（关键词:core）
+webcam-category-easy = 简单数列
+webcam-category-easy-desc = 各项容易计算（关键词:easy）
+webcam-category-hard = 困难数列
+webcam-category-hard-desc = 各项难以计算（关键词:hard）
+webcam-category-unknown = 未知项
+webcam-category-unknown-desc = 所知甚少（关键词:unkn）
+webcam-category-bookmarks = 我的书签
+webcam-category-bookmarks-desc = 从书签中随机选取
+webcam-category-empty = 此类别中未找到数列
+webcam-no-bookmarks = 还没有书签。在详情视图中按 'b' 添加。
+webcam-load-error = 无法加载数列：{ $message }
 webcam-interval = 刷新间隔
 webcam-interval-manual = 手动
 webcam-interval-manual-desc = 按空格键前进
@@ -196,11 +276,14 @@ webcam-current-sequence = 当前数列
 webcam-no-sequence = 未加载数列
 webcam-load-first = 按空格键或回车键加载第一个数列
 webcam-refresh-in = 下次刷新倒计时 { $seconds } 秒...
+webcam-retry-in = { $seconds } 秒后重试
+webcam-loading = 正在获取第一个数列…
 webcam-more-comments = ... 以及另外 { $count } 条评论
 webcam-help = Space/Enter 下一个 | ←→ 类别 | ↑↓ 间隔 | 0-5 快速 | d 详情 | Esc 返回
 
 # Settings Screen
 settings-title = 设置
+settings-block-settings = 设置
 settings-language = 语言
 settings-language-desc = 选择界面语言
 settings-theme = 主题
@@ -228,6 +311,10 @@ help-global = 全局控制
 help-global-quit = 退出应用程序
 help-global-help = 显示/隐藏帮助
 help-global-back = 返回 / 取消
+help-global-about = 显示/隐藏“关于”窗口
+help-global-theme = 切换配色主题
+help-global-preset = 切换快捷键预设 (default、vim、emacs、helix)
+help-global-palette = 命令面板：搜索所有操作
 help-search = 搜索屏幕
 help-search-input = 开始搜索
 help-search-navigate = 导航结果
@@ -236,10 +323,20 @@ help-search-view = 查看选定的数列
 help-search-random = 随机数列
 help-search-preview = 切换预览面板
 help-search-preview-tabs = 切换预览标签
+help-search-preview-scroll = 滚动预览窗格
+help-search-history = 聚焦搜索历史
+help-search-focus = 下一个面板
+help-search-bookmarks = 显示书签
+help-search-settings = 设置
 help-search-mouse-select = 点击选择结果
 help-search-mouse-open = 双击打开结果
 help-search-mouse-scroll = 滚轮滚动预览/结果
 help-search-webcam = 网络摄像头模式
+help-search-export = 导出搜索结果、书签或历史记录
+help-welcome = 欢迎窗口
+help-welcome-search = 开始搜索
+help-welcome-close = 关闭欢迎窗口
+help-welcome-daily = 打开每日序列
 help-detail = 详细视图
 help-detail-links = 循环高亮链接
 help-detail-tabs = 切换标签
@@ -250,19 +347,131 @@ help-detail-graph = 查看图表
 help-detail-export = 导出数列
 help-detail-browser = 在浏览器中打开
 help-detail-bookmark = 切换书签
+help-detail-table = 三角形或阵列视图（tabl/tabf）
+help-detail-bfile = 获取 b-file
+help-detail-notebook = 保存为 Jupyter 笔记本
+help-detail-help = 显示/隐藏此窗口
+help-overview = 概述标签
+help-overview-places = 增加 / 减少常数的小数位数 (cons/cofr)
+help-overview-copy = 以小数形式复制常数
+help-code = 代码标签
+help-code-select = 选择程序
+help-code-copy = 复制程序
+help-code-verify = 在本地运行程序并校验数列项
+help-code-save = 将程序保存为源文件
 help-graph = 图表视图
-help-graph-types = 切换图表类型
-help-export = 导出屏幕
+help-graph-types = 选择图表类型（折线、散点、对数、针状、直方图、模 k、乌拉姆螺旋、差分、比值）
+help-graph-cycle = 循环切换图表类型
+help-graph-modulus = 减小 / 增大模 k 图中的 k
+help-graph-save = 将图表保存为 SVG 或 PNG
+help-graph-zoom = 放大 / 缩小（鼠标滚轮以指针为中心缩放）
+help-graph-pan = 平移放大的图表（也可用鼠标拖动）
+help-graph-reset = 重新显示全部项
+help-graph-listen = 将序列保存为音乐（MIDI 或 WAV），每项一个音符
+help-table = 表格视图
+help-table-move = 在单元格之间移动
+help-table-rows = 按页跳转 / 跳到首行或末行
+help-table-row-ends = 行首 / 行尾
+help-table-shape = 在三角形和反对角线阵列布局之间切换
+help-table-save = 将表格保存为 CSV
+help-export = 导出标签
 help-export-select = 选择格式
 help-export-quick = 快速格式选择
 help-export-clipboard = 导出到剪贴板
 help-export-file = 保存到文件
 help-webcam = 网络摄像头模式
 help-webcam-next = 加载下一个数列
-help-webcam-category = 切换类别
-help-webcam-interval = 更改刷新间隔
 help-webcam-quick = 快速间隔选择
 help-webcam-detail = 跳转到详细视图
+help-webcam-fullscreen = 切换全屏布局（仅显示数列和图表）
+help-webcam-panel = 在类别与间隔列表之间切换
+help-webcam-select = 选择类别或间隔
+help-settings = 设置屏幕
+help-settings-panel = 切换分区
+help-settings-select = 在列表中移动
+help-settings-apply = 应用所选选项
+help-settings-language = 快速选择语言
+help-modal = 帮助窗口
+help-modal-ends = 跳到顶部 / 底部
+help-keybinding-warnings = 快捷键问题 (settings.json)
+keybindings-conflict = 按键 { $key } 同时绑定到 { $first } 和 { $second }
+keybindings-unknown-context = 未知的快捷键分区“{ $context }”
+keybindings-bad-section = 分区“{ $context }”应将操作映射到按键列表
+keybindings-unknown-action = 分区“{ $context }”中有未知操作“{ $action }”
+keybindings-bad-key = 无法识别 { $context }.{ $action } 的按键“{ $key }”
+keybindings-preset-changed = 快捷键：{ $preset }
+keys-pending-title = 按键序列
+keys-pending-next = 接着按：{ $keys }
+palette-title = 命令面板
+palette-placeholder = 输入以搜索命令，或输入 A 编号直接跳转
+palette-no-matches = 没有匹配的命令
+palette-jump = 转到 { $number }
+announce-palette = 命令面板：{ $label }，第 { $position } 项，共 { $count } 项
+announce-searching = 正在搜索…
+announce-search-empty = 搜索框，空
+announce-search-input = 搜索框：{ $query }
+announce-result = 结果 第 { $position } 项，共 { $count } 项：{ $anumber }，{ $name }
+announce-history = 最近查看 第 { $position } 项，共 { $count } 项：{ $anumber }，{ $name }
+announce-bookmark = 书签 第 { $position } 项，共 { $count } 项：{ $anumber } { $name }
+announce-no-results = 无结果
+announce-sequence = { $anumber }，{ $name }，{ $tab } 标签页
+announce-reference = 引用 第 { $position } 项，共 { $count } 项：{ $anumber }
+announce-graph = { $anumber } 的 { $graph }
+announce-settings = { $section }：{ $item }
+announce-current = （当前）
+command-screen = 转到
+command-theme = 主题
+command-export-format = 导出格式
+command-graph-type = 图表
+command-bookmark = 书签
+action-quit = 退出
+action-help = 显示/隐藏帮助
+action-about = 显示/隐藏关于窗口
+action-cycle-theme = 下一个配色主题
+action-toggle-preset = 下一个快捷键预设
+action-back = 返回
+action-next-panel = 下一个面板
+action-previous-panel = 上一个面板
+action-save = 保存到文件
+action-copy = 复制到剪贴板
+action-focus-input = 聚焦搜索框
+action-focus-history = 聚焦搜索历史
+action-previous-page = 上一页结果
+action-next-page = 下一页结果
+action-random = 随机数列
+action-toggle-preview = 显示/隐藏预览
+action-toggle-bookmarks = 显示/隐藏书签
+action-open-webcam = 网络摄像头模式
+action-open-settings = 设置
+action-bulk-export = 导出结果、书签或历史
+action-open-daily = 每日数列
+action-detail-help = 详情视图快捷键
+action-next-tab = 下一个标签页
+action-previous-tab = 上一个标签页
+action-open-graph = 查看图表
+action-export-tab = 导出数列
+action-open-browser = 在浏览器中打开
+action-toggle-bookmark = 切换书签
+action-fetch-bfile = 获取 b-file
+action-open-table = 三角形或数组视图
+action-notebook = 保存为 Jupyter 笔记本
+action-more-places = 更多小数位
+action-fewer-places = 更少小数位
+action-next-snippet = 下一段代码
+action-previous-snippet = 上一段代码
+action-verify = 运行代码并与各项比较
+action-next-graph-type = 下一种图表类型
+action-previous-graph-type = 上一种图表类型
+action-modulus-up = 增大 k（mod k 图）
+action-modulus-down = 减小 k（mod k 图）
+action-zoom-in = 放大
+action-zoom-out = 缩小
+action-reset-view = 重新显示所有项
+action-listen = 保存为音乐（MIDI 或 WAV）
+action-cycle-shape = 切换表格布局
+action-next-sequence = 下一个数列
+action-open-detail = 在详情视图中打开
+action-toggle-fullscreen = 切换全屏
 
 # Common
 common-loading = 加载中...
@@ -309,6 +518,7 @@ search-help-bookmarks = 书签
 search-help-random = 随机
 search-help-webcam = 网络摄像头
 search-help-settings = 设置
+search-help-export = 导出
 search-help-help = 帮助
 search-help-quit = 退出
 search-help-click = 选择
@@ -324,7 +534,62 @@ graph-help-line = 折线
 graph-help-scatter = 散点
 graph-help-log = 对数
 graph-help-pin = 针图
+graph-help-save = 保存 SVG/PNG
+graph-help-next-type = 下一种类型
+graph-help-choose-type = 选择类型
+graph-help-modulus = 更改 k
+graph-help-zoom = 缩放
+graph-help-pan = 平移
+graph-help-reset = 重置视图
+graph-help-listen = 聆听
+graph-points-terms = { $count } 项
+graph-points-bfile = b-file 共 { $count } 项
 graph-help-back = 返回详细视图
+
+# Table View
+table-title = 表格视图
+table-shape-triangle = 按行读取的三角形
+table-shape-array-down = 按反对角线读取的方阵（向下）
+table-shape-array-up = 按反对角线读取的方阵（向上）
+table-source-data = { $rows } 行 · { $terms } 项
+table-source-bfile = { $rows } 行 · B-file 中的 { $terms } 项
+table-row-sum = 第 { $index } 行之和
+table-antidiagonal-sum = 反对角线 n + k = { $index } 之和
+table-sum-incomplete = 不完整
+table-shape-chosen = 手动选择的布局
+table-unknown-shape = 该不规则三角形的名称未给出各行长度。按 s 选择布局。
+table-not-available = 该序列不是三角形或阵列（无 tabl/tabf 关键字）。按 s 仍可按表格排列。
+table-help-move = 移动
+table-help-shape = 布局
+table-help-save = 保存 CSV
+table-preview = 表格形式（{ $shape }）：
+table-preview-hint = 按 a 打开带行和与 CSV 导出的表格视图
+
+# Listen Dialog
+listen-title = 聆听
+listen-instrument = 乐器
+listen-tempo = 速度
+listen-tempo-value = { $tempo } bpm
+listen-range = 音域
+listen-range-value = { $count } 个音
+listen-lowest = 最低音
+listen-instrument-piano = 钢琴
+listen-instrument-marimba = 马林巴
+listen-instrument-organ = 管风琴
+listen-instrument-guitar = 吉他
+listen-instrument-strings = 弦乐
+listen-instrument-flute = 长笛
+listen-instrument-synth = 合成器主音
+listen-summary = { $count } 个音符 · { $duration } · { $pitches }
+listen-summary-bfile = B-file 中的 { $count } 个音符 · { $duration } · { $pitches }
+listen-formats = 根据文件名保存为 MIDI (.mid) 或 WAV (.wav)
+listen-help-save = 保存…
+listen-unsupported-format = 不支持的音频格式；请使用 .mid 或 .wav 文件名
+listen-no-terms = 没有可播放的整数项
+constant-value = 精确到小数点后 { $places } 位的值：
+constant-convergents = 渐近分数：
+constant-hint = +/- 小数位数（最多 { $max } 位） | y 复制数值
+constant-copy-success = 常数已复制到剪贴板（小数点后 { $places } 位）
 webcam-sequence-offset = 偏移量
 webcam-sequence-keywords = 关键词
 webcam-sequence-data-title = 序列数据
@@ -334,11 +599,79 @@ webcam-help-category = 类别
 webcam-help-interval = 间隔
 webcam-help-quick = 快速选择
 webcam-help-detail = 详细视图
+webcam-help-fullscreen = 全屏
 webcam-help-back = 返回
 settings-block-themes = 主题
+themes-reloaded = 已重新加载主题
+theme-file-error = 主题文件 { $file }：{ $error }
 settings-block-animation = 欢迎动画
 settings-help-switch = 切换部分
 settings-help-navigate = 导航
 settings-help-apply = 应用
 settings-help-cycle-theme = 循环主题
 settings-help-back = 返回
+
+# Command Line
+cli-empty-query = 查询不能为空
+cli-results-for = “{ $query }”的结果：找到 { $count } 个
+cli-too-many-results = 没有可显示的结果（OEIS 返回的匹配过多）。
+cli-no-completion = 不支持 { $shell } 的补全
+cli-man-written = 手册页已写入 { $path }
+cli-not-found = 未找到数列 { $id }
+cli-random-title = 随机数列
+cli-random-unavailable = 目前没有可用的随机数列。
+cli-random-no-match = 没有符合这些筛选条件的随机数列。
+cli-daily-no-match = 没有与 { $query } 匹配的数列。
+cli-no-programs = { $anumber } 没有程序
+cli-no-language-program = { $anumber } 中没有 { $language } 程序（可用：{ $available }）
+cli-saved = 已保存 { $path }
+cli-more-programs = 还有 { $count } 个 { $language } 程序；使用 --save 全部写出
+cli-plot-saved = 已将 { $description }（{ $count } 项）保存到 { $path }
+cli-listen-saved = 已将 { $count } 个音符（{ $duration }，{ $tempo } bpm，{ $pitches }）保存到 { $path }
+cli-interval-invalid = `{ $interval }` 不是 30s、2m 或 1h 这样的间隔
+cli-interval-unit = 未知单位 `{ $unit }`（请使用 s、m 或 h）
+cli-interval-too-short = 间隔至少为 { $seconds } 秒
+cli-export-not-combined = { $format } 无法合并为一个文件；添加 --split 为每个数列写一个文件
+cli-skipped = 已跳过 { $count } 个无法获取的数列
+cli-verify-any-language = PARI/GP 或 Python
+cli-nothing-to-verify = { $anumber } 没有可验证的 { $language } 程序
+cli-verify-failures = { $count } 个程序中有 { $failures } 个无法验证
+cli-verify-timed-out = （{ $seconds } 秒后超时）
+cli-verify-match = { $count } 项一致
+cli-verify-match-timed-out = { $count } 项一致，但程序未结束
+cli-verify-mismatch = a({ $index }) = { $actual }，应为 { $expected }（{ $count } 项一致）
+cli-verify-partial = 仅输出 { $expected } 项中的 { $count } 项，全部一致
+cli-verify-no-output = 未输出任何项
+shell-welcome = OEIS shell。输入 help 查看命令，输入 quit 退出。
+shell-help-title = 命令（数列可以是 A 编号，或最后显示的、经 | 传入的数列）：
+shell-help-search = 搜索 OEIS（非命令的输入也会被搜索）
+shell-help-more = 下一页结果
+shell-help-show = 显示上次搜索的第 n 个结果或某个数列（也可只输入 A 编号）
+shell-help-terms = 条目中列出的项
+shell-help-bfile = b-file 的前几项（未指定数量时为 20）
+shell-help-plot = ASCII 图：line, scatter, log, pin, histogram, mod, ulam, diff, ratio
+shell-help-bookmark = 加入书签
+shell-help-unbookmark = 移除书签
+shell-help-bookmarks = 列出书签
+shell-help-diff = 逐项比较两个数列
+shell-help-help = 此列表
+shell-help-quit = 退出（也可用 exit 或 Ctrl+D）
+shell-help-pipe = `|` 把一个命令的数列传给下一个命令：search prime | terms
+shell-search-usage = 用法：search <查询>
+shell-nothing-to-page = 还没有可翻页的内容；请先搜索
+shell-no-such-result = 本页没有第 { $index } 个结果
+shell-bad-count = “{ $count }”不是条目数
+shell-bfile-entries = { $count } 项
+shell-unknown-graph = 未知的图表类型“{ $name }”；请参阅 help
+shell-bookmarked = 已将 { $anumber } 加入书签
+shell-unbookmarked = 已移除 { $anumber } 的书签
+shell-no-bookmarks = 还没有书签。
+shell-diff-usage = 用法：diff <A 编号> <A 编号>
+shell-diff-count = diff 比较的是两个数列，而不是 { $count } 个
+shell-no-results = 没有 { $query } 的结果。
+shell-results = { $query } 的结果，共 { $count } 个，第 { $first } 至 { $last } 个：
+shell-more-results = 输入 more 查看接下来的 { $count } 个结果。
+shell-which-sequence = 哪个数列？请给出 A 编号，或先用 show 显示一个
+shell-diff-disjoint = 列出的项之间没有共同的 n。
+shell-diff-same = n = { $first }..{ $last } 的 { $count } 项完全相同。
+shell-diff-different = n = { $first }..{ $last } 的 { $count } 项中有 { $agreeing } 项一致；第一个不同之处在 n = { $n }：{ $a } 与 { $b }
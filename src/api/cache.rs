@@ -3,11 +3,14 @@
 use super::models::{OEISResponse, Sequence};
 use anyhow::{Context, Result};
 use chrono::{DateTime, Duration, Utc};
-use rusqlite::{params, Connection};
-use std::path::PathBuf;
+use rusqlite::{params, Connection, OpenFlags};
+use std::path::{Path, PathBuf};
 
 use crate::utils::paths;
 
+/// Name of the database file in the config directory
+const DB_FILE: &str = "oeis_cache.db";
+
 /// SQLite-based cache for OEIS API responses
 pub struct Cache {
     conn: Connection,
@@ -19,7 +22,7 @@ impl Cache {
         let cache_dir = Self::cache_dir()?;
         std::fs::create_dir_all(&cache_dir)?;
 
-        let db_path = cache_dir.join("oeis_cache.db");
+        let db_path = cache_dir.join(DB_FILE);
 
         let conn = Connection::open(db_path).context("Failed to open cache database")?;
 
@@ -29,6 +32,37 @@ impl Cache {
         Ok(cache)
     }
 
+    /// Open the cache database read-only, creating nothing; `None` when there is none yet
+    pub fn open_read_only() -> Result<Option<Self>> {
+        Self::open_read_only_at(&Self::db_path()?)
+    }
+
+    fn open_read_only_at(db_path: &Path) -> Result<Option<Self>> {
+        if !db_path.is_file() {
+            return Ok(None);
+        }
+        let conn = Connection::open_with_flags(
+            db_path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )
+        .context("Failed to open cache database")?;
+        Ok(Some(Self { conn }))
+    }
+
+    fn db_path() -> Result<PathBuf> {
+        Ok(paths::config_dir()?.join(DB_FILE))
+    }
+
+    /// A cache that lives in memory only, so tests leave the user's database alone
+    #[cfg(test)]
+    pub fn in_memory() -> Result<Self> {
+        let cache = Self {
+            conn: Connection::open_in_memory()?,
+        };
+        cache.init_tables()?;
+        Ok(cache)
+    }
+
     /// Initialize database tables
     fn init_tables(&self) -> Result<()> {
         self.conn.execute(
@@ -76,6 +110,25 @@ impl Cache {
             [],
         )?;
 
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS daily_pick (
+                date TEXT NOT NULL,
+                query TEXT NOT NULL,
+                number INTEGER NOT NULL,
+                PRIMARY KEY (date, query)
+            )",
+            [],
+        )?;
+
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS metadata (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )",
+            [],
+        )?;
+
         Ok(())
     }
 
@@ -185,6 +238,80 @@ impl Cache {
         }
     }
 
+    /// A cached sequence however old the copy is, for names and other details that
+    /// rarely change
+    pub fn get_any_cached_sequence(&self, number: i32) -> Result<Option<Sequence>> {
+        let result = self.conn.query_row(
+            "SELECT data FROM sequence_detail WHERE number = ?1",
+            params![number],
+            |row| row.get::<_, String>(0),
+        );
+
+        match result {
+            Ok(data_json) => Ok(Some(
+                serde_json::from_str(&data_json).context("Failed to deserialize cached sequence")?,
+            )),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// The sequence of the day picked earlier for `date` (YYYY-MM-DD) from `query`
+    pub fn get_daily_sequence(&self, date: &str, query: &str) -> Result<Option<Sequence>> {
+        let number = self.conn.query_row(
+            "SELECT number FROM daily_pick WHERE date = ?1 AND query = ?2",
+            params![date, query],
+            |row| row.get::<_, i32>(0),
+        );
+
+        match number {
+            Ok(number) => self.get_cached_sequence(number, 30),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Remember the sequence of the day so later runs don't search again
+    pub fn cache_daily_sequence(&self, date: &str, query: &str, sequence: &Sequence) -> Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO daily_pick (date, query, number) VALUES (?1, ?2, ?3)",
+            params![date, query, sequence.number],
+        )?;
+        self.cache_sequence(sequence)
+    }
+
+    /// Highest A-number in use, if it was looked up in the last `max_age_days` days
+    pub fn get_upper_bound(&self, max_age_days: i64) -> Result<Option<i32>> {
+        let result = self.conn.query_row(
+            "SELECT value, updated_at FROM metadata WHERE key = 'upper_bound'",
+            [],
+            |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)),
+        );
+
+        match result {
+            Ok((value, updated_at)) => {
+                let updated = DateTime::parse_from_rfc3339(&updated_at)
+                    .context("Invalid updated_at timestamp")?
+                    .with_timezone(&Utc);
+                if Utc::now() - updated > Duration::days(max_age_days) {
+                    return Ok(None);
+                }
+                Ok(value.parse().ok())
+            }
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Remember the highest A-number in use so random picks don't look it up every time
+    pub fn cache_upper_bound(&self, bound: i32) -> Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO metadata (key, value, updated_at) VALUES ('upper_bound', ?1, ?2)",
+            params![bound.to_string(), Utc::now().to_rfc3339()],
+        )?;
+        Ok(())
+    }
+
     /// Add a search to history
     pub fn add_search_history(&self, query: &str) -> Result<()> {
         let now = Utc::now().to_rfc3339();
@@ -448,6 +575,45 @@ mod tests {
         assert_eq!(cached_seq.name, "Test sequence");
     }
 
+    #[test]
+    fn test_open_read_only() {
+        let dir = std::env::temp_dir().join(format!("oeis-cache-{}", std::process::id()));
+        let db_path = dir.join("oeis_cache.db");
+        assert!(Cache::open_read_only_at(&db_path).unwrap().is_none());
+        assert!(!dir.exists());
+
+        std::fs::create_dir_all(&dir).unwrap();
+        let cache = Cache { conn: Connection::open(&db_path).unwrap() };
+        cache.init_tables().unwrap();
+        cache.add_bookmark(45, None).unwrap();
+        drop(cache);
+
+        let cache = Cache::open_read_only_at(&db_path).unwrap().unwrap();
+        assert_eq!(cache.get_bookmarks().unwrap(), [(45, None)]);
+        assert!(cache.add_bookmark(27, None).is_err());
+        drop(cache);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_any_cached_sequence() {
+        let cache = Cache::in_memory().unwrap();
+        assert!(cache.get_any_cached_sequence(45).unwrap().is_none());
+
+        cache.cache_sequence(&create_test_sequence(45)).unwrap();
+        cache
+            .conn
+            .execute(
+                "UPDATE sequence_detail SET cached_at = ?1 WHERE number = 45",
+                params!["1990-01-01T00:00:00+00:00"],
+            )
+            .unwrap();
+
+        assert!(cache.get_cached_sequence(45, 30).unwrap().is_none());
+        let cached = cache.get_any_cached_sequence(45).unwrap().unwrap();
+        assert_eq!(cached.name, "Test sequence");
+    }
+
     #[test]
     fn test_search_history() {
         let cache = Cache::new().unwrap();
@@ -3,9 +3,15 @@
 use super::models::{BFileEntry, OEISResponse, SearchQuery, Sequence};
 use super::OEISSearchResponse;
 use anyhow::{Context, Result};
+use chrono::{Datelike, NaiveDate};
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use reqwest::Client;
+use once_cell::sync::Lazy;
+use regex::Regex;
 use serde_json;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 /// OEIS API client with async support
@@ -13,6 +19,10 @@ use std::time::Duration;
 pub struct OEISClient {
     client: Client,
     base_url: String,
+    /// Highest A-number in use once known, shared by all clones
+    upper_bound: Arc<Mutex<Option<i32>>>,
+    /// Result counts of the searches random picks were made from, shared by all clones
+    result_counts: Arc<Mutex<HashMap<String, usize>>>,
 }
 
 impl OEISClient {
@@ -27,6 +37,8 @@ impl OEISClient {
         Ok(Self {
             client,
             base_url: "https://oeis.org".to_string(),
+            upper_bound: Arc::new(Mutex::new(None)),
+            result_counts: Arc::new(Mutex::new(HashMap::new())),
         })
     }
 
@@ -42,7 +54,7 @@ impl OEISClient {
         } else if text_lower.contains("no results")
             || text_lower.contains("sorry, but the terms do not match")
         {
-            anyhow::anyhow!("No results found.")
+            anyhow::Error::new(NoResults)
         } else {
             anyhow::anyhow!("Unable to parse OEIS response")
         }
@@ -121,6 +133,45 @@ impl OEISClient {
         })
     }
 
+    /// Total number of entries `query` finds, read from the text format since the JSON
+    /// one doesn't say
+    pub async fn count_results(&self, query: &str) -> Result<usize> {
+        let url = SearchQuery::new(query).with_format("text").to_url();
+
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .context("Failed to send text format request to OEIS API")?;
+
+        if !response.status().is_success() {
+            anyhow::bail!("OEIS API returned error: {}", response.status());
+        }
+
+        let text = response
+            .text()
+            .await
+            .context("Failed to read text response from OEIS API")?;
+
+        parse_result_count(&text).ok_or_else(|| Self::extract_error_message(&text))
+    }
+
+    /// Number of 10-entry result pages of `query`; 0 when nothing matches. Counts are
+    /// looked up once per client.
+    async fn result_pages(&self, query: &str) -> Result<i32> {
+        let known = self.result_counts.lock().unwrap().get(query).copied();
+        let count = match known {
+            Some(count) => count,
+            None => {
+                let count = self.count_results(query).await?;
+                self.result_counts.lock().unwrap().insert(query.to_string(), count);
+                count
+            }
+        };
+        Ok(count.div_ceil(10).min(i32::MAX as usize) as i32)
+    }
+
     /// Get a single sequence by its A-number (e.g., "A000055" or "55")
     pub async fn get_sequence(&self, a_number: &str) -> Result<Option<Sequence>> {
         let number = a_number
@@ -142,6 +193,17 @@ impl OEISClient {
         }))
     }
 
+    /// Fetch several sequences by number, skipping any that fail or don't exist
+    pub async fn get_sequences(&self, numbers: &[i32]) -> Vec<Sequence> {
+        let mut sequences = Vec::with_capacity(numbers.len());
+        for number in numbers {
+            if let Ok(Some(seq)) = self.get_sequence(&format!("A{:06}", number)).await {
+                sequences.push(seq);
+            }
+        }
+        sequences
+    }
+
     /// Fetch B-file data (extended sequence values)
     pub async fn fetch_b_file(&self, sequence_number: i32) -> Result<Vec<BFileEntry>> {
         let url = format!("{}/b{:06}.txt", self.base_url, sequence_number);
@@ -188,42 +250,201 @@ impl OEISClient {
             .context("Failed to read recent.txt content")
     }
 
+    /// Numbers of the recently added or changed sequences listed in recent.txt, newest first
+    pub async fn recent_numbers(&self) -> Result<Vec<i32>> {
+        let numbers = parse_recent(&self.fetch_recent().await?);
+        if numbers.is_empty() {
+            anyhow::bail!("recent.txt lists no sequences");
+        }
+        Ok(numbers)
+    }
+
+    /// Highest A-number in use: remembered from an earlier call (or [`Self::set_upper_bound`]),
+    /// else the newest entry in recent.txt, else a fixed estimate
+    pub async fn upper_bound(&self) -> i32 {
+        if let Some(bound) = self.known_upper_bound() {
+            return bound;
+        }
+        match self.recent_numbers().await {
+            Ok(numbers) => {
+                let bound = numbers.into_iter().max().unwrap_or(0).max(DEFAULT_UPPER_BOUND);
+                self.set_upper_bound(bound);
+                bound
+            }
+            Err(_) => DEFAULT_UPPER_BOUND,
+        }
+    }
+
+    /// The upper bound found so far, if any
+    pub fn known_upper_bound(&self) -> Option<i32> {
+        *self.upper_bound.lock().unwrap()
+    }
+
+    /// Use a known upper bound (e.g. from the cache) instead of looking it up
+    pub fn set_upper_bound(&self, bound: i32) {
+        *self.upper_bound.lock().unwrap() = Some(bound);
+    }
+
     /// Get a random sequence
-    /// This is done by searching for a random keyword and picking a random result
     pub async fn random_sequence(&self) -> Result<Option<Sequence>> {
-        let mut rng = StdRng::from_entropy();
+        self.random_matching(&RandomFilter::default(), &mut StdRng::from_entropy())
+            .await
+    }
 
-        // Generate a random sequence number between 1 and 370000 (approximate OEIS size)
-        let random_num = rng.gen_range(1..370000);
+    /// Pick a random sequence that passes `filter`, trying again on missing ids and
+    /// entries that don't match. The same seeded `rng` gives the same pick as long as
+    /// the upper bound and the OEIS answers stay the same.
+    pub async fn random_matching(
+        &self,
+        filter: &RandomFilter,
+        rng: &mut StdRng,
+    ) -> Result<Option<Sequence>> {
+        let query = filter.query();
+        // Every page of the keyword search; still shrinks when a page past the end comes
+        // back empty, in case entries went away since the count
+        let mut pages = match query {
+            Some(ref query) => match self.result_pages(query).await {
+                Ok(0) => return Ok(None),
+                Ok(pages) => pages,
+                Err(_) => FALLBACK_PAGES,
+            },
+            None => 0,
+        };
+        let mut last_error = None;
+
+        for _ in 0..RANDOM_ATTEMPTS {
+            let (search, page) = match query {
+                Some(ref query) => {
+                    let page = rng.gen_range(0..pages);
+                    (SearchQuery::new(query.as_str()).with_start(page * 10), Some(page))
+                }
+                None => {
+                    let number = rng.gen_range(1..=self.upper_bound().await);
+                    (SearchQuery::new(format!("id:A{:06}", number)), None)
+                }
+            };
+
+            let mut candidates = match self.search(&search, 10).await {
+                Ok(response) => response.results.unwrap_or_default(),
+                Err(e) if is_no_results(&e) => Vec::new(),
+                Err(e) => {
+                    last_error = Some(e);
+                    continue;
+                }
+            };
+            if candidates.is_empty() {
+                match page {
+                    Some(0) => return Ok(None),
+                    Some(page) => pages = page,
+                    None => {}
+                }
+                continue;
+            }
 
-        let query = SearchQuery::new(format!("id:A{:06}", random_num));
-        let response = self.search(&query, 10).await?;
+            candidates.retain(|seq| filter.matches(seq));
+            if !candidates.is_empty() {
+                let index = rng.gen_range(0..candidates.len());
+                return Ok(Some(candidates.swap_remove(index)));
+            }
+        }
 
-        Ok(response.results.and_then(|mut results| {
+        // Only report an error when the OEIS never answered at all
+        match last_error {
+            Some(e) => Err(e),
+            None => Ok(None),
+        }
+    }
+
+    /// The sequence of the day: the same pick for everyone on `date` from the entries any
+    /// of `queries` finds
+    pub async fn daily_sequence(&self, date: NaiveDate, queries: &[String]) -> Result<Option<Sequence>> {
+        let mut counts = Vec::with_capacity(queries.len());
+        for query in queries {
+            counts.push(self.count_results(query).await?);
+        }
+        let Some((index, position)) = daily_position(daily_seed(date), &counts) else {
+            return Ok(None);
+        };
+
+        // A page past the end (entries went away since the count) falls back to the first
+        let query = &queries[index];
+        let start = (position / 10 * 10) as i32;
+        let results = match self.search(&SearchQuery::new(query).with_start(start), 10).await {
+            Ok(response) if response.results.as_ref().is_some_and(|r| !r.is_empty()) => {
+                response.results
+            }
+            Ok(_) | Err(_) if start > 0 => self.search(&SearchQuery::new(query), 10).await?.results,
+            Ok(response) => response.results,
+            Err(e) => return Err(e),
+        };
+
+        Ok(results.and_then(|mut results| {
             if results.is_empty() {
                 None
             } else {
-                Some(results.remove(0))
+                let index = position % 10 % results.len();
+                Some(results.swap_remove(index))
             }
         }))
     }
 
-    /// Fetch sequences by category (for webcam feature)
-    pub async fn fetch_by_category(&self, category: SequenceCategory) -> Result<OEISResponse> {
-        let query = match category {
-            SequenceCategory::Best => SearchQuery::new("keyword:nice"),
-            SequenceCategory::NeedingTerms => SearchQuery::new("keyword:more"),
-            SequenceCategory::Recent => {
-                // For recent, we could parse recent.txt, but for now just search new
-                SearchQuery::new("keyword:new")
-            }
-            SequenceCategory::Unedited => {
-                // Search for sequences with low revision count
-                SearchQuery::new("keyword:new")
+    /// Fetch a page of a search-based category (for webcam feature)
+    pub async fn fetch_by_category(
+        &self,
+        category: SequenceCategory,
+        start: i32,
+    ) -> Result<OEISResponse> {
+        let Some(query) = category.query() else {
+            anyhow::bail!("{} is not a search category", category.as_str());
+        };
+        self.search(&SearchQuery::new(query).with_start(start), 10)
+            .await
+    }
+
+    /// Pick a random sequence from a category.
+    /// `pool` holds the candidates of list-based categories (recent.txt, bookmarks).
+    pub async fn random_in_category(
+        &self,
+        category: SequenceCategory,
+        pool: &[i32],
+    ) -> Result<Option<Sequence>> {
+        let mut rng = StdRng::from_entropy();
+
+        if category == SequenceCategory::All {
+            return self.random_sequence().await;
+        }
+
+        let Some(query) = category.query() else {
+            if pool.is_empty() {
+                return Ok(None);
             }
+            let number = pool[rng.gen_range(0..pool.len())];
+            return self.get_sequence(&format!("A{:06}", number)).await;
         };
 
-        self.search(&query, 10).await
+        // Any page of the category; one past the end (the count can be out of date) falls
+        // back to the first
+        let pages = match self.result_pages(query).await {
+            Ok(0) => return Ok(None),
+            Ok(pages) => pages,
+            Err(_) => FALLBACK_PAGES,
+        };
+        let start = rng.gen_range(0..pages) * 10;
+        let response = match self.fetch_by_category(category, start).await {
+            Ok(response) if response.results.as_ref().is_some_and(|r| !r.is_empty()) => response,
+            Ok(_) | Err(_) if start > 0 => self.fetch_by_category(category, 0).await?,
+            Ok(response) => response,
+            Err(e) => return Err(e),
+        };
+
+        Ok(response.results.and_then(|mut results| {
+            if results.is_empty() {
+                None
+            } else {
+                let index = rng.gen_range(0..results.len());
+                Some(results.swap_remove(index))
+            }
+        }))
     }
 
     /// Fetch the OEIS greeting message
@@ -246,28 +467,227 @@ impl Default for OEISClient {
     }
 }
 
+/// Result pages a random pick spreads over when the number of results can't be looked up
+const FALLBACK_PAGES: i32 = 20;
+
+/// Error of a search that found nothing, as opposed to one that failed
+#[derive(Debug)]
+pub struct NoResults;
+
+impl fmt::Display for NoResults {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("No results found.")
+    }
+}
+
+impl std::error::Error for NoResults {}
+
+/// Rough number of OEIS entries, used until the real upper bound is known
+const DEFAULT_UPPER_BOUND: i32 = 370000;
+/// How long a looked-up upper bound is kept in the cache
+pub const UPPER_BOUND_MAX_AGE_DAYS: i64 = 7;
+/// Random ids or result pages tried before giving up
+const RANDOM_ATTEMPTS: usize = 10;
+
+/// Whether a search error just means nothing matched
+fn is_no_results(error: &anyhow::Error) -> bool {
+    error.is::<NoResults>()
+}
+
+static RESULT_COUNT_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"Showing \d+-\d+ of (\d+)").expect("valid regex"));
+
+/// Number of results in a text format answer: its "Showing 1-10 of 4837" line, or 0 when
+/// it says nothing was found
+fn parse_result_count(text: &str) -> Option<usize> {
+    if let Some(caps) = RESULT_COUNT_PATTERN.captures(text) {
+        return caps[1].parse().ok();
+    }
+    let lower = text.to_lowercase();
+    (lower.contains("no results") || lower.contains("sorry, but the terms do not match"))
+        .then_some(0)
+}
+
+/// Constraints on a random pick, e.g. `--keyword nice --min-terms 20 --exclude dead`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RandomFilter {
+    /// Keywords the entry must have
+    pub keywords: Vec<String>,
+    /// Keywords the entry must not have
+    pub exclude: Vec<String>,
+    /// Fewest terms the entry must list
+    pub min_terms: usize,
+}
+
+impl RandomFilter {
+    /// Whether `seq` passes the filter
+    pub fn matches(&self, seq: &Sequence) -> bool {
+        self.keywords.iter().all(|keyword| seq.has_keyword(keyword))
+            && !self.exclude.iter().any(|keyword| seq.has_keyword(keyword))
+            && seq.parse_data().len() >= self.min_terms
+    }
+
+    /// Search narrowing the candidates to the required keywords, if there are any
+    fn query(&self) -> Option<String> {
+        if self.keywords.is_empty() {
+            return None;
+        }
+        Some(
+            self.keywords
+                .iter()
+                .map(|keyword| format!("keyword:{}", keyword))
+                .collect::<Vec<_>>()
+                .join(" "),
+        )
+    }
+}
+
+/// Searches the sequence of the day is picked from by default: entries that are `nice`
+/// or `core` (one search each, since terms of a single OEIS search must all match)
+pub const DAILY_QUERIES: &[&str] = &["keyword:nice", "keyword:core"];
+
+/// Where the sequence of the day falls when the results of searches with `counts`
+/// entries are laid end to end: the search, and the position in its results. An entry
+/// two searches find is a little more likely to come up.
+pub fn daily_position(seed: u64, counts: &[usize]) -> Option<(usize, usize)> {
+    let total: usize = counts.iter().sum();
+    if total == 0 {
+        return None;
+    }
+    let mut position = (seed % total as u64) as usize;
+    for (index, &count) in counts.iter().enumerate() {
+        if position < count {
+            return Some((index, position));
+        }
+        position -= count;
+    }
+    None
+}
+
+/// Seed for the sequence of the day (SplitMix64 of the day number), the same on every
+/// platform and build so everyone gets the same pick
+pub fn daily_seed(date: NaiveDate) -> u64 {
+    let mut z = (date.num_days_from_ce() as u64).wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
 /// Categories of sequences for webcam feature
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SequenceCategory {
+    All,
     Best,
     NeedingTerms,
     Recent,
     Unedited,
+    Core,
+    Easy,
+    Hard,
+    Unknown,
+    Bookmarks,
 }
 
 impl SequenceCategory {
+    pub const ALL: [Self; 10] = [
+        Self::All,
+        Self::Best,
+        Self::NeedingTerms,
+        Self::Recent,
+        Self::Unedited,
+        Self::Core,
+        Self::Easy,
+        Self::Hard,
+        Self::Unknown,
+        Self::Bookmarks,
+    ];
+
     pub fn as_str(&self) -> &str {
         match self {
+            Self::All => "All Sequences",
             Self::Best => "Best Sequences",
             Self::NeedingTerms => "Sequences Needing More Terms",
             Self::Recent => "Recent Additions",
             Self::Unedited => "Unedited Sequences",
+            Self::Core => "Core Sequences",
+            Self::Easy => "Easy Sequences",
+            Self::Hard => "Hard Sequences",
+            Self::Unknown => "Sequences With Unknown Terms",
+            Self::Bookmarks => "Bookmarks",
         }
     }
 
     pub fn all() -> Vec<Self> {
-        vec![Self::Best, Self::NeedingTerms, Self::Recent, Self::Unedited]
+        Self::ALL.to_vec()
     }
+
+    /// The OEIS search behind the category, `None` for random and list-based ones
+    pub fn query(&self) -> Option<&'static str> {
+        match self {
+            Self::Best => Some("keyword:nice"),
+            Self::NeedingTerms => Some("keyword:more"),
+            Self::Unedited => Some("keyword:uned"),
+            Self::Core => Some("keyword:core"),
+            Self::Easy => Some("keyword:easy"),
+            Self::Hard => Some("keyword:hard"),
+            Self::Unknown => Some("keyword:unkn"),
+            Self::All | Self::Recent | Self::Bookmarks => None,
+        }
+    }
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            Self::All => "webcam-category-all",
+            Self::Best => "webcam-category-best",
+            Self::NeedingTerms => "webcam-category-needing",
+            Self::Recent => "webcam-category-recent",
+            Self::Unedited => "webcam-category-unedited",
+            Self::Core => "webcam-category-core",
+            Self::Easy => "webcam-category-easy",
+            Self::Hard => "webcam-category-hard",
+            Self::Unknown => "webcam-category-unknown",
+            Self::Bookmarks => "webcam-category-bookmarks",
+        }
+    }
+
+    pub fn description_key(&self) -> &'static str {
+        match self {
+            Self::All => "webcam-category-all-desc",
+            Self::Best => "webcam-category-best-desc",
+            Self::NeedingTerms => "webcam-category-needing-desc",
+            Self::Recent => "webcam-category-recent-desc",
+            Self::Unedited => "webcam-category-unedited-desc",
+            Self::Core => "webcam-category-core-desc",
+            Self::Easy => "webcam-category-easy-desc",
+            Self::Hard => "webcam-category-hard-desc",
+            Self::Unknown => "webcam-category-unknown-desc",
+            Self::Bookmarks => "webcam-category-bookmarks-desc",
+        }
+    }
+}
+
+/// A-numbers in recent.txt, in order of appearance and without repeats.
+/// Entries are in the internal format (`%N A123456 Name...`), so the first
+/// A-number on each `%` line names the sequence it belongs to.
+pub fn parse_recent(text: &str) -> Vec<i32> {
+    let mut seen = std::collections::HashSet::new();
+    text.lines()
+        .filter_map(|line| {
+            let mut tokens = line.split_whitespace();
+            let first = tokens.next()?;
+            let token = if first.starts_with('%') {
+                tokens.next()?
+            } else {
+                first
+            };
+            let digits = token.strip_prefix('A')?;
+            if digits.len() < 6 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            digits.parse::<i32>().ok()
+        })
+        .filter(|number| seen.insert(*number))
+        .collect()
 }
 
 #[cfg(test)]
@@ -310,4 +730,108 @@ mod tests {
             "Sequences Needing More Terms"
         );
     }
+
+    #[test]
+    fn test_no_results_is_typed() {
+        let error = OEISClient::extract_error_message("<p>Sorry, but the terms do not match anything</p>");
+        assert!(is_no_results(&error));
+        assert_eq!(error.to_string(), "No results found.");
+        let other = OEISClient::extract_error_message("Please refine your search");
+        assert!(!is_no_results(&other));
+        assert!(!is_no_results(&anyhow::anyhow!("No results found.")));
+    }
+
+    #[test]
+    fn test_parse_result_count() {
+        let text = "\
+# Greetings from The On-Line Encyclopedia of Integer Sequences! http://oeis.org/
+
+Search: keyword:nice
+Showing 1-10 of 8963
+
+%I A000001 #313 Jan 05 2025 10:31:07
+";
+        assert_eq!(parse_result_count(text), Some(8963));
+        assert_eq!(parse_result_count("Search: id:A000045\nShowing 1-1 of 1\n"), Some(1));
+        assert_eq!(parse_result_count("Search: keyword:zzz\nNo results.\n"), Some(0));
+        assert_eq!(parse_result_count("<html>Too many results</html>"), None);
+    }
+
+    #[test]
+    fn test_daily_seed() {
+        let day = NaiveDate::from_ymd_opt(2025, 1, 5).unwrap();
+        assert_eq!(daily_seed(day), daily_seed(day));
+        assert_ne!(daily_seed(day), daily_seed(day.succ_opt().unwrap()));
+        // Pinned so the pick can't silently change between releases
+        assert_eq!(daily_seed(day), 0xB7A1_CA09_F726_CCFB);
+    }
+
+    #[test]
+    fn test_daily_position() {
+        assert_eq!(daily_position(7, &[]), None);
+        assert_eq!(daily_position(7, &[0, 0]), None);
+        assert_eq!(daily_position(7, &[10, 5]), Some((0, 7)));
+        assert_eq!(daily_position(12, &[10, 5]), Some((1, 2)));
+        assert_eq!(daily_position(15, &[10, 5]), Some((0, 0)));
+        assert_eq!(daily_position(3, &[0, 5]), Some((1, 3)));
+        // Spread over every result, not just the first pages
+        let seed = daily_seed(NaiveDate::from_ymd_opt(2025, 1, 5).unwrap());
+        assert_eq!(daily_position(seed, &[9000, 180]), Some((0, (seed % 9180) as usize)));
+    }
+
+    #[test]
+    fn test_parse_recent() {
+        let text = "\
+# Recent additions and changes
+%I A380001 #12 Jan 05 2025 10:31:07
+%S A380001 1,2,4,8,16
+%N A380001 Powers of 2 (example).
+%I A000045 #900 Jan 04 2025 09:00:00
+%N A000045 Fibonacci numbers.
+
+A380001 listed again
+See A123 and www.oeis.org
+";
+        assert_eq!(parse_recent(text), vec![380001, 45]);
+        assert!(parse_recent("<html>Not found</html>").is_empty());
+    }
+
+    #[test]
+    fn test_random_filter() {
+        let seq: Sequence = serde_json::from_value(serde_json::json!({
+            "number": 45,
+            "data": "0,1,1,2,3,5,8,13",
+            "name": "Fibonacci numbers.",
+            "offset": "0,4",
+            "keyword": "core,nonn,nice,easy",
+            "author": "N. J. A. Sloane",
+            "created": "1991-04-30T03:00:00-04:00",
+            "time": "2024-01-01T00:00:00-05:00",
+            "revision": 1,
+            "references": 0
+        }))
+        .unwrap();
+
+        assert!(RandomFilter::default().matches(&seq));
+        assert_eq!(RandomFilter::default().query(), None);
+
+        let filter = RandomFilter {
+            keywords: vec!["nice".to_string(), "core".to_string()],
+            exclude: vec!["dead".to_string()],
+            min_terms: 8,
+        };
+        assert!(filter.matches(&seq));
+        assert_eq!(filter.query().as_deref(), Some("keyword:nice keyword:core"));
+
+        let longer = RandomFilter {
+            min_terms: 20,
+            ..filter.clone()
+        };
+        assert!(!longer.matches(&seq));
+        let not_easy = RandomFilter {
+            exclude: vec!["easy".to_string()],
+            ..filter
+        };
+        assert!(!not_easy.matches(&seq));
+    }
 }
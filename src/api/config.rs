@@ -4,6 +4,7 @@ use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    audio::SoundSettings,
     i18n::Language,
     ui::animation::WelcomeAnimationMode,
     utils::{
@@ -16,12 +17,36 @@ use crate::{
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct UserSettings {
     pub language: Option<String>,
-    pub theme: Option<usize>,
+    pub theme: Option<ThemeChoice>,
     pub welcome_animation: Option<String>,
     pub welcome_animation_played: Option<bool>,
     #[serde(default)]
     pub keybindings: Option<KeyBindingsConfig>,
     pub keybindings_preset: Option<String>,
+    /// Default directory for exported files (`~` is expanded; unset means the current directory)
+    pub export_dir: Option<String>,
+    /// Export filename template, e.g. `{anumber}-{slug}.{ext}` (see `utils::filename`)
+    pub export_filename_template: Option<String>,
+    /// Tempo, instrument and pitch mapping for `oeis listen` and the graph's listen dialog
+    pub listen: Option<SoundSettings>,
+    /// Start in the accessibility mode, as with `oeis --accessible`
+    pub accessible: Option<bool>,
+}
+
+/// The chosen theme. It is saved by name; older settings files have its position in
+/// the list of built-in themes. `"auto"`, like no theme at all, picks Light or Dark to
+/// suit the terminal background.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ThemeChoice {
+    Name(String),
+    Index(usize),
+}
+
+impl ThemeChoice {
+    pub fn is_auto(&self) -> bool {
+        matches!(self, Self::Name(name) if name.eq_ignore_ascii_case("auto"))
+    }
 }
 
 impl UserSettings {
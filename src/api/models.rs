@@ -180,6 +180,179 @@ impl BFileEntry {
     }
 }
 
+/// A single program taken from a sequence's Maple, Mathematica or program fields
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodeSnippet {
+    /// Language as tagged in the OEIS entry (e.g. "PARI", "Python")
+    pub language: String,
+    /// Program source without the language tag
+    pub code: String,
+}
+
+impl CodeSnippet {
+    /// Normalized language key used for lookups (e.g. "Python 3" -> "python", "PARI/GP" -> "pari")
+    pub fn language_key(&self) -> String {
+        normalize_language(&self.language)
+    }
+
+    /// Whether this snippet is written in `language` (case-insensitive, accepts common aliases)
+    pub fn matches_language(&self, language: &str) -> bool {
+        self.language_key() == normalize_language(language)
+    }
+
+    /// File extension for saving the snippet
+    pub fn extension(&self) -> &'static str {
+        match self.language_key().as_str() {
+            "pari" => "gp",
+            "python" => "py",
+            "haskell" => "hs",
+            "magma" => "mag",
+            "sage" => "sage",
+            "maple" => "mpl",
+            "mathematica" => "wl",
+            "gap" => "g",
+            "maxima" => "mac",
+            "julia" => "jl",
+            "scheme" => "scm",
+            "ruby" => "rb",
+            "perl" => "pl",
+            "java" => "java",
+            "c" => "c",
+            "rust" => "rs",
+            "matlab" | "octave" => "m",
+            _ => "txt",
+        }
+    }
+
+    /// Line comment prefix for the language, used for the file header
+    fn comment_prefix(&self) -> &'static str {
+        match self.language_key().as_str() {
+            "pari" => "\\\\",
+            "haskell" => "--",
+            "magma" | "java" | "c" | "rust" => "//",
+            "matlab" | "octave" => "%",
+            "scheme" => ";",
+            _ => "#",
+        }
+    }
+
+    /// Source file contents: a short provenance header followed by the code
+    pub fn to_source(&self, seq: &Sequence) -> String {
+        let header = if self.language_key() == "mathematica" {
+            format!("(* {}: {} *)\n(* {} *)\n", seq.a_number(), seq.name, seq.url())
+        } else {
+            let prefix = self.comment_prefix();
+            format!(
+                "{} {}: {}\n{} {}\n",
+                prefix,
+                seq.a_number(),
+                seq.name,
+                prefix,
+                seq.url()
+            )
+        };
+        format!("{}\n{}\n", header, self.code)
+    }
+
+    /// Suggested filename, e.g. `A000045.py` or `A000045-2.py` for a second Python snippet
+    pub fn filename(&self, seq: &Sequence, ordinal: usize) -> String {
+        if ordinal <= 1 {
+            format!("{}.{}", seq.a_number(), self.extension())
+        } else {
+            format!("{}-{}.{}", seq.a_number(), ordinal, self.extension())
+        }
+    }
+}
+
+fn normalize_language(language: &str) -> String {
+    let key: String = language
+        .trim()
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '+' || *c == '#')
+        .collect::<String>()
+        .to_ascii_lowercase();
+
+    match key.as_str() {
+        "gp" | "parigp" => "pari".to_string(),
+        "py" | "python3" => "python".to_string(),
+        "sagemath" => "sage".to_string(),
+        "mma" | "wolfram" => "mathematica".to_string(),
+        "hs" => "haskell".to_string(),
+        _ => key,
+    }
+}
+
+impl Sequence {
+    /// Split the code fields into individual snippets.
+    ///
+    /// Maple and Mathematica blocks become one snippet each; `program` lines are grouped by
+    /// their leading `(Language)` tag, with untagged lines continuing the previous snippet.
+    pub fn code_snippets(&self) -> Vec<CodeSnippet> {
+        let mut snippets = Vec::new();
+
+        if !self.maple.is_empty() {
+            snippets.push(CodeSnippet {
+                language: "Maple".to_string(),
+                code: self.maple.join("\n"),
+            });
+        }
+        if !self.mathematica.is_empty() {
+            snippets.push(CodeSnippet {
+                language: "Mathematica".to_string(),
+                code: self.mathematica.join("\n"),
+            });
+        }
+
+        let mut current: Option<CodeSnippet> = None;
+        for line in &self.program {
+            match parse_program_tag(line) {
+                Some((language, rest)) => {
+                    snippets.extend(current.take());
+                    current = Some(CodeSnippet {
+                        language: language.to_string(),
+                        code: rest.to_string(),
+                    });
+                }
+                None => match current.as_mut() {
+                    Some(snippet) => {
+                        if !snippet.code.is_empty() {
+                            snippet.code.push('\n');
+                        }
+                        snippet.code.push_str(line);
+                    }
+                    None => {
+                        current = Some(CodeSnippet {
+                            language: "Other".to_string(),
+                            code: line.clone(),
+                        });
+                    }
+                },
+            }
+        }
+        snippets.extend(current);
+
+        snippets
+    }
+}
+
+/// Split `(PARI) a(n)=...` into `("PARI", "a(n)=...")`
+fn parse_program_tag(line: &str) -> Option<(&str, &str)> {
+    let rest = line.strip_prefix('(')?;
+    let close = rest.find(')')?;
+    let language = &rest[..close];
+    let capitalized = language.chars().next().is_some_and(|c| c.is_ascii_uppercase());
+    // Tags are short capitalized names; anything else is an expression such as `(n-1)*a(n)`
+    if !capitalized
+        || language.len() > 24
+        || !language
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || " ./+#-".contains(c))
+    {
+        return None;
+    }
+    Some((language, rest[close + 1..].trim_start()))
+}
+
 /// Search query builder for OEIS API
 #[derive(Debug, Clone, Default)]
 pub struct SearchQuery {
@@ -321,4 +494,51 @@ mod tests {
         let prev = next.prev_page(10);
         assert_eq!(prev.start, 0);
     }
+
+    #[test]
+    fn test_code_snippets() {
+        let seq = Sequence {
+            number: 45,
+            id: String::new(),
+            data: String::new(),
+            name: "Fibonacci numbers".to_string(),
+            offset: "0,4".to_string(),
+            comment: vec![],
+            reference: vec![],
+            link: vec![],
+            formula: vec![],
+            example: vec![],
+            maple: vec!["A000045 := n -> combinat[fibonacci](n);".to_string()],
+            mathematica: vec![],
+            program: vec![
+                "(PARI) a(n)=fibonacci(n)".to_string(),
+                "(Python)".to_string(),
+                "def a(n):".to_string(),
+                "    return n if n < 2 else a(n-1) + a(n-2)".to_string(),
+                "(Haskell) fibs = 0 : 1 : zipWith (+) fibs (tail fibs)".to_string(),
+            ],
+            xref: vec![],
+            keyword: String::new(),
+            author: String::new(),
+            created: String::new(),
+            time: String::new(),
+            references: 0,
+            revision: 0,
+        };
+
+        let snippets = seq.code_snippets();
+        let languages: Vec<&str> = snippets.iter().map(|s| s.language.as_str()).collect();
+        assert_eq!(languages, vec!["Maple", "PARI", "Python", "Haskell"]);
+
+        let python = &snippets[2];
+        assert_eq!(python.code, "def a(n):\n    return n if n < 2 else a(n-1) + a(n-2)");
+        assert!(python.matches_language("py"));
+        assert_eq!(python.extension(), "py");
+        assert_eq!(python.filename(&seq, 1), "A000045.py");
+        assert!(python.to_source(&seq).starts_with("# A000045: Fibonacci numbers\n"));
+
+        assert!(snippets[1].matches_language("PARI/GP"));
+        assert_eq!(snippets[1].extension(), "gp");
+        assert!(parse_program_tag("(n-1)*a(n-1)").is_none());
+    }
 }
@@ -1,18 +1,31 @@
-use crate::api::{models::BFileEntry, Cache, OEISClient, OEISResponse, SearchQuery, Sequence, UserSettings};
+use crate::api::{client::{self, SequenceCategory, DAILY_QUERIES}, config::ThemeChoice, models::{BFileEntry, CodeSnippet}, Cache, OEISClient, OEISResponse, SearchQuery, Sequence, UserSettings};
+use crate::audio::{AudioError, AudioFormat, Sonification, SoundSettings};
+use crate::constant::Constant;
 use crate::i18n::{I18n, Language};
+use crate::palette::{Command, CommandPalette, PaletteEntry};
+use crate::plot::{self, Plot, PlotError, PlotFormat, Series, Viewport};
+use crate::table::{NumberTable, TableShape};
+use crate::verify::{self, Interpreter, VerifyReport};
 use crate::ui::{
     self,
     animation::{WelcomeAnimation, WelcomeAnimationMode},
     Theme,
 };
-use crate::utils::{keybindings::KeyBindings, parse_search_terms};
+use crate::utils::{
+    color::{self, Background, ColorSupport},
+    filename,
+    keybindings::{Action, KeyBindingWarning, KeyBindings, KeyChord, KeyContext, PRESETS},
+    parse_search_terms, paths,
+    themes::{self, ThemeFileError},
+};
 use anyhow::Result;
 use crossterm::event::{
     self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent,
     MouseEventKind,
 };
 use ratatui::{layout::Rect, widgets::Block, Frame};
-use std::time::{Duration, Instant};
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime};
 use tokio::task::{JoinError, JoinHandle};
 
 const DOUBLE_CLICK_THRESHOLD_MS: u64 = 400;
@@ -20,6 +33,37 @@ const SCROLL_LINES: u16 = 3;
 const PREVIEW_SCROLL_LINES: u16 = 3;
 const DETAIL_TAB_COUNT: usize = 8;
 const PREVIEW_TAB_COUNT: usize = 6;
+/// How many recently viewed sequences a history export includes
+const BULK_HISTORY_LIMIT: usize = 100;
+/// Visible-span factor for one `+` press (and `-` zooms back out by its inverse)
+const GRAPH_ZOOM_STEP: f64 = 0.5;
+/// Gentler zoom per mouse-wheel notch
+const GRAPH_WHEEL_ZOOM: f64 = 0.8;
+/// Share of the visible width moved by one pan key press
+const GRAPH_PAN_STEP: f64 = 0.25;
+/// Largest k offered by the residue graph
+const MAX_GRAPH_MODULUS: u32 = 1000;
+/// Decimal places of a `cons`/`cofr` constant shown at first, and per `+`/`-` press
+const DEFAULT_CONSTANT_PLACES: usize = 30;
+const CONSTANT_PLACES_STEP: usize = 10;
+/// Beats per minute added or removed by one key press in the listen dialog
+const LISTEN_TEMPO_STEP: u32 = 10;
+/// How often the themes directory is checked for added, removed or edited files
+const THEME_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// How long to wait for the terminal to say what its background colour is
+const BACKGROUND_QUERY_TIMEOUT: Duration = Duration::from_millis(200);
+/// How long the downloaded recent.txt list is reused by the webcam
+const RECENT_LIST_TTL: Duration = Duration::from_secs(600);
+/// Wait after the first failed webcam fetch; it doubles with each further failure
+const WEBCAM_RETRY_MIN: Duration = Duration::from_secs(5);
+const WEBCAM_RETRY_MAX: Duration = Duration::from_secs(300);
+
+/// Wait before the next webcam fetch after `failures` failed ones in a row: doubling from
+/// `WEBCAM_RETRY_MIN` up to `WEBCAM_RETRY_MAX`
+fn webcam_retry_delay(failures: u32) -> Duration {
+    let factor = 1u32 << failures.saturating_sub(1).min(16);
+    WEBCAM_RETRY_MIN.saturating_mul(factor).min(WEBCAM_RETRY_MAX)
+}
 pub const PLACEHOLDER_EXAMPLES: &[&str] = &[
     "1,2,3,4",
     "id:A000045",
@@ -38,6 +82,8 @@ pub enum Screen {
     Detail,
     /// Graph visualization
     Graph,
+    /// Triangle or array layout of a `tabl`/`tabf` sequence
+    Table,
     /// Webcam mode (auto-refreshing sequences)
     Webcam,
     /// Settings screen
@@ -120,6 +166,10 @@ pub struct App {
     pub show_welcome_modal: bool,
     /// Has the welcome modal been dismissed before
     pub welcome_dismissed: bool,
+    /// Sequence of the day shown on the welcome modal
+    pub daily_sequence: Option<Sequence>,
+    /// Welcome modal line with the sequence of the day, for mouse clicks
+    pub daily_area: Option<Rect>,
     /// Help modal visibility
     pub help_modal_visible: bool,
     /// About modal visibility
@@ -170,28 +220,71 @@ pub struct App {
     pub detail_tabs_area: Option<Rect>,
     /// Cached detail content area for mouse interactions
     pub detail_content_area: Option<Rect>,
+    /// Selected program snippet on the Code tab
+    pub code_snippet_index: usize,
+    /// Result of the last local program verification
+    pub code_verification: Option<CodeVerification>,
+    /// Snippet awaiting a second `v` press before it is run locally
+    verify_armed: Option<(i32, usize)>,
 
     // Graph state
     /// Graph type for visualization
     pub graph_type: GraphType,
+    /// k for the residue (mod k) graph
+    pub graph_modulus: u32,
+    /// Zoomed n-range of the graph
+    pub graph_viewport: Viewport,
+    /// Plotting area of the graph (set during render), for mouse zoom and pan
+    pub graph_area: Option<Rect>,
+    /// Column where a mouse drag on the graph last was
+    graph_drag: Option<u16>,
+
+    // Table view state
+    /// Layout chosen with `s` on the table screen; None follows the keywords and name
+    pub table_shape: Option<TableShape>,
+    /// Selected cell of the table view as (row, column)
+    pub table_cursor: (usize, usize),
+    /// First row and column shown by the table view (kept around the cursor during render)
+    pub table_offset: (usize, usize),
+    /// Decimal places requested for the Overview's constant value
+    pub constant_places: usize,
 
     // Webcam state
     /// Webcam refresh interval in seconds
     pub webcam_interval: Option<WebcamInterval>,
     /// Last webcam update time
     pub webcam_last_update: Option<std::time::Instant>,
-    /// Webcam category
+    /// Webcam category (index into `SequenceCategory::ALL`)
     pub webcam_category: usize,
+    /// Sequence numbers from recent.txt, with the time they were fetched
+    pub webcam_recent: Option<(Instant, Vec<i32>)>,
     /// Focused region in webcam mode
     pub webcam_focus: WebcamFocus,
     /// Scroll offset for webcam category list
     pub webcam_category_scroll: u16,
     /// Scroll offset for webcam interval list
     pub webcam_interval_scroll: u16,
+    /// Started by `oeis webcam`: leaving the webcam quits
+    pub webcam_kiosk: bool,
+    /// Distraction-free webcam layout with a chart of each sequence
+    pub webcam_fullscreen: bool,
+    /// Next sequence, fetched while the current one is on screen
+    webcam_prefetched: Option<(SequenceCategory, Sequence)>,
+    /// Webcam fetch running in the background
+    pending_webcam: Option<PendingWebcam>,
+    /// Failed webcam fetches in a row, and when the next attempt may start
+    pub webcam_failures: u32,
+    pub webcam_retry_at: Option<Instant>,
 
     // Export state
     /// Export format
     pub export_format: ExportFormat,
+    /// Bulk export dialog (search screen), when open
+    pub bulk_export: Option<BulkExportDialog>,
+    /// Save-as prompt for a single export, when open
+    pub save_as: Option<SaveAsPrompt>,
+    /// Sonification settings dialog (graph view), when open
+    pub listen_dialog: Option<ListenDialog>,
 
     // Help state
     /// Scroll position in help screen
@@ -220,6 +313,16 @@ pub struct App {
     // Theme state
     pub themes: Vec<Theme>,
     pub active_theme: usize,
+    /// Where user theme files are read from
+    themes_dir: Option<PathBuf>,
+    /// Theme files and their modification times when the themes were last loaded
+    theme_files: Vec<(PathBuf, Option<SystemTime>)>,
+    themes_checked: Instant,
+    /// Colours the terminal can show; frames are converted to them before drawing
+    color_support: ColorSupport,
+    /// Accessibility mode: no animations, the high-contrast theme, and the focus
+    /// described on the bottom line (see `ui::announce`)
+    pub accessible: bool,
     /// Current welcome animation mode
     pub welcome_animation_mode: WelcomeAnimationMode,
     /// User-selected welcome animation mode (for persistence)
@@ -234,6 +337,14 @@ pub struct App {
     keybindings: KeyBindings,
     /// Current keybindings preset (e.g., "vim")
     keybindings_preset: Option<String>,
+    /// Keys of a sequence typed so far (`g` of `g g`); the whole sequence while it runs
+    key_sequence: Vec<KeyChord>,
+    /// Last key of an unfinished sequence and when it was pressed
+    pending_key: Option<(KeyEvent, Instant)>,
+    /// Command palette, while it is open
+    pub command_palette: Option<CommandPalette>,
+    /// Action picked in the command palette; it stands in for the typed keys while it runs
+    command_action: Option<(KeyContext, Action)>,
 
     // Animation state
     /// Spinner animation frame counter
@@ -248,6 +359,11 @@ pub struct App {
     pending_random: Option<PendingRandom>,
     /// Active background B-file fetch task (if any)
     pub pending_bfile: Option<PendingBFile>,
+    /// Active local program verification (if any)
+    pending_verify: Option<PendingVerify>,
+    pending_daily: Option<PendingDaily>,
+    /// Active bulk export fetching uncached sequences (if any)
+    pending_bulk_export: Option<PendingBulkExport>,
     /// B-file data for current sequence
     pub bfile_data: Option<Vec<BFileEntry>>,
     /// B-file error message
@@ -267,6 +383,76 @@ pub enum GraphType {
     Scatter,
     LogScatter,
     PinPlot,
+    /// How often each value occurs
+    Histogram,
+    /// a(n) mod k against n
+    ModK,
+    /// Members of the sequence marked on an Ulam spiral of the integers
+    UlamSpiral,
+    /// First differences a(n) - a(n-1)
+    Differences,
+    /// Successive ratios a(n) / a(n-1)
+    Ratio,
+}
+
+impl GraphType {
+    pub const ALL: [Self; 9] = [
+        Self::Line,
+        Self::Scatter,
+        Self::LogScatter,
+        Self::PinPlot,
+        Self::Histogram,
+        Self::ModK,
+        Self::UlamSpiral,
+        Self::Differences,
+        Self::Ratio,
+    ];
+
+    /// Human-readable name used in exported figures
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Line => "Line chart",
+            Self::Scatter => "Scatter plot",
+            Self::LogScatter => "Logarithmic scatter plot",
+            Self::PinPlot => "Pin plot",
+            Self::Histogram => "Histogram of values",
+            Self::ModK => "Residues mod k",
+            Self::UlamSpiral => "Ulam spiral",
+            Self::Differences => "First differences",
+            Self::Ratio => "Ratio of successive terms",
+        }
+    }
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            Self::Line => "graph-line",
+            Self::Scatter => "graph-scatter",
+            Self::LogScatter => "graph-log",
+            Self::PinPlot => "graph-pin",
+            Self::Histogram => "graph-histogram",
+            Self::ModK => "graph-modk",
+            Self::UlamSpiral => "graph-ulam",
+            Self::Differences => "graph-differences",
+            Self::Ratio => "graph-ratio",
+        }
+    }
+
+    /// Whether the horizontal axis is the index n (and so can be zoomed and panned)
+    pub fn is_indexed(&self) -> bool {
+        !matches!(self, Self::Histogram | Self::UlamSpiral)
+    }
+
+    pub fn index(&self) -> usize {
+        Self::ALL.iter().position(|t| t == self).unwrap_or(0)
+    }
+
+    pub fn next(&self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()].clone()
+    }
+
+    pub fn previous(&self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()].clone()
+    }
 }
 
 /// Webcam refresh intervals
@@ -278,12 +464,29 @@ pub enum WebcamInterval {
     TwentySeconds,
     ThirtySeconds,
     OneMinute,
+    /// Any other interval, from `oeis webcam --interval`
+    Custom(Duration),
 }
 
 impl WebcamInterval {
+    /// The preset matching `duration`, if there is one
+    pub fn from_duration(duration: Duration) -> Self {
+        [
+            Self::FiveSeconds,
+            Self::TenSeconds,
+            Self::TwentySeconds,
+            Self::ThirtySeconds,
+            Self::OneMinute,
+        ]
+        .into_iter()
+        .find(|preset| preset.as_duration() == Some(duration))
+        .unwrap_or(Self::Custom(duration))
+    }
+
     pub fn as_duration(&self) -> Option<Duration> {
         match self {
             Self::Manual => None,
+            Self::Custom(duration) => Some(*duration),
             Self::FiveSeconds => Some(Duration::from_secs(5)),
             Self::TenSeconds => Some(Duration::from_secs(10)),
             Self::TwentySeconds => Some(Duration::from_secs(20)),
@@ -301,9 +504,29 @@ pub enum ExportFormat {
     Txt,
     Markdown,
     BFile,
+    Latex,
+    BibTex,
+    Notebook,
+    PythonScript,
 }
 
 impl ExportFormat {
+    const ALL: [Self; 9] = [
+        Self::Json,
+        Self::Csv,
+        Self::Txt,
+        Self::Markdown,
+        Self::BFile,
+        Self::Latex,
+        Self::BibTex,
+        Self::Notebook,
+        Self::PythonScript,
+    ];
+
+    pub fn all() -> &'static [Self; 9] {
+        &Self::ALL
+    }
+
     pub fn as_str(&self) -> &str {
         match self {
             Self::Json => "JSON",
@@ -311,6 +534,10 @@ impl ExportFormat {
             Self::Txt => "TXT",
             Self::Markdown => "Markdown",
             Self::BFile => "B-file",
+            Self::Latex => "LaTeX",
+            Self::BibTex => "BibTeX",
+            Self::Notebook => "Jupyter",
+            Self::PythonScript => "Python",
         }
     }
 
@@ -321,10 +548,205 @@ impl ExportFormat {
             Self::Txt => "txt",
             Self::Markdown => "md",
             Self::BFile => "txt",
+            Self::Latex => "tex",
+            Self::BibTex => "bib",
+            Self::Notebook => "ipynb",
+            Self::PythonScript => "py",
+        }
+    }
+
+    fn index(&self) -> usize {
+        Self::ALL.iter().position(|f| f == self).unwrap_or(0)
+    }
+
+    /// Next format in the Export tab list (wraps around).
+    pub fn next(&self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()].clone()
+    }
+
+    /// Previous format in the Export tab list (wraps around).
+    pub fn previous(&self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()].clone()
+    }
+
+    /// Whether several sequences can share one file in this format
+    pub fn supports_combined(&self) -> bool {
+        !matches!(self, Self::BFile | Self::Notebook | Self::PythonScript)
+    }
+}
+
+/// Collection of sequences covered by a bulk export
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BulkSource {
+    SearchResults,
+    Bookmarks,
+    History,
+}
+
+impl BulkSource {
+    const ALL: [Self; 3] = [Self::SearchResults, Self::Bookmarks, Self::History];
+
+    /// Base name of the exported file or directory
+    pub fn file_stem(&self) -> &'static str {
+        match self {
+            Self::SearchResults => "search-results",
+            Self::Bookmarks => "bookmarks",
+            Self::History => "history",
+        }
+    }
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            Self::SearchResults => "bulk-export-source-results",
+            Self::Bookmarks => "bulk-export-source-bookmarks",
+            Self::History => "bulk-export-source-history",
+        }
+    }
+
+    fn index(&self) -> usize {
+        Self::ALL.iter().position(|s| s == self).unwrap_or(0)
+    }
+
+    pub fn next(&self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    pub fn previous(&self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// How a bulk export is laid out on disk
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BulkLayout {
+    /// One document containing every sequence
+    Combined,
+    /// A directory with one file per sequence
+    PerSequence,
+}
+
+impl BulkLayout {
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            Self::Combined => "bulk-export-layout-combined",
+            Self::PerSequence => "bulk-export-layout-files",
+        }
+    }
+
+    pub fn toggle(&self) -> Self {
+        match self {
+            Self::Combined => Self::PerSequence,
+            Self::PerSequence => Self::Combined,
         }
     }
 }
 
+/// Focused row of the bulk export dialog
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BulkExportField {
+    Source,
+    Format,
+    Layout,
+    Directory,
+}
+
+impl BulkExportField {
+    const ALL: [Self; 4] = [Self::Source, Self::Format, Self::Layout, Self::Directory];
+
+    fn index(&self) -> usize {
+        Self::ALL.iter().position(|f| f == self).unwrap_or(0)
+    }
+
+    pub fn next(&self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    pub fn previous(&self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// State of the bulk export dialog opened from the search screen
+#[derive(Debug, Clone)]
+pub struct BulkExportDialog {
+    pub source: BulkSource,
+    pub format: ExportFormat,
+    pub layout: BulkLayout,
+    /// Output directory as typed by the user (`~` is expanded on export)
+    pub directory: String,
+    pub field: BulkExportField,
+}
+
+/// State of the listen dialog opened from the graph view
+#[derive(Debug, Clone)]
+pub struct ListenDialog {
+    pub settings: SoundSettings,
+    pub field: ListenField,
+}
+
+/// Focused row of the listen dialog
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ListenField {
+    Instrument,
+    Tempo,
+    Range,
+    LowestNote,
+}
+
+impl ListenField {
+    const ALL: [Self; 4] = [Self::Instrument, Self::Tempo, Self::Range, Self::LowestNote];
+
+    fn index(&self) -> usize {
+        Self::ALL.iter().position(|f| f == self).unwrap_or(0)
+    }
+
+    pub fn next(&self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    pub fn previous(&self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// Save-as prompt shown before an export is written to disk
+#[derive(Debug, Clone)]
+pub struct SaveAsPrompt {
+    /// Target path as typed by the user (`~` is expanded on save)
+    pub input: String,
+    /// Suggested file name, used when the input names a directory
+    default_name: String,
+    /// What will be written
+    content: SaveAsContent,
+    /// Tab completion candidates for the current input
+    pub completions: Vec<String>,
+    /// Candidate currently shown while cycling with Tab
+    pub completion_index: Option<usize>,
+    /// Existing file waiting for an overwrite confirmation
+    pub confirm_overwrite: Option<std::path::PathBuf>,
+}
+
+/// Data behind a save-as prompt
+#[derive(Debug, Clone)]
+enum SaveAsContent {
+    /// Ready-made file contents
+    Text(String),
+    /// A graph, rendered as SVG or PNG depending on the chosen extension
+    Plot(Box<Plot>),
+    /// Notes, written as MIDI or WAV depending on the chosen extension
+    Audio(Box<Sonification>),
+}
+
+/// Bulk export waiting for sequences that were not in the cache
+struct PendingBulkExport {
+    handle: JoinHandle<Vec<Sequence>>,
+    dialog: BulkExportDialog,
+    /// Requested A-numbers, in export order
+    order: Vec<i32>,
+    /// Sequences already available from the cache
+    cached: Vec<Sequence>,
+}
+
 /// Different search flows that run in the background
 enum PendingSearchKind {
     /// Fresh search from user input
@@ -353,11 +775,61 @@ pub struct PendingBFile {
     started_at: Instant,
 }
 
+struct PendingVerify {
+    handle: JoinHandle<anyhow::Result<VerifyReport>>,
+}
+
+struct PendingDaily {
+    handle: JoinHandle<anyhow::Result<Option<Sequence>>>,
+    date: chrono::NaiveDate,
+}
+
+struct PendingWebcam {
+    handle: JoinHandle<anyhow::Result<WebcamFetch>>,
+    category: SequenceCategory,
+    /// Show the sequence as soon as it arrives (otherwise keep it for the next refresh)
+    show: bool,
+}
+
+/// Result of a background webcam fetch
+struct WebcamFetch {
+    sequence: Option<Sequence>,
+    /// recent.txt numbers, when they had to be downloaded again
+    recent: Option<Vec<i32>>,
+}
+
+/// How `oeis webcam` opens the webcam
+#[derive(Debug, Clone, Copy)]
+pub struct WebcamLaunch {
+    pub category: SequenceCategory,
+    pub interval: WebcamInterval,
+    pub fullscreen: bool,
+}
+
+/// Verification state of a Code tab program
+#[derive(Debug, Clone)]
+pub enum VerifyStatus {
+    Running,
+    Finished(VerifyReport),
+    Failed(String),
+}
+
+/// Which program a verification result belongs to
+#[derive(Debug, Clone)]
+pub struct CodeVerification {
+    pub number: i32,
+    pub snippet_index: usize,
+    pub status: VerifyStatus,
+}
+
 impl App {
     /// Create a new application instance
     pub async fn new() -> Result<Self> {
         let client = OEISClient::new()?;
         let cache = Cache::new()?;
+        if let Ok(Some(bound)) = cache.get_upper_bound(client::UPPER_BOUND_MAX_AGE_DAYS) {
+            client.set_upper_bound(bound);
+        }
         let mut i18n = I18n::new();
         let mut settings_store = UserSettings::load();
 
@@ -369,26 +841,21 @@ impl App {
         // Load bookmarks before moving cache into struct
         let bookmarks = cache.get_bookmarks().unwrap_or_default();
 
-        let themes = vec![
-            Theme::dark(),
-            Theme::light(),
-            Theme::dracula(),
-            Theme::nord(),
-            Theme::gruvbox(),
-            Theme::solarized_dark(),
-            Theme::solarized_light(),
-            Theme::monokai(),
-            Theme::catppuccin_mocha(),
-            Theme::one_dark(),
-            Theme::night_owl(),
-            Theme::phosphor_night(),
-            Theme::punchcard_light(),
-            Theme::terminal_trove(),
-        ];
-        let active_theme = settings_store
-            .theme
-            .unwrap_or(0)
-            .min(themes.len().saturating_sub(1));
+        let themes_dir = paths::config_dir()
+            .ok()
+            .map(|dir| dir.join(themes::THEMES_DIR));
+        let (themes, theme_errors, theme_files) = match &themes_dir {
+            Some(dir) => {
+                let theme_files = themes::theme_files(dir);
+                let (themes, errors) = themes::load_themes(dir, Theme::builtin());
+                (themes, errors, theme_files)
+            }
+            None => (Theme::builtin(), Vec::new(), Vec::new()),
+        };
+        let active_theme = match settings_store.theme.as_ref() {
+            Some(choice) if !choice.is_auto() => theme_position(&themes, choice).unwrap_or(0),
+            _ => auto_theme(&themes),
+        };
         let welcome_animation_user_pref = settings_store
             .welcome_animation_mode()
             .unwrap_or(WelcomeAnimationMode::Off);
@@ -412,7 +879,8 @@ impl App {
         }
         let keybindings = settings_store.keybindings();
         let mut settings_selected_language = 0;
-        if let Some(lang) = settings_store.language() {
+        // Until a language is chosen, the locale's is used (and not saved)
+        if let Some(lang) = settings_store.language().or_else(Language::from_env) {
             i18n.set_language(lang);
             settings_selected_language =
                 Language::all().iter().position(|l| *l == lang).unwrap_or(0);
@@ -441,6 +909,8 @@ impl App {
             search_preview_tabs_area: None,
             searching: false,
             show_welcome_modal: true,
+            daily_sequence: None,
+            daily_area: None,
             welcome_dismissed: false,
             help_modal_visible: false,
             about_modal_visible: false,
@@ -461,18 +931,39 @@ impl App {
             current_sequence: None,
             detail_tab: 0,
             detail_scroll: 0,
+            code_snippet_index: 0,
+            code_verification: None,
+            verify_armed: None,
             detail_references: Vec::new(),
             detail_reference_index: None,
             detail_tabs_area: None,
             detail_content_area: None,
             graph_type: GraphType::Line,
+            graph_modulus: plot::DEFAULT_MODULUS,
+            graph_viewport: Viewport::default(),
+            graph_area: None,
+            graph_drag: None,
+            table_shape: None,
+            table_cursor: (0, 0),
+            table_offset: (0, 0),
+            constant_places: DEFAULT_CONSTANT_PLACES,
             webcam_interval: None,
             webcam_last_update: None,
             webcam_category: 0,
+            webcam_recent: None,
             webcam_focus: WebcamFocus::Categories,
             webcam_category_scroll: 0,
             webcam_interval_scroll: 0,
+            webcam_kiosk: false,
+            webcam_fullscreen: false,
+            webcam_prefetched: None,
+            pending_webcam: None,
+            webcam_failures: 0,
+            webcam_retry_at: None,
             export_format: ExportFormat::Json,
+            bulk_export: None,
+            listen_dialog: None,
+            save_as: None,
             help_scroll: 0,
             detail_help_visible: false,
             detail_help_scroll: 0,
@@ -485,6 +976,11 @@ impl App {
             settings_animation_scroll: 0,
             themes,
             active_theme,
+            themes_dir,
+            theme_files,
+            themes_checked: Instant::now(),
+            color_support: ColorSupport::detect(),
+            accessible: false,
             welcome_animation_mode,
             welcome_animation_user_pref,
             welcome_animation_temp_active,
@@ -492,12 +988,19 @@ impl App {
             settings_store,
             keybindings,
             keybindings_preset,
+            key_sequence: Vec::new(),
+            pending_key: None,
+            command_palette: None,
+            command_action: None,
             spinner_frame: 0,
             spinner_last_tick: Instant::now(),
             welcome_animation,
             pending_search: None,
             pending_random: None,
             pending_bfile: None,
+            pending_verify: None,
+            pending_daily: None,
+            pending_bulk_export: None,
             bfile_data: None,
             bfile_error: None,
             last_result_click: None,
@@ -506,10 +1009,32 @@ impl App {
 
         // Persist initial settings on first landing so the config file is created immediately.
         app.persist_startup_settings();
+        app.load_daily_sequence();
+        app.report_keybinding_warnings();
+        app.report_theme_errors(&theme_errors);
+        if app.settings_store.accessible == Some(true) {
+            app.enable_accessibility();
+        }
 
         Ok(app)
     }
 
+    /// Turn on the accessibility mode for this session: the welcome animation, spinner
+    /// and rotating placeholder stop, the high-contrast theme is shown (without saving
+    /// it as the chosen theme) and the focus is described on the bottom line
+    pub fn enable_accessibility(&mut self) {
+        self.accessible = true;
+        self.welcome_animation_temp_active = false;
+        self.welcome_animation_mode = WelcomeAnimationMode::Off;
+        self.welcome_animation.set_mode(WelcomeAnimationMode::Off);
+        let high_contrast = ThemeChoice::Name(ui::theme::HIGH_CONTRAST.to_string());
+        if let Some(index) = theme_position(&self.themes, &high_contrast) {
+            self.active_theme = index;
+            self.settings_selected_theme = index;
+            self.welcome_animation.update_palette(self.theme());
+        }
+    }
+
     /// Render the current screen
     pub fn render(&mut self, f: &mut Frame) {
         // Render background first
@@ -528,6 +1053,7 @@ impl App {
             Screen::Search => ui::search::render(f, self),
             Screen::Detail => ui::detail::render(f, self),
             Screen::Graph => ui::graph::render(f, self),
+            Screen::Table => ui::table::render(f, self),
             Screen::Webcam => ui::webcam::render(f, self),
             Screen::Settings => ui::settings::render(f, self),
         }
@@ -539,6 +1065,30 @@ impl App {
         if self.about_modal_visible {
             ui::about::render_modal(f, self);
         }
+
+        if self.bulk_export.is_some() {
+            ui::bulk_export::render_modal(f, self);
+        }
+
+        if self.listen_dialog.is_some() {
+            ui::listen::render_modal(f, self);
+        }
+
+        if self.save_as.is_some() {
+            ui::save_as::render_modal(f, self);
+        }
+
+        if self.command_palette.is_some() {
+            ui::palette::render_modal(f, self);
+        }
+
+        ui::pending_keys::render(f, self);
+        if self.accessible {
+            ui::announce::render(f, self);
+        }
+
+        let background = self.theme().palette.background;
+        self.color_support.apply(f.buffer_mut(), background);
     }
 
     /// Handle terminal events
@@ -555,17 +1105,25 @@ impl App {
         self.poll_pending_search().await?;
         self.poll_pending_random().await?;
         self.poll_pending_bfile().await?;
+        self.poll_pending_verify().await?;
+        self.poll_pending_bulk_export().await?;
+        self.poll_pending_webcam().await?;
+        self.poll_pending_daily().await?;
+        self.expire_key_sequence().await?;
+        self.poll_theme_files();
         self.enforce_welcome_animation_timeout();
 
         // Update spinner / placeholder timers
-        if self.should_spin_spinner() {
-            if self.spinner_last_tick.elapsed() >= Duration::from_millis(150) {
-                self.spinner_frame = self.spinner_frame.wrapping_add(1);
-                self.spinner_last_tick = Instant::now();
-            }
+        if self.should_spin_spinner()
+            && !self.accessible
+            && self.spinner_last_tick.elapsed() >= Duration::from_millis(150)
+        {
+            self.spinner_frame = self.spinner_frame.wrapping_add(1);
+            self.spinner_last_tick = Instant::now();
         }
 
         if !self.searching
+            && !self.accessible
             && self.search_input.is_empty()
             && self.placeholder_timer.elapsed() > Duration::from_secs(4)
         {
@@ -575,15 +1133,7 @@ impl App {
 
         // Handle webcam auto-refresh
         if self.current_screen == Screen::Webcam {
-            if let Some(interval) = self.webcam_interval {
-                if let Some(duration) = interval.as_duration() {
-                    if let Some(last_update) = self.webcam_last_update {
-                        if last_update.elapsed() >= duration {
-                            self.webcam_next_sequence().await?;
-                        }
-                    }
-                }
-            }
+            self.webcam_tick();
         }
 
         Ok(())
@@ -599,7 +1149,7 @@ impl App {
             && self.show_welcome_modal
             && !matches!(self.welcome_animation_mode, WelcomeAnimationMode::Off);
 
-        animating_welcome || self.searching
+        animating_welcome || self.searching || self.pending_verify.is_some()
     }
 
     fn set_active_theme(&mut self, index: usize) {
@@ -608,17 +1158,68 @@ impl App {
         }
         self.active_theme = index;
         self.settings_selected_theme = index;
-        self.settings_store.theme = Some(index);
+        self.settings_store.theme = Some(ThemeChoice::Name(self.themes[index].name.clone()));
         self.persist_settings();
         self.welcome_animation.update_palette(self.theme());
     }
 
+    /// Load the themes again when a file in the themes directory was added, removed or changed
+    fn poll_theme_files(&mut self) {
+        if self.themes_checked.elapsed() < THEME_POLL_INTERVAL {
+            return;
+        }
+        self.themes_checked = Instant::now();
+        let Some(dir) = self.themes_dir.clone() else {
+            return;
+        };
+        let files = themes::theme_files(&dir);
+        if files == self.theme_files {
+            return;
+        }
+        self.theme_files = files;
+
+        let active = self.theme().name.clone();
+        let selected = self.themes.get(self.settings_selected_theme).map(|t| t.name.clone());
+        let (themes, errors) = themes::load_themes(&dir, Theme::builtin());
+        self.themes = themes;
+        // If the saved theme's file went away, the first one shows without being saved,
+        // so the choice comes back with the file
+        self.active_theme = reloaded_theme(&self.themes, self.settings_store.theme.as_ref(), &active);
+        self.settings_selected_theme = selected
+            .and_then(|name| theme_position(&self.themes, &ThemeChoice::Name(name)))
+            .unwrap_or(self.active_theme);
+        self.welcome_animation.update_palette(self.theme());
+
+        if errors.is_empty() {
+            self.error_message = Some(self.i18n.t("themes-reloaded"));
+        } else {
+            self.report_theme_errors(&errors);
+        }
+    }
+
+    /// Point out the first theme file that could not be loaded
+    fn report_theme_errors(&mut self, errors: &[ThemeFileError]) {
+        use fluent::FluentArgs;
+        if let Some(error) = errors.first() {
+            let mut args = FluentArgs::new();
+            args.set("file", error.file.clone());
+            args.set("error", error.message.clone());
+            self.error_message = Some(self.i18n.t_with_args("theme-file-error", Some(&args)));
+        }
+    }
+
     fn set_language(&mut self, lang: Language) {
+        self.use_language(lang);
+        self.settings_store.language = Some(lang.code().to_string());
+        self.persist_settings();
+    }
+
+    /// Show the interface in `lang` for this session (`--lang`), without saving it as the
+    /// chosen language
+    pub fn use_language(&mut self, lang: Language) {
         self.i18n.set_language(lang);
         self.settings_selected_language =
             Language::all().iter().position(|l| *l == lang).unwrap_or(0);
-        self.settings_store.language = Some(lang.code().to_string());
-        self.persist_settings();
     }
 
     fn animation_mode_index(mode: WelcomeAnimationMode) -> usize {
@@ -660,6 +1261,104 @@ impl App {
             self.settings_store.keybindings.as_ref(),
             self.keybindings_preset.as_deref(),
         );
+        self.report_keybinding_warnings();
+    }
+
+    pub fn keybindings(&self) -> &KeyBindings {
+        &self.keybindings
+    }
+
+    /// Keys of the sequence waiting for its next key (empty when none is)
+    pub fn pending_keys(&self) -> &[KeyChord] {
+        if self.pending_key.is_some() {
+            &self.key_sequence
+        } else {
+            &[]
+        }
+    }
+
+    /// Keys that would carry on the pending sequence
+    pub fn pending_next_keys(&self) -> Vec<KeyChord> {
+        self.keybindings.next_keys(&self.key_contexts(), self.pending_keys())
+    }
+
+    /// Time left before the pending sequence runs as typed (or is dropped)
+    pub fn pending_key_time_left(&self) -> Duration {
+        self.pending_key.map_or(Duration::ZERO, |(_, since)| {
+            self.keybindings.sequence_timeout().saturating_sub(since.elapsed())
+        })
+    }
+
+    /// Contexts the next key is looked up in, in the order they are tried
+    fn key_contexts(&self) -> Vec<KeyContext> {
+        let mut contexts = vec![KeyContext::Global];
+        if self.help_modal_visible || self.about_modal_visible {
+            contexts.push(KeyContext::Modal);
+            return contexts;
+        }
+        match self.current_screen {
+            Screen::Search => {
+                if self.show_welcome_modal {
+                    contexts.push(KeyContext::Welcome);
+                }
+                if self.input_mode == InputMode::Normal {
+                    contexts.push(KeyContext::Search);
+                }
+            }
+            Screen::Detail if self.detail_help_visible => {
+                contexts.extend([KeyContext::Modal, KeyContext::Detail]);
+            }
+            Screen::Detail => contexts.extend_from_slice(self.detail_contexts()),
+            Screen::Graph => contexts.push(KeyContext::Graph),
+            Screen::Table => contexts.push(KeyContext::Table),
+            Screen::Webcam => contexts.push(KeyContext::Webcam),
+            Screen::Settings => contexts.push(KeyContext::Settings),
+        }
+        contexts
+    }
+
+    /// Bindings of the detail view; tab-specific ones come before the view's own
+    fn detail_contexts(&self) -> &'static [KeyContext] {
+        match self.detail_tab {
+            0 => &[KeyContext::Overview, KeyContext::Detail],
+            2 => &[KeyContext::Code, KeyContext::Detail],
+            6 => &[KeyContext::Graph, KeyContext::Detail],
+            7 => &[KeyContext::Export, KeyContext::Detail],
+            _ => &[KeyContext::Detail],
+        }
+    }
+
+    /// The action the keys typed so far (or the command palette) trigger in `context`
+    fn key_action(&self, context: KeyContext) -> Option<Action> {
+        match self.command_action {
+            Some((chosen, action)) => (chosen == context).then_some(action),
+            None => self.keybindings.action(context, &self.key_sequence),
+        }
+    }
+
+    /// The first of `contexts` with an action for the typed keys, and that action
+    fn resolve_key(&self, contexts: &[KeyContext]) -> Option<(KeyContext, Action)> {
+        match self.command_action {
+            Some((chosen, action)) => contexts.contains(&chosen).then_some((chosen, action)),
+            None => self.keybindings.resolve(contexts, &self.key_sequence),
+        }
+    }
+
+    /// A keybinding problem from settings.json, as shown in the status bar and help window
+    pub fn describe_keybinding_warning(&self, warning: &KeyBindingWarning) -> String {
+        use fluent::FluentArgs;
+        let mut args = FluentArgs::new();
+        for (name, value) in warning.args() {
+            args.set(name, value);
+        }
+        self.i18n.t_with_args(warning.message_key(), Some(&args))
+    }
+
+    /// Point out the first keybinding problem; the help window lists them all
+    fn report_keybinding_warnings(&mut self) {
+        if let Some(warning) = self.keybindings.warnings().first() {
+            self.error_message = Some(self.describe_keybinding_warning(warning));
+        }
     }
 
     fn set_keybindings_preset(&mut self, preset: Option<&str>) {
@@ -669,11 +1368,64 @@ impl App {
         self.persist_settings();
     }
 
+    /// Move on to the next bundled preset (default, vim, emacs, helix)
     fn toggle_keybindings_preset(&mut self) {
-        match self.keybindings_preset.as_deref() {
-            Some("vim") => self.set_keybindings_preset(None),
-            _ => self.set_keybindings_preset(Some("vim")),
+        let current = self.keybindings_preset.as_deref().unwrap_or(PRESETS[0]);
+        let index = PRESETS.iter().position(|preset| *preset == current).unwrap_or(0);
+        let next = PRESETS[(index + 1) % PRESETS.len()];
+        self.set_keybindings_preset((next != PRESETS[0]).then_some(next));
+
+        use fluent::FluentArgs;
+        let mut args = FluentArgs::new();
+        args.set("preset", next);
+        self.error_message = Some(self.i18n.t_with_args("keybindings-preset-changed", Some(&args)));
+    }
+
+    /// Today's pick for the welcome modal, from the cache or fetched in the background
+    fn load_daily_sequence(&mut self) {
+        let date = chrono::Local::now().date_naive();
+        let key = DAILY_QUERIES.join(" | ");
+        if let Ok(Some(sequence)) = self.cache.get_daily_sequence(&date.to_string(), &key) {
+            self.daily_sequence = Some(sequence);
+            return;
+        }
+
+        let client = self.client.clone();
+        let queries: Vec<String> = DAILY_QUERIES.iter().map(|query| query.to_string()).collect();
+        let handle = tokio::spawn(async move { client.daily_sequence(date, &queries).await });
+        self.pending_daily = Some(PendingDaily { handle, date });
+    }
+
+    async fn poll_pending_daily(&mut self) -> Result<()> {
+        if !self
+            .pending_daily
+            .as_ref()
+            .is_some_and(|pending| pending.handle.is_finished())
+        {
+            return Ok(());
+        }
+        let PendingDaily { handle, date } = self
+            .pending_daily
+            .take()
+            .expect("pending daily fetch should still be present");
+
+        // Offline, the welcome modal simply goes without it
+        if let Ok(Ok(Some(sequence))) = handle.await {
+            let _ = self
+                .cache
+                .cache_daily_sequence(&date.to_string(), &DAILY_QUERIES.join(" | "), &sequence);
+            self.daily_sequence = Some(sequence);
+        }
+        Ok(())
+    }
+
+    /// Leave the welcome modal for the detail view of the sequence of the day
+    async fn open_daily_sequence(&mut self) -> Result<()> {
+        if let Some(number) = self.daily_sequence.as_ref().map(|seq| seq.number) {
+            self.close_welcome_modal();
+            self.load_sequence_by_number(number).await?;
         }
+        Ok(())
     }
 
     fn close_welcome_modal(&mut self) {
@@ -699,11 +1451,14 @@ impl App {
     }
 
     fn persist_startup_settings(&mut self) {
-        self.settings_store.theme = Some(self.active_theme);
+        // Only an index from an older settings file is rewritten: a missing theme file,
+        // an automatic theme or the locale's language should still be what is saved
+        if let Some(ThemeChoice::Index(_)) = self.settings_store.theme {
+            self.settings_store.theme = Some(ThemeChoice::Name(self.theme().name.clone()));
+        }
         self.settings_store.welcome_animation =
             Some(self.welcome_animation_user_pref.key().to_string());
         self.settings_store.welcome_animation_played = Some(self.welcome_animation_played);
-        self.settings_store.language = Some(self.i18n.get_current_language().code().to_string());
         self.settings_store.keybindings_preset = self.keybindings_preset.clone();
         let _ = self.settings_store.save();
     }
@@ -841,6 +1596,7 @@ impl App {
                     .pending_random
                     .take()
                     .expect("pending random should still be present");
+                self.remember_upper_bound();
 
                 match handle.await {
                     Ok(Ok(Some(sequence))) => {
@@ -879,6 +1635,21 @@ impl App {
         Ok(())
     }
 
+    /// Cache the upper bound the client looked up, so the next run can skip recent.txt
+    fn remember_upper_bound(&self) {
+        let Some(bound) = self.client.known_upper_bound() else {
+            return;
+        };
+        let cached = self
+            .cache
+            .get_upper_bound(client::UPPER_BOUND_MAX_AGE_DAYS)
+            .ok()
+            .flatten();
+        if cached != Some(bound) {
+            let _ = self.cache.cache_upper_bound(bound);
+        }
+    }
+
     fn cancel_pending_random(&mut self) {
         if let Some(pending) = self.pending_random.take() {
             pending.handle.abort();
@@ -895,6 +1666,7 @@ impl App {
         self.current_sequence = Some(sequence);
         self.current_screen = Screen::Detail;
         self.detail_tab = 0;
+        self.code_snippet_index = 0;
         self.detail_scroll = 0;
         self.reset_detail_reference_state();
         self.last_result_click = None;
@@ -968,7 +1740,8 @@ impl App {
     }
 
     fn should_spin_spinner(&self) -> bool {
-        if self.searching {
+        // A verification has its own line with a spinner, searches or not
+        if self.searching || self.pending_verify.is_some() {
             return true;
         }
 
@@ -1005,6 +1778,7 @@ impl App {
                 let cached = sequence.clone();
                 self.current_sequence = Some(sequence);
                 self.detail_tab = 0;
+                self.code_snippet_index = 0;
                 self.detail_scroll = 0;
                 self.reset_detail_reference_state();
                 let _ = self.cache.cache_sequence(&cached);
@@ -1026,81 +1800,306 @@ impl App {
             return Ok(());
         }
 
-        // Global keybindings (configurable via settings.json)
-        if self.keybindings.is_toggle_keybindings_preset(&key) {
-            self.toggle_keybindings_preset();
-            return Ok(());
+        // Path-editing dialogs take every key so paths can be typed freely
+        if self.save_as.is_some() {
+            return self.handle_save_as_input(key);
         }
-        if self.keybindings.is_quit(&key) {
-            self.should_quit = true;
-            return Ok(());
+        if self.bulk_export.is_some() {
+            return self.handle_bulk_export_input(key);
         }
-        if self.keybindings.is_help(&key) {
-            self.help_modal_visible = !self.help_modal_visible;
+        if self.listen_dialog.is_some() {
+            self.handle_listen_input(key);
             return Ok(());
         }
-        if self.keybindings.is_about(&key) {
-            self.about_modal_visible = !self.about_modal_visible;
-            return Ok(());
+        if self.command_palette.is_some() {
+            return self.handle_command_palette_input(key).await;
         }
-        if self.keybindings.is_cycle_theme(&key) {
-            self.cycle_theme();
-            return Ok(());
+
+        // Typed characters belong to the search box, whatever they are bound to
+        if self.current_screen == Screen::Search
+            && self.input_mode == InputMode::Editing
+            && !self.help_modal_visible
+            && !self.about_modal_visible
+            && is_text_key(&key)
+        {
+            self.pending_key = None;
+            self.key_sequence = vec![KeyChord::from(&key)];
+            let result = self.handle_search_input(key).await;
+            self.key_sequence.clear();
+            return result;
         }
 
-        if self.help_modal_visible {
-            match key.code {
-                KeyCode::Esc | KeyCode::Char('q') => {
-                    self.help_modal_visible = false;
-                    self.help_scroll = 0; // Reset scroll when closing
-                }
-                KeyCode::Up | KeyCode::Char('k') => {
-                    self.help_scroll = self.help_scroll.saturating_sub(1);
-                }
-                KeyCode::Down | KeyCode::Char('j') => {
-                    self.help_scroll = self.help_scroll.saturating_add(1);
-                }
-                KeyCode::PageUp => {
-                    self.help_scroll = self.help_scroll.saturating_sub(10);
-                }
-                KeyCode::PageDown => {
-                    self.help_scroll = self.help_scroll.saturating_add(10);
-                }
-                KeyCode::Home => {
-                    self.help_scroll = 0;
-                }
-                KeyCode::End => {
-                    self.help_scroll = 100; // Arbitrary large number to scroll to bottom
-                }
-                _ => {}
-            }
+        let contexts = self.key_contexts();
+        self.key_sequence.push(KeyChord::from(&key));
+        if self.key_sequence.len() > 1
+            && self.keybindings.resolve(&contexts, &self.key_sequence).is_none()
+            && self.keybindings.next_keys(&contexts, &self.key_sequence).is_empty()
+        {
+            // Not one of the sequences after all: the last key starts over on its own
+            self.key_sequence = vec![KeyChord::from(&key)];
+        }
+        if !self.keybindings.next_keys(&contexts, &self.key_sequence).is_empty() {
+            // Wait for the rest of `g g` or `ctrl+x ctrl+s`; a key that is also bound on
+            // its own runs when the wait times out
+            self.pending_key = Some((key, Instant::now()));
             return Ok(());
         }
+        self.run_key_sequence(key).await
+    }
 
-        if self.about_modal_visible {
-            match key.code {
-                KeyCode::Esc | KeyCode::Char('q') => {
-                    self.about_modal_visible = false;
-                }
-                _ => {}
+    /// Run whatever the typed sequence is bound to, then start afresh
+    async fn run_key_sequence(&mut self, key: KeyEvent) -> Result<()> {
+        self.pending_key = None;
+        let result = self.dispatch_key_sequence(key).await;
+        self.key_sequence.clear();
+        result
+    }
+
+    /// A sequence left waiting longer than the timeout runs as typed
+    async fn expire_key_sequence(&mut self) -> Result<()> {
+        match self.pending_key {
+            Some((key, since)) if since.elapsed() >= self.keybindings.sequence_timeout() => {
+                self.run_key_sequence(key).await
             }
-            return Ok(());
+            _ => Ok(()),
+        }
+    }
+
+    async fn dispatch_key_sequence(&mut self, key: KeyEvent) -> Result<()> {
+        // Global keybindings (configurable via settings.json)
+        match self.key_action(KeyContext::Global) {
+            Some(Action::TogglePreset) => {
+                self.toggle_keybindings_preset();
+                return Ok(());
+            }
+            Some(Action::Quit) => {
+                self.should_quit = true;
+                return Ok(());
+            }
+            Some(Action::Help) => {
+                self.help_modal_visible = !self.help_modal_visible;
+                return Ok(());
+            }
+            Some(Action::About) => {
+                self.about_modal_visible = !self.about_modal_visible;
+                return Ok(());
+            }
+            Some(Action::CycleTheme) => {
+                self.cycle_theme();
+                return Ok(());
+            }
+            Some(Action::CommandPalette) => {
+                self.open_command_palette();
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        if self.help_modal_visible {
+            let action = self.key_action(KeyContext::Modal);
+            if action == Some(Action::Back) {
+                self.help_modal_visible = false;
+                self.help_scroll = 0; // Reset scroll when closing
+            } else if let Some(action) = action {
+                self.help_scroll = scroll_modal(self.help_scroll, action);
+            }
+            return Ok(());
+        }
+
+        if self.about_modal_visible {
+            if self.key_action(KeyContext::Modal) == Some(Action::Back) {
+                self.about_modal_visible = false;
+            }
+            return Ok(());
         }
 
         // Screen-specific handling
         match self.current_screen {
             Screen::Search => self.handle_search_input(key).await?,
-            Screen::Detail => self.handle_detail_input(key).await?,
-            Screen::Graph => self.handle_graph_input(key).await?,
-            Screen::Webcam => self.handle_webcam_input(key).await?,
-            Screen::Settings => self.handle_settings_input(key).await?,
+            Screen::Detail => self.handle_detail_input().await?,
+            Screen::Graph => self.handle_graph_input(),
+            Screen::Table => self.handle_table_input(),
+            Screen::Webcam => self.handle_webcam_input().await?,
+            Screen::Settings => self.handle_settings_input().await?,
+        }
+
+        Ok(())
+    }
+
+    /// Open the command palette with everything that can be done from here
+    fn open_command_palette(&mut self) {
+        // Typing goes to the palette now, and search commands need the results focused
+        if self.current_screen == Screen::Search {
+            self.input_mode = InputMode::Normal;
+        }
+
+        let mut entries = Vec::new();
+        let mut seen = Vec::new();
+        for context in self.key_contexts() {
+            for binding in self.keybindings.bindings(context) {
+                let Some(label) = binding.action.label_key() else {
+                    continue;
+                };
+                // Like a key, an action shared by several contexts goes to the first
+                if seen.contains(&binding.action) {
+                    continue;
+                }
+                seen.push(binding.action);
+                let keys: Vec<String> = binding.keys.iter().map(ToString::to_string).collect();
+                entries.push(PaletteEntry {
+                    command: Command::Action(context, binding.action),
+                    label: self.i18n.t(label),
+                    keys: keys.join(", "),
+                });
+            }
+        }
+
+        let entry = |command, category: &str, name: &str| PaletteEntry {
+            command,
+            label: format!("{}: {}", self.i18n.t(category), name),
+            keys: String::new(),
+        };
+        let mut screens = vec![
+            (Screen::Search, KeyContext::Search),
+            (Screen::Webcam, KeyContext::Webcam),
+            (Screen::Settings, KeyContext::Settings),
+        ];
+        if self.current_sequence.is_some() {
+            screens.extend([
+                (Screen::Detail, KeyContext::Detail),
+                (Screen::Graph, KeyContext::Graph),
+                (Screen::Table, KeyContext::Table),
+            ]);
+        }
+        for (screen, context) in screens {
+            if screen != self.current_screen {
+                let name = self.i18n.t(context.label_key());
+                entries.push(entry(Command::Screen(screen), "command-screen", &name));
+            }
+        }
+        for (index, theme) in self.themes.iter().enumerate() {
+            entries.push(entry(Command::Theme(index), "command-theme", theme.name()));
+        }
+        if self.current_sequence.is_some() {
+            for format in ExportFormat::all() {
+                entries.push(entry(
+                    Command::ExportFormat(format.clone()),
+                    "command-export-format",
+                    format.as_str(),
+                ));
+            }
+            for graph_type in GraphType::ALL {
+                let name = self.i18n.t(graph_type.label_key());
+                entries.push(entry(Command::GraphType(graph_type), "command-graph-type", &name));
+            }
         }
+        for (index, (number, notes)) in self.bookmarks.iter().enumerate() {
+            let name = match self.cache.get_cached_sequence(*number, 30) {
+                Ok(Some(seq)) => seq.name,
+                _ => notes.clone().unwrap_or_default(),
+            };
+            let name = format!("A{:06} {}", number, name);
+            entries.push(entry(Command::Bookmark(index), "command-bookmark", name.trim_end()));
+        }
+
+        self.command_palette = Some(CommandPalette::new(entries));
+    }
+
+    async fn handle_command_palette_input(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(palette) = self.command_palette.as_mut() else {
+            return Ok(());
+        };
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+
+        match key.code {
+            KeyCode::Esc => self.command_palette = None,
+            KeyCode::Enter => {
+                let command = palette.selected_command();
+                self.command_palette = None;
+                if let Some(command) = command {
+                    return self.run_command(command).await;
+                }
+            }
+            KeyCode::Up | KeyCode::BackTab => palette.select_previous(),
+            KeyCode::Down | KeyCode::Tab => palette.select_next(),
+            KeyCode::Char('p') if ctrl => palette.select_previous(),
+            KeyCode::Char('n') if ctrl => palette.select_next(),
+            // Backspace on an empty query closes the palette, as in a vim command line
+            KeyCode::Backspace if palette.query().is_empty() => self.command_palette = None,
+            KeyCode::Backspace => palette.pop(),
+            KeyCode::Char(c) if is_text_key(&key) => palette.push(c),
+            _ => {}
+        }
+        Ok(())
+    }
 
+    /// Carry out a command chosen in the palette; actions go through the same dispatch as keys
+    async fn run_command(&mut self, command: Command) -> Result<()> {
+        match command {
+            Command::Action(context, action) => {
+                self.command_action = Some((context, action));
+                let result = self
+                    .run_key_sequence(KeyEvent::new(KeyCode::Null, KeyModifiers::NONE))
+                    .await;
+                self.command_action = None;
+                result?;
+            }
+            Command::Screen(screen) => self.go_to_screen(screen),
+            Command::Theme(index) => self.set_active_theme(index),
+            Command::ExportFormat(format) => {
+                self.export_format = format;
+                self.go_to_detail_tab(7);
+            }
+            Command::GraphType(graph_type) => {
+                self.graph_type = graph_type;
+                if self.current_screen != Screen::Graph {
+                    self.go_to_detail_tab(6);
+                }
+            }
+            Command::Bookmark(index) => self.load_bookmark(index).await?,
+            Command::Sequence(number) => self.load_sequence_by_number(number).await?,
+        }
         Ok(())
     }
 
+    fn close_modals(&mut self) {
+        self.help_modal_visible = false;
+        self.about_modal_visible = false;
+        self.detail_help_visible = false;
+    }
+
+    fn go_to_screen(&mut self, screen: Screen) {
+        self.close_modals();
+        match screen {
+            Screen::Webcam => self.open_webcam(),
+            Screen::Search => {
+                self.current_screen = Screen::Search;
+                self.reset_detail_reference_state();
+            }
+            screen => self.current_screen = screen,
+        }
+    }
+
+    fn go_to_detail_tab(&mut self, tab: usize) {
+        self.close_modals();
+        self.current_screen = Screen::Detail;
+        if self.detail_tab != tab {
+            self.detail_tab = tab;
+            self.detail_scroll = 0;
+            self.reset_detail_reference_state();
+        }
+    }
+
     /// Handle mouse input
     async fn handle_mouse_event(&mut self, event: MouseEvent) -> Result<()> {
+        if self.bulk_export.is_some()
+            || self.save_as.is_some()
+            || self.listen_dialog.is_some()
+            || self.command_palette.is_some()
+        {
+            return Ok(());
+        }
+
         if self.help_modal_visible {
             if matches!(event.kind, MouseEventKind::Down(MouseButton::Left)) {
                 self.help_modal_visible = false;
@@ -1117,6 +2116,11 @@ impl App {
 
         if self.show_welcome_modal {
             if matches!(event.kind, MouseEventKind::Down(MouseButton::Left)) {
+                if let Some(area) = self.daily_area {
+                    if Self::point_in_rect(area, event.column, event.row) {
+                        return self.open_daily_sequence().await;
+                    }
+                }
                 if let Some(area) = self.search_input_area {
                     if Self::point_in_rect(area, event.column, event.row) {
                         let inner_x = area.x.saturating_add(1);
@@ -1138,6 +2142,9 @@ impl App {
             Screen::Detail => {
                 self.handle_detail_mouse(event).await?;
             }
+            Screen::Graph => {
+                self.handle_graph_mouse(event);
+            }
             _ => {}
         }
         Ok(())
@@ -1202,6 +2209,7 @@ impl App {
                     if Self::point_in_rect(area, column, row) && self.current_sequence.is_some() {
                         self.current_screen = Screen::Detail;
                         self.detail_tab = 0;
+                        self.code_snippet_index = 0;
                         self.detail_scroll = 0;
                         self.reset_detail_reference_state();
                         self.last_result_click = None;
@@ -1265,6 +2273,10 @@ impl App {
         let column = event.column;
         let row = event.row;
 
+        if self.detail_tab == 6 && self.handle_graph_mouse(event) {
+            return Ok(());
+        }
+
         match event.kind {
             MouseEventKind::Down(MouseButton::Left) => {
                 if let Some(area) = self.detail_tabs_area {
@@ -1386,25 +2398,24 @@ impl App {
     /// Handle input on search screen
     async fn handle_search_input(&mut self, key: KeyEvent) -> Result<()> {
         if self.show_welcome_modal {
-            match key.code {
-                KeyCode::Enter => {
+            match self.key_action(KeyContext::Welcome) {
+                Some(Action::Confirm) => {
                     self.close_welcome_modal();
                     if !self.search_input.trim().is_empty() {
                         self.perform_search()?;
                     }
                     return Ok(());
                 }
-                KeyCode::Esc => {
+                Some(Action::Back) => {
                     self.close_welcome_modal();
                     return Ok(());
                 }
-                KeyCode::Char('w') => {
+                Some(Action::OpenDaily) if self.daily_sequence.is_some() => {
+                    return self.open_daily_sequence().await;
+                }
+                Some(Action::OpenWebcam) => {
                     self.close_welcome_modal();
-                    self.current_screen = Screen::Webcam;
-                    if self.webcam_interval.is_none() {
-                        self.webcam_interval = Some(WebcamInterval::Manual);
-                    }
-                    self.webcam_focus = WebcamFocus::Categories;
+                    self.open_webcam();
                     return Ok(());
                 }
                 _ => {}
@@ -1413,12 +2424,15 @@ impl App {
 
         match self.input_mode {
             InputMode::Normal => {
-                match key.code {
-                    KeyCode::Char('i') | KeyCode::Char('/') => {
+                let Some(action) = self.key_action(KeyContext::Search) else {
+                    return Ok(());
+                };
+                match action {
+                    Action::FocusInput => {
                         self.input_mode = InputMode::Editing;
                         self.search_focus = SearchFocus::Input;
                     }
-                    KeyCode::Enter => {
+                    Action::Confirm => {
                         if self.search_focus == SearchFocus::Results
                             && !self.search_results.is_empty()
                         {
@@ -1436,7 +2450,7 @@ impl App {
                             self.load_bookmark(self.bookmarks_selected).await?;
                         }
                     }
-                    KeyCode::Up | KeyCode::Char('k') => {
+                    Action::Up => {
                         if self.search_focus == SearchFocus::Results {
                             self.select_previous_result();
                         } else if self.search_focus == SearchFocus::History
@@ -1449,7 +2463,7 @@ impl App {
                             self.bookmarks_selected = self.bookmarks_selected.saturating_sub(1);
                         }
                     }
-                    KeyCode::Down | KeyCode::Char('j') => {
+                    Action::Down => {
                         if self.search_focus == SearchFocus::Results {
                             self.select_next_result();
                         } else if self.search_focus == SearchFocus::History
@@ -1464,7 +2478,7 @@ impl App {
                             self.bookmarks_selected = (self.bookmarks_selected + 1).min(max_idx);
                         }
                     }
-                    KeyCode::Char('h') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Action::FocusHistory => {
                         // Set focus to history panel
                         if !self.show_preview && !self.recent_sequences.is_empty() {
                             self.search_focus = SearchFocus::History;
@@ -1472,22 +2486,22 @@ impl App {
                             self.history_selected = 0;
                         }
                     }
-                    KeyCode::Left | KeyCode::Char('h') => {
+                    Action::PreviousPage => {
                         self.previous_page()?;
                     }
-                    KeyCode::Right | KeyCode::Char('l') => {
+                    Action::NextPage => {
                         self.next_page()?;
                     }
-                    KeyCode::Char('r') => {
+                    Action::Random => {
                         self.start_random_sequence()?;
                     }
-                    KeyCode::Char('p') => {
+                    Action::TogglePreview => {
                         self.toggle_preview().await?;
                     }
-                    KeyCode::Char('b') => {
+                    Action::ToggleBookmarks => {
                         self.toggle_bookmarks_panel();
                     }
-                    KeyCode::Tab => {
+                    Action::NextPanel => {
                         if self.show_preview {
                             // Cycle through preview tabs when preview is enabled
                             self.preview_tab = (self.preview_tab + 1) % 6;
@@ -1515,57 +2529,29 @@ impl App {
                             };
                         }
                     }
-                    KeyCode::Char('1'..='6') if self.show_preview => {
-                        let tab_num = key
-                            .code
-                            .to_string()
-                            .chars()
-                            .next()
-                            .unwrap()
-                            .to_digit(10)
-                            .unwrap() as usize;
-                        self.preview_tab = (tab_num - 1).min(5);
-                    }
-                    KeyCode::PageUp if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                        // Scroll preview up
-                        if self.show_preview && self.preview_scroll > 0 {
-                            self.preview_scroll = self.preview_scroll.saturating_sub(5);
-                        }
-                    }
-                    KeyCode::PageDown if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                        // Scroll preview down
-                        if self.show_preview {
-                            self.preview_scroll = self.preview_scroll.saturating_add(5);
-                        }
+                    Action::Pick(tab @ 1..=6) if self.show_preview => {
+                        self.preview_tab = tab as usize - 1;
                     }
-                    KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                        // Scroll preview up (Vim-style)
+                    Action::PreviewUp => {
                         if self.show_preview && self.preview_scroll > 0 {
                             self.preview_scroll = self.preview_scroll.saturating_sub(10);
                         }
                     }
-                    KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                        // Scroll preview down (Vim-style)
+                    Action::PreviewDown => {
                         if self.show_preview {
                             self.preview_scroll = self.preview_scroll.saturating_add(10);
                         }
                     }
-                    KeyCode::Char('w') => {
-                        self.current_screen = Screen::Webcam;
-                        // Initialize webcam mode if not set
-                        if self.webcam_interval.is_none() {
-                            self.webcam_interval = Some(WebcamInterval::Manual);
-                        }
-                        // Load first sequence if none loaded
-                        if self.current_sequence.is_none() {
-                            // Will be loaded when user presses space/enter
-                        }
-                        self.webcam_focus = WebcamFocus::Categories;
+                    Action::OpenWebcam => {
+                        self.open_webcam();
                     }
-                    KeyCode::Char('s') => {
+                    Action::OpenSettings => {
                         self.current_screen = Screen::Settings;
                     }
-                    KeyCode::Char('q') | KeyCode::Esc => {
+                    Action::BulkExport => {
+                        self.open_bulk_export();
+                    }
+                    Action::Quit => {
                         self.should_quit = true;
                     }
                     _ => {}
@@ -1621,55 +2607,57 @@ impl App {
     }
 
     /// Handle input on detail screen
-    async fn handle_detail_input(&mut self, key: KeyEvent) -> Result<()> {
+    async fn handle_detail_input(&mut self) -> Result<()> {
         // Handle detail help modal if visible
         if self.detail_help_visible {
-            match key.code {
-                KeyCode::Esc | KeyCode::Char('q') => {
-                    self.detail_help_visible = false;
-                    self.detail_help_scroll = 0; // Reset scroll when closing
-                }
-                KeyCode::Char('?') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    self.detail_help_visible = false;
-                    self.detail_help_scroll = 0; // Reset scroll when closing
-                }
-                KeyCode::Up | KeyCode::Char('k') => {
-                    self.detail_help_scroll = self.detail_help_scroll.saturating_sub(1);
-                }
-                KeyCode::Down | KeyCode::Char('j') => {
-                    self.detail_help_scroll = self.detail_help_scroll.saturating_add(1);
-                }
-                KeyCode::PageUp => {
-                    self.detail_help_scroll = self.detail_help_scroll.saturating_sub(10);
-                }
-                KeyCode::PageDown => {
-                    self.detail_help_scroll = self.detail_help_scroll.saturating_add(10);
-                }
-                KeyCode::Home => {
-                    self.detail_help_scroll = 0;
-                }
-                KeyCode::End => {
-                    self.detail_help_scroll = 100; // Arbitrary large number to scroll to bottom
-                }
-                _ => {}
+            let action = self.key_action(KeyContext::Modal);
+            let toggled = self.key_action(KeyContext::Detail) == Some(Action::DetailHelp);
+            if action == Some(Action::Back) || toggled {
+                self.detail_help_visible = false;
+                self.detail_help_scroll = 0; // Reset scroll when closing
+            } else if let Some(action) = action {
+                self.detail_help_scroll = scroll_modal(self.detail_help_scroll, action);
             }
             return Ok(());
         }
 
-        // Handle Ctrl+? key to show help modal
-        if let KeyCode::Char('?') = key.code {
-            if key.modifiers.contains(KeyModifiers::CONTROL) {
-                self.detail_help_visible = true;
-                return Ok(());
-            }
-        }
+        let Some((context, action)) = self.resolve_key(self.detail_contexts()) else {
+            return Ok(());
+        };
 
-        match key.code {
-            KeyCode::Char('q') | KeyCode::Esc | KeyCode::Backspace => {
+        match (context, action) {
+            (KeyContext::Overview, Action::MorePlaces) => self.change_constant_places(true),
+            (KeyContext::Overview, Action::FewerPlaces) => self.change_constant_places(false),
+            (KeyContext::Overview, Action::Copy) => self.copy_constant_to_clipboard(),
+            (KeyContext::Code, Action::NextSnippet) => self.select_code_snippet(1),
+            (KeyContext::Code, Action::PreviousSnippet) => self.select_code_snippet(-1),
+            (KeyContext::Code, Action::Copy) => self.copy_snippet_to_clipboard()?,
+            (KeyContext::Code, Action::Verify) => self.request_snippet_verification(),
+            (KeyContext::Code, Action::Save) => self.export_snippet_to_file()?,
+            (KeyContext::Export, Action::Up) => {
+                self.export_format = self.export_format.previous();
+            }
+            (KeyContext::Export, Action::Down) => {
+                self.export_format = self.export_format.next();
+            }
+            (KeyContext::Export, Action::Pick(n)) => {
+                if let Some(format) = ExportFormat::all().get((n as usize).wrapping_sub(1)) {
+                    self.export_format = format.clone();
+                }
+            }
+            (KeyContext::Export, Action::Confirm) => self.export_to_clipboard()?,
+            (KeyContext::Export, Action::Save) => self.export_to_file()?,
+            (KeyContext::Graph, action) if action != Action::Back => {
+                self.run_graph_action(action);
+            }
+            (_, Action::Back) => {
                 self.current_screen = Screen::Search;
                 self.reset_detail_reference_state();
             }
-            KeyCode::Char('g') => {
+            (_, Action::DetailHelp) => {
+                self.detail_help_visible = true;
+            }
+            (_, Action::OpenGraph) => {
                 // If already on Graph tab, open full-screen graph view
                 if self.detail_tab == 6 {
                     self.current_screen = Screen::Graph;
@@ -1679,26 +2667,27 @@ impl App {
                     self.detail_scroll = 0;
                 }
             }
-            KeyCode::Char('e') => {
+            (_, Action::ExportTab) => {
                 // Jump to Export tab
                 self.detail_tab = 7;
                 self.detail_scroll = 0;
             }
-            KeyCode::Char('o') => {
-                self.open_in_browser()?;
+            (_, Action::OpenBrowser) => self.open_in_browser()?,
+            (_, Action::ToggleBookmark) => self.toggle_bookmark().await?,
+            (_, Action::FetchBFile) => self.start_bfile_fetch()?,
+            (_, Action::OpenTable) => {
+                self.current_screen = Screen::Table;
             }
-            KeyCode::Char('b') => {
-                self.toggle_bookmark().await?;
+            (_, Action::Notebook) => {
+                // One-key hand-off to a Jupyter notebook
+                self.export_format_to_file(&ExportFormat::Notebook)?;
             }
-            KeyCode::Char('f') => {
-                self.start_bfile_fetch()?;
-            }
-            KeyCode::Tab => {
+            (_, Action::NextTab) => {
                 self.detail_tab = (self.detail_tab + 1) % DETAIL_TAB_COUNT;
                 self.detail_scroll = 0;
                 self.reset_detail_reference_state();
             }
-            KeyCode::BackTab => {
+            (_, Action::PreviousTab) => {
                 self.detail_tab = if self.detail_tab == 0 {
                     DETAIL_TAB_COUNT - 1
                 } else {
@@ -1707,152 +2696,174 @@ impl App {
                 self.detail_scroll = 0;
                 self.reset_detail_reference_state();
             }
-            KeyCode::Left | KeyCode::Char('h') => {
-                self.select_previous_detail_reference();
-            }
-            KeyCode::Right | KeyCode::Char('l') => {
-                self.select_next_detail_reference();
-            }
-            KeyCode::Up | KeyCode::Char('k') => {
-                // On Export tab, navigate formats instead of scrolling
-                if self.detail_tab == 7 {
-                    self.export_format = match self.export_format {
-                        ExportFormat::Json => ExportFormat::BFile,
-                        ExportFormat::Csv => ExportFormat::Json,
-                        ExportFormat::Txt => ExportFormat::Csv,
-                        ExportFormat::Markdown => ExportFormat::Txt,
-                        ExportFormat::BFile => ExportFormat::Markdown,
-                    };
-                } else {
-                    self.detail_scroll = self.detail_scroll.saturating_sub(1);
-                }
-            }
-            KeyCode::Down | KeyCode::Char('j') => {
-                // On Export tab, navigate formats instead of scrolling
-                if self.detail_tab == 7 {
-                    self.export_format = match self.export_format {
-                        ExportFormat::Json => ExportFormat::Csv,
-                        ExportFormat::Csv => ExportFormat::Txt,
-                        ExportFormat::Txt => ExportFormat::Markdown,
-                        ExportFormat::Markdown => ExportFormat::BFile,
-                        ExportFormat::BFile => ExportFormat::Json,
-                    };
-                } else {
-                    self.detail_scroll = self.detail_scroll.saturating_add(1);
-                }
+            (_, Action::Left) => self.select_previous_detail_reference(),
+            (_, Action::Right) => self.select_next_detail_reference(),
+            (_, Action::Up) => {
+                self.detail_scroll = self.detail_scroll.saturating_sub(1);
+            }
+            (_, Action::Down) => {
+                self.detail_scroll = self.detail_scroll.saturating_add(1);
             }
-            KeyCode::PageUp => {
+            (_, Action::PageUp) => {
                 self.detail_scroll = self.detail_scroll.saturating_sub(10);
             }
-            KeyCode::PageDown => {
+            (_, Action::PageDown) => {
                 self.detail_scroll = self.detail_scroll.saturating_add(10);
             }
-            KeyCode::Enter => {
-                // On Export tab, export to clipboard
-                if self.detail_tab == 7 {
-                    self.export_to_clipboard()?;
-                } else {
-                    self.open_selected_reference().await?;
-                }
-            }
-            KeyCode::Char('1') if self.detail_tab == 7 => {
-                self.export_format = ExportFormat::Json;
-            }
-            KeyCode::Char('2') if self.detail_tab == 7 => {
-                self.export_format = ExportFormat::Csv;
-            }
-            KeyCode::Char('3') if self.detail_tab == 7 => {
-                self.export_format = ExportFormat::Txt;
-            }
-            KeyCode::Char('4') if self.detail_tab == 7 => {
-                self.export_format = ExportFormat::Markdown;
-            }
-            KeyCode::Char('5') if self.detail_tab == 7 => {
-                self.export_format = ExportFormat::BFile;
-            }
-            KeyCode::Char('1') if self.detail_tab == 6 => {
-                self.graph_type = GraphType::Line;
-            }
-            KeyCode::Char('2') if self.detail_tab == 6 => {
-                self.graph_type = GraphType::Scatter;
-            }
-            KeyCode::Char('3') if self.detail_tab == 6 => {
-                self.graph_type = GraphType::LogScatter;
-            }
-            KeyCode::Char('4') if self.detail_tab == 6 => {
-                self.graph_type = GraphType::PinPlot;
-            }
-            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) && self.detail_tab == 7 => {
-                self.export_to_file()?;
-            }
+            (_, Action::Confirm) => self.open_selected_reference().await?,
             _ => {}
         }
         Ok(())
     }
 
     /// Handle input on graph screen
-    async fn handle_graph_input(&mut self, key: KeyEvent) -> Result<()> {
-        match key.code {
-            KeyCode::Char('q') | KeyCode::Esc | KeyCode::Backspace => {
+    fn handle_graph_input(&mut self) {
+        match self.key_action(KeyContext::Graph) {
+            Some(Action::Back) => {
+                self.current_screen = Screen::Detail;
+            }
+            Some(action) => {
+                self.run_graph_action(action);
+            }
+            None => {}
+        }
+    }
+
+    /// Handle input on the triangle/array screen
+    fn handle_table_input(&mut self) {
+        let Some(action) = self.key_action(KeyContext::Table) else {
+            return;
+        };
+        match action {
+            Action::Back => {
                 self.current_screen = Screen::Detail;
             }
-            KeyCode::Char('1') => self.graph_type = GraphType::Line,
-            KeyCode::Char('2') => self.graph_type = GraphType::Scatter,
-            KeyCode::Char('3') => self.graph_type = GraphType::LogScatter,
-            KeyCode::Char('4') => self.graph_type = GraphType::PinPlot,
+            Action::Save => {
+                self.export_table_to_file();
+            }
+            Action::CycleShape => {
+                // Irregular triangles without row lengths in the name start from the first layout
+                let shape = self
+                    .current_table()
+                    .map_or(TableShape::ALL[0], |table| table.shape.next());
+                self.table_shape = Some(shape);
+                self.table_cursor = (0, 0);
+                self.table_offset = (0, 0);
+            }
+            Action::Up => self.move_table_cursor(-1, 0),
+            Action::Down => self.move_table_cursor(1, 0),
+            Action::Left => self.move_table_cursor(0, -1),
+            Action::Right => self.move_table_cursor(0, 1),
+            Action::PageUp => self.move_table_cursor(-10, 0),
+            Action::PageDown => self.move_table_cursor(10, 0),
+            Action::LineStart => self.move_table_cursor(0, isize::MIN),
+            Action::LineEnd => self.move_table_cursor(0, isize::MAX),
+            Action::Top => self.move_table_cursor(isize::MIN, 0),
+            Action::Bottom => self.move_table_cursor(isize::MAX, 0),
             _ => {}
         }
-        Ok(())
+    }
+
+    /// The current sequence laid out as a triangle or array, if it is one
+    pub fn current_table(&self) -> Option<NumberTable> {
+        let seq = self.current_sequence.as_ref()?;
+        let shape = self.table_shape.or_else(|| TableShape::detect(seq))?;
+        Some(NumberTable::new(seq, self.bfile_data.as_deref(), shape))
+    }
+
+    /// Move the table cursor, staying on existing cells; rows may differ in length
+    fn move_table_cursor(&mut self, rows: isize, cols: isize) {
+        let Some(table) = self.current_table() else {
+            return;
+        };
+        if table.rows.is_empty() {
+            return;
+        }
+        let (row, col) = self.table_cursor;
+        let row = row.saturating_add_signed(rows).min(table.rows.len() - 1);
+        let last_col = table.rows[row].len().saturating_sub(1);
+        let col = col.saturating_add_signed(cols).min(last_col);
+        self.table_cursor = (row, col);
+    }
+
+    /// Save the triangle or array as CSV, one row per line
+    fn export_table_to_file(&mut self) {
+        let Some(table) = self.current_table() else {
+            return;
+        };
+        let Some(seq) = self.current_sequence.as_ref() else {
+            return;
+        };
+        let name = format!("{}-table.csv", seq.a_number());
+        self.open_save_as(name, SaveAsContent::Text(table.to_csv()));
     }
 
     /// Handle input on webcam screen
-    async fn handle_webcam_input(&mut self, key: KeyEvent) -> Result<()> {
-        match key.code {
-            KeyCode::Char('q') | KeyCode::Esc | KeyCode::Backspace => {
+    async fn handle_webcam_input(&mut self) -> Result<()> {
+        let Some(action) = self.key_action(KeyContext::Webcam) else {
+            return Ok(());
+        };
+        match action {
+            Action::Back if self.webcam_kiosk => {
+                self.should_quit = true;
+            }
+            Action::Back if self.webcam_fullscreen => {
+                self.webcam_fullscreen = false;
+            }
+            Action::Back => {
                 self.current_screen = Screen::Search;
                 self.webcam_interval = None;
+                self.webcam_fullscreen = false;
+                self.cancel_pending_webcam();
+                self.webcam_prefetched = None;
+                self.webcam_failures = 0;
+                self.webcam_retry_at = None;
             }
-            KeyCode::Char(' ') | KeyCode::Enter => {
+            Action::NextSequence => {
                 self.webcam_focus = WebcamFocus::Sequence;
-                self.webcam_next_sequence().await?;
+                self.webcam_next_sequence();
+            }
+            Action::ToggleFullscreen => {
+                self.webcam_fullscreen = !self.webcam_fullscreen;
             }
-            KeyCode::Char('d') => {
+            Action::OpenDetail => {
                 // Switch to detail view if sequence is loaded
                 if self.current_sequence.is_some() {
                     self.current_screen = Screen::Detail;
                     self.detail_tab = 0;
+                    self.code_snippet_index = 0;
                     self.detail_scroll = 0;
                     self.webcam_focus = WebcamFocus::Sequence;
                 }
             }
-            KeyCode::Tab | KeyCode::Right | KeyCode::Char('l') => {
-                // Tab or Right: Switch to next section
+            Action::NextPanel => {
                 self.webcam_focus = match self.webcam_focus {
                     WebcamFocus::Categories => WebcamFocus::Intervals,
                     WebcamFocus::Intervals | WebcamFocus::Sequence => WebcamFocus::Categories,
                 };
             }
-            KeyCode::BackTab | KeyCode::Left | KeyCode::Char('h') => {
-                // BackTab or Left: Switch to previous section
+            Action::PreviousPanel => {
                 self.webcam_focus = match self.webcam_focus {
                     WebcamFocus::Intervals => WebcamFocus::Categories,
                     WebcamFocus::Categories | WebcamFocus::Sequence => WebcamFocus::Intervals,
                 };
             }
             // Up/Down navigation works based on current focus
-            KeyCode::Up | KeyCode::Char('k') => match self.webcam_focus {
+            Action::Up => match self.webcam_focus {
                 WebcamFocus::Categories => {
                     // Navigate categories with wraparound
                     if self.webcam_category > 0 {
                         self.webcam_category -= 1;
                     } else {
-                        self.webcam_category = 3; // Wrap to last category
+                        self.webcam_category = SequenceCategory::ALL.len() - 1; // Wrap to last category
                     }
                 }
                 WebcamFocus::Intervals => {
                     // Navigate intervals upward
                     self.webcam_interval = match self.webcam_interval {
-                        None | Some(WebcamInterval::Manual) => Some(WebcamInterval::OneMinute),
+                        None | Some(WebcamInterval::Manual) | Some(WebcamInterval::Custom(_)) => {
+                            Some(WebcamInterval::OneMinute)
+                        }
                         Some(WebcamInterval::FiveSeconds) => Some(WebcamInterval::Manual),
                         Some(WebcamInterval::TenSeconds) => Some(WebcamInterval::FiveSeconds),
                         Some(WebcamInterval::TwentySeconds) => Some(WebcamInterval::TenSeconds),
@@ -1862,10 +2873,10 @@ impl App {
                 }
                 WebcamFocus::Sequence => {}
             },
-            KeyCode::Down | KeyCode::Char('j') => match self.webcam_focus {
+            Action::Down => match self.webcam_focus {
                 WebcamFocus::Categories => {
                     // Navigate categories with wraparound
-                    if self.webcam_category < 3 {
+                    if self.webcam_category + 1 < SequenceCategory::ALL.len() {
                         self.webcam_category += 1;
                     } else {
                         self.webcam_category = 0; // Wrap to first category
@@ -1874,7 +2885,9 @@ impl App {
                 WebcamFocus::Intervals => {
                     // Navigate intervals downward
                     self.webcam_interval = match self.webcam_interval {
-                        None | Some(WebcamInterval::Manual) => Some(WebcamInterval::FiveSeconds),
+                        None | Some(WebcamInterval::Manual) | Some(WebcamInterval::Custom(_)) => {
+                            Some(WebcamInterval::FiveSeconds)
+                        }
                         Some(WebcamInterval::FiveSeconds) => Some(WebcamInterval::TenSeconds),
                         Some(WebcamInterval::TenSeconds) => Some(WebcamInterval::TwentySeconds),
                         Some(WebcamInterval::TwentySeconds) => Some(WebcamInterval::ThirtySeconds),
@@ -1885,29 +2898,18 @@ impl App {
                 WebcamFocus::Sequence => {}
             },
             // Quick interval selection
-            KeyCode::Char('0') => {
-                self.webcam_focus = WebcamFocus::Intervals;
-                self.webcam_interval = Some(WebcamInterval::Manual);
-            }
-            KeyCode::Char('1') => {
-                self.webcam_focus = WebcamFocus::Intervals;
-                self.webcam_interval = Some(WebcamInterval::FiveSeconds);
-            }
-            KeyCode::Char('2') => {
-                self.webcam_focus = WebcamFocus::Intervals;
-                self.webcam_interval = Some(WebcamInterval::TenSeconds);
-            }
-            KeyCode::Char('3') => {
-                self.webcam_focus = WebcamFocus::Intervals;
-                self.webcam_interval = Some(WebcamInterval::TwentySeconds);
-            }
-            KeyCode::Char('4') => {
-                self.webcam_focus = WebcamFocus::Intervals;
-                self.webcam_interval = Some(WebcamInterval::ThirtySeconds);
-            }
-            KeyCode::Char('5') => {
+            Action::Pick(n) => {
+                let interval = match n {
+                    0 => WebcamInterval::Manual,
+                    1 => WebcamInterval::FiveSeconds,
+                    2 => WebcamInterval::TenSeconds,
+                    3 => WebcamInterval::TwentySeconds,
+                    4 => WebcamInterval::ThirtySeconds,
+                    5 => WebcamInterval::OneMinute,
+                    _ => return Ok(()),
+                };
                 self.webcam_focus = WebcamFocus::Intervals;
-                self.webcam_interval = Some(WebcamInterval::OneMinute);
+                self.webcam_interval = Some(interval);
             }
             _ => {}
         }
@@ -1917,31 +2919,34 @@ impl App {
     }
 
     /// Handle input on settings screen
-    async fn handle_settings_input(&mut self, key: KeyEvent) -> Result<()> {
+    async fn handle_settings_input(&mut self) -> Result<()> {
         let languages = Language::all();
         let language_max = languages.len().saturating_sub(1);
         let theme_max = self.themes.len().saturating_sub(1);
         let animation_max = WelcomeAnimationMode::modes().len().saturating_sub(1);
 
-        match key.code {
-            KeyCode::Char('q') | KeyCode::Esc | KeyCode::Backspace => {
+        let Some(action) = self.key_action(KeyContext::Settings) else {
+            return Ok(());
+        };
+        match action {
+            Action::Back => {
                 self.current_screen = Screen::Search;
             }
-            KeyCode::Tab | KeyCode::Right | KeyCode::Char('l') => {
+            Action::NextPanel => {
                 self.settings_focus = match self.settings_focus {
                     SettingsFocus::Language => SettingsFocus::Theme,
                     SettingsFocus::Theme => SettingsFocus::Animation,
                     SettingsFocus::Animation => SettingsFocus::Language,
                 };
             }
-            KeyCode::BackTab | KeyCode::Left | KeyCode::Char('h') => {
+            Action::PreviousPanel => {
                 self.settings_focus = match self.settings_focus {
                     SettingsFocus::Language => SettingsFocus::Animation,
                     SettingsFocus::Theme => SettingsFocus::Language,
                     SettingsFocus::Animation => SettingsFocus::Theme,
                 };
             }
-            KeyCode::Up | KeyCode::Char('k') => match self.settings_focus {
+            Action::Up => match self.settings_focus {
                 SettingsFocus::Language => {
                     if self.settings_selected_language > 0 {
                         self.settings_selected_language -= 1;
@@ -1964,7 +2969,7 @@ impl App {
                     }
                 }
             },
-            KeyCode::Down | KeyCode::Char('j') => match self.settings_focus {
+            Action::Down => match self.settings_focus {
                 SettingsFocus::Language => {
                     if self.settings_selected_language < language_max {
                         self.settings_selected_language += 1;
@@ -1987,7 +2992,7 @@ impl App {
                     }
                 }
             },
-            KeyCode::Enter => match self.settings_focus {
+            Action::Confirm => match self.settings_focus {
                 SettingsFocus::Language => {
                     if let Some(lang) = languages.get(self.settings_selected_language) {
                         self.set_language(*lang);
@@ -2001,39 +3006,11 @@ impl App {
                     self.set_welcome_animation_mode(mode);
                 }
             },
-            KeyCode::Char('1') if matches!(self.settings_focus, SettingsFocus::Language) => {
-                if let Some(lang) = languages.first() {
-                    self.settings_selected_language = 0;
-                    self.set_language(*lang);
-                }
-            }
-            KeyCode::Char('2') if matches!(self.settings_focus, SettingsFocus::Language) => {
-                if let Some(lang) = languages.get(1) {
-                    self.settings_selected_language = 1;
-                    self.set_language(*lang);
-                }
-            }
-            KeyCode::Char('3') if matches!(self.settings_focus, SettingsFocus::Language) => {
-                if let Some(lang) = languages.get(2) {
-                    self.settings_selected_language = 2;
-                    self.set_language(*lang);
-                }
-            }
-            KeyCode::Char('4') if matches!(self.settings_focus, SettingsFocus::Language) => {
-                if let Some(lang) = languages.get(3) {
-                    self.settings_selected_language = 3;
-                    self.set_language(*lang);
-                }
-            }
-            KeyCode::Char('5') if matches!(self.settings_focus, SettingsFocus::Language) => {
-                if let Some(lang) = languages.get(4) {
-                    self.settings_selected_language = 4;
-                    self.set_language(*lang);
-                }
-            }
-            KeyCode::Char('6') if matches!(self.settings_focus, SettingsFocus::Language) => {
-                if let Some(lang) = languages.get(5) {
-                    self.settings_selected_language = 5;
+            // Quick language selection
+            Action::Pick(n) if matches!(self.settings_focus, SettingsFocus::Language) => {
+                let index = (n as usize).wrapping_sub(1);
+                if let Some(lang) = languages.get(index) {
+                    self.settings_selected_language = index;
                     self.set_language(*lang);
                 }
             }
@@ -2106,6 +3083,7 @@ impl App {
             self.current_sequence = Some(sequence.clone());
             self.current_screen = Screen::Detail;
             self.detail_tab = 0;
+            self.code_snippet_index = 0;
             self.detail_scroll = 0;
             self.reset_detail_reference_state();
             self.last_result_click = None;
@@ -2132,6 +3110,7 @@ impl App {
             self.current_sequence = Some(sequence.clone());
             self.current_screen = Screen::Detail;
             self.detail_tab = 0;
+            self.code_snippet_index = 0;
             self.detail_scroll = 0;
             self.reset_detail_reference_state();
 
@@ -2149,6 +3128,7 @@ impl App {
                 self.current_sequence = Some(sequence.clone());
                 self.current_screen = Screen::Detail;
                 self.detail_tab = 0;
+                self.code_snippet_index = 0;
                 self.detail_scroll = 0;
                 self.reset_detail_reference_state();
 
@@ -2214,6 +3194,16 @@ impl App {
     }
 
     /// Toggle bookmarks panel visibility
+    /// Switch to the webcam screen with the category list focused
+    fn open_webcam(&mut self) {
+        self.current_screen = Screen::Webcam;
+        self.error_message = None;
+        if self.webcam_interval.is_none() {
+            self.webcam_interval = Some(WebcamInterval::Manual);
+        }
+        self.webcam_focus = WebcamFocus::Categories;
+    }
+
     fn toggle_bookmarks_panel(&mut self) {
         self.show_bookmarks = !self.show_bookmarks;
         // Switch focus based on which panel is now shown
@@ -2307,83 +3297,232 @@ impl App {
         self.searching = false;
     }
 
-    /// Clear B-file data
+    /// Clear B-file data (and the graph zoom that went with it)
     fn clear_bfile_data(&mut self) {
         self.bfile_data = None;
         self.bfile_error = None;
         self.cancel_pending_bfile();
+        self.graph_viewport.reset();
+        self.table_shape = None;
+        self.table_cursor = (0, 0);
+        self.table_offset = (0, 0);
+        self.constant_places = DEFAULT_CONSTANT_PLACES;
     }
 
-    /// Load next sequence in webcam mode
-    async fn webcam_next_sequence(&mut self) -> Result<()> {
-        // Build query based on selected category
-        let query = match self.webcam_category {
-            0 => {
-                // All Sequences - get a random one
-                match self.client.random_sequence().await {
-                    Ok(Some(sequence)) => {
-                        self.current_sequence = Some(sequence);
-                        self.webcam_last_update = Some(std::time::Instant::now());
-                        self.reset_detail_reference_state();
-                        self.webcam_focus = WebcamFocus::Sequence;
-                        return Ok(());
-                    }
-                    Ok(None) => {
-                        self.error_message = Some("No sequence found".to_string());
-                        return Ok(());
-                    }
-                    Err(e) => {
-                        self.error_message = Some(format!("Error loading sequence: {}", e));
-                        return Ok(());
-                    }
-                }
+    /// Show the next webcam sequence: the prefetched one when it is ready,
+    /// otherwise whatever the running (or a new) fetch returns
+    fn webcam_next_sequence(&mut self) {
+        let category = self.webcam_category();
+        match self.webcam_prefetched.take() {
+            Some((fetched_for, sequence)) if fetched_for == category => {
+                self.show_webcam_sequence(sequence);
+                self.start_webcam_fetch(false);
             }
-            1 => "keyword:nice", // Best Sequences
-            2 => "keyword:more", // Needing Terms
-            3 => "keyword:new",  // Recent Additions
-            _ => "keyword:nice",
-        };
+            _ => match self.pending_webcam.as_mut() {
+                Some(pending) if pending.category == category => pending.show = true,
+                _ => self.start_webcam_fetch(true),
+            },
+        }
+    }
 
-        // For category-based searches, fetch and pick a random one from results
-        let search_query = SearchQuery::new(query);
-
-        match self.client.search(&search_query, 10).await {
-            Ok(response) => {
-                if let Some(results) = response.results {
-                    if !results.is_empty() {
-                        // Pick a random sequence from the results
-                        use rand::Rng;
-                        let mut rng = rand::thread_rng();
-                        let index = rng.gen_range(0..results.len());
-                        self.current_sequence = Some(results[index].clone());
-                        self.webcam_last_update = Some(std::time::Instant::now());
-                        self.reset_detail_reference_state();
-                        self.webcam_focus = WebcamFocus::Sequence;
-                    } else {
-                        self.error_message =
-                            Some("No sequences found in this category".to_string());
-                    }
-                } else {
-                    self.error_message = Some("Too many results, please try again".to_string());
-                }
-            }
-            Err(e) => {
-                self.error_message = Some(format!("Error loading sequence: {}", e));
-            }
+    /// Auto-refresh and background prefetch, holding off after failed fetches
+    fn webcam_tick(&mut self) {
+        if self.webcam_retry_at.is_some_and(|at| Instant::now() < at) {
+            return;
+        }
+        let category = self.webcam_category();
+        if self
+            .webcam_prefetched
+            .as_ref()
+            .is_some_and(|(fetched_for, _)| *fetched_for != category)
+        {
+            self.webcam_prefetched = None;
         }
 
-        Ok(())
+        let interval = self.webcam_interval.and_then(|interval| interval.as_duration());
+        let due = match self.webcam_last_update {
+            Some(last_update) => interval.is_some_and(|interval| last_update.elapsed() >= interval),
+            // Nothing shown yet: try again once the first fetch has failed
+            None => self.webcam_retry_at.is_some(),
+        };
+        if due {
+            self.webcam_next_sequence();
+        } else if self.webcam_last_update.is_some()
+            && self.webcam_prefetched.is_none()
+            && self.pending_webcam.is_none()
+        {
+            self.start_webcam_fetch(false);
+        }
     }
 
-    /// Update webcam scroll offsets to keep selected items visible
-    /// visible_height: approximate number of visible items in the list (accounting for multi-line items)
-    fn update_webcam_scroll(&mut self) {
-        // For categories: 4 items total, each takes ~2 lines (name + description)
+    /// Fetch a sequence of the selected category in the background
+    fn start_webcam_fetch(&mut self, show: bool) {
+        self.cancel_pending_webcam();
+        let category = self.webcam_category();
+        if category == SequenceCategory::Bookmarks && self.bookmarks.is_empty() {
+            self.error_message = Some(self.i18n.t("webcam-no-bookmarks"));
+            return;
+        }
+
+        let client = self.client.clone();
+        let recent = self
+            .webcam_recent
+            .as_ref()
+            .filter(|(fetched, _)| fetched.elapsed() < RECENT_LIST_TTL)
+            .map(|(_, numbers)| numbers.clone());
+        let bookmarks: Vec<i32> = self.bookmarks.iter().map(|(number, _)| *number).collect();
+        let current = self.current_sequence.as_ref().map(|seq| seq.number);
+
+        let handle = tokio::spawn(async move {
+            // List-based categories pick from a pool of numbers
+            let (pool, downloaded) = match category {
+                SequenceCategory::Recent => match recent {
+                    Some(numbers) => (numbers, None),
+                    None => {
+                        let numbers = client.recent_numbers().await?;
+                        (numbers.clone(), Some(numbers))
+                    }
+                },
+                SequenceCategory::Bookmarks => (bookmarks, None),
+                _ => (Vec::new(), None),
+            };
+            // Don't show the same entry twice in a row when there is a choice
+            let pool: Vec<i32> = if pool.len() > 1 {
+                pool.into_iter().filter(|number| Some(*number) != current).collect()
+            } else {
+                pool
+            };
+
+            let sequence = client.random_in_category(category, &pool).await?;
+            Ok(WebcamFetch {
+                sequence,
+                recent: downloaded,
+            })
+        });
+
+        self.pending_webcam = Some(PendingWebcam {
+            handle,
+            category,
+            show,
+        });
+    }
+
+    fn cancel_pending_webcam(&mut self) {
+        if let Some(pending) = self.pending_webcam.take() {
+            pending.handle.abort();
+        }
+    }
+
+    async fn poll_pending_webcam(&mut self) -> Result<()> {
+        if !self
+            .pending_webcam
+            .as_ref()
+            .is_some_and(|pending| pending.handle.is_finished())
+        {
+            return Ok(());
+        }
+        let PendingWebcam {
+            handle,
+            category,
+            show,
+        } = self
+            .pending_webcam
+            .take()
+            .expect("pending webcam fetch should still be present");
+        self.remember_upper_bound();
+
+        let result = match handle.await {
+            Ok(result) => result,
+            Err(join_error) => Err(join_error.into()),
+        };
+        match result {
+            Ok(WebcamFetch { sequence, recent }) => {
+                if let Some(numbers) = recent {
+                    self.webcam_recent = Some((Instant::now(), numbers));
+                }
+                match sequence {
+                    Some(sequence) => {
+                        self.webcam_failures = 0;
+                        self.webcam_retry_at = None;
+                        let _ = self.cache.cache_sequence(&sequence);
+                        if show {
+                            self.show_webcam_sequence(sequence);
+                            self.start_webcam_fetch(false);
+                        } else {
+                            self.webcam_prefetched = Some((category, sequence));
+                        }
+                    }
+                    None => {
+                        self.error_message = Some(self.i18n.t("webcam-category-empty"));
+                        self.webcam_fetch_failed();
+                    }
+                }
+            }
+            Err(e) => {
+                self.set_webcam_error(&e.to_string());
+                self.webcam_fetch_failed();
+            }
+        }
+        Ok(())
+    }
+
+    /// Keep the last good sequence on screen and wait longer after each failure
+    fn webcam_fetch_failed(&mut self) {
+        self.webcam_failures += 1;
+        self.webcam_retry_at = Some(Instant::now() + webcam_retry_delay(self.webcam_failures));
+    }
+
+    fn show_webcam_sequence(&mut self, sequence: Sequence) {
+        // The full-screen chart changes style with every sequence after the first
+        if self.webcam_fullscreen && self.webcam_last_update.is_some() {
+            self.graph_type = self.graph_type.next();
+        }
+        self.clear_bfile_data();
+        self.current_sequence = Some(sequence);
+        self.webcam_last_update = Some(Instant::now());
+        self.reset_detail_reference_state();
+        self.webcam_focus = WebcamFocus::Sequence;
+        self.error_message = None;
+    }
+
+    /// Open the webcam straight away, as `oeis webcam` does
+    pub fn start_webcam(&mut self, launch: WebcamLaunch) {
+        self.close_welcome_modal();
+        self.current_screen = Screen::Webcam;
+        self.webcam_kiosk = true;
+        self.webcam_fullscreen = launch.fullscreen;
+        self.webcam_category = SequenceCategory::ALL
+            .iter()
+            .position(|category| *category == launch.category)
+            .unwrap_or(0);
+        self.webcam_interval = Some(launch.interval);
+        self.webcam_focus = WebcamFocus::Sequence;
+        self.update_webcam_scroll();
+        self.webcam_next_sequence();
+    }
+
+    /// The selected webcam category
+    pub fn webcam_category(&self) -> SequenceCategory {
+        SequenceCategory::ALL[self.webcam_category.min(SequenceCategory::ALL.len() - 1)]
+    }
+
+    fn set_webcam_error(&mut self, message: &str) {
+        use fluent::FluentArgs;
+
+        let mut args = FluentArgs::new();
+        args.set("message", message.to_string());
+        self.error_message = Some(self.i18n.t_with_args("webcam-load-error", Some(&args)));
+    }
+
+    /// Update webcam scroll offsets to keep selected items visible
+    /// visible_height: approximate number of visible items in the list (accounting for multi-line items)
+    fn update_webcam_scroll(&mut self) {
+        // For categories: each takes ~2 lines (name + description)
         // With borders and title, we have about 6 lines visible
         let category_visible = 2; // visible items (accounting for 2-line items)
         self.webcam_category_scroll = calculate_scroll_offset(
             self.webcam_category,
-            4, // total categories
+            SequenceCategory::ALL.len(),
             category_visible,
             self.webcam_category_scroll,
         );
@@ -2391,7 +3530,7 @@ impl App {
         // For intervals: 6 items total, each takes ~2 lines
         let interval_visible = 2; // visible items
         let selected_interval_index = match self.webcam_interval {
-            None | Some(WebcamInterval::Manual) => 0,
+            None | Some(WebcamInterval::Manual) | Some(WebcamInterval::Custom(_)) => 0,
             Some(WebcamInterval::FiveSeconds) => 1,
             Some(WebcamInterval::TenSeconds) => 2,
             Some(WebcamInterval::TwentySeconds) => 3,
@@ -2442,32 +3581,821 @@ impl App {
 
     /// Export current sequence to file
     fn export_to_file(&mut self) -> Result<()> {
-        use fluent::FluentArgs;
+        let format = self.export_format.clone();
+        self.export_format_to_file(&format)
+    }
 
+    /// Export current sequence to file in `format`, regardless of the Export tab selection.
+    /// Opens the save-as prompt prefilled from the export directory and filename template.
+    fn export_format_to_file(&mut self, format: &ExportFormat) -> Result<()> {
         if let Some(ref seq) = self.current_sequence {
-            let content = crate::ui::export::export_sequence(
-                seq,
-                &self.export_format,
-                self.bfile_data.as_ref(),
-            );
-            let filename = format!("{}.{}", seq.a_number(), self.export_format.extension());
+            let content =
+                crate::ui::export::export_sequence(seq, format, self.bfile_data.as_ref());
+            let template = self
+                .settings_store
+                .export_filename_template
+                .as_deref()
+                .unwrap_or(filename::DEFAULT_TEMPLATE);
+            let name = filename::render_template(template, seq, format.extension());
+            self.open_save_as(name, SaveAsContent::Text(content));
+        }
+        Ok(())
+    }
 
-            match std::fs::write(&filename, content) {
-                Ok(_) => {
-                    let mut args = FluentArgs::new();
-                    args.set("path", filename.clone());
-                    self.error_message = Some(self.i18n.t_with_args("export-file-success", Some(&args)));
+    /// n-range covered by graph `points`
+    fn graph_full_range(points: &[(f64, f64)]) -> Option<(f64, f64)> {
+        let lo = points.iter().map(|(x, _)| *x).reduce(f64::min)?;
+        let hi = points.iter().map(|(x, _)| *x).reduce(f64::max)?;
+        Some((lo, hi))
+    }
+
+    /// n-range of the graph as drawn now, or `None` when its x axis isn't n
+    fn current_graph_range(&self) -> Option<(f64, f64)> {
+        let seq = self.current_sequence.as_ref()?;
+        if !self.graph_type.is_indexed() {
+            return None;
+        }
+        let series = Series::new(seq, self.bfile_data.as_deref(), &self.graph_type, self.graph_modulus);
+        Self::graph_full_range(&series.points)
+    }
+
+    /// Zoom the graph by `factor` around `column` (a screen column), or its centre
+    fn zoom_graph(&mut self, factor: f64, column: Option<u16>) {
+        let Some(full) = self.current_graph_range() else {
+            return;
+        };
+
+        let anchor = column.zip(self.graph_area).map(|(column, area)| {
+            let (lo, hi) = self.graph_viewport.visible(full);
+            let position = column.saturating_sub(area.x) as f64 / area.width.max(1) as f64;
+            lo + (hi - lo) * position
+        });
+        self.graph_viewport.zoom(full, factor, anchor);
+    }
+
+    /// Pan the zoomed graph by `fraction` of the visible width
+    fn pan_graph(&mut self, fraction: f64) {
+        if let Some(full) = self.current_graph_range() {
+            self.graph_viewport.pan(full, fraction);
+        }
+    }
+
+    /// Graph actions shared by the graph tab and the full-screen graph
+    fn run_graph_action(&mut self, action: Action) {
+        match action {
+            Action::Pick(n) => {
+                if let Some(graph_type) = GraphType::ALL.get((n as usize).wrapping_sub(1)) {
+                    self.graph_type = graph_type.clone();
                 }
-                Err(e) => {
+            }
+            Action::NextGraphType => self.graph_type = self.graph_type.next(),
+            Action::PreviousGraphType => self.graph_type = self.graph_type.previous(),
+            Action::ModulusUp if self.graph_type == GraphType::ModK => {
+                self.graph_modulus = (self.graph_modulus + 1).min(MAX_GRAPH_MODULUS);
+            }
+            Action::ModulusDown if self.graph_type == GraphType::ModK => {
+                self.graph_modulus = self.graph_modulus.saturating_sub(1).max(2);
+            }
+            Action::ZoomIn => self.zoom_graph(GRAPH_ZOOM_STEP, None),
+            Action::ZoomOut => self.zoom_graph(1.0 / GRAPH_ZOOM_STEP, None),
+            Action::Left => self.pan_graph(-GRAPH_PAN_STEP),
+            Action::Right => self.pan_graph(GRAPH_PAN_STEP),
+            Action::ResetView => self.graph_viewport.reset(),
+            Action::Save => self.export_plot_to_file(),
+            Action::Listen => self.open_listen_dialog(),
+            _ => {}
+        }
+    }
+
+    /// Mouse wheel zooms around the pointer; dragging pans
+    fn handle_graph_mouse(&mut self, event: MouseEvent) -> bool {
+        let Some(area) = self.graph_area else {
+            return false;
+        };
+
+        match event.kind {
+            MouseEventKind::Drag(MouseButton::Left) => {
+                if let Some(last) = self.graph_drag {
+                    let delta = last as f64 - event.column as f64;
+                    self.pan_graph(delta / area.width.max(1) as f64);
+                    self.graph_drag = Some(event.column);
+                    return true;
+                }
+                false
+            }
+            MouseEventKind::Up(MouseButton::Left) => self.graph_drag.take().is_some(),
+            _ if !Self::point_in_rect(area, event.column, event.row) => false,
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.graph_drag = Some(event.column);
+                true
+            }
+            MouseEventKind::ScrollUp => {
+                self.zoom_graph(GRAPH_WHEEL_ZOOM, Some(event.column));
+                true
+            }
+            MouseEventKind::ScrollDown => {
+                self.zoom_graph(1.0 / GRAPH_WHEEL_ZOOM, Some(event.column));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Save the graph as it is currently shown (b-file and zoom included) as SVG or PNG
+    fn export_plot_to_file(&mut self) {
+        let Some(ref seq) = self.current_sequence else {
+            return;
+        };
+
+        let mut plot = Plot::new(
+            seq,
+            self.bfile_data.as_deref(),
+            self.graph_type.clone(),
+            self.graph_modulus,
+        );
+        let zoomed = self.graph_viewport.is_zoomed() && self.graph_type.is_indexed();
+        if let Some(full) = Self::graph_full_range(&plot.series.points).filter(|_| zoomed) {
+            let (lo, hi) = self.graph_viewport.visible(full);
+            plot.series.points.retain(|(x, _)| *x >= lo && *x <= hi);
+        }
+        let template = self
+            .settings_store
+            .export_filename_template
+            .as_deref()
+            .unwrap_or(filename::DEFAULT_TEMPLATE);
+        let name = filename::render_template(template, seq, "svg");
+        self.open_save_as(name, SaveAsContent::Plot(Box::new(plot)));
+    }
+
+    /// Open the listen dialog with the last used sonification settings
+    fn open_listen_dialog(&mut self) {
+        if self.current_sequence.is_none() {
+            return;
+        }
+        self.listen_dialog = Some(ListenDialog {
+            settings: self.settings_store.listen.unwrap_or_default().clamped(),
+            field: ListenField::Instrument,
+        });
+    }
+
+    /// Handle input while the listen dialog is open
+    fn handle_listen_input(&mut self, key: KeyEvent) {
+        let Some(dialog) = self.listen_dialog.as_mut() else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                self.listen_dialog = None;
+            }
+            KeyCode::Enter => {
+                self.export_sound_to_file();
+            }
+            KeyCode::Up | KeyCode::BackTab => {
+                dialog.field = dialog.field.previous();
+            }
+            KeyCode::Down | KeyCode::Tab => {
+                dialog.field = dialog.field.next();
+            }
+            KeyCode::Left | KeyCode::Right => {
+                let forward = key.code == KeyCode::Right;
+                let settings = &mut dialog.settings;
+                match dialog.field {
+                    ListenField::Instrument => {
+                        settings.instrument = if forward {
+                            settings.instrument.next()
+                        } else {
+                            settings.instrument.previous()
+                        };
+                    }
+                    ListenField::Tempo => {
+                        settings.tempo = if forward {
+                            settings.tempo + LISTEN_TEMPO_STEP
+                        } else {
+                            settings.tempo.saturating_sub(LISTEN_TEMPO_STEP)
+                        };
+                    }
+                    ListenField::Range => {
+                        settings.range = if forward {
+                            settings.range + 1
+                        } else {
+                            settings.range.saturating_sub(1)
+                        };
+                    }
+                    ListenField::LowestNote => {
+                        settings.lowest_note = if forward {
+                            settings.lowest_note.saturating_add(1)
+                        } else {
+                            settings.lowest_note.saturating_sub(1)
+                        };
+                    }
+                }
+                *settings = settings.clamped();
+            }
+            _ => {}
+        }
+    }
+
+    /// Remember the dialog's settings and ask where to write the MIDI or WAV file
+    fn export_sound_to_file(&mut self) {
+        let Some(dialog) = self.listen_dialog.take() else {
+            return;
+        };
+        let Some(ref seq) = self.current_sequence else {
+            return;
+        };
+
+        let sound = Sonification::new(seq, self.bfile_data.as_deref(), dialog.settings);
+        let template = self
+            .settings_store
+            .export_filename_template
+            .as_deref()
+            .unwrap_or(filename::DEFAULT_TEMPLATE);
+        let name = filename::render_template(template, seq, "mid");
+
+        self.settings_store.listen = Some(dialog.settings);
+        self.persist_settings();
+        self.open_save_as(name, SaveAsContent::Audio(Box::new(sound)));
+    }
+
+    /// Show the save-as prompt for `content`, suggesting `name` inside the export directory
+    fn open_save_as(&mut self, name: String, content: SaveAsContent) {
+        let input = match self.settings_store.export_dir.as_deref().map(str::trim) {
+            Some(dir) if !dir.is_empty() => format!("{}/{}", dir.trim_end_matches('/'), name),
+            _ => name.clone(),
+        };
+
+        self.save_as = Some(SaveAsPrompt {
+            input,
+            default_name: name,
+            content,
+            completions: Vec::new(),
+            completion_index: None,
+            confirm_overwrite: None,
+        });
+    }
+
+    /// Handle input while the save-as prompt (or its overwrite confirmation) is open
+    fn handle_save_as_input(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(prompt) = self.save_as.as_mut() else {
+            return Ok(());
+        };
+
+        if let Some(path) = prompt.confirm_overwrite.clone() {
+            match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                    self.write_save_as(&path);
+                }
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    prompt.confirm_overwrite = None;
+                }
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        match key.code {
+            KeyCode::Esc => {
+                self.save_as = None;
+            }
+            KeyCode::Enter => {
+                self.submit_save_as();
+            }
+            KeyCode::Tab => {
+                self.complete_save_as_path();
+            }
+            KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                prompt.input.clear();
+                prompt.completions.clear();
+                prompt.completion_index = None;
+            }
+            KeyCode::Char(c) => {
+                prompt.input.push(c);
+                prompt.completions.clear();
+                prompt.completion_index = None;
+            }
+            KeyCode::Backspace => {
+                prompt.input.pop();
+                prompt.completions.clear();
+                prompt.completion_index = None;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Tab completion: extend to the longest shared prefix, then cycle through the matches
+    fn complete_save_as_path(&mut self) {
+        let Some(prompt) = self.save_as.as_mut() else {
+            return;
+        };
+
+        if let Some(index) = prompt.completion_index {
+            if !prompt.completions.is_empty() {
+                let next = (index + 1) % prompt.completions.len();
+                prompt.input = prompt.completions[next].clone();
+                prompt.completion_index = Some(next);
+                return;
+            }
+        }
+
+        let candidates = paths::complete_path(&prompt.input);
+        match candidates.len() {
+            0 => {}
+            1 => {
+                prompt.input = candidates[0].clone();
+                prompt.completions.clear();
+                prompt.completion_index = None;
+            }
+            _ => {
+                let prefix = paths::common_prefix(&candidates);
+                if prefix.len() > prompt.input.len() {
+                    prompt.input = prefix;
+                    prompt.completion_index = None;
+                } else {
+                    prompt.input = candidates[0].clone();
+                    prompt.completion_index = Some(0);
+                }
+                prompt.completions = candidates;
+            }
+        }
+    }
+
+    /// Resolve the typed path and write it, asking first if the file already exists
+    fn submit_save_as(&mut self) {
+        let Some(prompt) = self.save_as.as_mut() else {
+            return;
+        };
+        if prompt.input.trim().is_empty() {
+            return;
+        }
+
+        let mut path = paths::expand_home(&prompt.input);
+        if path.is_dir() || prompt.input.ends_with('/') {
+            path = path.join(&prompt.default_name);
+        }
+
+        if matches!(prompt.content, SaveAsContent::Plot(_)) && PlotFormat::from_path(&path).is_none() {
+            self.error_message = Some(self.i18n.t("plot-unsupported-format"));
+            return;
+        }
+        if matches!(prompt.content, SaveAsContent::Audio(_)) && AudioFormat::from_path(&path).is_none() {
+            self.error_message = Some(self.i18n.t("listen-unsupported-format"));
+            return;
+        }
+
+        if path.exists() {
+            prompt.confirm_overwrite = Some(path);
+        } else {
+            self.write_save_as(&path);
+        }
+    }
+
+    /// Write the prompt's content to `path`, creating parent directories, and close the prompt
+    fn write_save_as(&mut self, path: &std::path::Path) {
+        use fluent::FluentArgs;
+
+        let Some(prompt) = self.save_as.take() else {
+            return;
+        };
+
+        let bytes = match prompt.content {
+            SaveAsContent::Text(text) => Ok(text.into_bytes()),
+            SaveAsContent::Plot(plot) => PlotFormat::from_path(path)
+                .ok_or_else(|| PlotError::UnsupportedFormat.into())
+                .and_then(|format| plot.render(format)),
+            SaveAsContent::Audio(sound) => AudioFormat::from_path(path)
+                .ok_or_else(|| AudioError::UnsupportedFormat.into())
+                .and_then(|format| sound.render(format)),
+        };
+        let result = bytes.and_then(|bytes| {
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, bytes)?;
+            Ok(())
+        });
+
+        match result {
+            Ok(_) => {
+                let mut args = FluentArgs::new();
+                args.set("path", path.display().to_string());
+                self.error_message = Some(self.i18n.t_with_args("export-file-success", Some(&args)));
+            }
+            Err(e) => {
+                let mut args = FluentArgs::new();
+                args.set("message", self.i18n.error(&e));
+                self.error_message = Some(self.i18n.t_with_args("error-file", Some(&args)));
+            }
+        }
+    }
+
+    /// Open the bulk export dialog, preselecting the focused search panel as the source
+    fn open_bulk_export(&mut self) {
+        let source = match self.search_focus {
+            SearchFocus::Bookmarks => BulkSource::Bookmarks,
+            SearchFocus::History => BulkSource::History,
+            _ if self.search_results.is_empty() && !self.bookmarks.is_empty() => {
+                BulkSource::Bookmarks
+            }
+            _ => BulkSource::SearchResults,
+        };
+        let directory = match self.settings_store.export_dir.as_deref().map(str::trim) {
+            Some(dir) if !dir.is_empty() => dir.to_string(),
+            _ => std::env::current_dir()
+                .map(|dir| dir.display().to_string())
+                .unwrap_or_else(|_| ".".to_string()),
+        };
+
+        self.bulk_export = Some(BulkExportDialog {
+            source,
+            format: self.export_format.clone(),
+            layout: BulkLayout::Combined,
+            directory,
+            field: BulkExportField::Source,
+        });
+    }
+
+    /// Handle input while the bulk export dialog is open
+    fn handle_bulk_export_input(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(dialog) = self.bulk_export.as_mut() else {
+            return Ok(());
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                self.bulk_export = None;
+            }
+            KeyCode::Enter => {
+                self.start_bulk_export()?;
+            }
+            KeyCode::Up | KeyCode::BackTab => {
+                dialog.field = dialog.field.previous();
+            }
+            KeyCode::Down | KeyCode::Tab => {
+                dialog.field = dialog.field.next();
+            }
+            KeyCode::Left | KeyCode::Right => {
+                let forward = key.code == KeyCode::Right;
+                match dialog.field {
+                    BulkExportField::Source => {
+                        dialog.source = if forward {
+                            dialog.source.next()
+                        } else {
+                            dialog.source.previous()
+                        };
+                    }
+                    BulkExportField::Format => {
+                        dialog.format = if forward {
+                            dialog.format.next()
+                        } else {
+                            dialog.format.previous()
+                        };
+                    }
+                    BulkExportField::Layout => {
+                        dialog.layout = dialog.layout.toggle();
+                    }
+                    BulkExportField::Directory => {}
+                }
+            }
+            KeyCode::Char('u')
+                if key.modifiers.contains(KeyModifiers::CONTROL)
+                    && dialog.field == BulkExportField::Directory =>
+            {
+                dialog.directory.clear();
+            }
+            KeyCode::Char(c) if dialog.field == BulkExportField::Directory => {
+                dialog.directory.push(c);
+            }
+            KeyCode::Backspace if dialog.field == BulkExportField::Directory => {
+                dialog.directory.pop();
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Collect the dialog's sequences and write them, fetching uncached ones in the background
+    fn start_bulk_export(&mut self) -> Result<()> {
+        use fluent::FluentArgs;
+
+        let Some(dialog) = self.bulk_export.clone() else {
+            return Ok(());
+        };
+
+        if dialog.layout == BulkLayout::Combined && !dialog.format.supports_combined() {
+            let mut args = FluentArgs::new();
+            args.set("format", dialog.format.as_str().to_string());
+            self.error_message =
+                Some(self.i18n.t_with_args("bulk-export-combined-unsupported", Some(&args)));
+            return Ok(());
+        }
+
+        let numbers = match dialog.source {
+            BulkSource::SearchResults => {
+                let seqs = self.search_results.clone();
+                self.bulk_export = None;
+                self.finish_bulk_export(&dialog, seqs, 0);
+                return Ok(());
+            }
+            BulkSource::Bookmarks => self
+                .cache
+                .get_bookmarks()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(number, _)| number)
+                .collect::<Vec<_>>(),
+            BulkSource::History => self
+                .cache
+                .get_recently_viewed(BULK_HISTORY_LIMIT)
+                .unwrap_or_default(),
+        };
+        self.bulk_export = None;
+
+        let mut cached = Vec::new();
+        let mut missing = Vec::new();
+        for &number in &numbers {
+            match self.cache.get_cached_sequence(number, 30) {
+                Ok(Some(seq)) => cached.push(seq),
+                _ => missing.push(number),
+            }
+        }
+
+        if missing.is_empty() {
+            self.finish_bulk_export(&dialog, cached, 0);
+            return Ok(());
+        }
+
+        if let Some(pending) = self.pending_bulk_export.take() {
+            pending.handle.abort();
+        }
+        let client = self.client.clone();
+        let handle = tokio::spawn(async move { client.get_sequences(&missing).await });
+        let mut args = FluentArgs::new();
+        args.set("count", numbers.len() - cached.len());
+        self.error_message = Some(self.i18n.t_with_args("bulk-export-fetching", Some(&args)));
+        self.pending_bulk_export = Some(PendingBulkExport {
+            handle,
+            dialog,
+            order: numbers,
+            cached,
+        });
+        self.searching = true;
+        Ok(())
+    }
+
+    /// Poll the background fetch of a bulk export and write the files once it completes
+    async fn poll_pending_bulk_export(&mut self) -> Result<()> {
+        if let Some(pending) = self.pending_bulk_export.as_ref() {
+            if pending.handle.is_finished() {
+                let pending = self.pending_bulk_export.take().unwrap();
+                let fetched = pending.handle.await.unwrap_or_default();
+                for seq in &fetched {
+                    let _ = self.cache.cache_sequence(seq);
+                }
+
+                let mut available = pending.cached;
+                available.extend(fetched);
+                let seqs: Vec<Sequence> = pending
+                    .order
+                    .iter()
+                    .filter_map(|number| available.iter().find(|s| s.number == *number).cloned())
+                    .collect();
+                let skipped = pending.order.len() - seqs.len();
+
+                self.searching = false;
+                self.finish_bulk_export(&pending.dialog, seqs, skipped);
+            }
+        }
+        Ok(())
+    }
+
+    /// Write a bulk export and report the outcome in the status bar
+    fn finish_bulk_export(&mut self, dialog: &BulkExportDialog, seqs: Vec<Sequence>, skipped: usize) {
+        use fluent::FluentArgs;
+
+        if seqs.is_empty() {
+            self.error_message = Some(self.i18n.t("bulk-export-empty").to_string());
+            return;
+        }
+
+        let dir = paths::expand_home(&dialog.directory);
+        let stem = dialog.source.file_stem();
+        match crate::ui::export::write_bulk_export(&seqs, &dialog.format, dialog.layout, &dir, stem)
+        {
+            Ok(written) => {
+                let path = match dialog.layout {
+                    BulkLayout::Combined => written.first().cloned().unwrap_or(dir),
+                    BulkLayout::PerSequence => dir.join(stem),
+                };
+                let mut args = FluentArgs::new();
+                args.set("count", seqs.len());
+                args.set("path", path.display().to_string());
+                let mut message = self.i18n.t_with_args("bulk-export-success", Some(&args));
+                if skipped > 0 {
                     let mut args = FluentArgs::new();
-                    args.set("message", e.to_string());
-                    self.error_message = Some(self.i18n.t_with_args("error-file", Some(&args)));
+                    args.set("count", skipped);
+                    message.push(' ');
+                    message.push_str(&self.i18n.t_with_args("bulk-export-skipped", Some(&args)));
+                }
+                self.error_message = Some(message);
+            }
+            Err(e) => {
+                let mut args = FluentArgs::new();
+                args.set("message", e.to_string());
+                self.error_message = Some(self.i18n.t_with_args("error-file", Some(&args)));
+            }
+        }
+    }
+
+    /// The program snippet currently selected on the Code tab, with its 1-based
+    /// position among snippets of the same language
+    fn selected_code_snippet(&self) -> Option<(CodeSnippet, usize)> {
+        let seq = self.current_sequence.as_ref()?;
+        let snippets = seq.code_snippets();
+        let index = self.code_snippet_index.min(snippets.len().checked_sub(1)?);
+        let snippet = snippets[index].clone();
+        let ordinal = snippets[..=index]
+            .iter()
+            .filter(|s| s.language_key() == snippet.language_key())
+            .count();
+        Some((snippet, ordinal))
+    }
+
+    /// Move the Code tab selection by `delta` snippets and scroll it into view
+    fn select_code_snippet(&mut self, delta: isize) {
+        let Some(seq) = self.current_sequence.as_ref() else {
+            return;
+        };
+        let snippets = seq.code_snippets();
+        if snippets.is_empty() {
+            return;
+        }
+
+        let len = snippets.len() as isize;
+        let current = self.code_snippet_index.min(snippets.len() - 1) as isize;
+        self.code_snippet_index = (current + delta).rem_euclid(len) as usize;
+        self.verify_armed = None;
+
+        // Header line plus code lines plus a blank separator per preceding snippet
+        let offset: usize = snippets[..self.code_snippet_index]
+            .iter()
+            .map(|s| s.code.lines().count() + 2)
+            .sum();
+        self.detail_scroll = offset.min(u16::MAX as usize) as u16;
+    }
+
+    /// Run the selected Code tab snippet locally; the first press only asks for confirmation
+    fn request_snippet_verification(&mut self) {
+        use fluent::FluentArgs;
+
+        let Some((snippet, _)) = self.selected_code_snippet() else {
+            self.error_message = Some(self.i18n.t("code-no-snippets").to_string());
+            return;
+        };
+        let Some(seq) = self.current_sequence.clone() else {
+            return;
+        };
+
+        let Some(interpreter) = Interpreter::for_snippet(&snippet) else {
+            self.error_message = Some(self.i18n.t("verify-unsupported").to_string());
+            return;
+        };
+        if interpreter.locate().is_none() {
+            let mut args = FluentArgs::new();
+            args.set("interpreter", interpreter.label());
+            self.error_message = Some(self.i18n.t_with_args("verify-missing-interpreter", Some(&args)));
+            return;
+        }
+
+        let index = self.code_snippet_index.min(seq.code_snippets().len().saturating_sub(1));
+        let target = (seq.number, index);
+        if self.verify_armed != Some(target) {
+            self.verify_armed = Some(target);
+            let mut args = FluentArgs::new();
+            args.set("language", snippet.language.clone());
+            args.set("seconds", verify::DEFAULT_TIMEOUT.as_secs());
+            self.error_message = Some(self.i18n.t_with_args("verify-confirm", Some(&args)));
+            return;
+        }
+        self.verify_armed = None;
+
+        if let Some(pending) = self.pending_verify.take() {
+            pending.handle.abort();
+        }
+        let handle = tokio::spawn(async move {
+            verify::verify_snippet(&snippet, &seq, verify::DEFAULT_TIMEOUT).await
+        });
+        self.pending_verify = Some(PendingVerify { handle });
+        self.code_verification = Some(CodeVerification {
+            number: target.0,
+            snippet_index: target.1,
+            status: VerifyStatus::Running,
+        });
+        self.error_message = None;
+    }
+
+    /// Poll the running verification and record its result
+    async fn poll_pending_verify(&mut self) -> Result<()> {
+        if let Some(pending) = self.pending_verify.as_ref() {
+            if pending.handle.is_finished() {
+                let pending = self.pending_verify.take().unwrap();
+                let status = match pending.handle.await {
+                    Ok(Ok(report)) => VerifyStatus::Finished(report),
+                    Ok(Err(e)) => VerifyStatus::Failed(self.i18n.error(&e)),
+                    Err(e) => VerifyStatus::Failed(e.to_string()),
+                };
+                if let Some(verification) = self.code_verification.as_mut() {
+                    verification.status = status;
                 }
             }
         }
         Ok(())
     }
 
+    /// Copy the selected Code tab snippet to the clipboard
+    fn copy_snippet_to_clipboard(&mut self) -> Result<()> {
+        use fluent::FluentArgs;
+
+        let Some((snippet, _)) = self.selected_code_snippet() else {
+            self.error_message = Some(self.i18n.t("code-no-snippets").to_string());
+            return Ok(());
+        };
+
+        match arboard::Clipboard::new().and_then(|mut clip| clip.set_text(snippet.code.clone())) {
+            Ok(_) => {
+                let mut args = FluentArgs::new();
+                args.set("language", snippet.language.clone());
+                self.error_message = Some(self.i18n.t_with_args("code-copy-success", Some(&args)));
+            }
+            Err(e) => {
+                let mut args = FluentArgs::new();
+                args.set("message", e.to_string());
+                self.error_message = Some(self.i18n.t_with_args("error-clipboard", Some(&args)));
+            }
+        }
+        Ok(())
+    }
+
+    /// The real constant behind a `cons`/`cofr` entry, from the b-file when it is loaded
+    pub fn current_constant(&self) -> Option<Constant> {
+        let seq = self.current_sequence.as_ref()?;
+        Constant::from_sequence(seq, self.bfile_data.as_deref())
+    }
+
+    /// Show more or fewer decimal places of the constant, up to what its terms determine
+    fn change_constant_places(&mut self, more: bool) {
+        let Some(constant) = self.current_constant() else {
+            return;
+        };
+        let max = constant.max_places().max(CONSTANT_PLACES_STEP);
+        let places = self.constant_places.min(max);
+        self.constant_places = if more {
+            (places + CONSTANT_PLACES_STEP).min(max)
+        } else {
+            places.saturating_sub(CONSTANT_PLACES_STEP).max(CONSTANT_PLACES_STEP)
+        };
+    }
+
+    /// Copy the constant, as shown in the Overview, as a plain decimal string
+    fn copy_constant_to_clipboard(&mut self) {
+        use fluent::FluentArgs;
+
+        let Some(constant) = self.current_constant() else {
+            return;
+        };
+        let value = constant.to_decimal(self.constant_places);
+
+        match arboard::Clipboard::new().and_then(|mut clip| clip.set_text(value)) {
+            Ok(_) => {
+                let mut args = FluentArgs::new();
+                args.set("places", self.constant_places.min(constant.max_places()));
+                self.error_message = Some(self.i18n.t_with_args("constant-copy-success", Some(&args)));
+            }
+            Err(e) => {
+                let mut args = FluentArgs::new();
+                args.set("message", e.to_string());
+                self.error_message = Some(self.i18n.t_with_args("error-clipboard", Some(&args)));
+            }
+        }
+    }
+
+    /// Save the selected Code tab snippet as a source file with the matching extension
+    fn export_snippet_to_file(&mut self) -> Result<()> {
+        let Some((snippet, ordinal)) = self.selected_code_snippet() else {
+            self.error_message = Some(self.i18n.t("code-no-snippets").to_string());
+            return Ok(());
+        };
+        let Some(seq) = self.current_sequence.as_ref() else {
+            return Ok(());
+        };
+
+        let filename = snippet.filename(seq, ordinal);
+        let content = snippet.to_source(seq);
+        self.open_save_as(filename, SaveAsContent::Text(content));
+        Ok(())
+    }
+
     /// Get the current spinner character based on animation frame
     pub fn get_spinner_char(&self) -> char {
         // Divide by 2 to slow down animation (updates every ~200ms instead of ~100ms)
@@ -2510,3 +4438,104 @@ fn calculate_scroll_offset(
     // Selected item is already visible, keep current offset
     current_offset as u16
 }
+
+/// Where the saved theme is in `themes`; an index from an older settings file is a
+/// position among the built-in themes, which keep their order
+fn theme_position(themes: &[Theme], choice: &ThemeChoice) -> Option<usize> {
+    match choice {
+        ThemeChoice::Name(name) => themes
+            .iter()
+            .position(|theme| theme.name.eq_ignore_ascii_case(name)),
+        ThemeChoice::Index(index) => (*index < themes.len()).then_some(*index),
+    }
+}
+
+/// The theme shown after the theme files were loaded again: the saved choice while it is
+/// there, else the one shown before (an automatic pick), else the first
+fn reloaded_theme(themes: &[Theme], saved: Option<&ThemeChoice>, shown: &str) -> usize {
+    saved
+        .filter(|choice| !choice.is_auto())
+        .and_then(|choice| theme_position(themes, choice))
+        .or_else(|| theme_position(themes, &ThemeChoice::Name(shown.to_string())))
+        .unwrap_or(0)
+}
+
+/// Light or Dark, whichever suits the terminal background (Dark when it cannot be told)
+fn auto_theme(themes: &[Theme]) -> usize {
+    let name = match color::detect_background(BACKGROUND_QUERY_TIMEOUT) {
+        Some(Background::Light) => "Light",
+        Some(Background::Dark) | None => "Dark",
+    };
+    theme_position(themes, &ThemeChoice::Name(name.to_string())).unwrap_or(0)
+}
+
+/// Characters typed into a text field: no Ctrl or Alt
+fn is_text_key(key: &KeyEvent) -> bool {
+    matches!(key.code, KeyCode::Char(_))
+        && !key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+}
+
+/// Scroll position of a help window after a modal navigation action
+fn scroll_modal(scroll: u16, action: Action) -> u16 {
+    match action {
+        Action::Up => scroll.saturating_sub(1),
+        Action::Down => scroll.saturating_add(1),
+        Action::PageUp => scroll.saturating_sub(10),
+        Action::PageDown => scroll.saturating_add(10),
+        Action::Top => 0,
+        Action::Bottom => 100, // Arbitrary large number to scroll to bottom
+        _ => scroll,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reloaded_theme() {
+        let builtin = Theme::builtin();
+        let mut custom = builtin[1].clone();
+        custom.name = "Mine".to_string();
+        let mut with_file = builtin.clone();
+        with_file.push(custom);
+        let saved = ThemeChoice::Name("mine".to_string());
+        let light = theme_position(&builtin, &ThemeChoice::Name("Light".to_string())).unwrap();
+
+        // The file goes away: the first theme shows, and the saved one is back with the file
+        assert_eq!(reloaded_theme(&builtin, Some(&saved), "Mine"), 0);
+        assert_eq!(reloaded_theme(&builtin, Some(&saved), &builtin[0].name), 0);
+        assert_eq!(reloaded_theme(&with_file, Some(&saved), &builtin[0].name), builtin.len());
+        // Nothing saved, or an automatic pick: the theme shown stays
+        assert_eq!(reloaded_theme(&with_file, None, "Light"), light);
+        let auto = ThemeChoice::Name("auto".to_string());
+        assert_eq!(reloaded_theme(&with_file, Some(&auto), "Light"), light);
+    }
+
+    #[test]
+    fn test_webcam_interval_from_duration() {
+        assert_eq!(
+            WebcamInterval::from_duration(Duration::from_secs(5)),
+            WebcamInterval::FiveSeconds
+        );
+        assert_eq!(
+            WebcamInterval::from_duration(Duration::from_secs(60)),
+            WebcamInterval::OneMinute
+        );
+        let custom = WebcamInterval::from_duration(Duration::from_secs(90));
+        assert_eq!(custom, WebcamInterval::Custom(Duration::from_secs(90)));
+        assert_eq!(custom.as_duration(), Some(Duration::from_secs(90)));
+        assert_eq!(WebcamInterval::Manual.as_duration(), None);
+    }
+
+    #[test]
+    fn test_webcam_retry_delay() {
+        assert_eq!(webcam_retry_delay(1), WEBCAM_RETRY_MIN);
+        assert_eq!(webcam_retry_delay(2), WEBCAM_RETRY_MIN * 2);
+        assert_eq!(webcam_retry_delay(3), WEBCAM_RETRY_MIN * 4);
+        assert_eq!(webcam_retry_delay(7), WEBCAM_RETRY_MAX);
+        assert_eq!(webcam_retry_delay(u32::MAX), WEBCAM_RETRY_MAX);
+    }
+}
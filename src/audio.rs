@@ -0,0 +1,453 @@
+//! Sequence sonification, after the OEIS "listen" page.
+//!
+//! Every term becomes one beat-long note whose pitch is `lowest + (a(n) mod range)`
+//! (MIDI note numbers, so the default 88 notes from A0 span a piano keyboard).
+//! The notes are written as a Standard MIDI File, or synthesized into a mono
+//! 16-bit WAV with a simple additive voice per instrument.
+
+use crate::api::{models::BFileEntry, Sequence};
+use crate::i18n::Localized;
+use crate::plot;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::f64::consts::TAU;
+use std::path::Path;
+
+pub const DEFAULT_TEMPO: u32 = 100;
+pub const MIN_TEMPO: u32 = 20;
+pub const MAX_TEMPO: u32 = 400;
+/// Pitch range and lowest note of the OEIS player: the 88 keys of a piano from A0
+pub const DEFAULT_RANGE: u32 = 88;
+pub const DEFAULT_LOWEST_NOTE: u8 = 21;
+/// Longest piece rendered (b-files can hold tens of thousands of terms)
+pub const MAX_NOTES: usize = 1000;
+
+const TICKS_PER_BEAT: u16 = 480;
+const VELOCITY: u8 = 80;
+const SAMPLE_RATE: u32 = 22_050;
+/// Peak level of the synthesized voice, as a share of full scale
+const WAV_LEVEL: f64 = 0.5;
+/// Fade at both ends of every synthesized note so notes join without clicks
+const WAV_FADE_SECONDS: f64 = 0.005;
+
+/// Why a sequence could not be played or saved
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum AudioError {
+    #[error("Unsupported audio format; use a .mid or .wav file name")]
+    UnsupportedFormat,
+    #[error("No integer terms to play")]
+    NoTerms,
+}
+
+impl Localized for AudioError {
+    fn message_key(&self) -> &'static str {
+        match self {
+            Self::UnsupportedFormat => "listen-unsupported-format",
+            Self::NoTerms => "listen-no-terms",
+        }
+    }
+}
+
+/// File types the notes can be written as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioFormat {
+    Midi,
+    Wav,
+}
+
+impl AudioFormat {
+    /// Pick the format from a path's extension (`.mid`, `.midi` or `.wav`)
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path
+            .extension()?
+            .to_str()?
+            .to_ascii_lowercase()
+            .as_str()
+        {
+            "mid" | "midi" => Some(Self::Midi),
+            "wav" => Some(Self::Wav),
+            _ => None,
+        }
+    }
+}
+
+/// Instruments offered for the notes: a General MIDI program for MIDI files
+/// and a matching additive voice for WAV files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Instrument {
+    #[default]
+    Piano,
+    Marimba,
+    Organ,
+    Guitar,
+    Strings,
+    Flute,
+    Synth,
+}
+
+impl Instrument {
+    pub const ALL: [Self; 7] = [
+        Self::Piano,
+        Self::Marimba,
+        Self::Organ,
+        Self::Guitar,
+        Self::Strings,
+        Self::Flute,
+        Self::Synth,
+    ];
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            Self::Piano => "listen-instrument-piano",
+            Self::Marimba => "listen-instrument-marimba",
+            Self::Organ => "listen-instrument-organ",
+            Self::Guitar => "listen-instrument-guitar",
+            Self::Strings => "listen-instrument-strings",
+            Self::Flute => "listen-instrument-flute",
+            Self::Synth => "listen-instrument-synth",
+        }
+    }
+
+    /// General MIDI program number (0-based)
+    pub fn program(&self) -> u8 {
+        match self {
+            Self::Piano => 0,
+            Self::Marimba => 12,
+            Self::Organ => 19,
+            Self::Guitar => 24,
+            Self::Strings => 48,
+            Self::Flute => 73,
+            Self::Synth => 80,
+        }
+    }
+
+    fn index(&self) -> usize {
+        Self::ALL.iter().position(|i| i == self).unwrap_or(0)
+    }
+
+    pub fn next(&self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    pub fn previous(&self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    /// Relative strength of the 1st, 2nd, 3rd… harmonics of the WAV voice
+    fn harmonics(&self) -> &'static [f64] {
+        match self {
+            Self::Piano => &[1.0, 0.5, 0.25, 0.12, 0.06],
+            Self::Marimba => &[1.0, 0.0, 0.0, 0.3],
+            Self::Organ => &[1.0, 0.7, 0.5, 0.0, 0.3, 0.0, 0.0, 0.2],
+            Self::Guitar => &[1.0, 0.6, 0.4, 0.3, 0.2, 0.1],
+            Self::Strings => &[1.0, 0.5, 0.33, 0.25, 0.2, 0.17, 0.14, 0.12],
+            Self::Flute => &[1.0, 0.1, 0.05],
+            Self::Synth => &[1.0, 0.0, 0.33, 0.0, 0.2, 0.0, 0.14, 0.0, 0.11],
+        }
+    }
+
+    /// Exponential decay rate per second for struck and plucked voices (None sustains)
+    fn decay(&self) -> Option<f64> {
+        match self {
+            Self::Piano => Some(3.0),
+            Self::Marimba => Some(9.0),
+            Self::Guitar => Some(5.0),
+            Self::Organ | Self::Strings | Self::Flute | Self::Synth => None,
+        }
+    }
+}
+
+/// How terms are turned into notes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SoundSettings {
+    /// Beats (notes) per minute
+    pub tempo: u32,
+    pub instrument: Instrument,
+    /// Number of distinct pitches; terms are taken mod this
+    pub range: u32,
+    /// MIDI note number of a term ≡ 0
+    pub lowest_note: u8,
+}
+
+impl Default for SoundSettings {
+    fn default() -> Self {
+        Self {
+            tempo: DEFAULT_TEMPO,
+            instrument: Instrument::default(),
+            range: DEFAULT_RANGE,
+            lowest_note: DEFAULT_LOWEST_NOTE,
+        }
+    }
+}
+
+impl SoundSettings {
+    /// The settings with every value inside what MIDI can play
+    pub fn clamped(self) -> Self {
+        let lowest_note = self.lowest_note.min(126);
+        Self {
+            tempo: self.tempo.clamp(MIN_TEMPO, MAX_TEMPO),
+            instrument: self.instrument,
+            range: self.range.clamp(2, 128 - lowest_note as u32),
+            lowest_note,
+        }
+    }
+}
+
+/// A sequence mapped to notes, ready to be written as MIDI or WAV
+#[derive(Debug, Clone)]
+pub struct Sonification {
+    pub sequence: Sequence,
+    pub settings: SoundSettings,
+    /// MIDI note numbers, one per term
+    pub notes: Vec<u8>,
+    /// Whether the terms came from the b-file rather than the entry's data
+    pub from_bfile: bool,
+}
+
+impl Sonification {
+    /// Notes for the b-file when it is available, otherwise the terms listed in the entry
+    /// (at most [`MAX_NOTES`]; terms that are not integers are skipped)
+    pub fn new(sequence: &Sequence, bfile: Option<&[BFileEntry]>, settings: SoundSettings) -> Self {
+        let settings = settings.clamped();
+        let (values, from_bfile) = match bfile.filter(|entries| !entries.is_empty()) {
+            Some(entries) => (
+                entries.iter().map(|entry| entry.value.clone()).collect(),
+                true,
+            ),
+            None => (sequence.parse_data(), false),
+        };
+
+        let notes = values
+            .iter()
+            .filter_map(|value| plot::residue(value, settings.range))
+            .map(|r| (settings.lowest_note as u32 + r).min(127) as u8)
+            .take(MAX_NOTES)
+            .collect();
+
+        Self {
+            sequence: sequence.clone(),
+            settings,
+            notes,
+            from_bfile,
+        }
+    }
+
+    /// Length of the piece in seconds
+    pub fn duration_seconds(&self) -> f64 {
+        self.notes.len() as f64 * 60.0 / self.settings.tempo as f64
+    }
+
+    /// Write the notes in `format`
+    pub fn render(&self, format: AudioFormat) -> Result<Vec<u8>> {
+        if self.notes.is_empty() {
+            return Err(AudioError::NoTerms.into());
+        }
+        Ok(match format {
+            AudioFormat::Midi => self.to_midi(),
+            AudioFormat::Wav => self.to_wav(),
+        })
+    }
+
+    /// Render the notes and write them to `path`, choosing the format from its extension
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let format = AudioFormat::from_path(path)
+            .ok_or(AudioError::UnsupportedFormat)?;
+        let bytes = self.render(format)?;
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, bytes).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Standard MIDI File (format 0): one track, one note per beat
+    pub fn to_midi(&self) -> Vec<u8> {
+        let mut track = Vec::new();
+
+        // Track name, tempo and instrument
+        let name = format!("{} {}", self.sequence.a_number(), self.sequence.name);
+        let name: String = name.chars().take(80).collect();
+        track.extend([0x00, 0xFF, 0x03]);
+        write_vlq(&mut track, name.len() as u32);
+        track.extend(name.as_bytes());
+        let micros_per_beat = 60_000_000 / self.settings.tempo;
+        track.extend([0x00, 0xFF, 0x51, 0x03]);
+        track.extend(&micros_per_beat.to_be_bytes()[1..]);
+        track.extend([0x00, 0xC0, self.settings.instrument.program()]);
+
+        for &note in &self.notes {
+            track.extend([0x00, 0x90, note, VELOCITY]);
+            write_vlq(&mut track, TICKS_PER_BEAT as u32);
+            track.extend([0x80, note, 0x40]);
+        }
+        track.extend([0x00, 0xFF, 0x2F, 0x00]);
+
+        let mut file = Vec::with_capacity(track.len() + 22);
+        file.extend(b"MThd");
+        file.extend(6u32.to_be_bytes());
+        file.extend(0u16.to_be_bytes()); // format 0
+        file.extend(1u16.to_be_bytes()); // one track
+        file.extend(TICKS_PER_BEAT.to_be_bytes());
+        file.extend(b"MTrk");
+        file.extend((track.len() as u32).to_be_bytes());
+        file.extend(track);
+        file
+    }
+
+    /// Mono 16-bit PCM WAV with the instrument's additive voice
+    pub fn to_wav(&self) -> Vec<u8> {
+        let note_samples = (SAMPLE_RATE as u64 * 60 / self.settings.tempo as u64) as usize;
+        let rate = SAMPLE_RATE as f64;
+        let fade = (WAV_FADE_SECONDS * rate) as usize;
+        let instrument = self.settings.instrument;
+        let harmonics = instrument.harmonics();
+
+        let mut samples: Vec<i16> = Vec::with_capacity(note_samples * self.notes.len());
+        for &note in &self.notes {
+            let frequency = 440.0 * 2f64.powf((note as f64 - 69.0) / 12.0);
+            // Harmonics above the Nyquist frequency would alias
+            let partials: Vec<(f64, f64)> = harmonics
+                .iter()
+                .enumerate()
+                .map(|(i, weight)| ((i + 1) as f64 * frequency, *weight))
+                .filter(|(f, weight)| *f < rate / 2.0 && *weight > 0.0)
+                .collect();
+            let total: f64 = partials.iter().map(|(_, weight)| weight).sum::<f64>().max(1.0);
+
+            for i in 0..note_samples {
+                let t = i as f64 / rate;
+                let wave: f64 = partials
+                    .iter()
+                    .map(|(f, weight)| weight * (TAU * f * t).sin())
+                    .sum::<f64>()
+                    / total;
+                let mut envelope = match instrument.decay() {
+                    Some(decay) => (-decay * t).exp(),
+                    // Sustained voices ease off over the last fifth of the beat
+                    None => ((note_samples - i) as f64 / (note_samples as f64 * 0.2)).min(1.0),
+                };
+                envelope *= (i.min(note_samples - 1 - i) as f64 / fade.max(1) as f64).min(1.0);
+                samples.push((wave * envelope * WAV_LEVEL * i16::MAX as f64) as i16);
+            }
+        }
+
+        let data_len = (samples.len() * 2) as u32;
+        let mut file = Vec::with_capacity(44 + data_len as usize);
+        file.extend(b"RIFF");
+        file.extend((36 + data_len).to_le_bytes());
+        file.extend(b"WAVE");
+        file.extend(b"fmt ");
+        file.extend(16u32.to_le_bytes());
+        file.extend(1u16.to_le_bytes()); // PCM
+        file.extend(1u16.to_le_bytes()); // mono
+        file.extend(SAMPLE_RATE.to_le_bytes());
+        file.extend((SAMPLE_RATE * 2).to_le_bytes()); // bytes per second
+        file.extend(2u16.to_le_bytes()); // block align
+        file.extend(16u16.to_le_bytes()); // bits per sample
+        file.extend(b"data");
+        file.extend(data_len.to_le_bytes());
+        for sample in samples {
+            file.extend(sample.to_le_bytes());
+        }
+        file
+    }
+}
+
+/// MIDI variable-length quantity: 7 bits per byte, high bit set on all but the last
+fn write_vlq(out: &mut Vec<u8>, mut value: u32) {
+    let mut bytes = vec![(value & 0x7F) as u8];
+    value >>= 7;
+    while value > 0 {
+        bytes.push((value & 0x7F) as u8 | 0x80);
+        value >>= 7;
+    }
+    out.extend(bytes.iter().rev());
+}
+
+/// Scientific pitch name of a MIDI note number, e.g. 60 → "C4", 21 → "A0"
+pub fn note_name(note: u8) -> String {
+    const NAMES: [&str; 12] = [
+        "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+    ];
+    format!("{}{}", NAMES[note as usize % 12], note as i32 / 12 - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Sequence {
+        serde_json::from_value(serde_json::json!({
+            "number": 45,
+            "data": "0,1,1,2,3,5,8,13,21,34,55,89,144",
+            "name": "Fibonacci numbers: F(n) = F(n-1) + F(n-2) with F(0) = 0 & F(1) = 1.",
+            "offset": "0,4",
+            "keyword": "core,nonn",
+            "author": "N. J. A. Sloane",
+            "created": "1991-04-30T03:00:00-04:00",
+            "time": "2024-01-01T00:00:00-05:00",
+            "revision": 1,
+            "references": 0
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_pitch_mapping() {
+        let settings = SoundSettings {
+            range: 12,
+            lowest_note: 60,
+            ..SoundSettings::default()
+        };
+        let sound = Sonification::new(&sample(), None, settings);
+        assert_eq!(
+            sound.notes,
+            vec![60, 61, 61, 62, 63, 65, 68, 61, 69, 70, 67, 65, 60]
+        );
+        assert_eq!(sound.duration_seconds(), 13.0 * 60.0 / 100.0);
+
+        // Ranges that would run past note 127 are cut down
+        let high = SoundSettings {
+            range: 88,
+            lowest_note: 100,
+            ..SoundSettings::default()
+        }
+        .clamped();
+        assert_eq!(high.range, 28);
+        assert_eq!(note_name(60), "C4");
+        assert_eq!(note_name(21), "A0");
+    }
+
+    #[test]
+    fn test_midi_and_wav() {
+        let settings = SoundSettings {
+            tempo: 120,
+            instrument: Instrument::Organ,
+            ..SoundSettings::default()
+        };
+        let sound = Sonification::new(&sample(), None, settings);
+
+        let midi = sound.render(AudioFormat::Midi).unwrap();
+        assert_eq!(&midi[0..4], b"MThd");
+        assert_eq!(&midi[8..14], &[0, 0, 0, 1, 0x01, 0xE0]);
+        assert_eq!(&midi[14..18], b"MTrk");
+        let track_len = u32::from_be_bytes(midi[18..22].try_into().unwrap()) as usize;
+        assert_eq!(midi.len(), 22 + track_len);
+        // 500000 µs per beat at 120 bpm, then the organ program
+        assert!(midi.windows(7).any(|w| w == [0xFF, 0x51, 0x03, 0x07, 0xA1, 0x20, 0x00]));
+        assert!(midi.windows(2).any(|w| w == [0xC0, 19]));
+        assert_eq!(midi.windows(2).filter(|w| w[0] == 0x90).count(), 13);
+        assert!(midi.ends_with(&[0xFF, 0x2F, 0x00]));
+
+        let wav = sound.render(AudioFormat::Wav).unwrap();
+        assert_eq!(&wav[0..4], b"RIFF");
+        assert_eq!(&wav[8..16], b"WAVEfmt ");
+        let data_len = u32::from_le_bytes(wav[40..44].try_into().unwrap()) as usize;
+        assert_eq!(data_len, 13 * (SAMPLE_RATE as usize / 2) * 2);
+        assert_eq!(wav.len(), 44 + data_len);
+
+        assert_eq!(AudioFormat::from_path(Path::new("fib.MID")), Some(AudioFormat::Midi));
+        assert_eq!(AudioFormat::from_path(Path::new("fib.ogg")), None);
+    }
+}
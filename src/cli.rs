@@ -1,7 +1,18 @@
-use crate::api::{OEISClient, SearchQuery, Sequence};
+use crate::api::{client::{self, RandomFilter, SequenceCategory}, models::CodeSnippet, Cache, OEISClient, SearchQuery, Sequence, UserSettings};
+use crate::app::{BulkLayout, ExportFormat, GraphType, WebcamInterval, WebcamLaunch};
+use crate::audio::{self, Instrument, Sonification};
+use crate::i18n::{I18n, Language, Localized};
+use crate::plot::Plot;
+use crate::verify::{self, Interpreter, VerifyOutcome, VerifyReport};
+use crate::ui::export::{export_sequence, write_bulk_export};
+use crate::utils::{filename, paths};
+use anstream::{eprintln, print, println};
 use anyhow::{anyhow, Result};
-use clap::{builder::Styles, Parser, Subcommand, ValueEnum};
+use clap::{builder::Styles, ArgGroup, CommandFactory, Parser, Subcommand, ValueEnum};
+use clap_complete::{engine::ArgValueCompleter, env::{EnvCompleter, Shells}, CompletionCandidate, Shell};
 use owo_colors::OwoColorize;
+use rand::{rngs::StdRng, SeedableRng};
+use std::{ffi::OsStr, io::{self, Write}, path::PathBuf, time::Duration};
 
 /// Create custom color styles for help output
 fn styles() -> Styles {
@@ -53,12 +64,22 @@ fn styles() -> Styles {
     # Fetch sequence in different formats\n  \
     oeis fetch A000045                    # Human-readable\n  \
     oeis fetch A000045 -f csv             # CSV format\n  \
-    oeis fetch A000045 -f values -q       # Just numbers\n\n  \
+    oeis fetch A000045 -f values -q       # Just numbers\n  \
+    oeis fetch A000045 -f bibtex          # Citation entry\n\n  \
     # Pipe to external tools\n  \
     oeis fetch A000045 -f tsv -q | gnuplot -p -e \"plot '-' with lines\"\n  \
     oeis search \"prime\" -f values | head -3 | xargs -n1 oeis fetch\n\n  \
-    # Random sequence\n  \
-    oeis random -f json | jq .name\n\n\
+    # Random sequence, or the sequence of the day\n  \
+    oeis random -f json | jq .name\n  \
+    oeis daily\n\n  \
+    # Programs from the entry\n  \
+    oeis code A000045 --lang python\n\n  \
+    # Figures for slides\n  \
+    oeis plot A000045 --type log -o fib.svg\n\n  \
+    # Lobby display\n  \
+    oeis webcam --category nice --interval 30s --fullscreen\n\n  \
+    # Export all bookmarks at once\n  \
+    oeis export --bookmarks -f markdown -o ~/notes\n\n\
     For more information, visit: https://github.com/hako/oeis-tui",
     version,
     long_version = concat!(
@@ -72,6 +93,39 @@ fn styles() -> Styles {
 pub struct Cli {
     #[command(subcommand)]
     pub command: Option<Command>,
+
+    /// Accessibility mode: no animations, a high-contrast theme, and the focus described
+    /// on the bottom line for screen readers
+    #[arg(long)]
+    pub accessible: bool,
+
+    /// Plain line-oriented session instead of the full-screen interface
+    #[arg(long)]
+    pub plain: bool,
+
+    /// Language of the interface and of CLI output (en, fr, ja, es, ko, zh, de, pt, ru),
+    /// given before the command; defaults to the one chosen in the settings, then to the
+    /// locale (LANG, LC_MESSAGES)
+    // Not global: `code` and `verify` have a `--lang` of their own for the program language
+    #[arg(long, value_parser = parse_language)]
+    pub lang: Option<Language>,
+}
+
+impl Cli {
+    /// `--lang`, else the language chosen in the settings, else the one of the locale
+    pub fn language(&self) -> Language {
+        self.lang
+            .or_else(|| UserSettings::load().language())
+            .or_else(Language::from_env)
+            .unwrap_or(Language::English)
+    }
+}
+
+fn parse_language(value: &str) -> Result<Language, String> {
+    Language::from_locale(value).ok_or_else(|| {
+        let codes: Vec<&str> = Language::all().iter().map(Language::code).collect();
+        format!("no translation for \"{}\" (available: {})", value, codes.join(", "))
+    })
 }
 
 #[derive(Debug, Clone, ValueEnum)]
@@ -86,6 +140,207 @@ pub enum OutputFormat {
     Tsv,
     /// Just sequence values, one per line
     Values,
+    /// LaTeX fragment with terms and typeset formulas
+    Latex,
+    /// BibTeX citation entry
+    Bibtex,
+    /// Jupyter notebook with the terms, Python programs and a starter plot
+    Ipynb,
+    /// Python script with the same cells as the notebook (`# %%` markers)
+    Py,
+}
+
+/// File formats for `oeis export`
+#[derive(Debug, Clone, ValueEnum)]
+pub enum ExportFileFormat {
+    Json,
+    Csv,
+    Txt,
+    Markdown,
+    /// B-file style index/value pairs (one file per sequence only)
+    Bfile,
+    Latex,
+    Bibtex,
+    /// Jupyter notebook (one file per sequence only)
+    Ipynb,
+    /// Python script (one file per sequence only)
+    Py,
+}
+
+impl ExportFileFormat {
+    fn export_format(&self) -> ExportFormat {
+        match self {
+            Self::Json => ExportFormat::Json,
+            Self::Csv => ExportFormat::Csv,
+            Self::Txt => ExportFormat::Txt,
+            Self::Markdown => ExportFormat::Markdown,
+            Self::Bfile => ExportFormat::BFile,
+            Self::Latex => ExportFormat::Latex,
+            Self::Bibtex => ExportFormat::BibTex,
+            Self::Ipynb => ExportFormat::Notebook,
+            Self::Py => ExportFormat::PythonScript,
+        }
+    }
+}
+
+/// Graph types for `oeis plot`
+#[derive(Debug, Clone, ValueEnum)]
+pub enum PlotType {
+    Line,
+    Scatter,
+    /// Scatter plot with a logarithmic y axis
+    Log,
+    Pin,
+    /// Histogram of the values
+    Histogram,
+    /// a(n) mod k (see --modulus)
+    Mod,
+    /// Members marked on an Ulam spiral
+    Ulam,
+    /// First differences a(n) - a(n-1)
+    Diff,
+    /// Ratios a(n) / a(n-1)
+    Ratio,
+}
+
+impl PlotType {
+    pub(crate) fn graph_type(&self) -> GraphType {
+        match self {
+            Self::Line => GraphType::Line,
+            Self::Scatter => GraphType::Scatter,
+            Self::Log => GraphType::LogScatter,
+            Self::Pin => GraphType::PinPlot,
+            Self::Histogram => GraphType::Histogram,
+            Self::Mod => GraphType::ModK,
+            Self::Ulam => GraphType::UlamSpiral,
+            Self::Diff => GraphType::Differences,
+            Self::Ratio => GraphType::Ratio,
+        }
+    }
+}
+
+/// Instruments for `oeis listen`
+#[derive(Debug, Clone, ValueEnum)]
+pub enum ListenInstrument {
+    Piano,
+    Marimba,
+    Organ,
+    Guitar,
+    Strings,
+    Flute,
+    /// Square-wave synth lead
+    Synth,
+}
+
+impl ListenInstrument {
+    fn instrument(&self) -> Instrument {
+        match self {
+            Self::Piano => Instrument::Piano,
+            Self::Marimba => Instrument::Marimba,
+            Self::Organ => Instrument::Organ,
+            Self::Guitar => Instrument::Guitar,
+            Self::Strings => Instrument::Strings,
+            Self::Flute => Instrument::Flute,
+            Self::Synth => Instrument::Synth,
+        }
+    }
+}
+
+/// Categories for `oeis webcam`
+#[derive(Debug, Clone, ValueEnum)]
+pub enum WebcamCategory {
+    /// Any sequence
+    All,
+    /// Best sequences (keyword:nice)
+    #[value(alias = "best")]
+    Nice,
+    /// Sequences needing more terms (keyword:more)
+    More,
+    /// Recently added or changed (recent.txt)
+    Recent,
+    /// Not yet edited (keyword:uned)
+    #[value(alias = "unedited")]
+    Uned,
+    Core,
+    Easy,
+    Hard,
+    /// Little is known (keyword:unkn)
+    #[value(alias = "unknown")]
+    Unkn,
+    /// Your bookmarks
+    Bookmarks,
+}
+
+impl WebcamCategory {
+    fn category(&self) -> SequenceCategory {
+        match self {
+            Self::All => SequenceCategory::All,
+            Self::Nice => SequenceCategory::Best,
+            Self::More => SequenceCategory::NeedingTerms,
+            Self::Recent => SequenceCategory::Recent,
+            Self::Uned => SequenceCategory::Unedited,
+            Self::Core => SequenceCategory::Core,
+            Self::Easy => SequenceCategory::Easy,
+            Self::Hard => SequenceCategory::Hard,
+            Self::Unkn => SequenceCategory::Unknown,
+            Self::Bookmarks => SequenceCategory::Bookmarks,
+        }
+    }
+}
+
+/// Shortest `oeis webcam --interval`, to go easy on oeis.org
+const MIN_WEBCAM_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Why `oeis webcam --interval` could not be read
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+enum IntervalError {
+    #[error("`{0}` is not an interval like 30s, 2m or 1h")]
+    Invalid(String),
+    #[error("unknown unit `{0}` (use s, m or h)")]
+    UnknownUnit(String),
+    #[error("the interval must be at least {}s", MIN_WEBCAM_INTERVAL.as_secs())]
+    TooShort,
+}
+
+impl Localized for IntervalError {
+    fn message_key(&self) -> &'static str {
+        match self {
+            Self::Invalid(_) => "cli-interval-invalid",
+            Self::UnknownUnit(_) => "cli-interval-unit",
+            Self::TooShort => "cli-interval-too-short",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match self {
+            Self::Invalid(interval) => vec![("interval", interval.clone())],
+            Self::UnknownUnit(unit) => vec![("unit", unit.clone())],
+            Self::TooShort => vec![("seconds", MIN_WEBCAM_INTERVAL.as_secs().to_string())],
+        }
+    }
+}
+
+/// Parse an interval such as `30s`, `2m`, `1h` or `45` (seconds)
+fn parse_interval(text: &str) -> std::result::Result<Duration, IntervalError> {
+    let text = text.trim();
+    let (digits, unit) = match text.find(|c: char| !c.is_ascii_digit()) {
+        Some(index) => text.split_at(index),
+        None => (text, "s"),
+    };
+    let not_an_interval = || IntervalError::Invalid(text.to_string());
+    let value: u64 = digits.parse().map_err(|_| not_an_interval())?;
+    let seconds = match unit {
+        "s" | "sec" => Some(value),
+        "m" | "min" => value.checked_mul(60),
+        "h" => value.checked_mul(3600),
+        _ => return Err(IntervalError::UnknownUnit(unit.to_string())),
+    }
+    .ok_or_else(not_an_interval)?;
+    let interval = Duration::from_secs(seconds);
+    if interval < MIN_WEBCAM_INTERVAL {
+        return Err(IntervalError::TooShort);
+    }
+    Ok(interval)
 }
 
 #[derive(Subcommand, Debug)]
@@ -102,7 +357,8 @@ pub enum Command {
     # Output formats for piping\n  \
     oeis search \"fibonacci\" -f values        # Just A-numbers\n  \
     oeis search \"prime\" -f values -v         # A-numbers + names\n  \
-    oeis search \"triangle\" -f json           # Full JSON\n\n  \
+    oeis search \"triangle\" -f json           # Full JSON\n  \
+    oeis search \"catalan\" -f bibtex          # Citations for each result\n\n  \
     # Limit results\n  \
     oeis search \"prime\" --limit 5")]
     Search {
@@ -126,16 +382,180 @@ pub enum Command {
     oeis fetch A000045 -f json                # Full JSON\n  \
     oeis fetch A000045 -f csv                 # CSV (index,value)\n  \
     oeis fetch A000045 -f tsv                 # TSV for gnuplot\n  \
-    oeis fetch A000045 -f values              # Just numbers\n\n  \
+    oeis fetch A000045 -f values              # Just numbers\n  \
+    oeis fetch A000045 -f latex               # LaTeX fragment\n  \
+    oeis fetch A000045 -f bibtex              # BibTeX citation\n  \
+    oeis fetch A000045 -f ipynb > fib.ipynb   # Jupyter notebook\n\n  \
     # Piping to external tools\n  \
     oeis fetch A000045 -f values -q | head -20\n  \
     oeis fetch A000045 -f json | jq '.name'\n  \
     oeis fetch A000045 -f tsv -q | gnuplot -p -e \"plot '-' with lines\"\n\n  \
     # Export to file\n  \
-    oeis fetch A000045 -f csv > fibonacci.csv")]
+    oeis fetch A000045 -f csv > fibonacci.csv\n  \
+    oeis fetch A000045 -f bibtex >> references.bib")]
     Fetch {
         /// A-number of the sequence
+        #[arg(add = ArgValueCompleter::new(complete_anumber))]
+        id: String,
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "plain")]
+        format: OutputFormat,
+        /// Quiet mode (minimal output)
+        #[arg(short, long)]
+        quiet: bool,
+    },
+    /// Print the programs listed in a sequence entry (PARI, Python, Haskell, ...)
+    #[command(after_help = "EXAMPLES:\n  \
+    # List the programs available for a sequence\n  \
+    oeis code A000045\n\n  \
+    # Print one language, ready to run\n  \
+    oeis code A000045 --lang python | python3 -\n  \
+    oeis code A000045 --lang pari > fib.gp\n\n  \
+    # Save every Haskell program as A000045.hs, A000045-2.hs, ...\n  \
+    oeis code A000045 --lang haskell --save")]
+    Code {
+        /// A-number of the sequence
+        #[arg(add = ArgValueCompleter::new(complete_anumber))]
+        id: String,
+        /// Only print programs in this language (e.g. python, pari, haskell, magma, sage)
+        #[arg(short, long)]
+        lang: Option<String>,
+        /// Write each program to a source file with the matching extension
+        #[arg(short, long)]
+        save: bool,
+    },
+    /// Run a sequence's PARI/GP and Python programs locally and compare their output with the entry
+    #[command(after_help = "EXAMPLES:\n  \
+    # Verify every PARI/GP and Python program in the entry\n  \
+    oeis verify A000045\n\n  \
+    # Only the Python programs, with a longer time limit\n  \
+    oeis verify A000045 --lang python --timeout 30\n\n\
+    Programs run with the local gp/python3 interpreter in a scratch directory with a\n\
+    minimal environment and a time limit. This is not a security sandbox: only verify\n\
+    programs you are comfortable running.")]
+    Verify {
+        /// A-number of the sequence
+        #[arg(add = ArgValueCompleter::new(complete_anumber))]
+        id: String,
+        /// Only verify programs in this language (pari or python)
+        #[arg(short, long)]
+        lang: Option<String>,
+        /// Time limit per program, in seconds
+        #[arg(short, long, default_value_t = crate::verify::DEFAULT_TIMEOUT.as_secs())]
+        timeout: u64,
+    },
+    /// Save a graph of a sequence as an SVG or PNG figure
+    #[command(after_help = "EXAMPLES:\n  \
+    # Logarithmic plot as SVG\n  \
+    oeis plot A000045 --type log -o fib.svg\n\n  \
+    # Scatter plot of the whole b-file as a PNG\n  \
+    oeis plot A000040 --type scatter --bfile -o primes.png\n\n  \
+    # Primes mod 6 and on the Ulam spiral\n  \
+    oeis plot A000040 --type mod -k 6 --bfile -o primes-mod6.svg\n  \
+    oeis plot A000040 --type ulam --bfile -o ulam.png\n\n  \
+    # Default name from the export settings (A000108.svg)\n  \
+    oeis plot A000108 --type pin")]
+    Plot {
+        /// A-number of the sequence
+        #[arg(add = ArgValueCompleter::new(complete_anumber))]
+        id: String,
+        /// Graph type
+        #[arg(short = 't', long = "type", value_enum, default_value = "line")]
+        graph_type: PlotType,
+        /// Output file; the extension (.svg or .png) picks the format
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Plot the b-file instead of the terms listed in the entry
+        #[arg(short, long)]
+        bfile: bool,
+        /// k for `--type mod`
+        #[arg(short = 'k', long, default_value_t = crate::plot::DEFAULT_MODULUS, value_parser = clap::value_parser!(u32).range(2..))]
+        modulus: u32,
+    },
+    /// Play a sequence as notes (term mod range) and save it as a MIDI or WAV file
+    #[command(after_help = "EXAMPLES:\n  \
+    # Fibonacci numbers as a MIDI file\n  \
+    oeis listen A000045 -o fib.mid\n\n  \
+    # The b-file, a little faster on strings, as a WAV file\n  \
+    oeis listen A000040 --bfile --tempo 160 --instrument strings -o primes.wav\n\n  \
+    # One octave of pitches from middle C\n  \
+    oeis listen A000027 --range 12 --lowest 60 -o naturals.mid\n\n  \
+    Options left out use the settings last chosen in the TUI's listen dialog.")]
+    Listen {
+        /// A-number of the sequence
+        #[arg(add = ArgValueCompleter::new(complete_anumber))]
         id: String,
+        /// Output file; the extension (.mid or .wav) picks the format
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Play the b-file instead of the terms listed in the entry (first 1000 terms)
+        #[arg(short, long)]
+        bfile: bool,
+        /// Notes per minute
+        #[arg(short, long, value_parser = clap::value_parser!(u32).range(audio::MIN_TEMPO as i64..=audio::MAX_TEMPO as i64))]
+        tempo: Option<u32>,
+        /// Instrument (General MIDI program in MIDI files, a matching voice in WAV files)
+        #[arg(short, long, value_enum)]
+        instrument: Option<ListenInstrument>,
+        /// Number of pitches; each term is played as lowest + (a(n) mod range) [default: 88]
+        #[arg(short, long, value_parser = clap::value_parser!(u32).range(2..=128))]
+        range: Option<u32>,
+        /// MIDI note number of the lowest pitch [default: 21, the piano's A0]
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(0..=126))]
+        lowest: Option<u8>,
+    },
+    /// Export search results, bookmarks or recently viewed sequences in one go
+    #[command(
+        group(ArgGroup::new("source").required(true).args(["search", "bookmarks", "history"])),
+        after_help = "EXAMPLES:\n  \
+    # All bookmarks as one Markdown document (./bookmarks.md)\n  \
+    oeis export --bookmarks -f markdown\n\n  \
+    # Search results as one file per sequence (~/exports/search-results/A*.json)\n  \
+    oeis export --search \"keyword:nice\" --limit 20 --split -o ~/exports\n\n  \
+    # Bibliography of the last 30 sequences viewed in the TUI\n  \
+    oeis export --history --limit 30 -f bibtex"
+    )]
+    Export {
+        /// Export the results of this search query
+        #[arg(long)]
+        search: Option<String>,
+        /// Export all bookmarked sequences
+        #[arg(long)]
+        bookmarks: bool,
+        /// Export recently viewed sequences
+        #[arg(long)]
+        history: bool,
+        /// Maximum number of search results (1-50) or history entries
+        #[arg(short, long)]
+        limit: Option<usize>,
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "json")]
+        format: ExportFileFormat,
+        /// Write one file per sequence into a subdirectory instead of one combined document
+        #[arg(long)]
+        split: bool,
+        /// Directory to write into (defaults to `export_dir` from settings.json, else the current directory)
+        #[arg(short, long)]
+        output_dir: Option<PathBuf>,
+    },
+    /// Show the sequence of the day (the same for everyone on a given date)
+    #[command(after_help = "EXAMPLES:\n  \
+    # Today's sequence\n  \
+    oeis daily\n\n  \
+    # The pick for another day, as JSON\n  \
+    oeis daily --date 2025-01-01 -f json\n\n  \
+    # Pick from other entries (repeat --query to pick from what any of them finds)\n  \
+    oeis daily --query \"keyword:nice keyword:easy\"\n  \
+    oeis daily --query keyword:core --query keyword:hard\n\n\
+    The pick is seeded from the date and cached, so repeated runs are instant.")]
+    Daily {
+        /// Date to pick for (YYYY-MM-DD), today by default
+        #[arg(short, long)]
+        date: Option<chrono::NaiveDate>,
+        /// Search the pick is made from; repeated, an entry any of them finds can be picked
+        /// (entries that are nice or core by default)
+        #[arg(long = "query", default_values = client::DAILY_QUERIES)]
+        queries: Vec<String>,
         /// Output format
         #[arg(short, long, value_enum, default_value = "plain")]
         format: OutputFormat,
@@ -143,6 +563,26 @@ pub enum Command {
         #[arg(short, long)]
         quiet: bool,
     },
+    /// Open the TUI in webcam mode, cycling through sequences (for displays and screensavers)
+    #[command(after_help = "EXAMPLES:\n  \
+    # Lobby display: a new nice sequence every 30 seconds, full screen\n  \
+    oeis webcam --category nice --interval 30s --fullscreen\n\n  \
+    # Recently changed entries, once a minute\n  \
+    oeis webcam --category recent --interval 1m\n\n\
+    The next sequence is fetched while the current one is shown. When oeis.org cannot be\n\
+    reached the last sequence stays up and the webcam retries with growing pauses.\n\
+    Press q or Esc to quit.")]
+    Webcam {
+        /// Which sequences to show
+        #[arg(short, long, value_enum, default_value = "all")]
+        category: WebcamCategory,
+        /// Time per sequence, e.g. 30s, 2m or 1h (at least 5s)
+        #[arg(short, long, default_value = "30s")]
+        interval: String,
+        /// Distraction-free layout: the sequence and a chart, with no menus
+        #[arg(short, long)]
+        fullscreen: bool,
+    },
     /// Display a random OEIS sequence
     #[command(after_help = "EXAMPLES:\n  \
     # Get a random sequence\n  \
@@ -152,7 +592,14 @@ pub enum Command {
     # Fun with random sequences\n  \
     oeis random -f json | jq '.name'\n  \
     oeis random -q > sequence-of-the-day.txt\n  \
-    oeis random -f values -q | head -10       # First 10 terms")]
+    oeis random -f values -q | head -10       # First 10 terms\n\n  \
+    # Only some entries\n  \
+    oeis random --keyword nice --min-terms 20 --exclude dead\n  \
+    oeis random --keyword core,easy\n\n  \
+    # The same pick on every run, for scripts and tests\n  \
+    oeis random --seed 42\n\n\
+    Missing ids and entries that don't pass the filters are skipped, up to 10 tries.\n\
+    The highest A-number is looked up once a week and cached.")]
     Random {
         /// Output format
         #[arg(short, long, value_enum, default_value = "plain")]
@@ -160,11 +607,88 @@ pub enum Command {
         /// Quiet mode (minimal output)
         #[arg(short, long)]
         quiet: bool,
+        /// Only entries with this OEIS keyword (repeat or separate with commas)
+        #[arg(short, long, value_delimiter = ',')]
+        keyword: Vec<String>,
+        /// Skip entries with this OEIS keyword (repeat or separate with commas)
+        #[arg(short = 'x', long, value_delimiter = ',')]
+        exclude: Vec<String>,
+        /// Only entries listing at least this many terms
+        #[arg(short, long, default_value_t = 0)]
+        min_terms: usize,
+        /// Seed for a reproducible pick
+        #[arg(short, long)]
+        seed: Option<u64>,
+    },
+    /// Interactive session: search, show, plot and compare sequences at a prompt
+    #[command(after_help = "EXAMPLES:\n  \
+    oeis shell\n\n  \
+    oeis> search 1,2,3,5,8\n  \
+    oeis> show 1                                # First result\n  \
+    oeis> plot log                              # The sequence shown last\n  \
+    oeis> diff A45 A32                          # Compare two sequences\n  \
+    oeis> search keyword:nice | bookmark        # Pipe results on\n\n\
+    Lines are kept in the search history; Tab completes commands and the\n\
+    A-numbers of bookmarks and recently viewed sequences.")]
+    Shell,
+    /// Print the shell completion script
+    #[command(after_help = "EXAMPLES:\n  \
+    # Load at shell start (recommended, so the script follows upgrades)\n  \
+    echo 'source <(oeis completions bash)' >> ~/.bashrc\n  \
+    echo 'source <(oeis completions zsh)' >> ~/.zshrc\n  \
+    echo 'oeis completions fish | source' >> ~/.config/fish/config.fish\n  \
+    echo 'eval (oeis completions elvish | slurp)' >> ~/.config/elvish/rc.elv\n  \
+    echo 'oeis completions powershell | Out-String | Invoke-Expression' >> $PROFILE\n\n\
+    Besides commands and options, A-numbers complete from the bookmarks and recently\n\
+    viewed sequences, e.g. oeis fetch A4<Tab>.")]
+    Completions {
+        /// Shell to complete in
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+    /// Write man pages: oeis(1) to stdout, or one per command into a directory
+    #[command(after_help = "EXAMPLES:\n  \
+    oeis man | man -l -\n  \
+    oeis man -o ~/.local/share/man/man1")]
+    Man {
+        /// Directory for oeis.1 and a page per command (oeis-fetch.1, ...)
+        #[arg(short, long)]
+        output_dir: Option<PathBuf>,
     },
 }
 
-pub async fn run(command: Command) -> Result<()> {
+impl Command {
+    /// How to open the TUI for commands that run in it rather than printing, or why
+    /// their options cannot be used (in `language`)
+    pub fn webcam_launch(&self, language: Language) -> Option<Result<WebcamLaunch>> {
+        match self {
+            Self::Webcam {
+                category,
+                interval,
+                fullscreen,
+            } => Some(
+                parse_interval(interval)
+                    .map(|interval| WebcamLaunch {
+                        category: category.category(),
+                        interval: WebcamInterval::from_duration(interval),
+                        fullscreen: *fullscreen,
+                    })
+                    .map_err(|error| {
+                        let mut i18n = I18n::new();
+                        i18n.set_language(language);
+                        anyhow!(i18n.localized(&error))
+                    }),
+            ),
+            _ => None,
+        }
+    }
+}
+
+pub async fn run(command: Command, language: Language) -> Result<()> {
     let client = OEISClient::new()?;
+    let mut i18n = I18n::new();
+    i18n.set_language(language);
+    let i18n = &i18n;
 
     match command {
         Command::Search {
@@ -172,9 +696,93 @@ pub async fn run(command: Command) -> Result<()> {
             limit,
             format,
             verbose,
-        } => run_search(&client, &query, limit, &format, verbose).await?,
-        Command::Fetch { id, format, quiet } => run_fetch(&client, &id, &format, quiet).await?,
-        Command::Random { format, quiet } => run_random(&client, &format, quiet).await?,
+        } => run_search(&client, i18n, &query, limit, &format, verbose).await?,
+        Command::Fetch { id, format, quiet } => run_fetch(&client, i18n, &id, &format, quiet).await?,
+        Command::Random {
+            format,
+            quiet,
+            keyword,
+            exclude,
+            min_terms,
+            seed,
+        } => {
+            let filter = RandomFilter {
+                keywords: keyword,
+                exclude,
+                min_terms,
+            };
+            run_random(&client, i18n, &filter, seed, &format, quiet).await?
+        }
+        Command::Daily {
+            date,
+            queries,
+            format,
+            quiet,
+        } => {
+            let date = date.unwrap_or_else(|| chrono::Local::now().date_naive());
+            run_daily(&client, i18n, date, &queries, &format, quiet).await?
+        }
+        // Runs in the TUI, see `Command::webcam_launch`
+        Command::Webcam { .. } => {}
+        Command::Shell => crate::shell::run(false, language).await?,
+        Command::Completions { shell } => run_completions(i18n, shell)?,
+        Command::Man { output_dir } => run_man(i18n, output_dir)?,
+        Command::Code { id, lang, save } => {
+            run_code(&client, i18n, &id, lang.as_deref(), save).await?
+        }
+        Command::Verify { id, lang, timeout } => {
+            run_verify(&client, i18n, &id, lang.as_deref(), timeout).await?
+        }
+        Command::Plot {
+            id,
+            graph_type,
+            output,
+            bfile,
+            modulus,
+        } => run_plot(&client, i18n, &id, graph_type.graph_type(), modulus, output, bfile).await?,
+        Command::Listen {
+            id,
+            output,
+            bfile,
+            tempo,
+            instrument,
+            range,
+            lowest,
+        } => {
+            let mut settings = UserSettings::load().listen.unwrap_or_default();
+            settings.tempo = tempo.unwrap_or(settings.tempo);
+            if let Some(instrument) = instrument {
+                settings.instrument = instrument.instrument();
+            }
+            settings.range = range.unwrap_or(settings.range);
+            settings.lowest_note = lowest.unwrap_or(settings.lowest_note);
+            run_listen(&client, i18n, &id, settings, output, bfile).await?
+        }
+        Command::Export {
+            search,
+            bookmarks,
+            history,
+            limit,
+            format,
+            split,
+            output_dir,
+        } => {
+            let source = match (search, bookmarks, history) {
+                (Some(query), _, _) => ExportSource::Search(query),
+                (None, true, _) => ExportSource::Bookmarks,
+                _ => ExportSource::History,
+            };
+            let layout = if split {
+                BulkLayout::PerSequence
+            } else {
+                BulkLayout::Combined
+            };
+            let output_dir = output_dir.unwrap_or_else(|| {
+                paths::expand_home(UserSettings::load().export_dir.as_deref().unwrap_or(""))
+            });
+            run_export(&client, i18n, source, limit, &format.export_format(), layout, &output_dir)
+                .await?
+        }
     }
 
     Ok(())
@@ -182,13 +790,14 @@ pub async fn run(command: Command) -> Result<()> {
 
 async fn run_search(
     client: &OEISClient,
+    i18n: &I18n,
     query: &str,
     limit: usize,
     format: &OutputFormat,
     verbose: bool,
 ) -> Result<()> {
     if query.trim().is_empty() {
-        return Err(anyhow!("Query cannot be empty"));
+        return Err(anyhow!(i18n.t("cli-empty-query")));
     }
 
     let search_query = SearchQuery::new(query);
@@ -201,19 +810,27 @@ async fn run_search(
             println!("{}", json);
         }
         OutputFormat::Plain => {
-            println!(
-                "{} '{}': {} {}",
-                "Results for".bright_white(),
-                query.cyan(),
-                response.count.to_string().bright_yellow().bold(),
-                "found".bright_white()
+            let heading = i18n.t_args(
+                "cli-results-for",
+                [
+                    ("query", query.cyan().to_string().into()),
+                    ("count", response.count.to_string().bright_yellow().bold().to_string().into()),
+                ],
             );
+            println!("{}", heading.bright_white());
             if let Some(results) = response.results {
                 for (i, seq) in results.into_iter().take(page_size).enumerate() {
-                    print_sequence_summary(i + 1, &seq);
+                    print_sequence_summary(i18n, i + 1, &seq);
                 }
             } else {
-                println!("{}", "No results available (OEIS returned too many matches).".yellow());
+                println!("{}", i18n.t("cli-too-many-results").yellow());
+            }
+        }
+        OutputFormat::Bibtex => {
+            if let Some(results) = response.results {
+                for seq in results.into_iter().take(page_size) {
+                    println!("{}", export_sequence(&seq, &ExportFormat::BibTex, None));
+                }
             }
         }
         _ => {
@@ -233,8 +850,68 @@ async fn run_search(
     Ok(())
 }
 
+/// The registration script of the dynamic completer: the shell calls `COMPLETE=<shell> oeis`
+/// back on Tab, so A-numbers can come from the cache
+fn run_completions(i18n: &I18n, shell: Shell) -> Result<()> {
+    let shells = Shells::builtins();
+    let completer = shells.completer(&shell.to_string()).ok_or_else(|| {
+        anyhow!(i18n.t_args("cli-no-completion", [("shell", shell.to_string().into())]))
+    })?;
+    write_registration(completer, &mut io::stdout())
+}
+
+fn write_registration(completer: &dyn EnvCompleter, out: &mut dyn Write) -> Result<()> {
+    let bin = Cli::command().get_name().to_string();
+    completer.write_registration("COMPLETE", &bin, &bin, &bin, out)?;
+    Ok(())
+}
+
+/// A-numbers of the bookmarks and recently viewed sequences that start with what was typed,
+/// described by their names
+fn complete_anumber(current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(current) = current.to_str() else {
+        return Vec::new();
+    };
+    // Completion only reads: it must not create the config directory or the database
+    match Cache::open_read_only() {
+        Ok(Some(cache)) => anumber_candidates(&cache, current),
+        Ok(None) | Err(_) => Vec::new(),
+    }
+}
+
+fn anumber_candidates(cache: &Cache, current: &str) -> Vec<CompletionCandidate> {
+    let digits = current.strip_prefix(['A', 'a']).unwrap_or(current);
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Vec::new();
+    }
+    crate::shell::known_anumbers(cache)
+        .into_iter()
+        .filter(|(anumber, _)| crate::shell::anumber_matches(digits, anumber))
+        .map(|(anumber, name)| CompletionCandidate::new(anumber).help(Some(name.into())))
+        .collect()
+}
+
+fn run_man(i18n: &I18n, output_dir: Option<PathBuf>) -> Result<()> {
+    match output_dir {
+        Some(dir) => {
+            std::fs::create_dir_all(&dir)?;
+            clap_mangen::generate_to(Cli::command(), &dir)?;
+            let written = i18n.t_args("cli-man-written", [("path", dir.display().to_string().into())]);
+            eprintln!("{} {}", "✓".green(), written);
+        }
+        None => clap_mangen::Man::new(Cli::command()).render(&mut io::stdout())?,
+    }
+    Ok(())
+}
+
+/// Error for an A-number that OEIS does not know
+fn not_found(i18n: &I18n, id: &str) -> anyhow::Error {
+    anyhow!(i18n.t_args("cli-not-found", [("id", id.into())]))
+}
+
 async fn run_fetch(
     client: &OEISClient,
+    i18n: &I18n,
     id: &str,
     format: &OutputFormat,
     quiet: bool,
@@ -242,30 +919,515 @@ async fn run_fetch(
     let sequence = client
         .get_sequence(id)
         .await?
-        .ok_or_else(|| anyhow!("Sequence {id} not found"))?;
+        .ok_or_else(|| not_found(i18n, id))?;
+
+    format_sequence_output(i18n, &sequence, format, quiet);
+    Ok(())
+}
+
+async fn run_random(
+    client: &OEISClient,
+    i18n: &I18n,
+    filter: &RandomFilter,
+    seed: Option<u64>,
+    format: &OutputFormat,
+    quiet: bool,
+) -> Result<()> {
+    // A cached upper bound saves fetching recent.txt on every run
+    let cache = Cache::new().ok();
+    let cached = cache
+        .as_ref()
+        .and_then(|cache| cache.get_upper_bound(client::UPPER_BOUND_MAX_AGE_DAYS).ok().flatten());
+    if let Some(bound) = cached {
+        client.set_upper_bound(bound);
+    }
 
-    format_sequence_output(&sequence, format, quiet);
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let picked = client.random_matching(filter, &mut rng).await?;
+
+    if let (Some(cache), None, Some(bound)) = (&cache, cached, client.known_upper_bound()) {
+        let _ = cache.cache_upper_bound(bound);
+    }
+
+    match picked {
+        Some(sequence) => {
+            if !quiet && matches!(format, OutputFormat::Plain) {
+                println!("{}:", i18n.t("cli-random-title").bright_magenta().bold());
+            }
+            format_sequence_output(i18n, &sequence, format, quiet);
+        }
+        None => {
+            if !quiet {
+                let message = if *filter == RandomFilter::default() {
+                    "cli-random-unavailable"
+                } else {
+                    "cli-random-no-match"
+                };
+                println!("{}", i18n.t(message).yellow());
+            }
+        }
+    }
     Ok(())
 }
 
-async fn run_random(client: &OEISClient, format: &OutputFormat, quiet: bool) -> Result<()> {
-    match client.random_sequence().await? {
+async fn run_daily(
+    client: &OEISClient,
+    i18n: &I18n,
+    date: chrono::NaiveDate,
+    queries: &[String],
+    format: &OutputFormat,
+    quiet: bool,
+) -> Result<()> {
+    let day = date.to_string();
+    let query = queries.join(" | ");
+    let cache = Cache::new().ok();
+    let cached = cache
+        .as_ref()
+        .and_then(|cache| cache.get_daily_sequence(&day, &query).ok().flatten());
+
+    let sequence = match cached {
+        Some(sequence) => Some(sequence),
+        None => {
+            let sequence = client.daily_sequence(date, queries).await?;
+            if let (Some(cache), Some(sequence)) = (cache.as_ref(), sequence.as_ref()) {
+                let _ = cache.cache_daily_sequence(&day, &query, sequence);
+            }
+            sequence
+        }
+    };
+
+    match sequence {
         Some(sequence) => {
             if !quiet && matches!(format, OutputFormat::Plain) {
-                println!("{}:", "Random sequence".bright_magenta().bold());
+                println!("{} {}:", i18n.t("welcome-daily").bright_magenta().bold(), day);
             }
-            format_sequence_output(&sequence, format, quiet);
+            format_sequence_output(i18n, &sequence, format, quiet);
         }
         None => {
             if !quiet {
-                println!("{}", "No random sequence available right now.".yellow());
+                println!("{}", i18n.t_args("cli-daily-no-match", [("query", query.as_str().into())]).yellow());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The languages of `snippets`, each once, in the order the entry lists them
+fn program_languages(snippets: &[CodeSnippet]) -> String {
+    let mut languages: Vec<&str> = Vec::new();
+    for snippet in snippets {
+        if !languages.contains(&snippet.language.as_str()) {
+            languages.push(&snippet.language);
+        }
+    }
+    languages.join(", ")
+}
+
+async fn run_code(
+    client: &OEISClient,
+    i18n: &I18n,
+    id: &str,
+    lang: Option<&str>,
+    save: bool,
+) -> Result<()> {
+    let sequence = client
+        .get_sequence(id)
+        .await?
+        .ok_or_else(|| not_found(i18n, id))?;
+
+    let snippets = sequence.code_snippets();
+    if snippets.is_empty() {
+        return Err(anyhow!(i18n.t_args("cli-no-programs", [("anumber", sequence.a_number().into())])));
+    }
+
+    let selected: Vec<&CodeSnippet> = match lang {
+        Some(lang) => snippets.iter().filter(|s| s.matches_language(lang)).collect(),
+        None => snippets.iter().collect(),
+    };
+    if selected.is_empty() {
+        return Err(anyhow!(i18n.t_args(
+            "cli-no-language-program",
+            [
+                ("language", lang.unwrap_or_default().into()),
+                ("anumber", sequence.a_number().into()),
+                ("available", program_languages(&snippets).into()),
+            ]
+        )));
+    }
+
+    if save {
+        let mut counts = std::collections::HashMap::new();
+        for snippet in &selected {
+            let ordinal = counts.entry(snippet.language_key()).or_insert(0);
+            *ordinal += 1;
+            let filename = snippet.filename(&sequence, *ordinal);
+            std::fs::write(&filename, snippet.to_source(&sequence))?;
+            println!("{}", i18n.t_args("cli-saved", [("path", filename.into())]).green());
+        }
+        return Ok(());
+    }
+
+    match lang {
+        // A single language prints the first program as bare source so it can be piped
+        // into an interpreter; the rest are only mentioned on stderr
+        Some(_) => {
+            println!("{}", selected[0].code);
+            if selected.len() > 1 {
+                let more = i18n.t_args(
+                    "cli-more-programs",
+                    [
+                        ("count", (selected.len() - 1).into()),
+                        ("language", selected[0].language.as_str().into()),
+                    ],
+                );
+                eprintln!("{}", more.dimmed());
+            }
+        }
+        None => {
+            for (i, snippet) in selected.iter().enumerate() {
+                if i > 0 {
+                    println!();
+                }
+                println!("{}", format!("({})", snippet.language).bright_cyan().bold());
+                println!("{}", snippet.code);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Where `plot` and `listen` write without `--output`: the file name template from
+/// settings.json with `extension`, in `export_dir` (else the current directory)
+fn default_output(sequence: &Sequence, extension: &str) -> PathBuf {
+    let settings = UserSettings::load();
+    let template = settings
+        .export_filename_template
+        .as_deref()
+        .unwrap_or(filename::DEFAULT_TEMPLATE);
+    paths::expand_home(settings.export_dir.as_deref().unwrap_or(""))
+        .join(filename::render_template(template, sequence, extension))
+}
+
+async fn run_plot(
+    client: &OEISClient,
+    i18n: &I18n,
+    id: &str,
+    graph_type: GraphType,
+    modulus: u32,
+    output: Option<PathBuf>,
+    bfile: bool,
+) -> Result<()> {
+    let sequence = client
+        .get_sequence(id)
+        .await?
+        .ok_or_else(|| not_found(i18n, id))?;
+
+    let entries = if bfile {
+        Some(client.fetch_b_file(sequence.number).await?)
+    } else {
+        None
+    };
+
+    let output = output.unwrap_or_else(|| default_output(&sequence, "svg"));
+
+    let plot = Plot::new(&sequence, entries.as_deref(), graph_type, modulus);
+    plot.save(&output).map_err(|e| anyhow!(i18n.error(&e)))?;
+    let description = match plot.graph_type {
+        GraphType::ModK => i18n.t_args("graph-modk", [("k", plot.modulus.into())]),
+        _ => i18n.t(plot.graph_type.label_key()),
+    };
+    let saved = i18n.t_args(
+        "cli-plot-saved",
+        [
+            ("description", description.into()),
+            ("count", plot.series.terms.into()),
+            ("path", output.display().to_string().into()),
+        ],
+    );
+    println!("{}", saved.green());
+
+    Ok(())
+}
+
+async fn run_listen(
+    client: &OEISClient,
+    i18n: &I18n,
+    id: &str,
+    settings: audio::SoundSettings,
+    output: Option<PathBuf>,
+    bfile: bool,
+) -> Result<()> {
+    let sequence = client
+        .get_sequence(id)
+        .await?
+        .ok_or_else(|| not_found(i18n, id))?;
+
+    let entries = if bfile {
+        Some(client.fetch_b_file(sequence.number).await?)
+    } else {
+        None
+    };
+
+    let output = output.unwrap_or_else(|| default_output(&sequence, "mid"));
+
+    let sound = Sonification::new(&sequence, entries.as_deref(), settings);
+    sound.save(&output).map_err(|e| anyhow!(i18n.error(&e)))?;
+    let seconds = sound.duration_seconds().round() as u64;
+    let lowest = sound.settings.lowest_note;
+    let highest = (lowest as u32 + sound.settings.range - 1).min(127) as u8;
+    let saved = i18n.t_args(
+        "cli-listen-saved",
+        [
+            ("count", sound.notes.len().into()),
+            ("duration", format!("{}:{:02}", seconds / 60, seconds % 60).into()),
+            ("tempo", sound.settings.tempo.into()),
+            ("pitches", format!("{}–{}", audio::note_name(lowest), audio::note_name(highest)).into()),
+            ("path", output.display().to_string().into()),
+        ],
+    );
+    println!("{}", saved.green());
+
+    Ok(())
+}
+
+/// Sequences selected by `oeis export`
+enum ExportSource {
+    Search(String),
+    Bookmarks,
+    History,
+}
+
+async fn run_export(
+    client: &OEISClient,
+    i18n: &I18n,
+    source: ExportSource,
+    limit: Option<usize>,
+    format: &ExportFormat,
+    layout: BulkLayout,
+    output_dir: &std::path::Path,
+) -> Result<()> {
+    if layout == BulkLayout::Combined && !format.supports_combined() {
+        return Err(anyhow!(i18n.t_args("cli-export-not-combined", [("format", format.as_str().into())])));
+    }
+
+    let (sequences, stem) = match source {
+        ExportSource::Search(query) => {
+            if query.trim().is_empty() {
+                return Err(anyhow!(i18n.t("cli-empty-query")));
+            }
+            let page_size = limit.unwrap_or(10).clamp(1, 50);
+            let response = client.search(&SearchQuery::new(&query), page_size).await?;
+            let mut results = response.results.unwrap_or_default();
+            results.truncate(page_size);
+            (results, "search-results")
+        }
+        ExportSource::Bookmarks => {
+            let cache = Cache::new()?;
+            let mut numbers: Vec<i32> =
+                cache.get_bookmarks()?.into_iter().map(|(number, _)| number).collect();
+            if let Some(limit) = limit {
+                numbers.truncate(limit);
+            }
+            (load_sequences(client, i18n, &cache, &numbers).await, "bookmarks")
+        }
+        ExportSource::History => {
+            let cache = Cache::new()?;
+            let numbers = cache.get_recently_viewed(limit.unwrap_or(100))?;
+            (load_sequences(client, i18n, &cache, &numbers).await, "history")
+        }
+    };
+
+    if sequences.is_empty() {
+        return Err(anyhow!(i18n.t("bulk-export-empty")));
+    }
+
+    let written = write_bulk_export(&sequences, format, layout, output_dir, stem)?;
+    let target = match layout {
+        BulkLayout::Combined => written[0].clone(),
+        BulkLayout::PerSequence => output_dir.join(stem),
+    };
+    let exported = i18n.t_args(
+        "bulk-export-success",
+        [
+            ("count", sequences.len().into()),
+            ("path", target.display().to_string().into()),
+        ],
+    );
+    println!("{}", exported.green());
+
+    Ok(())
+}
+
+/// Look sequences up in the cache, fetching (and caching) the rest, in the given order
+async fn load_sequences(
+    client: &OEISClient,
+    i18n: &I18n,
+    cache: &Cache,
+    numbers: &[i32],
+) -> Vec<Sequence> {
+    let mut found = Vec::with_capacity(numbers.len());
+    let mut missing = Vec::new();
+    for &number in numbers {
+        match cache.get_cached_sequence(number, 30) {
+            Ok(Some(seq)) => found.push(seq),
+            _ => missing.push(number),
+        }
+    }
+
+    for seq in client.get_sequences(&missing).await {
+        let _ = cache.cache_sequence(&seq);
+        found.push(seq);
+    }
+    let skipped = numbers.len() - found.len();
+    if skipped > 0 {
+        eprintln!("{}", i18n.t_args("cli-skipped", [("count", skipped.into())]).yellow());
+    }
+
+    numbers
+        .iter()
+        .filter_map(|number| found.iter().find(|s| s.number == *number).cloned())
+        .collect()
+}
+
+async fn run_verify(
+    client: &OEISClient,
+    i18n: &I18n,
+    id: &str,
+    lang: Option<&str>,
+    timeout: u64,
+) -> Result<()> {
+    let sequence = client
+        .get_sequence(id)
+        .await?
+        .ok_or_else(|| not_found(i18n, id))?;
+
+    let snippets: Vec<CodeSnippet> = sequence
+        .code_snippets()
+        .into_iter()
+        .filter(|s| Interpreter::for_snippet(s).is_some())
+        .filter(|s| lang.is_none_or(|lang| s.matches_language(lang)))
+        .collect();
+    if snippets.is_empty() {
+        let language = match lang {
+            Some(lang) => lang.to_string(),
+            None => i18n.t("cli-verify-any-language"),
+        };
+        return Err(anyhow!(i18n.t_args(
+            "cli-nothing-to-verify",
+            [
+                ("anumber", sequence.a_number().into()),
+                ("language", language.into()),
+            ]
+        )));
+    }
+
+    println!(
+        "{} {} {}",
+        sequence.a_number().bright_cyan().bold(),
+        "-".dimmed(),
+        sequence.name.bright_white()
+    );
+
+    let timeout = Duration::from_secs(timeout.max(1));
+    let mut failures = 0;
+    for snippet in &snippets {
+        let label = format!("{:<8}", snippet.language);
+        match verify::verify_snippet(snippet, &sequence, timeout).await {
+            Ok(report) => {
+                if !report.passed() {
+                    failures += 1;
+                }
+                print_verify_report(i18n, &label, &report);
+            }
+            Err(e) => {
+                failures += 1;
+                println!("  {} {} {}", "!".yellow().bold(), label.yellow(), i18n.error(&e));
             }
         }
     }
+
+    if failures > 0 {
+        return Err(anyhow!(i18n.t_args(
+            "cli-verify-failures",
+            [("failures", failures.into()), ("count", snippets.len().into())]
+        )));
+    }
     Ok(())
 }
 
-fn print_sequence_summary(index: usize, seq: &Sequence) {
+fn print_verify_report(i18n: &I18n, label: &str, report: &VerifyReport) {
+    let timing = if report.timed_out {
+        let seconds = format!("{:.1}", report.elapsed.as_secs_f64());
+        i18n.t_args("cli-verify-timed-out", [("seconds", seconds.into())])
+    } else {
+        format!("({:.2}s)", report.elapsed.as_secs_f64())
+    };
+
+    match &report.outcome {
+        VerifyOutcome::Match { checked } if report.timed_out => println!(
+            "  {} {} {} {}",
+            "!".yellow().bold(),
+            label.yellow(),
+            i18n.t_args("cli-verify-match-timed-out", [("count", (*checked).into())]),
+            timing.dimmed()
+        ),
+        VerifyOutcome::Match { checked } => println!(
+            "  {} {} {} {}",
+            "✓".green().bold(),
+            label.green(),
+            i18n.t_args("cli-verify-match", [("count", (*checked).into())]),
+            timing.dimmed()
+        ),
+        VerifyOutcome::Partial { checked, expected } => println!(
+            "  {} {} {} {}",
+            "!".yellow().bold(),
+            label.yellow(),
+            i18n.t_args(
+                "cli-verify-partial",
+                [("count", (*checked).into()), ("expected", (*expected).into())]
+            ),
+            timing.dimmed()
+        ),
+        VerifyOutcome::Mismatch {
+            index,
+            expected,
+            actual,
+            matched,
+        } => println!(
+            "  {} {} {} {}",
+            "✗".red().bold(),
+            label.red(),
+            i18n.t_args(
+                "cli-verify-mismatch",
+                [
+                    ("index", (*index).into()),
+                    ("actual", actual.as_str().into()),
+                    ("expected", expected.as_str().into()),
+                    ("count", (*matched).into()),
+                ]
+            ),
+            timing.dimmed()
+        ),
+        VerifyOutcome::NoOutput => println!(
+            "  {} {} {}{} {}",
+            "!".yellow().bold(),
+            label.yellow(),
+            i18n.t("cli-verify-no-output"),
+            report
+                .error
+                .as_ref()
+                .map(|e| format!(": {}", e))
+                .unwrap_or_default(),
+            timing.dimmed()
+        ),
+    }
+}
+
+pub(crate) fn print_sequence_summary(i18n: &I18n, index: usize, seq: &Sequence) {
     println!(
         "{}{} {} {} {}",
         format!("{:>2}.", index).dimmed(),
@@ -277,12 +1439,12 @@ fn print_sequence_summary(index: usize, seq: &Sequence) {
     let data_preview = seq.data.split(',').take(12).collect::<Vec<_>>().join(", ");
     println!("    {}", data_preview.green());
     if !seq.keyword.is_empty() {
-        println!("    {}: {}", "keywords".yellow(), seq.keyword.dimmed());
+        println!("    {}: {}", i18n.t("detail-keywords").yellow(), seq.keyword.dimmed());
     }
     println!();
 }
 
-fn print_sequence_detail(seq: &Sequence) {
+pub(crate) fn print_sequence_detail(i18n: &I18n, seq: &Sequence) {
     println!(
         "{} {} {}",
         seq.a_number().bright_cyan().bold(),
@@ -290,21 +1452,21 @@ fn print_sequence_detail(seq: &Sequence) {
         seq.name.bright_white().bold()
     );
     println!(
-        "{} {} {} {}: {}",
-        "Offset:".yellow(),
+        "{}: {} {} {}: {}",
+        i18n.t("detail-offset").yellow(),
         seq.offset.to_string().white(),
         "|".dimmed(),
-        "Keywords".yellow(),
+        i18n.t("detail-keywords").yellow(),
         seq.keyword.dimmed()
     );
     if !seq.author.is_empty() {
-        println!("{}: {}", "Author".yellow(), seq.author.white());
+        println!("{}: {}", i18n.t("detail-author").yellow(), seq.author.white());
     }
     if !seq.data.is_empty() {
-        println!("{}: {}", "Data".yellow(), seq.data.green());
+        println!("{}: {}", i18n.t("detail-section-data").yellow(), seq.data.green());
     }
     if !seq.comment.is_empty() {
-        println!("{}:", "Comments".yellow());
+        println!("{}:", i18n.t("detail-comments").yellow());
         for comment in &seq.comment {
             println!("  {} {}", "-".dimmed(), comment.white());
         }
@@ -313,7 +1475,7 @@ fn print_sequence_detail(seq: &Sequence) {
 }
 
 /// Format sequence output based on the specified format
-fn format_sequence_output(seq: &Sequence, format: &OutputFormat, quiet: bool) {
+fn format_sequence_output(i18n: &I18n, seq: &Sequence, format: &OutputFormat, quiet: bool) {
     match format {
         OutputFormat::Plain => {
             if quiet {
@@ -321,7 +1483,7 @@ fn format_sequence_output(seq: &Sequence, format: &OutputFormat, quiet: bool) {
                 println!("{}", seq.data);
             } else {
                 // Full detail view
-                print_sequence_detail(seq);
+                print_sequence_detail(i18n, seq);
             }
         }
         OutputFormat::Json => {
@@ -355,6 +1517,18 @@ fn format_sequence_output(seq: &Sequence, format: &OutputFormat, quiet: bool) {
                 println!("{}\t{}", i, value);
             }
         }
+        OutputFormat::Latex => {
+            print!("{}", export_sequence(seq, &ExportFormat::Latex, None));
+        }
+        OutputFormat::Bibtex => {
+            print!("{}", export_sequence(seq, &ExportFormat::BibTex, None));
+        }
+        OutputFormat::Ipynb => {
+            println!("{}", export_sequence(seq, &ExportFormat::Notebook, None));
+        }
+        OutputFormat::Py => {
+            print!("{}", export_sequence(seq, &ExportFormat::PythonScript, None));
+        }
     }
 }
 
@@ -365,3 +1539,126 @@ fn parse_sequence_values(data: &str) -> Vec<String> {
         .filter(|s| !s.is_empty())
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequence(number: i32, name: &str) -> Sequence {
+        serde_json::from_value(serde_json::json!({
+            "number": number,
+            "data": "1,2,3",
+            "name": name,
+            "offset": "0,1",
+            "keyword": "",
+            "author": "",
+            "created": "1991-04-30T03:00:00-04:00",
+            "time": "2024-01-01T00:00:00-05:00",
+            "revision": 1,
+            "references": 0
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_lang_options() {
+        let cli = Cli::try_parse_from(["oeis", "--lang", "de", "code", "A000045", "--lang", "python"])
+            .unwrap();
+        assert_eq!(cli.lang, Some(Language::German));
+        assert!(matches!(cli.command, Some(Command::Code { lang: Some(ref lang), .. }) if lang == "python"));
+
+        let cli = Cli::try_parse_from(["oeis", "--lang", "pt_BR.UTF-8", "verify", "A000045"]).unwrap();
+        assert_eq!(cli.lang, Some(Language::Portuguese));
+        assert!(matches!(cli.command, Some(Command::Verify { lang: None, .. })));
+
+        assert!(Cli::try_parse_from(["oeis", "--lang", "xx"]).is_err());
+    }
+
+    #[test]
+    fn test_program_languages() {
+        let snippet = |language: &str| CodeSnippet {
+            language: language.to_string(),
+            code: String::new(),
+        };
+        let snippets = [snippet("PARI"), snippet("Haskell"), snippet("PARI"), snippet("Python"), snippet("Haskell")];
+        assert_eq!(program_languages(&snippets), "PARI, Haskell, Python");
+    }
+
+    #[test]
+    fn test_parse_interval() {
+        assert_eq!(parse_interval("45"), Ok(Duration::from_secs(45)));
+        assert_eq!(parse_interval("30s"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_interval(" 2min "), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_interval("1h"), Ok(Duration::from_secs(3600)));
+        assert_eq!(parse_interval("4s"), Err(IntervalError::TooShort));
+        assert_eq!(parse_interval("10d"), Err(IntervalError::UnknownUnit("d".to_string())));
+        assert_eq!(parse_interval("m"), Err(IntervalError::Invalid("m".to_string())));
+        assert!(parse_interval("-5m").is_err());
+        assert!(parse_interval("9999999999999999999m").is_err());
+        assert!(parse_interval("9999999999999999999h").is_err());
+        assert!(parse_interval("99999999999999999999").is_err());
+    }
+
+    #[test]
+    fn test_anumber_candidates() {
+        let cache = Cache::in_memory().unwrap();
+        cache.cache_sequence(&sequence(45, "Fibonacci numbers")).unwrap();
+        cache.add_bookmark(45, None).unwrap();
+        cache.add_bookmark(40, None).unwrap();
+        cache.add_bookmark(1113, None).unwrap();
+
+        let values = |current: &str| -> Vec<String> {
+            anumber_candidates(&cache, current)
+                .iter()
+                .map(|candidate| candidate.get_value().to_string_lossy().into_owned())
+                .collect()
+        };
+        let sorted = |current: &str| {
+            let mut values = values(current);
+            values.sort();
+            values
+        };
+        assert_eq!(sorted("A"), ["A000040", "A000045", "A001113"]);
+        assert_eq!(sorted("A4"), ["A000040", "A000045"]);
+        assert_eq!(values("45"), ["A000045"]);
+        assert!(values("A2").is_empty());
+        assert!(values("fib").is_empty());
+
+        let fibonacci = anumber_candidates(&cache, "a45");
+        assert_eq!(
+            fibonacci[0].get_help().map(ToString::to_string).as_deref(),
+            Some("Fibonacci numbers")
+        );
+    }
+
+    #[test]
+    fn test_completion_registration() {
+        let shells = Shells::builtins();
+        for shell in ["bash", "zsh", "fish", "elvish", "powershell"] {
+            let completer = shells.completer(shell).unwrap();
+            let mut script = Vec::new();
+            write_registration(completer, &mut script).unwrap();
+            let script = String::from_utf8(script).unwrap();
+            assert!(script.contains("COMPLETE"), "{shell}");
+            assert!(script.contains("oeis"), "{shell}");
+        }
+    }
+
+    #[test]
+    fn test_man_pages() {
+        let dir = std::env::temp_dir().join(format!("oeis-man-{}", std::process::id()));
+        run_man(&I18n::new(), Some(dir.clone())).unwrap();
+        let page = std::fs::read_to_string(dir.join("oeis.1")).unwrap();
+        assert!(page.starts_with(".ie"));
+        assert!(page.contains("oeis\\-fetch(1)"));
+        for command in ["search", "fetch", "shell", "completions", "man"] {
+            assert!(dir.join(format!("oeis-{command}.1")).exists(), "{command}");
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,299 @@
+//! Real constants behind `cons` (decimal expansion) and `cofr` (continued fraction) entries.
+//!
+//! A decimal expansion lists the digits and uses the offset as the number of digits
+//! before the decimal point, so `3,1,4,1,5` with offset 1 is 3.1415 and `5,7,7` with
+//! offset 0 is 0.577. A continued fraction is evaluated through its convergents,
+//! which are exact fractions; only the decimal places they are guaranteed to get
+//! right are shown.
+
+use crate::api::{models::BFileEntry, Sequence};
+
+/// Numerators and denominators stay below this so long division never overflows
+const CONVERGENT_LIMIT: u128 = 10u128.pow(37);
+/// Most decimal places produced for an exact (finite) continued fraction
+const MAX_EXACT_PLACES: usize = 1000;
+
+/// The constant behind a `cons` or `cofr` entry
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constant {
+    /// Decimal digits, with `point` digits before the decimal point (negative for 0.00…)
+    Decimal { digits: Vec<u8>, point: i64 },
+    /// Continued fraction terms [a0; a1, a2, …]; `finite` when the entry lists all of them
+    ContinuedFraction { terms: Vec<i128>, finite: bool },
+}
+
+/// One convergent p/q of a continued fraction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Convergent {
+    pub numerator: i128,
+    pub denominator: i128,
+}
+
+impl Constant {
+    /// Read the constant from the loaded b-file (when there is one) or the entry's terms.
+    /// Expansions in bases other than 10 are left alone.
+    pub fn from_sequence(seq: &Sequence, bfile: Option<&[BFileEntry]>) -> Option<Self> {
+        let values: Vec<String> = match bfile.filter(|entries| !entries.is_empty()) {
+            Some(entries) => entries.iter().map(|entry| entry.value.clone()).collect(),
+            None => seq.parse_data(),
+        };
+        if values.is_empty() {
+            return None;
+        }
+
+        if seq.has_keyword("cofr") {
+            let terms = values
+                .iter()
+                .map(|value| value.parse().ok())
+                .collect::<Option<Vec<i128>>>()?;
+            // Partial quotients after the first are positive
+            if terms.iter().skip(1).any(|term| *term <= 0) {
+                return None;
+            }
+            return Some(Self::ContinuedFraction {
+                terms,
+                finite: seq.has_keyword("fini") && seq.has_keyword("full"),
+            });
+        }
+
+        if seq.has_keyword("cons") && is_decimal_expansion(&seq.name) {
+            let digits = values
+                .iter()
+                .map(|value| match value.as_bytes() {
+                    [digit @ b'0'..=b'9'] => Some(digit - b'0'),
+                    _ => None,
+                })
+                .collect::<Option<Vec<u8>>>()?;
+            let (point, _) = seq.parse_offset();
+            return Some(Self::Decimal {
+                digits,
+                point: point as i64,
+            });
+        }
+
+        None
+    }
+
+    /// Most decimal places that can be shown correctly
+    pub fn max_places(&self) -> usize {
+        match self {
+            Self::Decimal { digits, point } => (digits.len() as i64 - point).max(0) as usize,
+            Self::ContinuedFraction { terms, finite } => {
+                let convergents = convergents(terms);
+                match convergents.last() {
+                    Some(_) if *finite && convergents.len() == terms.len() => MAX_EXACT_PLACES,
+                    Some(last) => {
+                        // |x - p/q| < 1/(q * q'), with q' the next denominator
+                        let q = last.denominator as f64;
+                        let q_next = match (terms.get(convergents.len()), convergents.iter().rev().nth(1)) {
+                            (Some(a), Some(previous)) => *a as f64 * q + previous.denominator as f64,
+                            (Some(a), None) => *a as f64 * q + 1.0,
+                            _ => q,
+                        };
+                        // One place is given up so a run of 9s cannot round into a wrong digit
+                        ((q.log10() + q_next.log10()).floor() as i64 - 1).max(0) as usize
+                    }
+                    None => 0,
+                }
+            }
+        }
+    }
+
+    /// The value with `places` digits after the decimal point (cut, not rounded),
+    /// limited to [`Self::max_places`]
+    pub fn to_decimal(&self, places: usize) -> String {
+        let places = places.min(self.max_places());
+        match self {
+            Self::Decimal { digits, point } => {
+                let digit = |i: i64| -> char {
+                    let d = usize::try_from(i)
+                        .ok()
+                        .and_then(|i| digits.get(i))
+                        .copied()
+                        .unwrap_or(0);
+                    char::from(b'0' + d)
+                };
+                let mut text = if *point > 0 {
+                    (0..*point).map(digit).collect::<String>()
+                } else {
+                    "0".to_string()
+                };
+                if places > 0 {
+                    text.push('.');
+                    text.extend((0..places as i64).map(|i| digit(point + i)));
+                }
+                text
+            }
+            Self::ContinuedFraction { terms, .. } => match convergents(terms).last() {
+                Some(last) => divide(last.numerator, last.denominator, places),
+                None => "0".to_string(),
+            },
+        }
+    }
+
+    /// Convergents of a continued fraction, as far as they fit in 128 bits
+    pub fn convergents(&self) -> Vec<Convergent> {
+        match self {
+            Self::ContinuedFraction { terms, .. } => convergents(terms),
+            Self::Decimal { .. } => Vec::new(),
+        }
+    }
+}
+
+/// Whether a `cons` entry's name describes base-10 digits
+fn is_decimal_expansion(name: &str) -> bool {
+    let name = name.to_lowercase();
+    let other_base = ["binary", "ternary", "octal", "hexadecimal", "base "]
+        .iter()
+        .any(|word| name.contains(word));
+    !other_base || name.contains("base 10") || name.contains("decimal expansion")
+}
+
+/// p_n = a_n p_{n-1} + p_{n-2} and the same for q, stopping before the numbers get too large
+fn convergents(terms: &[i128]) -> Vec<Convergent> {
+    let mut result = Vec::new();
+    let (mut p_prev, mut p) = (0i128, 1i128);
+    let (mut q_prev, mut q) = (1i128, 0i128);
+
+    for &a in terms {
+        let next_p = a.checked_mul(p).and_then(|ap| ap.checked_add(p_prev));
+        let next_q = a.checked_mul(q).and_then(|aq| aq.checked_add(q_prev));
+        let (Some(next_p), Some(next_q)) = (next_p, next_q) else {
+            break;
+        };
+        if next_p.unsigned_abs() >= CONVERGENT_LIMIT || next_q.unsigned_abs() >= CONVERGENT_LIMIT {
+            break;
+        }
+        (p_prev, p) = (p, next_p);
+        (q_prev, q) = (q, next_q);
+        result.push(Convergent {
+            numerator: p,
+            denominator: q,
+        });
+    }
+    result
+}
+
+/// `numerator / denominator` written out by long division with `places` decimals (cut)
+fn divide(numerator: i128, denominator: i128, places: usize) -> String {
+    let negative = (numerator < 0) != (denominator < 0) && numerator != 0;
+    let (n, d) = (numerator.unsigned_abs(), denominator.unsigned_abs());
+    if d == 0 {
+        return "∞".to_string();
+    }
+
+    let whole = n / d;
+    let mut remainder = n % d;
+    let mut fraction = String::with_capacity(places);
+    for _ in 0..places {
+        remainder *= 10;
+        fraction.push(char::from(b'0' + (remainder / d) as u8));
+        remainder %= d;
+    }
+
+    // Cutting a negative value towards zero keeps the digits of its absolute value
+    let mut text = String::new();
+    if negative {
+        text.push('-');
+    }
+    text.push_str(&whole.to_string());
+    if places > 0 {
+        text.push('.');
+        text.push_str(&fraction);
+    }
+    text
+}
+
+impl Convergent {
+    /// The fraction as "p/q" (or just "p" when q is 1)
+    pub fn fraction(&self) -> String {
+        if self.denominator == 1 {
+            self.numerator.to_string()
+        } else {
+            format!("{}/{}", self.numerator, self.denominator)
+        }
+    }
+
+    /// Decimal value of the fraction with `places` digits after the point (cut)
+    pub fn to_decimal(self, places: usize) -> String {
+        divide(self.numerator, self.denominator, places)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequence(name: &str, keyword: &str, offset: &str, data: &str) -> Sequence {
+        serde_json::from_value(serde_json::json!({
+            "number": 796,
+            "data": data,
+            "name": name,
+            "offset": offset,
+            "keyword": keyword,
+            "author": "N. J. A. Sloane",
+            "created": "1991-04-30T03:00:00-04:00",
+            "time": "2024-01-01T00:00:00-05:00",
+            "revision": 1,
+            "references": 0
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_decimal_expansion() {
+        let pi = sequence(
+            "Decimal expansion of Pi (or digits of Pi).",
+            "cons,nonn,core,easy",
+            "1,1",
+            "3,1,4,1,5,9,2,6,5,3,5",
+        );
+        let constant = Constant::from_sequence(&pi, None).unwrap();
+        assert_eq!(constant.max_places(), 10);
+        assert_eq!(constant.to_decimal(4), "3.1415");
+        assert_eq!(constant.to_decimal(50), "3.1415926535");
+        assert_eq!(constant.to_decimal(0), "3");
+
+        let small = sequence("Decimal expansion of 1/137.", "cons,nonn", "-2,1", "7,2,9,9");
+        let constant = Constant::from_sequence(&small, None).unwrap();
+        assert_eq!(constant.to_decimal(10), "0.007299");
+
+        let binary = sequence("Binary expansion of Pi.", "cons,nonn", "2,1", "1,1,0,0,1");
+        assert_eq!(Constant::from_sequence(&binary, None), None);
+        let plain = sequence("Fibonacci numbers.", "nonn,core", "0,4", "0,1,1,2");
+        assert_eq!(Constant::from_sequence(&plain, None), None);
+    }
+
+    #[test]
+    fn test_continued_fraction() {
+        let pi = sequence(
+            "Continued fraction for Pi.",
+            "cofr,nonn,core",
+            "0,1",
+            "3,7,15,1,292,1,1,1,2,1,3,1,14",
+        );
+        let constant = Constant::from_sequence(&pi, None).unwrap();
+        let fractions: Vec<String> = constant
+            .convergents()
+            .iter()
+            .take(5)
+            .map(Convergent::fraction)
+            .collect();
+        assert_eq!(fractions, ["3", "22/7", "333/106", "355/113", "103993/33102"]);
+        assert_eq!(constant.convergents()[1].to_decimal(3), "3.142");
+
+        // The last convergent pins down well over 10 places of Pi
+        assert!(constant.max_places() >= 10);
+        assert_eq!(constant.to_decimal(10), "3.1415926535");
+
+        let half = sequence("Continued fraction for 1/2.", "cofr,nonn,fini,full", "0,2", "0,2");
+        let constant = Constant::from_sequence(&half, None).unwrap();
+        assert_eq!(constant.to_decimal(3), "0.500");
+
+        let negative = Constant::ContinuedFraction {
+            terms: vec![-1, 2],
+            finite: true,
+        };
+        assert_eq!(negative.convergents()[1].to_decimal(2), "-0.50");
+    }
+}
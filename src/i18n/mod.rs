@@ -1,7 +1,18 @@
-use fluent::{FluentArgs, FluentBundle, FluentResource};
+use fluent::{FluentArgs, FluentBundle, FluentResource, FluentValue};
 use std::collections::HashMap;
 use unic_langid::LanguageIdentifier;
 
+/// A message with a locale key, such as an error raised outside the UI
+pub trait Localized {
+    /// i18n key of the message
+    fn message_key(&self) -> &'static str;
+
+    /// Arguments of the message
+    fn args(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Language {
     English,
@@ -10,6 +21,9 @@ pub enum Language {
     Spanish,
     Korean,
     Chinese,
+    German,
+    Portuguese,
+    Russian,
 }
 
 impl Language {
@@ -21,6 +35,9 @@ impl Language {
             Language::Spanish => "es",
             Language::Korean => "ko",
             Language::Chinese => "zh",
+            Language::German => "de",
+            Language::Portuguese => "pt",
+            Language::Russian => "ru",
         }
     }
 
@@ -32,6 +49,9 @@ impl Language {
             Language::Spanish => "Español",
             Language::Korean => "한국어",
             Language::Chinese => "中文",
+            Language::German => "Deutsch",
+            Language::Portuguese => "Português",
+            Language::Russian => "Русский",
         }
     }
 
@@ -43,10 +63,12 @@ impl Language {
             Language::Spanish,
             Language::Korean,
             Language::Chinese,
+            Language::German,
+            Language::Portuguese,
+            Language::Russian,
         ]
     }
 
-    #[allow(dead_code)]
     pub fn from_code(code: &str) -> Option<Language> {
         match code {
             "en" => Some(Language::English),
@@ -55,9 +77,33 @@ impl Language {
             "es" => Some(Language::Spanish),
             "ko" => Some(Language::Korean),
             "zh" => Some(Language::Chinese),
+            "de" => Some(Language::German),
+            "pt" => Some(Language::Portuguese),
+            "ru" => Some(Language::Russian),
             _ => None,
         }
     }
+
+    /// Language of a POSIX locale name such as `de_DE.UTF-8`, `pt-BR` or `ru`; None for
+    /// `C`, `POSIX` and languages without a translation
+    pub fn from_locale(locale: &str) -> Option<Language> {
+        let language = locale
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        Self::from_code(&language)
+    }
+
+    /// Language of the messages locale: the first of `LC_ALL`, `LC_MESSAGES` and `LANG`
+    /// that is set decides, as for other programs
+    pub fn from_env() -> Option<Language> {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|locale| Self::from_locale(&locale))
+    }
 }
 
 pub struct I18n {
@@ -90,11 +136,16 @@ impl I18n {
             Language::Spanish => include_str!("../../locales/es.ftl"),
             Language::Korean => include_str!("../../locales/ko.ftl"),
             Language::Chinese => include_str!("../../locales/zh.ftl"),
+            Language::German => include_str!("../../locales/de.ftl"),
+            Language::Portuguese => include_str!("../../locales/pt.ftl"),
+            Language::Russian => include_str!("../../locales/ru.ftl"),
         };
 
         let resource = FluentResource::try_new(ftl_string.to_string()).ok()?;
         let lang_id: LanguageIdentifier = lang.code().parse().ok()?;
         let mut bundle = FluentBundle::new(vec![lang_id]);
+        // No bidi isolation marks around arguments: they would end up in CLI output
+        bundle.set_use_isolating(false);
 
         if bundle.add_resource(resource).is_ok() {
             Some(bundle)
@@ -115,6 +166,41 @@ impl I18n {
         self.t_with_args(key, None)
     }
 
+    /// `t_with_args` with the arguments given inline: `t_args("key", [("count", 3.into())])`
+    pub fn t_args<'a>(
+        &self,
+        key: &str,
+        args: impl IntoIterator<Item = (&'static str, FluentValue<'a>)>,
+    ) -> String {
+        let mut fluent_args = FluentArgs::new();
+        for (name, value) in args {
+            fluent_args.set(name, value);
+        }
+        self.t_with_args(key, Some(&fluent_args))
+    }
+
+    /// The message of an error the app raises itself
+    pub fn localized(&self, message: &dyn Localized) -> String {
+        self.t_args(
+            message.message_key(),
+            message.args().into_iter().map(|(name, value)| (name, value.into())),
+        )
+    }
+
+    /// `error` in the current language when it is one of the app's own errors (a missing
+    /// interpreter, nothing to plot, ...); others keep their text
+    pub fn error(&self, error: &anyhow::Error) -> String {
+        if let Some(error) = error.downcast_ref::<crate::verify::VerifyError>() {
+            self.localized(error)
+        } else if let Some(error) = error.downcast_ref::<crate::plot::PlotError>() {
+            self.localized(error)
+        } else if let Some(error) = error.downcast_ref::<crate::audio::AudioError>() {
+            self.localized(error)
+        } else {
+            format!("{:#}", error)
+        }
+    }
+
     pub fn t_with_args(&self, key: &str, args: Option<&FluentArgs>) -> String {
         let lang_code = self.current_language.code();
 
@@ -134,7 +220,7 @@ impl I18n {
                 if let Some(message) = bundle.get_message(key) {
                     if let Some(pattern) = message.value() {
                         let mut errors = vec![];
-                        let value = bundle.format_pattern(pattern, None, &mut errors);
+                        let value = bundle.format_pattern(pattern, args, &mut errors);
                         return value.to_string();
                     }
                 }
@@ -151,3 +237,76 @@ impl Default for I18n {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_locale() {
+        assert_eq!(Language::from_locale("de_DE.UTF-8"), Some(Language::German));
+        assert_eq!(Language::from_locale("pt-BR"), Some(Language::Portuguese));
+        assert_eq!(Language::from_locale("ru_RU@euro"), Some(Language::Russian));
+        assert_eq!(Language::from_locale("FR"), Some(Language::French));
+        assert_eq!(Language::from_locale("C"), None);
+        assert_eq!(Language::from_locale("POSIX"), None);
+        assert_eq!(Language::from_locale(""), None);
+    }
+
+    #[test]
+    fn test_every_locale_has_every_message() {
+        let i18n = I18n::new();
+        let keys: Vec<&str> = include_str!("../../locales/en.ftl")
+            .lines()
+            .filter_map(|line| line.split_once(" = ").map(|(key, _)| key))
+            .filter(|key| !key.starts_with([' ', '#', '*', '[']))
+            .collect();
+        assert!(keys.contains(&"app-title"));
+        for lang in Language::all() {
+            let bundle = i18n
+                .bundles
+                .get(lang.code())
+                .unwrap_or_else(|| panic!("{} does not load", lang.code()));
+            for key in &keys {
+                assert!(bundle.has_message(key), "{} lacks {key}", lang.code());
+            }
+        }
+    }
+
+    #[test]
+    fn test_plurals() {
+        let mut i18n = I18n::new();
+        i18n.set_language(Language::Russian);
+        assert_eq!(
+            i18n.t_args("search-view-count", [("count", 3.into())]),
+            "3 просмотра"
+        );
+        assert_eq!(
+            i18n.t_args("search-view-count", [("count", 11.into())]),
+            "11 просмотров"
+        );
+        i18n.set_language(Language::German);
+        assert_eq!(
+            i18n.t_args("search-view-count", [("count", 1.into())]),
+            "1 Aufruf"
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        use crate::{audio::AudioError, plot::PlotError, verify::VerifyError};
+
+        let mut i18n = I18n::new();
+        i18n.set_language(Language::German);
+        let spiral = anyhow::Error::new(PlotError::NoSpiralTerms);
+        assert_eq!(i18n.error(&spiral), "Keine Glieder zwischen 1 und 40000 für die Spirale");
+        let missing = anyhow::Error::new(VerifyError::MissingInterpreter("Python"));
+        assert_eq!(i18n.error(&missing.context("Python")), "Interpreter Python nicht im PATH gefunden");
+        assert_eq!(
+            i18n.error(&AudioError::NoTerms.into()),
+            "Keine ganzzahligen Glieder zum Abspielen"
+        );
+        // Other errors keep their text
+        assert_eq!(i18n.error(&anyhow::anyhow!("disk full").context("saving")), "saving: disk full");
+    }
+}
@@ -1,14 +1,21 @@
 mod api;
 mod app;
+mod audio;
 mod cli;
+mod constant;
 mod error;
 mod i18n;
+mod palette;
+mod plot;
+mod shell;
+mod table;
 mod ui;
 mod utils;
+mod verify;
 
 use anyhow::Result;
 use app::App;
-use clap::Parser;
+use clap::{CommandFactory, Parser};
 use crossterm::{
     event::{DisableMouseCapture, EnableMouseCapture},
     execute,
@@ -22,11 +29,24 @@ use std::{
 
 #[tokio::main(flavor = "current_thread")]
 async fn main() -> Result<()> {
+    // Answers the shell when it asks for completions (`COMPLETE=bash oeis ...`)
+    clap_complete::CompleteEnv::with_factory(cli::Cli::command).complete();
     let cli = cli::Cli::parse();
-    if let Some(command) = cli.command {
-        cli::run(command).await?;
-        return Ok(());
-    }
+    let language = cli.language();
+    let webcam = match cli.command {
+        Some(command) => match command.webcam_launch(language) {
+            Some(launch) => Some(launch?),
+            None => {
+                cli::run(command, language).await?;
+                return Ok(());
+            }
+        },
+        None if cli.plain => {
+            shell::run(true, language).await?;
+            return Ok(());
+        }
+        None => None,
+    };
 
     // Setup terminal
     enable_raw_mode()?;
@@ -44,6 +64,15 @@ async fn main() -> Result<()> {
 
     // Create app and run it
     let mut app = App::new().await?;
+    if cli.accessible {
+        app.enable_accessibility();
+    }
+    if let Some(lang) = cli.lang {
+        app.use_language(lang);
+    }
+    if let Some(launch) = webcam {
+        app.start_webcam(launch);
+    }
     let res = run_app(&mut terminal, &mut app).await;
 
     // Restore terminal
@@ -0,0 +1,277 @@
+//! Command palette: the actions bound on the current screen, plus commands that have no
+//! key of their own (switching screen, theme, export format or graph type, opening a
+//! bookmark), narrowed down by a fuzzy match on their labels.
+//!
+//! A query that reads as an A-number (`A45`, `a000045` or just `45`) also offers to jump
+//! straight to that sequence, above everything else.
+
+use crate::app::{ExportFormat, GraphType, Screen};
+use crate::utils::keybindings::{Action, KeyContext};
+
+/// A-numbers have six digits
+const MAX_SEQUENCE_NUMBER: i32 = 999_999;
+
+/// What a palette entry does when chosen
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// Run an action as if its key had been pressed in `KeyContext`
+    Action(KeyContext, Action),
+    Screen(Screen),
+    /// Index into the theme list
+    Theme(usize),
+    ExportFormat(ExportFormat),
+    GraphType(GraphType),
+    /// Index into the bookmarks
+    Bookmark(usize),
+    /// Load a sequence by its A-number
+    Sequence(i32),
+}
+
+/// One row of the palette
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaletteEntry {
+    pub command: Command,
+    pub label: String,
+    /// Keys currently bound to the command, already formatted (empty when there are none)
+    pub keys: String,
+}
+
+/// Palette state while it is open
+#[derive(Debug, Clone)]
+pub struct CommandPalette {
+    query: String,
+    entries: Vec<PaletteEntry>,
+    /// Indices into `entries` that match the query, best first
+    matches: Vec<usize>,
+    /// Row under the cursor; the jump row, when there is one, is row 0
+    selected: usize,
+}
+
+impl CommandPalette {
+    pub fn new(entries: Vec<PaletteEntry>) -> Self {
+        let mut palette = Self {
+            query: String::new(),
+            matches: Vec::new(),
+            entries,
+            selected: 0,
+        };
+        palette.refresh();
+        palette
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.query.push(c);
+        self.refresh();
+    }
+
+    pub fn pop(&mut self) {
+        self.query.pop();
+        self.refresh();
+    }
+
+    /// The A-number the query names, offered as the first row
+    pub fn sequence_number(&self) -> Option<i32> {
+        parse_sequence_number(&self.query)
+    }
+
+    /// Matching entries, best first (the jump row is not among them)
+    pub fn matches(&self) -> impl Iterator<Item = &PaletteEntry> {
+        self.matches.iter().map(|&index| &self.entries[index])
+    }
+
+    /// Number of rows, counting the jump row
+    pub fn len(&self) -> usize {
+        self.matches.len() + usize::from(self.sequence_number().is_some())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.is_empty() {
+            self.selected = (self.selected + 1) % self.len();
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.is_empty() {
+            self.selected = (self.selected + self.len() - 1) % self.len();
+        }
+    }
+
+    /// The command on the selected row
+    pub fn selected_command(&self) -> Option<Command> {
+        let mut row = self.selected;
+        if let Some(number) = self.sequence_number() {
+            if row == 0 {
+                return Some(Command::Sequence(number));
+            }
+            row -= 1;
+        }
+        self.matches
+            .get(row)
+            .map(|&index| self.entries[index].command.clone())
+    }
+
+    fn refresh(&mut self) {
+        let mut scored: Vec<(i64, usize)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| fuzzy_score(&self.query, &entry.label).map(|score| (score, index)))
+            .collect();
+        // Stable, so equal scores keep the order the entries were given in
+        scored.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+        self.matches = scored.into_iter().map(|(_, index)| index).collect();
+        self.selected = 0;
+    }
+}
+
+/// How well `query` matches `text`: every query character has to appear in order
+/// (ignoring case and spaces in the query). Runs of consecutive characters and
+/// characters at the start of a word score higher, skipped characters lower.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut score = 0i64;
+    let mut position = 0;
+    let mut previous: Option<usize> = None;
+
+    for c in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let found = position + text[position..].iter().position(|t| *t == c)?;
+        score += 1;
+        if previous.is_some_and(|p| p + 1 == found) {
+            score += 5;
+        }
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += 3;
+        }
+        score -= (found - position).min(10) as i64;
+        previous = Some(found);
+        position = found + 1;
+    }
+    Some(score)
+}
+
+/// `A45`, `a000045` or `45`
+fn parse_sequence_number(query: &str) -> Option<i32> {
+    let query = query.trim();
+    let digits = query
+        .strip_prefix('A')
+        .or_else(|| query.strip_prefix('a'))
+        .unwrap_or(query);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits
+        .parse()
+        .ok()
+        .filter(|number| (1..=MAX_SEQUENCE_NUMBER).contains(number))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(label: &str, command: Command) -> PaletteEntry {
+        PaletteEntry {
+            command,
+            label: label.to_string(),
+            keys: String::new(),
+        }
+    }
+
+    fn palette() -> CommandPalette {
+        CommandPalette::new(vec![
+            entry("Quit", Command::Action(KeyContext::Global, Action::Quit)),
+            entry("Fetch the b-file", Command::Action(KeyContext::Detail, Action::FetchBFile)),
+            entry("Theme: Dark", Command::Theme(0)),
+            entry("Theme: Solarized", Command::Theme(1)),
+            entry("Export format: JSON", Command::ExportFormat(ExportFormat::Json)),
+        ])
+    }
+
+    fn labels(palette: &CommandPalette) -> Vec<&str> {
+        palette.matches().map(|entry| entry.label.as_str()).collect()
+    }
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert!(fuzzy_score("", "anything").is_some());
+        assert!(fuzzy_score("bfl", "Fetch the b-file").is_some());
+        assert!(fuzzy_score("xyz", "Fetch the b-file").is_none());
+        // Order matters
+        assert!(fuzzy_score("elif", "Fetch the b-file").is_none());
+        // Consecutive characters and word starts beat scattered ones
+        let prefix = fuzzy_score("the", "Theme: Dark").unwrap();
+        let scattered = fuzzy_score("the", "Fetch the b-file").unwrap();
+        assert!(prefix > scattered);
+    }
+
+    #[test]
+    fn test_filtering() {
+        let mut palette = palette();
+        assert_eq!(palette.len(), 5);
+        assert_eq!(palette.selected_command(), Some(Command::Action(KeyContext::Global, Action::Quit)));
+
+        for c in "sol".chars() {
+            palette.push(c);
+        }
+        assert_eq!(labels(&palette), ["Theme: Solarized"]);
+        assert_eq!(palette.selected_command(), Some(Command::Theme(1)));
+
+        palette.pop();
+        palette.pop();
+        palette.pop();
+        palette.push('t');
+        palette.push('h');
+        assert_eq!(labels(&palette)[..2], ["Theme: Dark", "Theme: Solarized"]);
+
+        palette.push('q');
+        assert!(palette.is_empty());
+        assert_eq!(palette.selected_command(), None);
+    }
+
+    #[test]
+    fn test_sequence_jump() {
+        assert_eq!(parse_sequence_number("A000045"), Some(45));
+        assert_eq!(parse_sequence_number("a45"), Some(45));
+        assert_eq!(parse_sequence_number(" 1 "), Some(1));
+        assert_eq!(parse_sequence_number("A0"), None);
+        assert_eq!(parse_sequence_number("A"), None);
+        assert_eq!(parse_sequence_number("A4x"), None);
+
+        let mut palette = palette();
+        palette.push('A');
+        palette.push('4');
+        palette.push('5');
+        assert_eq!(palette.sequence_number(), Some(45));
+        assert_eq!(palette.selected_command(), Some(Command::Sequence(45)));
+    }
+
+    #[test]
+    fn test_selection_wraps() {
+        let mut palette = palette();
+        palette.select_previous();
+        assert_eq!(palette.selected(), 4);
+        assert_eq!(
+            palette.selected_command(),
+            Some(Command::ExportFormat(ExportFormat::Json))
+        );
+        palette.select_next();
+        assert_eq!(palette.selected(), 0);
+
+        // Typing starts again from the best match
+        palette.select_next();
+        palette.push('t');
+        assert_eq!(palette.selected(), 0);
+    }
+}
@@ -0,0 +1,1057 @@
+//! Graph data, the zoomable graph viewport and figure export.
+//!
+//! Plots are drawn as a standalone SVG (title, axes, ticks and labels) so the same
+//! drawing backs both formats; PNGs are rasterized from that SVG with resvg using
+//! the system fonts.
+
+use crate::api::{models::BFileEntry, Sequence};
+use crate::app::GraphType;
+use crate::i18n::Localized;
+use anyhow::{Context, Result};
+use std::{fmt::Write as _, path::Path};
+
+/// Figure size in SVG user units (pixels at 1x)
+const WIDTH: f64 = 960.0;
+const HEIGHT: f64 = 600.0;
+/// PNGs are rendered at twice the SVG size so they stay sharp on slides
+const PNG_SCALE: f32 = 2.0;
+
+const MARGIN_LEFT: f64 = 100.0;
+const MARGIN_RIGHT: f64 = 40.0;
+const MARGIN_TOP: f64 = 90.0;
+const MARGIN_BOTTOM: f64 = 70.0;
+
+/// Longest title drawn before it is cut with an ellipsis
+const MAX_TITLE_CHARS: usize = 80;
+/// Line charts only get point markers up to this many points
+const MAX_MARKED_POINTS: usize = 150;
+/// Most bars in a value histogram
+const MAX_HISTOGRAM_BINS: usize = 40;
+/// Largest integer laid out on the Ulam spiral (a 200 × 200 square)
+const ULAM_LIMIT: u64 = 40_000;
+
+/// k used by the residue plot until the user changes it
+pub const DEFAULT_MODULUS: u32 = 10;
+
+const FONT_FAMILY: &str = "DejaVu Sans, Liberation Sans, Arial, Helvetica, sans-serif";
+const COLOR_TEXT: &str = "#222222";
+const COLOR_MUTED: &str = "#666666";
+const COLOR_GRID: &str = "#e5e5e5";
+const COLOR_BACKDROP: &str = "#f7f7f7";
+const COLOR_AXIS: &str = "#444444";
+const COLOR_SERIES: &str = "#1f77b4";
+const COLOR_PIN: &str = "#9ecae1";
+const COLOR_POINT: &str = "#d62728";
+
+/// Why a figure could not be drawn or saved
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum PlotError {
+    #[error("Unsupported plot format; use a .svg or .png file name")]
+    UnsupportedFormat,
+    #[error("No positive values to plot on a logarithmic scale")]
+    NoPositiveValues,
+    #[error("No terms between 1 and {} to place on the spiral", ULAM_LIMIT)]
+    NoSpiralTerms,
+    #[error("No numeric data to plot")]
+    NoData,
+}
+
+impl Localized for PlotError {
+    fn message_key(&self) -> &'static str {
+        match self {
+            Self::UnsupportedFormat => "plot-unsupported-format",
+            Self::NoPositiveValues => "plot-no-positive-values",
+            Self::NoSpiralTerms => "plot-no-spiral-terms",
+            Self::NoData => "plot-no-data",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match self {
+            Self::NoSpiralTerms => vec![("limit", ULAM_LIMIT.to_string())],
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// File formats a plot can be saved as
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlotFormat {
+    Svg,
+    Png,
+}
+
+impl PlotFormat {
+    /// Pick the format from a path's extension (`.svg` or `.png`)
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path
+            .extension()?
+            .to_str()?
+            .to_ascii_lowercase()
+            .as_str()
+        {
+            "svg" => Some(Self::Svg),
+            "png" => Some(Self::Png),
+            _ => None,
+        }
+    }
+}
+
+/// A sequence graph ready to be written as a figure
+#[derive(Debug, Clone)]
+pub struct Plot {
+    pub sequence: Sequence,
+    pub graph_type: GraphType,
+    /// k for [`GraphType::ModK`]
+    pub modulus: u32,
+    pub series: Series,
+}
+
+impl Plot {
+    /// Plot the b-file when it is available, otherwise the terms listed in the entry
+    pub fn new(
+        sequence: &Sequence,
+        bfile: Option<&[BFileEntry]>,
+        graph_type: GraphType,
+        modulus: u32,
+    ) -> Self {
+        Self {
+            sequence: sequence.clone(),
+            series: Series::new(sequence, bfile, &graph_type, modulus),
+            graph_type,
+            modulus,
+        }
+    }
+
+    /// Render the figure in `format`
+    pub fn render(&self, format: PlotFormat) -> Result<Vec<u8>> {
+        let svg = self.to_svg()?;
+        match format {
+            PlotFormat::Svg => Ok(svg.into_bytes()),
+            PlotFormat::Png => svg_to_png(&svg),
+        }
+    }
+
+    /// Render the figure and write it to `path`, choosing the format from its extension
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let format = PlotFormat::from_path(path)
+            .ok_or(PlotError::UnsupportedFormat)?;
+        let bytes = self.render(format)?;
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, bytes).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Standalone SVG document for the plot
+    pub fn to_svg(&self) -> Result<String> {
+        let points = self.plotted_points()?;
+
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{WIDTH}" height="{HEIGHT}" viewBox="0 0 {WIDTH} {HEIGHT}" font-family="{FONT_FAMILY}">"#
+        )?;
+        writeln!(svg, r#"<rect width="100%" height="100%" fill="white"/>"#)?;
+
+        // Title and subtitle
+        let seq = &self.sequence;
+        let title = truncate(&format!("{}: {}", seq.a_number(), seq.name), MAX_TITLE_CHARS);
+        writeln!(
+            svg,
+            r#"<text x="{}" y="38" text-anchor="middle" font-size="20" font-weight="bold" fill="{COLOR_TEXT}">{}</text>"#,
+            WIDTH / 2.0,
+            escape_xml(&title)
+        )?;
+        let source = if self.series.from_bfile { "b-file" } else { "terms" };
+        writeln!(
+            svg,
+            r#"<text x="{}" y="62" text-anchor="middle" font-size="13" fill="{COLOR_MUTED}">{} · {} {} · {}</text>"#,
+            WIDTH / 2.0,
+            self.description(),
+            self.series.terms,
+            source,
+            escape_xml(&seq.url())
+        )?;
+
+        if self.graph_type == GraphType::UlamSpiral {
+            write_ulam(&mut svg, &points)?;
+        } else {
+            self.write_chart(&mut svg, &points)?;
+        }
+        writeln!(svg, "</svg>")?;
+
+        Ok(svg)
+    }
+
+    /// The plot as text for a terminal: `*` marks on a `width` × `height` grid, with the
+    /// a(n) range on the left and the n range below (a log scale shows powers of ten)
+    pub fn to_text(&self, width: usize, height: usize) -> Result<String> {
+        let points = self.plotted_points()?;
+        let log = self.graph_type == GraphType::LogScatter;
+        let (x_lo, x_hi) = (min_of(points.iter().map(|p| p.0)), max_of(points.iter().map(|p| p.0)));
+        let (y_lo, y_hi) = (min_of(points.iter().map(|p| p.1)), max_of(points.iter().map(|p| p.1)));
+        let y_label = |value: f64| {
+            if log {
+                power_of_ten(value)
+            } else {
+                format_tick(value)
+            }
+        };
+        let (top, bottom) = (y_label(y_hi), y_label(y_lo));
+        let label_width = top.chars().count().max(bottom.chars().count());
+        let columns = width.saturating_sub(label_width + 2).max(1);
+        let rows = height.max(2);
+
+        // Fraction of the way from `lo` to `hi`, the middle when they are equal
+        let scale = |value: f64, lo: f64, hi: f64, cells: usize| {
+            let fraction = if hi > lo { (value - lo) / (hi - lo) } else { 0.5 };
+            (fraction * (cells - 1) as f64).round() as usize
+        };
+        let mut grid = vec![vec![' '; columns]; rows];
+        for &(x, y) in &points {
+            let column = scale(x, x_lo, x_hi, columns);
+            let row = rows - 1 - scale(y, y_lo, y_hi, rows);
+            grid[row][column] = '*';
+        }
+
+        let seq = &self.sequence;
+        let mut text = String::new();
+        writeln!(text, "{}: {}", seq.a_number(), truncate(&seq.name, MAX_TITLE_CHARS))?;
+        let source = if self.series.from_bfile { "b-file" } else { "terms" };
+        writeln!(text, "{} · {} {}", self.description(), self.series.terms, source)?;
+        for (index, row) in grid.iter().enumerate() {
+            let label = match index {
+                0 => top.as_str(),
+                i if i == rows - 1 => bottom.as_str(),
+                _ => "",
+            };
+            let row: String = row.iter().collect();
+            writeln!(text, "{:>width$} |{}", label, row.trim_end(), width = label_width)?;
+        }
+        writeln!(text, "{:>width$} +{}", "", "-".repeat(columns), width = label_width)?;
+        let (left, right) = (format_tick(x_lo), format_tick(x_hi));
+        let gap = columns.saturating_sub(left.chars().count() + right.chars().count());
+        writeln!(text, "{:>width$}  {}{}{}", "", left, " ".repeat(gap), right, width = label_width)?;
+        Ok(text)
+    }
+
+    /// The points as drawn: log10 of the positive values on a log scale
+    fn plotted_points(&self) -> Result<Vec<(f64, f64)>> {
+        let points: Vec<(f64, f64)> = if self.graph_type == GraphType::LogScatter {
+            self.series
+                .points
+                .iter()
+                .filter(|(_, y)| *y > 0.0)
+                .map(|(x, y)| (*x, y.log10()))
+                .collect()
+        } else {
+            self.series.points.clone()
+        };
+        if points.is_empty() {
+            return Err(match self.graph_type {
+                GraphType::LogScatter => PlotError::NoPositiveValues,
+                GraphType::UlamSpiral => PlotError::NoSpiralTerms,
+                _ => PlotError::NoData,
+            }
+            .into());
+        }
+        Ok(points)
+    }
+
+    /// Graph type name, with k for the residue plot
+    pub fn description(&self) -> String {
+        match self.graph_type {
+            GraphType::ModK => format!("Residues mod {}", self.modulus),
+            _ => self.graph_type.label().to_string(),
+        }
+    }
+
+    /// Axes, grid and data for every graph type drawn on x/y axes
+    fn write_chart(&self, svg: &mut String, points: &[(f64, f64)]) -> Result<()> {
+        let log = self.graph_type == GraphType::LogScatter;
+        let histogram = self.graph_type == GraphType::Histogram;
+        let bin_width = bin_width(points);
+
+        let (x_lo, x_hi) = (min_of(points.iter().map(|p| p.0)), max_of(points.iter().map(|p| p.0)));
+        let x_axis = if histogram {
+            Axis::linear(x_lo, x_hi + bin_width, bin_width >= 1.0)
+        } else {
+            Axis::linear(x_lo, x_hi, true)
+        };
+        let (y_lo, y_hi) = (min_of(points.iter().map(|p| p.1)), max_of(points.iter().map(|p| p.1)));
+        let y_axis = match self.graph_type {
+            GraphType::LogScatter => Axis::log10(y_lo, y_hi),
+            GraphType::ModK => Axis::linear(0.0, self.modulus.saturating_sub(1).max(1) as f64, true),
+            // Pins and bars grow from zero, so keep it in view
+            GraphType::PinPlot | GraphType::Histogram => {
+                Axis::linear(y_lo.min(0.0), y_hi.max(0.0), histogram)
+            }
+            _ => Axis::linear(y_lo, y_hi, false),
+        };
+
+        let plot_w = WIDTH - MARGIN_LEFT - MARGIN_RIGHT;
+        let plot_h = HEIGHT - MARGIN_TOP - MARGIN_BOTTOM;
+        let sx = |x: f64| MARGIN_LEFT + (x - x_axis.min) / (x_axis.max - x_axis.min) * plot_w;
+        let sy = |y: f64| MARGIN_TOP + plot_h - (y - y_axis.min) / (y_axis.max - y_axis.min) * plot_h;
+
+        // Clip a little outside the frame so markers on the axis limits stay whole
+        writeln!(
+            svg,
+            r#"<defs><clipPath id="plot-area"><rect x="{}" y="{}" width="{}" height="{}"/></clipPath></defs>"#,
+            MARGIN_LEFT - 6.0,
+            MARGIN_TOP - 6.0,
+            plot_w + 12.0,
+            plot_h + 12.0
+        )?;
+
+        // Grid and tick labels
+        for &tick in &x_axis.ticks {
+            let x = sx(tick);
+            writeln!(
+                svg,
+                r#"<line x1="{x:.2}" y1="{MARGIN_TOP}" x2="{x:.2}" y2="{:.2}" stroke="{COLOR_GRID}"/>"#,
+                MARGIN_TOP + plot_h
+            )?;
+            writeln!(
+                svg,
+                r#"<text x="{x:.2}" y="{:.2}" text-anchor="middle" font-size="12" fill="{COLOR_TEXT}">{}</text>"#,
+                MARGIN_TOP + plot_h + 20.0,
+                format_tick(tick)
+            )?;
+        }
+        for &tick in &y_axis.ticks {
+            let y = sy(tick);
+            let label = if log { power_of_ten(tick) } else { format_tick(tick) };
+            writeln!(
+                svg,
+                r#"<line x1="{MARGIN_LEFT}" y1="{y:.2}" x2="{:.2}" y2="{y:.2}" stroke="{COLOR_GRID}"/>"#,
+                MARGIN_LEFT + plot_w
+            )?;
+            writeln!(
+                svg,
+                r#"<text x="{:.2}" y="{:.2}" text-anchor="end" font-size="12" fill="{COLOR_TEXT}">{}</text>"#,
+                MARGIN_LEFT - 8.0,
+                y + 4.0,
+                label
+            )?;
+        }
+
+        // Axes frame and labels
+        let (x_label, y_label) = axis_labels(&self.graph_type, self.modulus);
+        writeln!(
+            svg,
+            r#"<rect x="{MARGIN_LEFT}" y="{MARGIN_TOP}" width="{plot_w}" height="{plot_h}" fill="none" stroke="{COLOR_AXIS}"/>"#
+        )?;
+        writeln!(
+            svg,
+            r#"<text x="{:.2}" y="{:.2}" text-anchor="middle" font-size="14" fill="{COLOR_TEXT}">{x_label}</text>"#,
+            MARGIN_LEFT + plot_w / 2.0,
+            HEIGHT - 22.0
+        )?;
+        writeln!(
+            svg,
+            r#"<text x="24" y="{:.2}" text-anchor="middle" font-size="14" fill="{COLOR_TEXT}" transform="rotate(-90 24 {:.2})">{y_label}</text>"#,
+            MARGIN_TOP + plot_h / 2.0,
+            MARGIN_TOP + plot_h / 2.0
+        )?;
+
+        // Data
+        writeln!(svg, r#"<g clip-path="url(#plot-area)">"#)?;
+        let radius = if points.len() > 2000 { 1.5 } else { 3.0 };
+        match self.graph_type {
+            GraphType::Line | GraphType::Differences | GraphType::Ratio => {
+                let coords: Vec<String> = points
+                    .iter()
+                    .map(|(x, y)| format!("{:.2},{:.2}", sx(*x), sy(*y)))
+                    .collect();
+                writeln!(
+                    svg,
+                    r#"<polyline fill="none" stroke="{COLOR_SERIES}" stroke-width="2" stroke-linejoin="round" points="{}"/>"#,
+                    coords.join(" ")
+                )?;
+                if points.len() <= MAX_MARKED_POINTS {
+                    for (x, y) in points {
+                        write_circle(svg, sx(*x), sy(*y), 2.5, COLOR_SERIES)?;
+                    }
+                }
+            }
+            GraphType::Scatter | GraphType::LogScatter | GraphType::ModK => {
+                for (x, y) in points {
+                    write_circle(svg, sx(*x), sy(*y), radius, COLOR_SERIES)?;
+                }
+            }
+            GraphType::PinPlot => {
+                let base = sy(0.0_f64.clamp(y_axis.min, y_axis.max));
+                for (x, y) in points {
+                    writeln!(
+                        svg,
+                        r#"<line x1="{:.2}" y1="{base:.2}" x2="{:.2}" y2="{:.2}" stroke="{COLOR_PIN}" stroke-width="1.5"/>"#,
+                        sx(*x),
+                        sx(*x),
+                        sy(*y)
+                    )?;
+                    write_circle(svg, sx(*x), sy(*y), radius, COLOR_POINT)?;
+                }
+            }
+            GraphType::Histogram => {
+                for (start, count) in points.iter().filter(|(_, count)| *count > 0.0) {
+                    writeln!(
+                        svg,
+                        r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="{COLOR_SERIES}" stroke="white"/>"#,
+                        sx(*start),
+                        sy(*count),
+                        sx(start + bin_width) - sx(*start),
+                        sy(0.0) - sy(*count)
+                    )?;
+                }
+            }
+            GraphType::UlamSpiral => {}
+        }
+        writeln!(svg, "</g>")?;
+        Ok(())
+    }
+}
+
+/// The spiral as a square of cells, with the members filled in
+fn write_ulam(svg: &mut String, cells: &[(f64, f64)]) -> Result<()> {
+    let half = ulam_half_width(cells);
+    let size = HEIGHT - MARGIN_TOP - 30.0;
+    let cell = size / (2.0 * half + 1.0);
+    let left = (WIDTH - size) / 2.0;
+    let top = MARGIN_TOP;
+
+    writeln!(
+        svg,
+        r#"<rect x="{left:.2}" y="{top:.2}" width="{size:.2}" height="{size:.2}" fill="{COLOR_BACKDROP}" stroke="{COLOR_AXIS}"/>"#
+    )?;
+    for (x, y) in cells {
+        writeln!(
+            svg,
+            r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="{COLOR_SERIES}"/>"#,
+            left + (x + half) * cell,
+            top + (half - y) * cell,
+            cell,
+            cell
+        )?;
+    }
+    // Mark where the spiral starts
+    write_circle(
+        svg,
+        left + (half + 0.5) * cell,
+        top + (half + 0.5) * cell,
+        (cell / 2.0).max(2.0),
+        COLOR_POINT,
+    )?;
+    Ok(())
+}
+
+/// Axis titles for a graph type: (horizontal, vertical)
+pub fn axis_labels(graph_type: &GraphType, modulus: u32) -> (&'static str, String) {
+    match graph_type {
+        GraphType::Histogram => ("a(n)", "count".to_string()),
+        GraphType::ModK => ("n", format!("a(n) mod {}", modulus)),
+        GraphType::Differences => ("n", "a(n) − a(n−1)".to_string()),
+        GraphType::Ratio => ("n", "a(n) / a(n−1)".to_string()),
+        GraphType::LogScatter => ("n", "a(n), log scale".to_string()),
+        GraphType::UlamSpiral => ("", String::new()),
+        _ => ("n", "a(n)".to_string()),
+    }
+}
+
+/// What a graph type draws, computed from a sequence's terms.
+///
+/// Points are (n, y) for the n-indexed types, (bin start, count) for the histogram (every
+/// bin, empty ones included) and the (x, y) cells of the members for the Ulam spiral.
+#[derive(Debug, Clone, Default)]
+pub struct Series {
+    pub points: Vec<(f64, f64)>,
+    /// Number of terms the points were computed from
+    pub terms: usize,
+    /// Whether the terms come from the b-file rather than the entry
+    pub from_bfile: bool,
+}
+
+impl Series {
+    /// Use the whole b-file when one is loaded, otherwise the terms listed in the entry.
+    /// Values too large for an `f64` are skipped (residues and the spiral use exact values).
+    pub fn new(
+        sequence: &Sequence,
+        bfile: Option<&[BFileEntry]>,
+        graph_type: &GraphType,
+        modulus: u32,
+    ) -> Self {
+        let (terms, from_bfile) = match bfile.filter(|entries| !entries.is_empty()) {
+            Some(entries) => (
+                entries
+                    .iter()
+                    .map(|entry| (entry.index, entry.value.as_str()))
+                    .collect::<Vec<_>>(),
+                true,
+            ),
+            None => {
+                let (start, _) = sequence.parse_offset();
+                let terms = sequence
+                    .data
+                    .split(',')
+                    .enumerate()
+                    .map(|(i, value)| (start as i64 + i as i64, value))
+                    .collect();
+                (terms, false)
+            }
+        };
+
+        let points = match graph_type {
+            GraphType::ModK => terms
+                .iter()
+                .filter_map(|(n, value)| Some((*n as f64, residue(value, modulus)? as f64)))
+                .collect(),
+            GraphType::UlamSpiral => {
+                ulam_cells(terms.iter().filter_map(|(_, value)| value.trim().parse().ok()))
+            }
+            _ => {
+                let values: Vec<(f64, f64)> = terms
+                    .iter()
+                    .filter_map(|(n, value)| Some((*n as f64, parse_value(value)?)))
+                    .collect();
+                match graph_type {
+                    GraphType::Histogram => histogram(&values),
+                    GraphType::Differences => successive(&values, |prev, cur| Some(cur - prev)),
+                    GraphType::Ratio => {
+                        successive(&values, |prev, cur| (prev != 0.0).then(|| cur / prev))
+                    }
+                    _ => values,
+                }
+            }
+        };
+
+        Self {
+            points,
+            terms: terms.len(),
+            from_bfile,
+        }
+    }
+}
+
+/// Non-negative `value mod k`, worked out digit by digit so huge terms stay exact
+pub fn residue(value: &str, modulus: u32) -> Option<u32> {
+    let value = value.trim();
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, value),
+    };
+    if modulus == 0 || digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let k = modulus as u64;
+    let r = digits
+        .bytes()
+        .fold(0u64, |acc, b| (acc * 10 + (b - b'0') as u64) % k);
+    Some(if negative && r != 0 { (k - r) as u32 } else { r as u32 })
+}
+
+/// `f(a(n-1), a(n))` at each n whose predecessor is also present
+fn successive(values: &[(f64, f64)], f: impl Fn(f64, f64) -> Option<f64>) -> Vec<(f64, f64)> {
+    values
+        .windows(2)
+        .filter(|w| w[1].0 - w[0].0 == 1.0)
+        .filter_map(|w| Some((w[1].0, f(w[0].1, w[1].1)?)))
+        .filter(|(_, y)| y.is_finite())
+        .collect()
+}
+
+/// (bin start, count) for every bin; integer values with a small spread get one bin per value
+fn histogram(values: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    if values.is_empty() {
+        return Vec::new();
+    }
+
+    let lo = min_of(values.iter().map(|v| v.1));
+    let hi = max_of(values.iter().map(|v| v.1));
+    let integers = values.iter().all(|(_, y)| y.fract() == 0.0);
+    let width = if hi == lo || (integers && hi - lo < MAX_HISTOGRAM_BINS as f64) {
+        1.0
+    } else if integers {
+        nice_step(hi - lo, MAX_HISTOGRAM_BINS).max(1.0)
+    } else {
+        nice_step(hi - lo, MAX_HISTOGRAM_BINS)
+    };
+
+    let start = (lo / width).floor() * width;
+    let bins = ((hi - start) / width).floor() as usize + 1;
+    let mut counts = vec![0usize; bins];
+    for (_, y) in values {
+        counts[(((y - start) / width).floor() as usize).min(bins - 1)] += 1;
+    }
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| (start + i as f64 * width, count as f64))
+        .collect()
+}
+
+/// Width of the bars in a histogram series
+pub fn bin_width(points: &[(f64, f64)]) -> f64 {
+    match points {
+        [first, second, ..] => second.0 - first.0,
+        _ => 1.0,
+    }
+}
+
+/// Spiral cells of the distinct members between 1 and [`ULAM_LIMIT`]
+fn ulam_cells(values: impl Iterator<Item = u64>) -> Vec<(f64, f64)> {
+    let mut members: Vec<u64> = values.filter(|v| (1..=ULAM_LIMIT).contains(v)).collect();
+    members.sort_unstable();
+    members.dedup();
+    members
+        .into_iter()
+        .map(|m| {
+            let (x, y) = ulam_position(m);
+            (x as f64, y as f64)
+        })
+        .collect()
+}
+
+/// Cells from the centre to the edge of the smallest spiral holding `cells`
+pub fn ulam_half_width(cells: &[(f64, f64)]) -> f64 {
+    cells
+        .iter()
+        .map(|(x, y)| x.abs().max(y.abs()))
+        .fold(1.0, f64::max)
+}
+
+/// Where `m` sits on the Ulam spiral: 1 at the origin, 2 to its right, then counterclockwise
+fn ulam_position(m: u64) -> (i64, i64) {
+    if m <= 1 {
+        return (0, 0);
+    }
+
+    // Ring k runs from (2k-1)² + 1 up to (2k+1)², which sits at its bottom-right corner
+    let k = ((((m as f64).sqrt() - 1.0) / 2.0).ceil()) as i64;
+    let side = 2 * k;
+    let mut corner = (2 * k + 1) * (2 * k + 1);
+    let m = m as i64;
+    if m >= corner - side {
+        return (k - (corner - m), -k);
+    }
+    corner -= side;
+    if m >= corner - side {
+        return (-k, -k + (corner - m));
+    }
+    corner -= side;
+    if m >= corner - side {
+        return (-k + (corner - m), k);
+    }
+    (k, k - (corner - m - side))
+}
+
+fn parse_value(text: &str) -> Option<f64> {
+    text.trim().parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+/// Thin `points` (sorted by n) down to the lowest and highest a(n) of each of `buckets`
+/// equal-width n-ranges, so a screen column keeps its spikes however many terms fall in it.
+pub fn decimate(points: &[(f64, f64)], buckets: usize) -> Vec<(f64, f64)> {
+    if buckets == 0 || points.len() <= buckets * 2 {
+        return points.to_vec();
+    }
+
+    let first = points[0].0;
+    let width = (points[points.len() - 1].0 - first) / buckets as f64;
+    let mut result = Vec::with_capacity(buckets * 2);
+    // Current bucket with its lowest and highest point so far
+    let mut bucket = 0;
+    let (mut low, mut high) = (points[0], points[0]);
+
+    let flush = |result: &mut Vec<(f64, f64)>, low: (f64, f64), high: (f64, f64)| {
+        if low == high {
+            result.push(low);
+        } else if low.0 <= high.0 {
+            result.extend([low, high]);
+        } else {
+            result.extend([high, low]);
+        }
+    };
+
+    for &point in &points[1..] {
+        let index = if width > 0.0 {
+            (((point.0 - first) / width) as usize).min(buckets - 1)
+        } else {
+            0
+        };
+        if index != bucket {
+            flush(&mut result, low, high);
+            bucket = index;
+            (low, high) = (point, point);
+        } else if point.1 < low.1 {
+            low = point;
+        } else if point.1 > high.1 {
+            high = point;
+        }
+    }
+    flush(&mut result, low, high);
+    result
+}
+
+/// Narrowest n-range the graph view zooms into
+const MIN_VIEW_SPAN: f64 = 4.0;
+
+/// The n-range shown by the graph view; everything is shown until the user zooms in.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Viewport {
+    range: Option<(f64, f64)>,
+}
+
+impl Viewport {
+    /// Visible n-range within the data's `full` range
+    pub fn visible(&self, full: (f64, f64)) -> (f64, f64) {
+        match self.range {
+            Some((lo, hi)) if hi - lo < full.1 - full.0 => shift_into(lo, hi - lo, full),
+            _ => full,
+        }
+    }
+
+    pub fn is_zoomed(&self) -> bool {
+        self.range.is_some()
+    }
+
+    /// Scale the visible span by `factor` (below 1 zooms in), keeping `anchor` (an n value,
+    /// the centre by default) at the same place on screen
+    pub fn zoom(&mut self, full: (f64, f64), factor: f64, anchor: Option<f64>) {
+        let (lo, hi) = self.visible(full);
+        let span = ((hi - lo) * factor).max(MIN_VIEW_SPAN);
+        if span >= full.1 - full.0 {
+            self.range = None;
+            return;
+        }
+
+        let anchor = anchor.unwrap_or((lo + hi) / 2.0).clamp(lo, hi);
+        let position = if hi > lo { (anchor - lo) / (hi - lo) } else { 0.5 };
+        self.range = Some(shift_into(anchor - span * position, span, full));
+    }
+
+    /// Move the visible range by `fraction` of its width (negative moves left)
+    pub fn pan(&mut self, full: (f64, f64), fraction: f64) {
+        if self.range.is_some() {
+            let (lo, hi) = self.visible(full);
+            self.range = Some(shift_into(lo + (hi - lo) * fraction, hi - lo, full));
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.range = None;
+    }
+}
+
+/// A `span`-wide range starting near `lo`, moved so it stays inside `full`
+fn shift_into(lo: f64, span: f64, full: (f64, f64)) -> (f64, f64) {
+    let lo = lo.min(full.1 - span).max(full.0);
+    (lo, lo + span)
+}
+
+/// Axis range snapped to tick boundaries, with the tick positions
+struct Axis {
+    min: f64,
+    max: f64,
+    ticks: Vec<f64>,
+}
+
+impl Axis {
+    /// Linear axis covering `lo..=hi` with about eight "nice" ticks (1, 2 or 5 × 10^k)
+    fn linear(lo: f64, hi: f64, integer: bool) -> Self {
+        let (lo, hi) = if hi > lo { (lo, hi) } else { (lo - 1.0, hi + 1.0) };
+        let mut step = nice_step(hi - lo, 8);
+        if integer {
+            step = step.max(1.0);
+        }
+        let min = (lo / step).floor() * step;
+        let max = (hi / step).ceil() * step;
+        let ticks = (0..=((max - min) / step).round() as usize)
+            .map(|i| min + i as f64 * step)
+            .collect();
+        Self { min, max, ticks }
+    }
+
+    /// Axis over log10 values, ticking whole powers of ten
+    fn log10(lo: f64, hi: f64) -> Self {
+        let min = lo.floor();
+        let max = if hi.ceil() > min { hi.ceil() } else { min + 1.0 };
+        let step = ((max - min) / 8.0).ceil().max(1.0);
+        let ticks = (0..=((max - min) / step) as usize)
+            .map(|i| min + i as f64 * step)
+            .collect();
+        Self { min, max, ticks }
+    }
+}
+
+/// Round `span / target` up to 1, 2 or 5 times a power of ten
+fn nice_step(span: f64, target: usize) -> f64 {
+    let raw = span / target as f64;
+    let magnitude = 10f64.powf(raw.log10().floor());
+    let normalized = raw / magnitude;
+    let nice = if normalized <= 1.0 {
+        1.0
+    } else if normalized <= 2.0 {
+        2.0
+    } else if normalized <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+    nice * magnitude
+}
+
+/// Tick label: plain numbers up to a million, then `m×10ᵉ`
+pub fn format_tick(value: f64) -> String {
+    if value == 0.0 {
+        return "0".to_string();
+    }
+    if value.abs() >= 1e6 {
+        let exponent = value.abs().log10().floor() as i32;
+        let mantissa = value / 10f64.powi(exponent);
+        let mantissa = trim_decimal(&format!("{:.2}", mantissa));
+        return if mantissa == "1" {
+            power_of_ten(exponent as f64)
+        } else {
+            format!("{}×{}", mantissa, power_of_ten(exponent as f64))
+        };
+    }
+    trim_decimal(&format!("{:.3}", value))
+}
+
+/// `10` with a superscript exponent, e.g. `10⁶`
+fn power_of_ten(exponent: f64) -> String {
+    let superscript: String = (exponent.round() as i64)
+        .to_string()
+        .chars()
+        .map(|c| match c {
+            '-' => '⁻',
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            _ => '⁹',
+        })
+        .collect();
+    format!("10{}", superscript)
+}
+
+fn trim_decimal(text: &str) -> String {
+    if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+fn min_of(values: impl Iterator<Item = f64>) -> f64 {
+    values.fold(f64::INFINITY, f64::min)
+}
+
+fn max_of(values: impl Iterator<Item = f64>) -> f64 {
+    values.fold(f64::NEG_INFINITY, f64::max)
+}
+
+fn write_circle(svg: &mut String, x: f64, y: f64, r: f64, fill: &str) -> std::fmt::Result {
+    writeln!(svg, r#"<circle cx="{x:.2}" cy="{y:.2}" r="{r}" fill="{fill}"/>"#)
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        text.to_string()
+    } else {
+        let cut: String = text.chars().take(max_chars - 1).collect();
+        format!("{}…", cut.trim_end())
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Rasterize an SVG document to PNG bytes
+fn svg_to_png(svg: &str) -> Result<Vec<u8>> {
+    use resvg::{tiny_skia, usvg};
+
+    let mut options = usvg::Options::default();
+    options.fontdb_mut().load_system_fonts();
+    let tree = usvg::Tree::from_str(svg, &options).context("Failed to parse plot SVG")?;
+
+    let size = tree.size().to_int_size().scale_by(PNG_SCALE).context("Invalid plot size")?;
+    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())
+        .context("Failed to allocate plot image")?;
+    resvg::render(
+        &tree,
+        tiny_skia::Transform::from_scale(PNG_SCALE, PNG_SCALE),
+        &mut pixmap.as_mut(),
+    );
+    pixmap.encode_png().context("Failed to encode PNG")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Sequence {
+        serde_json::from_value(serde_json::json!({
+            "number": 45,
+            "data": "0,1,1,2,3,5,8,13,21,34,55,89,144",
+            "name": "Fibonacci numbers: F(n) = F(n-1) + F(n-2) with F(0) = 0 & F(1) = 1.",
+            "offset": "0,4",
+            "keyword": "core,nonn",
+            "author": "N. J. A. Sloane",
+            "created": "1991-04-30T03:00:00-04:00",
+            "time": "2024-01-01T00:00:00-05:00",
+            "revision": 1,
+            "references": 0
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_axis_ticks() {
+        let axis = Axis::linear(0.0, 144.0, false);
+        assert_eq!(axis.min, 0.0);
+        assert_eq!(axis.max, 160.0);
+        assert_eq!(axis.ticks.len(), 9);
+
+        let axis = Axis::linear(0.0, 3.0, true);
+        assert_eq!(axis.ticks, vec![0.0, 1.0, 2.0, 3.0]);
+
+        let axis = Axis::log10(0.0, 2.158);
+        assert_eq!(axis.ticks, vec![0.0, 1.0, 2.0, 3.0]);
+
+        assert_eq!(format_tick(120.0), "120");
+        assert_eq!(format_tick(0.25), "0.25");
+        assert_eq!(format_tick(2_500_000.0), "2.5×10⁶");
+        assert_eq!(format_tick(1e9), "10⁹");
+        assert_eq!(power_of_ten(-3.0), "10⁻³");
+    }
+
+    #[test]
+    fn test_decimate_keeps_extremes() {
+        let points: Vec<(f64, f64)> = (0..10_000)
+            .map(|n| (n as f64, if n == 4_321 { 1e9 } else { (n % 7) as f64 }))
+            .collect();
+        let thinned = decimate(&points, 100);
+        assert!(thinned.len() <= 200);
+        assert!(thinned.contains(&(4_321.0, 1e9)));
+        assert!(thinned.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(decimate(&points[..50], 100).len(), 50);
+    }
+
+    #[test]
+    fn test_viewport_zoom_and_pan() {
+        let full = (0.0, 1000.0);
+        let mut view = Viewport::default();
+        assert_eq!(view.visible(full), full);
+
+        view.zoom(full, 0.5, None);
+        assert_eq!(view.visible(full), (250.0, 750.0));
+        view.pan(full, 1.0);
+        assert_eq!(view.visible(full), (500.0, 1000.0));
+
+        view.zoom(full, 0.5, Some(1000.0));
+        assert_eq!(view.visible(full), (750.0, 1000.0));
+        view.zoom(full, 0.0, None);
+        assert_eq!(view.visible(full).1 - view.visible(full).0, MIN_VIEW_SPAN);
+
+        view.zoom(full, 1e6, None);
+        assert!(!view.is_zoomed());
+        view.pan(full, 1.0);
+        assert_eq!(view.visible(full), full);
+    }
+
+    #[test]
+    fn test_derived_series() {
+        let seq = sample();
+        let series = |graph_type, modulus| Series::new(&seq, None, &graph_type, modulus).points;
+
+        let residues: Vec<f64> = series(GraphType::ModK, 3).iter().map(|p| p.1).collect();
+        assert_eq!(residues[..8], [0.0, 1.0, 1.0, 2.0, 0.0, 2.0, 2.0, 1.0]);
+        assert_eq!(residue("-7", 5), Some(3));
+        assert_eq!(residue("123456789012345678901234567890", 97), Some(52));
+        assert_eq!(residue("1.5", 5), None);
+
+        let differences = series(GraphType::Differences, 0);
+        assert_eq!(differences[0], (1.0, 1.0));
+        assert_eq!(differences[12 - 1], (12.0, 55.0));
+        let ratios = series(GraphType::Ratio, 0);
+        assert_eq!(ratios[0], (2.0, 1.0));
+        assert!((ratios.last().unwrap().1 - 1.618).abs() < 0.01);
+
+        let histogram = series(GraphType::Histogram, 0);
+        assert_eq!(histogram.iter().map(|p| p.1).sum::<f64>(), 13.0);
+        assert_eq!(bin_width(&histogram), 5.0);
+        assert_eq!(histogram[0], (0.0, 5.0));
+
+        let positions: Vec<(i64, i64)> = (1..=10).map(ulam_position).collect();
+        assert_eq!(
+            positions,
+            [(0, 0), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1), (0, -1), (1, -1), (2, -1)]
+        );
+        let cells = series(GraphType::UlamSpiral, 0);
+        assert_eq!(cells.len(), 11);
+        assert_eq!(ulam_half_width(&cells), 6.0);
+    }
+
+    #[test]
+    fn test_plot_svg_and_png() {
+        let seq = sample();
+        let plot = Plot::new(&seq, None, GraphType::Line, DEFAULT_MODULUS);
+        assert_eq!(plot.series.points.len(), 13);
+        assert!(!plot.series.from_bfile);
+
+        let svg = plot.to_svg().unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("A000045: Fibonacci numbers"));
+        assert!(svg.contains("&amp;"));
+        assert!(svg.contains("<polyline"));
+
+        let bfile = vec![
+            BFileEntry { index: 0, value: "0".to_string() },
+            BFileEntry { index: 1, value: "1".to_string() },
+            BFileEntry { index: 2, value: "1".to_string() },
+        ];
+        let log = Plot::new(&seq, Some(&bfile), GraphType::LogScatter, DEFAULT_MODULUS);
+        assert!(log.series.from_bfile);
+        assert_eq!(log.to_svg().unwrap().matches("<circle").count(), 2);
+
+        let png = plot.render(PlotFormat::Png).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+
+        for graph_type in GraphType::ALL {
+            let svg = Plot::new(&seq, None, graph_type.clone(), 3).to_svg().unwrap();
+            assert!(svg.ends_with("</svg>\n"), "{:?}", graph_type);
+        }
+
+        assert_eq!(PlotFormat::from_path(Path::new("fib.SVG")), Some(PlotFormat::Svg));
+        assert_eq!(PlotFormat::from_path(Path::new("fib.pdf")), None);
+    }
+
+    #[test]
+    fn test_plot_text() {
+        let seq = sample();
+        let text = Plot::new(&seq, None, GraphType::Line, DEFAULT_MODULUS)
+            .to_text(40, 8)
+            .unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines[0].starts_with("A000045: Fibonacci numbers"));
+        // Title, description, eight rows, the axis and the n range
+        assert_eq!(lines.len(), 12);
+        assert!(lines[2].starts_with("144 |"));
+        assert!(lines[2].ends_with('*'));
+        assert!(lines[9].starts_with("  0 |*"));
+        assert!(lines[11].trim_start().starts_with('0') && lines[11].ends_with("12"));
+        assert_eq!(text.matches('*').count(), 13);
+    }
+}
@@ -0,0 +1,698 @@
+//! `oeis shell`: an interactive session between the full-screen interface and one-shot
+//! commands. Lines are edited with readline keys, recalled from the session and from the
+//! searches in the cache's `search_history` table (shared with the TUI), and A-numbers
+//! complete with Tab from the bookmarks and recently viewed sequences.
+//!
+//! `|` hands the sequences one command produced to the next, which works on each of them
+//! (`search keyword:nice | bookmark`); only the last command of a line prints.
+//! `oeis --plain` runs the same session without colours.
+
+use crate::api::{Cache, OEISClient, SearchQuery, Sequence};
+use crate::cli::{print_sequence_detail, print_sequence_summary, PlotType};
+use crate::i18n::{I18n, Language};
+use crate::plot::{Plot, DEFAULT_MODULUS};
+use anstream::println;
+use anyhow::{anyhow, bail, Result};
+use clap::ValueEnum;
+use owo_colors::OwoColorize;
+use rustyline::{
+    completion::{Completer, Pair},
+    error::ReadlineError,
+    highlight::Highlighter,
+    hint::Hinter,
+    validate::Validator,
+    Context, Editor, Helper,
+};
+
+const PROMPT: &str = "oeis> ";
+/// Results listed per search or `more`
+const PAGE_SIZE: usize = 10;
+/// Cached sequences younger than this are shown without fetching them again
+const CACHE_MAX_AGE_DAYS: i64 = 30;
+/// Lines of history loaded at start
+const HISTORY_SIZE: usize = 1000;
+/// Recently viewed sequences offered for completion, besides the bookmarks
+const COMPLETION_RECENT: usize = 200;
+/// b-file entries printed by `bfile` unless a count is given
+const BFILE_LINES: usize = 20;
+/// Rows of an ASCII plot
+const PLOT_HEIGHT: usize = 16;
+
+const COMMANDS: &[&str] = &[
+    "search", "more", "show", "terms", "bfile", "plot", "bookmark", "unbookmark", "bookmarks",
+    "diff", "help", "quit",
+];
+
+/// Usage of each command for `help`, with the key of its description
+const HELP: &[(&str, &str)] = &[
+    ("search <terms>", "shell-help-search"),
+    ("more", "shell-help-more"),
+    ("show <n | A-number>", "shell-help-show"),
+    ("terms [sequence]", "shell-help-terms"),
+    ("bfile [sequence] [count]", "shell-help-bfile"),
+    ("plot [sequence] [type]", "shell-help-plot"),
+    ("bookmark [sequence] [notes]", "shell-help-bookmark"),
+    ("unbookmark [sequence]", "shell-help-unbookmark"),
+    ("bookmarks", "shell-help-bookmarks"),
+    ("diff <sequence> [sequence]", "shell-help-diff"),
+    ("help", "shell-help-help"),
+    ("quit", "shell-help-quit"),
+];
+
+/// What to do after a line
+enum Flow {
+    Continue,
+    Quit,
+}
+
+/// State kept between commands
+struct Session {
+    client: OEISClient,
+    cache: Cache,
+    i18n: I18n,
+    /// Last search, moved on by `more`
+    query: Option<SearchQuery>,
+    results: Vec<Sequence>,
+    /// Sequence shown last, used by commands given no A-number
+    current: Option<i32>,
+}
+
+pub async fn run(plain: bool, language: Language) -> Result<()> {
+    if plain {
+        anstream::ColorChoice::Never.write_global();
+    }
+    let mut i18n = I18n::new();
+    i18n.set_language(language);
+    let mut session = Session {
+        client: OEISClient::new()?,
+        cache: Cache::new()?,
+        i18n,
+        query: None,
+        results: Vec::new(),
+        current: None,
+    };
+
+    let mut editor: Editor<ShellHelper, _> = Editor::new()?;
+    for line in history_lines(&session.cache) {
+        editor.add_history_entry(line)?;
+    }
+    editor.set_helper(Some(ShellHelper {
+        anumbers: known_anumbers(&session.cache),
+    }));
+    println!("{}", session.i18n.t("shell-welcome"));
+
+    loop {
+        let line = match editor.readline(PROMPT) {
+            Ok(line) => line,
+            // Ctrl+C drops the line being typed
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => return Ok(()),
+            Err(err) => return Err(err.into()),
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        editor.add_history_entry(line)?;
+
+        match session.execute_line(line).await {
+            Ok(Flow::Continue) => {}
+            Ok(Flow::Quit) => return Ok(()),
+            Err(err) => println!("{} {}", format!("{}:", session.i18n.t("common-error")).red(), session.i18n.error(&err)),
+        }
+        if let Some(helper) = editor.helper_mut() {
+            helper.anumbers = known_anumbers(&session.cache);
+        }
+    }
+}
+
+impl Session {
+    /// Run the commands of a line, each getting the sequences of the one before
+    async fn execute_line(&mut self, line: &str) -> Result<Flow> {
+        let stages: Vec<&str> = line.split('|').map(str::trim).collect();
+        let mut piped = None;
+        for (index, stage) in stages.iter().enumerate() {
+            let last = index + 1 == stages.len();
+            match self.execute(stage, piped.take(), last).await? {
+                Some(numbers) => piped = Some(numbers),
+                None => return Ok(Flow::Quit),
+            }
+        }
+        Ok(Flow::Continue)
+    }
+
+    /// Run one command on the sequences piped into it, printing only when `print` is set.
+    /// Returns the sequences it produced, or None to leave the shell.
+    async fn execute(
+        &mut self,
+        stage: &str,
+        piped: Option<Vec<i32>>,
+        print: bool,
+    ) -> Result<Option<Vec<i32>>> {
+        let (command, argument) = match stage.split_once(char::is_whitespace) {
+            Some((command, argument)) => (command, argument.trim()),
+            None => (stage, ""),
+        };
+        let numbers = match command.to_ascii_lowercase().as_str() {
+            "" => piped.unwrap_or_default(),
+            "quit" | "exit" | "q" => return Ok(None),
+            "help" | "?" => {
+                if print {
+                    self.print_help();
+                }
+                Vec::new()
+            }
+            "search" | "s" if argument.is_empty() => bail!(self.i18n.t("shell-search-usage")),
+            "search" | "s" => self.search(SearchQuery::new(argument), print).await?,
+            "more" | "next" => match &self.query {
+                Some(query) => self.search(query.next_page(PAGE_SIZE), print).await?,
+                None => bail!(self.i18n.t("shell-nothing-to-page")),
+            },
+            "show" | "open" => {
+                let numbers = match argument.parse::<usize>() {
+                    Ok(index) => vec![self
+                        .results
+                        .get(index.wrapping_sub(1))
+                        .map(|seq| seq.number)
+                        .ok_or_else(|| {
+                            anyhow!(self.i18n.t_args("shell-no-such-result", [("index", index.into())]))
+                        })?],
+                    Err(_) => self.targets(argument, piped)?.0,
+                };
+                for &number in &numbers {
+                    let sequence = self.sequence(number).await?;
+                    let _ = self.cache.record_view(number);
+                    self.current = Some(number);
+                    if print {
+                        print_sequence_detail(&self.i18n, &sequence);
+                    }
+                }
+                numbers
+            }
+            "terms" => {
+                let (numbers, _) = self.targets(argument, piped)?;
+                for &number in &numbers {
+                    let sequence = self.sequence(number).await?;
+                    if print {
+                        let terms = sequence.data.split(',').collect::<Vec<_>>().join(", ");
+                        println!("{}: {}", sequence.a_number().bright_cyan(), terms);
+                    }
+                }
+                numbers
+            }
+            "bfile" => {
+                let (numbers, rest) = self.targets(argument, piped)?;
+                let count = match rest {
+                    "" => BFILE_LINES,
+                    count => count
+                        .parse()
+                        .map_err(|_| anyhow!(self.i18n.t_args("shell-bad-count", [("count", count.into())])))?,
+                };
+                for &number in &numbers {
+                    let entries = self.client.fetch_b_file(number).await?;
+                    if print {
+                        let total = self.i18n.t_args("shell-bfile-entries", [("count", entries.len().into())]);
+                        println!("{} ({})", format!("A{:06}", number).bright_cyan(), total);
+                        for entry in entries.iter().take(count) {
+                            println!("{} {}", entry.index, entry.value);
+                        }
+                    }
+                }
+                numbers
+            }
+            "plot" => {
+                let (numbers, rest) = self.targets(argument, piped)?;
+                let graph_type = match rest {
+                    "" => PlotType::Line,
+                    name => PlotType::from_str(name, true)
+                        .map_err(|_| anyhow!(self.i18n.t_args("shell-unknown-graph", [("name", name.into())])))?,
+                }
+                .graph_type();
+                let width = crossterm::terminal::size()
+                    .map(|(columns, _)| columns as usize)
+                    .unwrap_or(80)
+                    .clamp(40, 160);
+                for &number in &numbers {
+                    let sequence = self.sequence(number).await?;
+                    if print {
+                        let plot = Plot::new(&sequence, None, graph_type.clone(), DEFAULT_MODULUS);
+                        println!("{}", plot.to_text(width, PLOT_HEIGHT)?);
+                    }
+                }
+                numbers
+            }
+            "bookmark" => {
+                let (numbers, notes) = self.targets(argument, piped)?;
+                let notes = (!notes.is_empty()).then_some(notes);
+                for &number in &numbers {
+                    self.cache.add_bookmark(number, notes)?;
+                    if print {
+                        println!("{}", self.i18n.t_args("shell-bookmarked", [("anumber", format!("A{:06}", number).into())]));
+                    }
+                }
+                numbers
+            }
+            "unbookmark" => {
+                let (numbers, _) = self.targets(argument, piped)?;
+                for &number in &numbers {
+                    self.cache.remove_bookmark(number)?;
+                    if print {
+                        println!("{}", self.i18n.t_args("shell-unbookmarked", [("anumber", format!("A{:06}", number).into())]));
+                    }
+                }
+                numbers
+            }
+            "bookmarks" => {
+                let bookmarks = self.cache.get_bookmarks()?;
+                if print {
+                    if bookmarks.is_empty() {
+                        println!("{}", self.i18n.t("shell-no-bookmarks"));
+                    }
+                    for (number, notes) in &bookmarks {
+                        let name = cached_name(&self.cache, *number);
+                        let notes = notes.as_deref().map(|notes| format!(" [{}]", notes));
+                        println!(
+                            "{} {}{}",
+                            format!("A{:06}", number).bright_cyan(),
+                            name,
+                            notes.unwrap_or_default()
+                        );
+                    }
+                }
+                bookmarks.into_iter().map(|(number, _)| number).collect()
+            }
+            "diff" => {
+                let (mut numbers, _) = self.targets(argument, piped)?;
+                if numbers.len() == 1 {
+                    // One sequence named: compare the one shown last with it
+                    let current = self
+                        .current
+                        .filter(|current| *current != numbers[0])
+                        .ok_or_else(|| anyhow!(self.i18n.t("shell-diff-usage")))?;
+                    numbers.insert(0, current);
+                }
+                if numbers.len() != 2 {
+                    bail!(self.i18n.t_args("shell-diff-count", [("count", numbers.len().into())]));
+                }
+                let a = self.sequence(numbers[0]).await?;
+                let b = self.sequence(numbers[1]).await?;
+                if print {
+                    print_diff(&self.i18n, &a, &b);
+                }
+                numbers
+            }
+            _ if parse_anumber(command).is_some() && argument.is_empty() => {
+                return Box::pin(self.execute(&format!("show {}", command), piped, print)).await;
+            }
+            _ => self.search(SearchQuery::new(stage), print).await?,
+        };
+        Ok(Some(numbers))
+    }
+
+    fn print_help(&self) {
+        println!("{}", self.i18n.t("shell-help-title"));
+        let width = HELP.iter().map(|(usage, _)| usage.len()).max().unwrap_or(0);
+        for (usage, key) in HELP {
+            println!("  {:<width$}  {}", usage, self.i18n.t(key));
+        }
+        println!();
+        println!("{}", self.i18n.t("shell-help-pipe"));
+    }
+
+    async fn search(&mut self, query: SearchQuery, print: bool) -> Result<Vec<i32>> {
+        let response = self.client.search(&query, PAGE_SIZE).await?;
+        // Only new searches go in the history the TUI shares, not `more` or other commands
+        if query.start == 0 {
+            let _ = self.cache.add_search_history(&query.query);
+        }
+        let results: Vec<Sequence> = response
+            .results
+            .unwrap_or_default()
+            .into_iter()
+            .take(PAGE_SIZE)
+            .collect();
+        for seq in &results {
+            let _ = self.cache.cache_sequence(seq);
+        }
+
+        if print {
+            if results.is_empty() {
+                println!("{}", self.i18n.t_args("shell-no-results", [("query", query.query.as_str().into())]));
+            } else {
+                let first = query.start as usize + 1;
+                let heading = self.i18n.t_args(
+                    "shell-results",
+                    [
+                        ("first", first.into()),
+                        ("last", (first + results.len() - 1).into()),
+                        ("count", response.count.into()),
+                        ("query", query.query.cyan().to_string().into()),
+                    ],
+                );
+                println!("{}", heading);
+                for (index, seq) in results.iter().enumerate() {
+                    print_sequence_summary(&self.i18n, index + 1, seq);
+                }
+                if (query.start as usize + results.len()) < response.count.max(0) as usize {
+                    println!("{}", self.i18n.t_args("shell-more-results", [("count", PAGE_SIZE.into())]));
+                }
+            }
+        }
+        let numbers = results.iter().map(|seq| seq.number).collect();
+        self.results = results;
+        self.query = Some(query);
+        Ok(numbers)
+    }
+
+    /// The sequences a command works on, and the rest of its argument: the A-numbers at
+    /// the start of the argument, else the piped ones, else the one shown last
+    fn targets<'a>(&self, argument: &'a str, piped: Option<Vec<i32>>) -> Result<(Vec<i32>, &'a str)> {
+        let mut numbers = Vec::new();
+        let mut rest = argument;
+        while let Some(word) = rest.split_whitespace().next() {
+            let Some(number) = parse_anumber(word) else {
+                break;
+            };
+            numbers.push(number);
+            rest = rest.trim_start()[word.len()..].trim_start();
+        }
+        if numbers.is_empty() {
+            numbers = match (piped, self.current) {
+                (Some(piped), _) => piped,
+                (None, Some(current)) => vec![current],
+                (None, None) => bail!(self.i18n.t("shell-which-sequence")),
+            };
+        }
+        Ok((numbers, rest))
+    }
+
+    async fn sequence(&self, number: i32) -> Result<Sequence> {
+        if let Ok(Some(sequence)) = self.cache.get_cached_sequence(number, CACHE_MAX_AGE_DAYS) {
+            return Ok(sequence);
+        }
+        let sequence = self
+            .client
+            .get_sequence(&format!("A{:06}", number))
+            .await?
+            .ok_or_else(|| anyhow!(self.i18n.t_args("cli-not-found", [("id", format!("A{:06}", number).into())])))?;
+        let _ = self.cache.cache_sequence(&sequence);
+        Ok(sequence)
+    }
+}
+
+/// Searches kept in the cache, oldest first, as shell lines that run them again
+fn history_lines(cache: &Cache) -> Vec<String> {
+    let mut history = cache.get_search_history(HISTORY_SIZE).unwrap_or_default();
+    history.sort_by_key(|(_, searched_at)| *searched_at);
+    history.into_iter().map(|(query, _)| format!("search {query}")).collect()
+}
+
+/// Name of a cached sequence, however old the copy
+fn cached_name(cache: &Cache, number: i32) -> String {
+    match cache.get_any_cached_sequence(number) {
+        Ok(Some(sequence)) => sequence.name,
+        _ => String::new(),
+    }
+}
+
+/// A-numbers and names to complete: the bookmarks, then the recently viewed sequences
+pub(crate) fn known_anumbers(cache: &Cache) -> Vec<(String, String)> {
+    let mut anumbers: Vec<(String, String)> = Vec::new();
+    for (number, _) in cache.get_bookmarks().unwrap_or_default() {
+        anumbers.push((format!("A{:06}", number), cached_name(cache, number)));
+    }
+    let recent = cache
+        .get_recently_viewed_with_details(COMPLETION_RECENT)
+        .unwrap_or_default();
+    for (number, name, _, _) in recent {
+        let anumber = format!("A{:06}", number);
+        if !anumbers.iter().any(|(known, _)| *known == anumber) {
+            anumbers.push((anumber, name));
+        }
+    }
+    anumbers
+}
+
+/// Whether the digits typed so far start `anumber`, with or without its leading zeros
+pub(crate) fn anumber_matches(digits: &str, anumber: &str) -> bool {
+    let known = anumber.trim_start_matches(['A', 'a']);
+    known.starts_with(digits) || (!digits.is_empty() && known.trim_start_matches('0').starts_with(digits))
+}
+
+/// Where two sequences agree, term by term at the same n
+#[derive(Debug, PartialEq)]
+struct TermsDiff {
+    /// n range present in both
+    common: Option<(i64, i64)>,
+    agreeing: usize,
+    /// n of the first term that differs, with both values
+    first_difference: Option<(i64, String, String)>,
+}
+
+fn diff_terms(a: &Sequence, b: &Sequence) -> TermsDiff {
+    let terms = |seq: &Sequence| {
+        let (start, _) = seq.parse_offset();
+        seq.data
+            .split(',')
+            .map(str::trim)
+            .filter(|term| !term.is_empty())
+            .enumerate()
+            .map(|(i, term)| (start as i64 + i as i64, term.to_string()))
+            .collect::<Vec<_>>()
+    };
+    let (a, b) = (terms(a), terms(b));
+    let mut diff = TermsDiff {
+        common: None,
+        agreeing: 0,
+        first_difference: None,
+    };
+    for (n, value) in &a {
+        let Some((_, other)) = b.iter().find(|(m, _)| m == n) else {
+            continue;
+        };
+        diff.common = Some(match diff.common {
+            Some((first, _)) => (first, *n),
+            None => (*n, *n),
+        });
+        if value == other {
+            diff.agreeing += 1;
+        } else if diff.first_difference.is_none() {
+            diff.first_difference = Some((*n, value.clone(), other.clone()));
+        }
+    }
+    diff
+}
+
+fn print_diff(i18n: &I18n, a: &Sequence, b: &Sequence) {
+    println!("{} {}", a.a_number().bright_cyan(), a.name);
+    println!("{} {}", b.a_number().bright_cyan(), b.name);
+    let diff = diff_terms(a, b);
+    let Some((first, last)) = diff.common else {
+        println!("{}", i18n.t("shell-diff-disjoint"));
+        return;
+    };
+    let compared = (last - first + 1) as usize;
+    match diff.first_difference {
+        None => {
+            let same = i18n.t_args(
+                "shell-diff-same",
+                [("count", compared.into()), ("first", first.into()), ("last", last.into())],
+            );
+            println!("{}", same.green());
+        }
+        Some((n, x, y)) => println!(
+            "{}",
+            i18n.t_args(
+                "shell-diff-different",
+                [
+                    ("agreeing", diff.agreeing.into()),
+                    ("count", compared.into()),
+                    ("first", first.into()),
+                    ("last", last.into()),
+                    ("n", n.into()),
+                    ("a", x.yellow().to_string().into()),
+                    ("b", y.yellow().to_string().into()),
+                ]
+            )
+        ),
+    }
+}
+
+/// `A45` or `a000045`; the letter is required, so that plain numbers are searched for
+fn parse_anumber(text: &str) -> Option<i32> {
+    let digits = text.strip_prefix(['A', 'a'])?;
+    if digits.is_empty() || digits.len() > 6 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok().filter(|number| *number > 0)
+}
+
+/// Tab completion: command names first on a line or after `|`, A-numbers elsewhere
+struct ShellHelper {
+    anumbers: Vec<(String, String)>,
+}
+
+impl Completer for ShellHelper {
+    type Candidate = Pair;
+
+    fn complete(&self, line: &str, pos: usize, _ctx: &Context<'_>) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let before = &line[..pos];
+        let start = before
+            .rfind(|c: char| c.is_whitespace() || c == '|')
+            .map_or(0, |index| index + 1);
+        let word = &before[start..];
+        let first_word = before[..start].rsplit('|').next().unwrap_or("").trim().is_empty();
+
+        let candidates = if first_word {
+            COMMANDS
+                .iter()
+                .filter(|command| command.starts_with(&word.to_ascii_lowercase()))
+                .map(|command| Pair {
+                    display: command.to_string(),
+                    replacement: format!("{} ", command),
+                })
+                .collect()
+        } else {
+            complete_anumber(word, &self.anumbers)
+        };
+        Ok((start, candidates))
+    }
+}
+
+/// Known A-numbers that `word` starts, written in full (`A45` or `a0000` finds A000045)
+fn complete_anumber(word: &str, anumbers: &[(String, String)]) -> Vec<Pair> {
+    let Some(digits) = word.strip_prefix(['A', 'a']) else {
+        return Vec::new();
+    };
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Vec::new();
+    }
+    anumbers
+        .iter()
+        .filter(|(anumber, _)| anumber_matches(digits, anumber))
+        .map(|(anumber, name)| Pair {
+            display: format!("{}  {}", anumber, name),
+            replacement: anumber.clone(),
+        })
+        .collect()
+}
+
+impl Hinter for ShellHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ShellHelper {}
+
+impl Validator for ShellHelper {}
+
+impl Helper for ShellHelper {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequence(number: i32, offset: &str, data: &str) -> Sequence {
+        serde_json::from_value(serde_json::json!({
+            "number": number,
+            "data": data,
+            "name": "",
+            "offset": offset,
+            "keyword": "",
+            "author": "",
+            "created": "1991-04-30T03:00:00-04:00",
+            "time": "2024-01-01T00:00:00-05:00",
+            "revision": 1,
+            "references": 0
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_anumber() {
+        assert_eq!(parse_anumber("A000045"), Some(45));
+        assert_eq!(parse_anumber("a45"), Some(45));
+        assert_eq!(parse_anumber("45"), None);
+        assert_eq!(parse_anumber("A0"), None);
+        assert_eq!(parse_anumber("Avogadro"), None);
+        assert_eq!(parse_anumber("A1234567"), None);
+    }
+
+    #[test]
+    fn test_diff_terms() {
+        // Fibonacci from n = 0, Lucas-like terms from n = 1
+        let a = sequence(45, "0,4", "0,1,1,2,3,5,8");
+        let b = sequence(1, "1,1", "1,1,2,4,8");
+        assert_eq!(
+            diff_terms(&a, &b),
+            TermsDiff {
+                common: Some((1, 5)),
+                agreeing: 3,
+                first_difference: Some((4, "3".to_string(), "4".to_string())),
+            }
+        );
+        assert_eq!(diff_terms(&a, &a).first_difference, None);
+        let c = sequence(2, "10,1", "1,2");
+        assert_eq!(diff_terms(&a, &c).common, None);
+    }
+
+    #[test]
+    fn test_complete_anumber() {
+        let known = vec![
+            ("A000045".to_string(), "Fibonacci numbers".to_string()),
+            ("A000040".to_string(), "The prime numbers".to_string()),
+            ("A001113".to_string(), "Decimal expansion of e".to_string()),
+        ];
+        let replacements = |word: &str| -> Vec<String> {
+            complete_anumber(word, &known)
+                .into_iter()
+                .map(|pair| pair.replacement)
+                .collect()
+        };
+        assert_eq!(replacements("A00004"), ["A000045", "A000040"]);
+        assert_eq!(replacements("a45"), ["A000045"]);
+        assert_eq!(replacements("A"), ["A000045", "A000040", "A001113"]);
+        assert!(replacements("fib").is_empty());
+        // `oeis fetch` completes digits without the letter too
+        assert!(anumber_matches("45", "A000045"));
+        assert!(anumber_matches("", "A000045"));
+        assert!(!anumber_matches("46", "A000045"));
+    }
+
+    #[test]
+    fn test_known_anumbers() {
+        let cache = Cache::in_memory().unwrap();
+        assert!(known_anumbers(&cache).is_empty());
+
+        let mut fibonacci = sequence(45, "0,4", "0,1,1,2,3");
+        fibonacci.name = "Fibonacci numbers".to_string();
+        let mut primes = sequence(40, "1,1", "2,3,5,7,11");
+        primes.name = "The prime numbers".to_string();
+        cache.cache_sequence(&fibonacci).unwrap();
+        cache.cache_sequence(&primes).unwrap();
+        cache.add_bookmark(45, None).unwrap();
+        cache.add_bookmark(27, None).unwrap();
+        cache.record_view(40).unwrap();
+        cache.record_view(45).unwrap();
+
+        let known = known_anumbers(&cache);
+        let anumbers: Vec<&str> = known.iter().map(|(anumber, _)| anumber.as_str()).collect();
+        assert_eq!(anumbers.len(), 3);
+        assert!(anumbers[..2].contains(&"A000045") && anumbers[..2].contains(&"A000027"));
+        assert_eq!(anumbers[2], "A000040");
+        let name = |anumber: &str| known.iter().find(|(a, _)| a == anumber).unwrap().1.clone();
+        assert_eq!(name("A000045"), "Fibonacci numbers");
+        assert_eq!(name("A000027"), "");
+        assert_eq!(name("A000040"), "The prime numbers");
+    }
+
+    #[test]
+    fn test_history_lines() {
+        let cache = Cache::in_memory().unwrap();
+        assert!(history_lines(&cache).is_empty());
+
+        cache.add_search_history("fibonacci").unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        cache.add_search_history("keyword:nice").unwrap();
+        assert_eq!(history_lines(&cache), ["search fibonacci", "search keyword:nice"]);
+    }
+}
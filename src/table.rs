@@ -0,0 +1,488 @@
+//! Two-dimensional layouts of `tabl`/`tabf` sequences.
+//!
+//! OEIS stores triangles and arrays as one flat list of terms. The shape is
+//! recovered from the keywords and the name: "read by antidiagonals" means a
+//! square array, a bound such as `0 <= k <= 2n` gives the row lengths of an
+//! irregular triangle, and plain `tabl` entries are regular triangles.
+
+use crate::api::{models::BFileEntry, Sequence};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Limb size used for the exact row sums
+const BASE: i64 = 1_000_000_000;
+/// Decimal digits per limb
+const BASE_DIGITS: usize = 9;
+
+/// How the flat list of terms is laid out in two dimensions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableShape {
+    /// Read by rows; row n holds `slope * n + intercept` terms
+    Triangle { slope: i64, intercept: i64 },
+    /// Square array read by antidiagonals, downwards (T(0,d) first) or upwards (T(d,0) first)
+    Array { upwards: bool },
+}
+
+impl TableShape {
+    /// Shapes offered when the user overrides detection (row n has n + 1 terms)
+    pub const ALL: [Self; 3] = [
+        Self::Triangle {
+            slope: 1,
+            intercept: 1,
+        },
+        Self::Array { upwards: false },
+        Self::Array { upwards: true },
+    ];
+
+    /// Work out the layout of a `tabl`/`tabf` entry, or None if it is not a table
+    /// or its row lengths cannot be read from the name.
+    pub fn detect(seq: &Sequence) -> Option<Self> {
+        let tabl = seq.has_keyword("tabl");
+        if !tabl && !seq.has_keyword("tabf") {
+            return None;
+        }
+
+        let name = seq.name.to_lowercase();
+        if name.contains("antidiagonal") {
+            let upwards = name.contains("upward") || name.contains("ascending");
+            return Some(Self::Array { upwards });
+        }
+
+        let (first_row, _) = seq.parse_offset();
+        if let Some((lo, slope, shift)) = column_bounds(&seq.name) {
+            return Some(Self::Triangle {
+                slope,
+                intercept: shift - lo + 1,
+            });
+        }
+
+        // Regular triangle whose first row has a single term
+        tabl.then_some(Self::Triangle {
+            slope: 1,
+            intercept: 1 - first_row as i64,
+        })
+    }
+
+    /// Index of the first column for an entry starting at row `first_row`
+    fn first_column(&self, seq: &Sequence, first_row: i64) -> i64 {
+        match self {
+            Self::Triangle { .. } => column_bounds(&seq.name)
+                .map(|(lo, _, _)| lo)
+                .unwrap_or(first_row),
+            Self::Array { .. } => first_row,
+        }
+    }
+
+    /// The shape after this one in [`Self::ALL`] (detected triangles count as the first)
+    pub fn next(&self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|shape| shape == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            Self::Triangle { .. } => "table-shape-triangle",
+            Self::Array { upwards: false } => "table-shape-array-down",
+            Self::Array { upwards: true } => "table-shape-array-up",
+        }
+    }
+
+    pub fn is_array(&self) -> bool {
+        matches!(self, Self::Array { .. })
+    }
+}
+
+/// Column bounds written as `lo <= k <= a*n + b` in the name, as (lo, a, b)
+fn column_bounds(name: &str) -> Option<(i64, i64, i64)> {
+    static BOUNDS: OnceLock<Regex> = OnceLock::new();
+    let re = BOUNDS.get_or_init(|| {
+        Regex::new(
+            r"(-?\d+)\s*(?:<=|≤)\s*k\s*(?:<=|≤)\s*(\d*)\s*\*?\s*n\b(?:\s*([+-])\s*(\d+))?\s*([/^*(]?)",
+        )
+        .expect("valid bounds pattern")
+    });
+
+    let caps = re.captures(name)?;
+    // n/2, n^2 and friends are not linear row lengths
+    if !caps[5].is_empty() {
+        return None;
+    }
+    let lo = caps[1].parse().ok()?;
+    let slope = match &caps[2] {
+        "" => 1,
+        digits => digits.parse().ok()?,
+    };
+    let shift = match (caps.get(3), caps.get(4)) {
+        (Some(sign), Some(value)) => {
+            let value: i64 = value.as_str().parse().ok()?;
+            if sign.as_str() == "-" {
+                -value
+            } else {
+                value
+            }
+        }
+        _ => 0,
+    };
+    (slope > 0).then_some((lo, slope, shift))
+}
+
+/// A sequence reshaped into rows and columns
+#[derive(Debug, Clone)]
+pub struct NumberTable {
+    pub shape: TableShape,
+    /// Terms by row; the last row (or antidiagonal) may be cut short
+    pub rows: Vec<Vec<String>>,
+    /// Index n of the first row
+    pub first_row: i64,
+    /// Index k of the first column
+    pub first_col: i64,
+    /// Exact sums of each row (triangles) or antidiagonal (arrays); None when incomplete
+    pub sums: Vec<Option<String>>,
+    /// Whether the terms came from the b-file rather than the entry's data
+    pub from_bfile: bool,
+}
+
+impl NumberTable {
+    /// Lay out the loaded b-file (when there is one) or the entry's terms as `shape`
+    pub fn new(seq: &Sequence, bfile: Option<&[BFileEntry]>, shape: TableShape) -> Self {
+        let (values, from_bfile) = match bfile.filter(|entries| !entries.is_empty()) {
+            Some(entries) => (
+                entries.iter().map(|entry| entry.value.clone()).collect(),
+                true,
+            ),
+            None => (seq.parse_data(), false),
+        };
+        let first_row = seq.parse_offset().0 as i64;
+
+        let (rows, sums) = match shape {
+            TableShape::Triangle { slope, intercept } => {
+                triangle_rows(values, first_row, slope, intercept)
+            }
+            TableShape::Array { upwards } => antidiagonal_rows(values, upwards),
+        };
+
+        Self {
+            shape,
+            rows,
+            first_row,
+            first_col: shape.first_column(seq, first_row),
+            sums,
+            from_bfile,
+        }
+    }
+
+    /// Number of terms laid out
+    pub fn len(&self) -> usize {
+        self.rows.iter().map(Vec::len).sum()
+    }
+
+    /// Length of the longest row
+    pub fn width(&self) -> usize {
+        self.rows.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&str> {
+        self.rows.get(row)?.get(col).map(String::as_str)
+    }
+
+    /// The table as CSV: a header of column indices, then `n,T(n,k),…` per row,
+    /// padded with empty fields so every line has the same number of columns.
+    pub fn to_csv(&self) -> String {
+        let width = self.width();
+        let mut csv = String::from("n");
+        for col in 0..width {
+            csv.push(',');
+            csv.push_str(&(self.first_col + col as i64).to_string());
+        }
+        csv.push('\n');
+
+        for (index, row) in self.rows.iter().enumerate() {
+            csv.push_str(&(self.first_row + index as i64).to_string());
+            for col in 0..width {
+                csv.push(',');
+                if let Some(value) = row.get(col) {
+                    csv.push_str(value);
+                }
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+fn triangle_rows(
+    values: Vec<String>,
+    first_row: i64,
+    slope: i64,
+    intercept: i64,
+) -> (Vec<Vec<String>>, Vec<Option<String>>) {
+    let mut rows = Vec::new();
+    let mut sums = Vec::new();
+    let mut terms = values.into_iter().peekable();
+    let mut n = first_row;
+
+    while terms.peek().is_some() {
+        let length = slope * n + intercept;
+        n += 1;
+        if length <= 0 {
+            // Rows before the triangle starts (e.g. 1 <= k <= n with n = 0)
+            rows.push(Vec::new());
+            sums.push(Some("0".to_string()));
+            continue;
+        }
+        let row: Vec<String> = terms.by_ref().take(length as usize).collect();
+        let complete = row.len() == length as usize;
+        sums.push(complete.then(|| sum_decimal(row.iter().map(String::as_str))).flatten());
+        rows.push(row);
+    }
+    (rows, sums)
+}
+
+fn antidiagonal_rows(values: Vec<String>, upwards: bool) -> (Vec<Vec<String>>, Vec<Option<String>>) {
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut sums = Vec::new();
+    let mut terms = values.into_iter().peekable();
+    let mut diagonal = 0;
+
+    while terms.peek().is_some() {
+        let entries: Vec<String> = terms.by_ref().take(diagonal + 1).collect();
+        let complete = entries.len() == diagonal + 1;
+        sums.push(complete.then(|| sum_decimal(entries.iter().map(String::as_str))).flatten());
+
+        rows.push(Vec::new());
+        for (position, value) in entries.into_iter().enumerate() {
+            // Each term lands in the next free column of its row
+            let row = if upwards { diagonal - position } else { position };
+            rows[row].push(value);
+        }
+        diagonal += 1;
+    }
+    (rows, sums)
+}
+
+/// Exact sum of decimal integers of any size, or None if one of them is not an integer
+pub fn sum_decimal<'a>(values: impl IntoIterator<Item = &'a str>) -> Option<String> {
+    // Little-endian base-10^9 limbs; only the top limb may go negative
+    let mut limbs: Vec<i64> = vec![0];
+
+    for value in values {
+        let value = value.trim();
+        let (negative, digits) = match value.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, value.strip_prefix('+').unwrap_or(value)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let bytes = digits.as_bytes();
+        let mut end = bytes.len();
+        let mut index = 0;
+        while end > 0 {
+            let start = end.saturating_sub(BASE_DIGITS);
+            let limb: i64 = std::str::from_utf8(&bytes[start..end]).ok()?.parse().ok()?;
+            if index == limbs.len() {
+                limbs.push(0);
+            }
+            limbs[index] += if negative { -limb } else { limb };
+            end = start;
+            index += 1;
+        }
+        normalize(&mut limbs);
+    }
+
+    let negative = limbs.last().is_some_and(|top| *top < 0);
+    if negative {
+        for limb in limbs.iter_mut() {
+            *limb = -*limb;
+        }
+        normalize(&mut limbs);
+    }
+    while limbs.len() > 1 && limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+
+    let mut text = if negative { "-".to_string() } else { String::new() };
+    let mut rest = limbs.iter().rev();
+    if let Some(top) = rest.next() {
+        text.push_str(&top.to_string());
+    }
+    for limb in rest {
+        text.push_str(&format!("{:0width$}", limb, width = BASE_DIGITS));
+    }
+    Some(text)
+}
+
+/// Carry between limbs so every limb but the top one lies in 0..BASE
+fn normalize(limbs: &mut Vec<i64>) {
+    let mut index = 0;
+    while index < limbs.len() {
+        let top = index + 1 == limbs.len();
+        if top && limbs[index].abs() < BASE {
+            break;
+        }
+        let carry = limbs[index].div_euclid(BASE);
+        limbs[index] = limbs[index].rem_euclid(BASE);
+        if top {
+            limbs.push(carry);
+        } else {
+            limbs[index + 1] += carry;
+        }
+        index += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequence(name: &str, keyword: &str, offset: &str, data: &str) -> Sequence {
+        serde_json::from_value(serde_json::json!({
+            "number": 7318,
+            "data": data,
+            "name": name,
+            "offset": offset,
+            "keyword": keyword,
+            "author": "N. J. A. Sloane",
+            "created": "1991-04-30T03:00:00-04:00",
+            "time": "2024-01-01T00:00:00-05:00",
+            "revision": 1,
+            "references": 0
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_detect_shape() {
+        let pascal = sequence(
+            "Pascal's triangle read by rows: C(n,k) = binomial(n,k) = n!/(k!*(n-k)!), 0 <= k <= n.",
+            "nonn,tabl,nice,easy,core",
+            "0,5",
+            "1,1,1,1,2,1",
+        );
+        assert_eq!(
+            TableShape::detect(&pascal),
+            Some(TableShape::Triangle {
+                slope: 1,
+                intercept: 1
+            })
+        );
+
+        let stirling = sequence("Triangle of Stirling numbers of the second kind.", "nonn,tabl", "1,5", "1");
+        assert_eq!(
+            TableShape::detect(&stirling),
+            Some(TableShape::Triangle {
+                slope: 1,
+                intercept: 0
+            })
+        );
+
+        let irregular = sequence(
+            "Triangle T(n,k), n >= 0, 0 <= k <= 2n, of trinomial coefficients.",
+            "nonn,tabf",
+            "0,3",
+            "1",
+        );
+        assert_eq!(
+            TableShape::detect(&irregular),
+            Some(TableShape::Triangle {
+                slope: 2,
+                intercept: 1
+            })
+        );
+
+        let array = sequence(
+            "Square array A(n,k) = n XOR k read by ascending antidiagonals.",
+            "nonn,tabl",
+            "0,5",
+            "0",
+        );
+        assert_eq!(TableShape::detect(&array), Some(TableShape::Array { upwards: true }));
+
+        let unknown = sequence("Irregular triangle of divisors.", "nonn,tabf", "1,2", "1");
+        assert_eq!(TableShape::detect(&unknown), None);
+        let flat = sequence("Fibonacci numbers.", "nonn,core", "0,4", "0,1,1");
+        assert_eq!(TableShape::detect(&flat), None);
+    }
+
+    #[test]
+    fn test_triangle_rows_and_sums() {
+        let pascal = sequence(
+            "Pascal's triangle read by rows, 0 <= k <= n.",
+            "nonn,tabl",
+            "0,5",
+            "1,1,1,1,2,1,1,3,3,1,1,4",
+        );
+        let shape = TableShape::detect(&pascal).unwrap();
+        let table = NumberTable::new(&pascal, None, shape);
+        assert_eq!(table.rows.len(), 5);
+        assert_eq!(table.rows[3], vec!["1", "3", "3", "1"]);
+        assert_eq!(table.rows[4], vec!["1", "4"]);
+        assert_eq!(
+            table.sums,
+            vec![
+                Some("1".to_string()),
+                Some("2".to_string()),
+                Some("4".to_string()),
+                Some("8".to_string()),
+                None
+            ]
+        );
+        assert_eq!(table.get(2, 1), Some("2"));
+        assert_eq!(table.len(), 12);
+
+        let csv = table.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "n,0,1,2,3");
+        assert_eq!(lines[2], "1,1,1,,");
+        assert_eq!(lines[5], "4,1,4,,");
+    }
+
+    #[test]
+    fn test_antidiagonal_rows() {
+        // T(n,k) = 10n + k, downwards: T(0,0); T(0,1), T(1,0); T(0,2), T(1,1), T(2,0); T(0,3)
+        let down = sequence(
+            "Square array read by antidiagonals.",
+            "nonn,tabl",
+            "0,1",
+            "0,1,10,2,11,20,3",
+        );
+        let table = NumberTable::new(&down, None, TableShape::detect(&down).unwrap());
+        assert_eq!(table.rows[0], vec!["0", "1", "2", "3"]);
+        assert_eq!(table.rows[1], vec!["10", "11"]);
+        assert_eq!(table.rows[2], vec!["20"]);
+        assert_eq!(table.sums[2], Some("33".to_string()));
+        assert_eq!(table.sums[3], None);
+
+        let up = sequence(
+            "Square array read by upward antidiagonals.",
+            "nonn,tabl",
+            "0,1",
+            "0,10,1,20,11,2",
+        );
+        let table = NumberTable::new(&up, None, TableShape::detect(&up).unwrap());
+        assert_eq!(table.rows[0], vec!["0", "1", "2"]);
+        assert_eq!(table.rows[1], vec!["10", "11"]);
+        assert_eq!(table.rows[2], vec!["20"]);
+    }
+
+    #[test]
+    fn test_sum_decimal() {
+        assert_eq!(sum_decimal(["1", "2", "3"]), Some("6".to_string()));
+        assert_eq!(sum_decimal(["-5", "3"]), Some("-2".to_string()));
+        assert_eq!(sum_decimal(["1", "-1"]), Some("0".to_string()));
+        assert_eq!(
+            sum_decimal(["999999999999999999999", "1"]),
+            Some("1000000000000000000000".to_string())
+        );
+        assert_eq!(
+            sum_decimal(["-1000000000000000000000", "1"]),
+            Some("-999999999999999999999".to_string())
+        );
+        assert_eq!(sum_decimal(["12", "x"]), None);
+        assert_eq!(sum_decimal([]), Some("0".to_string()));
+    }
+}
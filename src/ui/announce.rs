@@ -0,0 +1,206 @@
+use crate::app::{App, InputMode, Screen, SearchFocus, SettingsFocus};
+use crate::i18n::Language;
+use crate::ui::{animation::WelcomeAnimationMode, detail, palette};
+use crate::utils::keybindings::KeyContext;
+use fluent::FluentArgs;
+use ratatui::{
+    layout::{Position, Rect},
+    text::Span,
+    widgets::{Clear, Paragraph},
+    Frame,
+};
+
+/// Bottom line of the accessibility mode: what has focus, in words, after the latest
+/// status message. The terminal cursor is parked at its start so that screen readers,
+/// which follow the cursor, read it out when it changes.
+pub fn render(f: &mut Frame, app: &App) {
+    let area = f.area();
+    if area.height == 0 {
+        return;
+    }
+    let rect = Rect {
+        y: area.y + area.height - 1,
+        height: 1,
+        ..area
+    };
+    f.render_widget(Clear, rect);
+    f.render_widget(
+        Paragraph::new(Span::styled(announcement(app), app.theme().highlight_bg())),
+        rect,
+    );
+
+    // Typing in the search box keeps the cursor there
+    let typing = app.current_screen == Screen::Search
+        && app.input_mode == InputMode::Editing
+        && app.command_palette.is_none();
+    if !typing {
+        f.set_cursor_position(Position::new(rect.x, rect.y));
+    }
+}
+
+/// The status message, if there is one, then a description of the focus
+pub fn announcement(app: &App) -> String {
+    let focus = focus(app);
+    match &app.error_message {
+        Some(message) => format!("{}. {}", message.trim_end_matches('.'), focus),
+        None => focus,
+    }
+}
+
+fn focus(app: &App) -> String {
+    let t = |key: &str| app.i18n.t(key);
+    if let Some(command_palette) = &app.command_palette {
+        let rows = palette::rows(app, command_palette);
+        return match rows.get(command_palette.selected()) {
+            Some((label, _)) => {
+                let mut args = position(command_palette.selected(), rows.len());
+                args.set("label", label.clone());
+                app.i18n.t_with_args("announce-palette", Some(&args))
+            }
+            None => format!("{}: {}", t("palette-title"), t("palette-no-matches")),
+        };
+    }
+    if app.help_modal_visible || (app.current_screen == Screen::Detail && app.detail_help_visible) {
+        return t("help-title");
+    }
+    if app.about_modal_visible {
+        return t("about-title");
+    }
+
+    match app.current_screen {
+        Screen::Search if app.show_welcome_modal => t("welcome-title"),
+        Screen::Search => search_focus(app),
+        Screen::Detail => detail_focus(app),
+        Screen::Graph => match &app.current_sequence {
+            Some(seq) => {
+                let mut args = FluentArgs::new();
+                args.set("graph", t(app.graph_type.label_key()));
+                args.set("anumber", seq.a_number());
+                app.i18n.t_with_args("announce-graph", Some(&args))
+            }
+            None => t(KeyContext::Graph.label_key()),
+        },
+        Screen::Table => t(KeyContext::Table.label_key()),
+        Screen::Webcam => t(KeyContext::Webcam.label_key()),
+        Screen::Settings => settings_focus(app),
+    }
+}
+
+fn search_focus(app: &App) -> String {
+    if app.searching {
+        return app.i18n.t("announce-searching");
+    }
+    if app.input_mode == InputMode::Editing || app.search_focus == SearchFocus::Input {
+        if app.search_input.is_empty() {
+            return app.i18n.t("announce-search-empty");
+        }
+        let mut args = FluentArgs::new();
+        args.set("query", app.search_input.clone());
+        return app.i18n.t_with_args("announce-search-input", Some(&args));
+    }
+
+    let (key, index, count, entry) = match app.search_focus {
+        SearchFocus::History => (
+            "announce-history",
+            app.history_selected,
+            app.recent_sequences.len(),
+            app.recent_sequences
+                .get(app.history_selected)
+                .map(|(number, name, _, _)| (*number, name.clone())),
+        ),
+        SearchFocus::Bookmarks => (
+            "announce-bookmark",
+            app.bookmarks_selected,
+            app.bookmarks.len(),
+            app.bookmarks
+                .get(app.bookmarks_selected)
+                .map(|(number, notes)| (*number, notes.clone().unwrap_or_default())),
+        ),
+        _ => (
+            "announce-result",
+            app.selected_result,
+            app.search_results.len(),
+            app.search_results
+                .get(app.selected_result)
+                .map(|seq| (seq.number, seq.name.clone())),
+        ),
+    };
+    let Some((number, name)) = entry else {
+        return app.i18n.t("announce-no-results");
+    };
+    let mut args = position(index, count);
+    args.set("anumber", format!("A{:06}", number));
+    args.set("name", name);
+    app.i18n.t_with_args(key, Some(&args))
+}
+
+fn detail_focus(app: &App) -> String {
+    let Some(seq) = &app.current_sequence else {
+        return app.i18n.t(KeyContext::Detail.label_key());
+    };
+    let mut args = FluentArgs::new();
+    args.set("anumber", seq.a_number());
+    args.set("name", seq.name.clone());
+    args.set(
+        "tab",
+        app.i18n.t(detail::TAB_KEYS.get(app.detail_tab).copied().unwrap_or("detail-tab-overview")),
+    );
+    let mut text = app.i18n.t_with_args("announce-sequence", Some(&args));
+
+    if let Some(reference) = app
+        .detail_reference_index
+        .and_then(|index| app.detail_references.get(index).map(|anumber| (index, anumber)))
+    {
+        let (index, anumber) = reference;
+        let mut args = position(index, app.detail_references.len());
+        args.set("anumber", anumber.clone());
+        text.push_str(". ");
+        text.push_str(&app.i18n.t_with_args("announce-reference", Some(&args)));
+    }
+    text
+}
+
+fn settings_focus(app: &App) -> String {
+    let (section, item, current) = match app.settings_focus {
+        SettingsFocus::Language => {
+            let languages = Language::all();
+            let language = languages.get(app.settings_selected_language);
+            (
+                app.i18n.t("settings-language"),
+                language.map(|language| language.name().to_string()),
+                language.is_some_and(|language| *language == app.i18n.get_current_language()),
+            )
+        }
+        SettingsFocus::Theme => (
+            app.i18n.t("settings-block-themes"),
+            app.themes
+                .get(app.settings_selected_theme)
+                .map(|theme| theme.name().to_string()),
+            app.settings_selected_theme == app.active_theme,
+        ),
+        SettingsFocus::Animation => {
+            let mode = WelcomeAnimationMode::modes().get(app.settings_selected_animation);
+            (
+                app.i18n.t("settings-block-animation"),
+                mode.map(|mode| mode.label().to_string()),
+                mode.is_some_and(|mode| *mode == app.welcome_animation_mode),
+            )
+        }
+    };
+    let mut item = item.unwrap_or_default();
+    if current {
+        item = format!("{} {}", item, app.i18n.t("announce-current"));
+    }
+    let mut args = FluentArgs::new();
+    args.set("section", section);
+    args.set("item", item);
+    app.i18n.t_with_args("announce-settings", Some(&args))
+}
+
+/// `position` and `count` arguments for "3 of 10"
+fn position(index: usize, count: usize) -> FluentArgs<'static> {
+    let mut args = FluentArgs::new();
+    args.set("position", index + 1);
+    args.set("count", count);
+    args
+}
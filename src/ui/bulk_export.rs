@@ -0,0 +1,149 @@
+use crate::app::{App, BulkExportDialog, BulkExportField, BulkLayout};
+use crate::utils::paths;
+use fluent::FluentArgs;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn render_modal(f: &mut Frame, app: &App) {
+    let Some(dialog) = app.bulk_export.as_ref() else {
+        return;
+    };
+
+    let area = centered_rect(64, 14, f.area());
+    f.render_widget(Clear, area);
+
+    let paragraph = Paragraph::new(build_dialog_lines(app, dialog))
+        .wrap(Wrap { trim: false })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(app.theme().selected_border())
+                .title(format!(" {} ", app.i18n.t("bulk-export-title")))
+                .title_alignment(Alignment::Center),
+        );
+
+    f.render_widget(paragraph, area);
+}
+
+fn build_dialog_lines(app: &App, dialog: &BulkExportDialog) -> Vec<Line<'static>> {
+    let theme = app.theme();
+    let source = app.i18n.t(dialog.source.label_key());
+    let layout = app.i18n.t(dialog.layout.label_key());
+
+    let mut lines = vec![
+        Line::from(""),
+        choice_line(app, dialog, BulkExportField::Source, "bulk-export-source", source),
+        choice_line(
+            app,
+            dialog,
+            BulkExportField::Format,
+            "bulk-export-format",
+            dialog.format.as_str().to_string(),
+        ),
+        choice_line(app, dialog, BulkExportField::Layout, "bulk-export-layout", layout),
+    ];
+
+    let editing = dialog.field == BulkExportField::Directory;
+    let mut directory = vec![
+        field_label(app, dialog, BulkExportField::Directory, "bulk-export-directory"),
+        Span::styled(dialog.directory.clone(), theme.text()),
+    ];
+    if editing {
+        directory.push(Span::styled("█", theme.accent()));
+    }
+    lines.push(Line::from(directory));
+    lines.push(Line::from(""));
+
+    // Where the export will end up, or why it cannot run
+    if dialog.layout == BulkLayout::Combined && !dialog.format.supports_combined() {
+        let mut args = FluentArgs::new();
+        args.set("format", dialog.format.as_str().to_string());
+        lines.push(Line::from(Span::styled(
+            format!(
+                "  {}",
+                app.i18n
+                    .t_with_args("bulk-export-combined-unsupported", Some(&args))
+            ),
+            theme.warning(),
+        )));
+    } else {
+        let dir = paths::expand_home(&dialog.directory);
+        let stem = dialog.source.file_stem();
+        let target = match dialog.layout {
+            BulkLayout::Combined => dir.join(format!("{}.{}", stem, dialog.format.extension())),
+            BulkLayout::PerSequence => dir.join(stem).join(format!("A######.{}", dialog.format.extension())),
+        };
+        lines.push(Line::from(vec![
+            Span::styled("  → ", theme.accent()),
+            Span::styled(target.display().to_string(), theme.muted()),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("  ↑↓", theme.accent_bold()),
+        Span::styled(format!(" {} | ", app.i18n.t("bulk-export-help-field")), theme.muted()),
+        Span::styled("←→", theme.accent_bold()),
+        Span::styled(format!(" {} | ", app.i18n.t("bulk-export-help-change")), theme.muted()),
+        Span::styled("Enter", theme.accent_bold()),
+        Span::styled(format!(" {} | ", app.i18n.t("bulk-export-help-export")), theme.muted()),
+        Span::styled("Esc", theme.accent_bold()),
+        Span::styled(format!(" {}", app.i18n.t("bulk-export-help-cancel")), theme.muted()),
+    ]));
+
+    lines
+}
+
+fn field_label(
+    app: &App,
+    dialog: &BulkExportDialog,
+    field: BulkExportField,
+    key: &str,
+) -> Span<'static> {
+    let theme = app.theme();
+    let focused = dialog.field == field;
+    let marker = if focused { "▶ " } else { "  " };
+    let style = if focused { theme.highlight_bold() } else { theme.accent_bold() };
+    Span::styled(format!("{}{:<12}", marker, format!("{}:", app.i18n.t(key))), style)
+}
+
+fn choice_line(
+    app: &App,
+    dialog: &BulkExportDialog,
+    field: BulkExportField,
+    key: &str,
+    value: String,
+) -> Line<'static> {
+    let theme = app.theme();
+    let arrows = if dialog.field == field { theme.accent() } else { theme.muted() };
+    Line::from(vec![
+        field_label(app, dialog, field, key),
+        Span::styled("◀ ", arrows),
+        Span::styled(value, theme.text()),
+        Span::styled(" ▶", arrows),
+    ])
+}
+
+fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
+    let horizontal = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(area);
+
+    let height = height.min(area.height);
+    let top = area.height.saturating_sub(height) / 2;
+    Rect {
+        x: horizontal[1].x,
+        y: area.y + top,
+        width: horizontal[1].width,
+        height,
+    }
+}
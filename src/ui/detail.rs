@@ -1,13 +1,39 @@
-use crate::app::App;
-use crate::utils::highlight_anumbers_line;
+use crate::app::{App, VerifyStatus};
+use crate::constant::Constant;
+use crate::table::{NumberTable, TableShape};
+use crate::verify::VerifyOutcome;
+use crate::utils::{
+    highlight_anumbers_line,
+    keybindings::{Action, KeyContext},
+    ReferenceFocus,
+};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Modifier, Style},
+    style::Modifier,
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Tabs, Wrap},
     Frame,
 };
 
+/// Rows and columns of a triangle shown in the Overview (the table view has the rest)
+const TABLE_PREVIEW_ROWS: usize = 8;
+const TABLE_PREVIEW_COLUMNS: usize = 12;
+/// Convergents listed for a continued fraction, and the decimal places given for each
+const CONVERGENTS_SHOWN: usize = 8;
+const CONVERGENT_PLACES: usize = 12;
+
+/// Titles (i18n keys) of the detail tabs, in order
+pub const TAB_KEYS: [&str; 8] = [
+    "detail-tab-overview",
+    "detail-tab-formulas",
+    "detail-tab-code",
+    "detail-tab-references",
+    "detail-tab-crossrefs",
+    "detail-tab-metadata",
+    "detail-tab-graph",
+    "detail-tab-export",
+];
+
 pub fn render(f: &mut Frame, app: &mut App) {
     let theme = app.theme().clone();
     let chunks = Layout::default()
@@ -23,6 +49,7 @@ pub fn render(f: &mut Frame, app: &mut App) {
 
     app.detail_tabs_area = None;
     app.detail_content_area = None;
+    app.graph_area = None;
 
     if let Some(seq) = app.current_sequence.clone() {
         render_title(f, chunks[0], app, &seq, &theme);
@@ -92,16 +119,7 @@ fn render_tabs(
     app: &mut App,
     theme: &crate::ui::Theme,
 ) {
-    let tab_titles = vec![
-        app.i18n.t("detail-tab-overview"),
-        app.i18n.t("detail-tab-formulas"),
-        app.i18n.t("detail-tab-code"),
-        app.i18n.t("detail-tab-references"),
-        app.i18n.t("detail-tab-crossrefs"),
-        app.i18n.t("detail-tab-metadata"),
-        app.i18n.t("detail-tab-graph"),
-        app.i18n.t("detail-tab-export"),
-    ];
+    let tab_titles: Vec<String> = TAB_KEYS.iter().map(|key| app.i18n.t(key)).collect();
 
     let tabs = Tabs::new(tab_titles)
         .select(app.detail_tab)
@@ -125,16 +143,26 @@ fn render_content(
     theme: &crate::ui::Theme,
 ) {
     app.detail_content_area = Some(area);
-    let selected = app.detail_reference_index;
+    let focus = ReferenceFocus {
+        selected: app.detail_reference_index,
+        markers: app.accessible,
+    };
     let mut references = Vec::new();
 
     let content = match app.detail_tab {
-        0 => render_overview_content(app, seq, selected, &mut references, theme),
-        1 => render_formulas_content(seq, selected, &mut references, theme),
-        2 => render_code_content(seq, selected, &mut references, theme),
-        3 => render_references_content(seq, selected, &mut references, theme),
-        4 => render_crossrefs_content(seq, selected, &mut references, theme),
-        5 => render_metadata_content(seq, selected, &mut references, theme),
+        0 => render_overview_content(app, seq, focus, &mut references, theme),
+        1 => render_formulas_content(seq, focus, &mut references, theme),
+        2 => render_code_content(
+            seq,
+            focus,
+            Some(app.code_snippet_index),
+            code_verification_line(app, seq, theme),
+            &mut references,
+            theme,
+        ),
+        3 => render_references_content(seq, focus, &mut references, theme),
+        4 => render_crossrefs_content(seq, focus, &mut references, theme),
+        5 => render_metadata_content(seq, focus, &mut references, theme),
         _ => vec![Line::from("Invalid tab")],
     };
 
@@ -157,7 +185,7 @@ fn render_content(
 pub fn render_overview_content(
     app: &App,
     seq: &crate::api::Sequence,
-    selected: Option<usize>,
+    focus: ReferenceFocus,
     references: &mut Vec<String>,
     theme: &crate::ui::Theme,
 ) -> Vec<Line<'static>> {
@@ -170,6 +198,14 @@ pub fn render_overview_content(
     )]));
     lines.push(Line::from(""));
 
+    if let Some(shape) = TableShape::detect(seq) {
+        lines.extend(table_preview_lines(app, seq, shape, theme));
+    }
+
+    if let Some(constant) = Constant::from_sequence(seq, app.bfile_data.as_deref()) {
+        lines.extend(constant_lines(app, &constant, theme));
+    }
+
     if !seq.comment.is_empty() {
         lines.push(Line::from(Span::styled(
             "Comments:",
@@ -177,7 +213,7 @@ pub fn render_overview_content(
         )));
         for comment in &seq.comment {
             lines.push(highlight_anumbers_line(
-                comment, references, selected, theme,
+                comment, references, focus, theme,
             ));
         }
         lines.push(Line::from(""));
@@ -190,7 +226,7 @@ pub fn render_overview_content(
         )));
         for example in &seq.example {
             lines.push(highlight_anumbers_line(
-                example, references, selected, theme,
+                example, references, focus, theme,
             ));
         }
         lines.push(Line::from(""));
@@ -255,9 +291,102 @@ pub fn render_overview_content(
     lines
 }
 
+/// First rows of a `tabl`/`tabf` entry laid out as a table, with right-aligned columns
+fn table_preview_lines(
+    app: &App,
+    seq: &crate::api::Sequence,
+    shape: TableShape,
+    theme: &crate::ui::Theme,
+) -> Vec<Line<'static>> {
+    let table = NumberTable::new(seq, None, shape);
+    let rows = &table.rows[..table.rows.len().min(TABLE_PREVIEW_ROWS)];
+    let columns = table.width().min(TABLE_PREVIEW_COLUMNS);
+    let widths: Vec<usize> = (0..columns)
+        .map(|c| rows.iter().filter_map(|r| r.get(c)).map(String::len).max().unwrap_or(0))
+        .collect();
+    let label_width = (table.first_row + rows.len().saturating_sub(1) as i64)
+        .to_string()
+        .len();
+
+    let mut args = fluent::FluentArgs::new();
+    args.set("shape", app.i18n.t(shape.label_key()).to_string());
+    let mut lines = vec![Line::from(Span::styled(
+        app.i18n.t_with_args("table-preview", Some(&args)).to_string(),
+        theme.highlight_bold(),
+    ))];
+    for (index, row) in rows.iter().enumerate() {
+        let mut text = row
+            .iter()
+            .zip(&widths)
+            .map(|(value, width)| format!("{:>width$}", value, width = *width))
+            .collect::<Vec<_>>()
+            .join(" ");
+        if row.len() > columns {
+            text.push_str(" …");
+        }
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:>width$}: ", table.first_row + index as i64, width = label_width),
+                theme.muted(),
+            ),
+            Span::styled(text, theme.text()),
+        ]));
+    }
+    lines.push(Line::from(Span::styled(
+        app.i18n.t("table-preview-hint").to_string(),
+        theme.muted(),
+    )));
+    lines.push(Line::from(""));
+    lines
+}
+
+/// The value of a `cons`/`cofr` constant and, for continued fractions, its first convergents
+fn constant_lines(app: &App, constant: &Constant, theme: &crate::ui::Theme) -> Vec<Line<'static>> {
+    let max = constant.max_places();
+    let mut args = fluent::FluentArgs::new();
+    args.set("places", app.constant_places.min(max));
+    let mut lines = vec![
+        Line::from(Span::styled(
+            app.i18n.t_with_args("constant-value", Some(&args)).to_string(),
+            theme.highlight_bold(),
+        )),
+        Line::from(Span::styled(
+            constant.to_decimal(app.constant_places),
+            theme.success(),
+        )),
+    ];
+
+    let convergents = constant.convergents();
+    if !convergents.is_empty() {
+        lines.push(Line::from(Span::styled(
+            app.i18n.t("constant-convergents").to_string(),
+            theme.highlight_bold(),
+        )));
+        for convergent in convergents.iter().take(CONVERGENTS_SHOWN) {
+            let mut spans = vec![Span::styled(convergent.fraction(), theme.text())];
+            if convergent.denominator != 1 {
+                spans.push(Span::styled(
+                    format!(" ≈ {}", convergent.to_decimal(CONVERGENT_PLACES)),
+                    theme.muted(),
+                ));
+            }
+            lines.push(Line::from(spans));
+        }
+    }
+
+    let mut args = fluent::FluentArgs::new();
+    args.set("max", max);
+    lines.push(Line::from(Span::styled(
+        app.i18n.t_with_args("constant-hint", Some(&args)).to_string(),
+        theme.muted(),
+    )));
+    lines.push(Line::from(""));
+    lines
+}
+
 pub fn render_formulas_content(
     seq: &crate::api::Sequence,
-    selected: Option<usize>,
+    focus: ReferenceFocus,
     references: &mut Vec<String>,
     theme: &crate::ui::Theme,
 ) -> Vec<Line<'static>> {
@@ -271,7 +400,7 @@ pub fn render_formulas_content(
     } else {
         for formula in &seq.formula {
             lines.push(highlight_anumbers_line(
-                formula, references, selected, theme,
+                formula, references, focus, theme,
             ));
             lines.push(Line::from(""));
         }
@@ -282,41 +411,38 @@ pub fn render_formulas_content(
 
 pub fn render_code_content(
     seq: &crate::api::Sequence,
-    selected: Option<usize>,
+    focus: ReferenceFocus,
+    selected_snippet: Option<usize>,
+    snippet_status: Option<Line<'static>>,
     references: &mut Vec<String>,
     theme: &crate::ui::Theme,
 ) -> Vec<Line<'static>> {
     let mut lines = vec![];
+    let snippets = seq.code_snippets();
+    let mut snippet_status = snippet_status;
+
+    for (i, snippet) in snippets.iter().enumerate() {
+        let is_selected = selected_snippet == Some(i);
+        let (prefix, style) = if is_selected {
+            ("▶ ", theme.highlight_bold())
+        } else if selected_snippet.is_some() {
+            ("  ", theme.success().add_modifier(Modifier::BOLD))
+        } else {
+            ("", theme.success().add_modifier(Modifier::BOLD))
+        };
 
-    if !seq.maple.is_empty() {
         lines.push(Line::from(Span::styled(
-            "Maple:",
-            theme.success().add_modifier(Modifier::BOLD),
+            format!("{}{}:", prefix, snippet.language),
+            style,
         )));
-        for code in &seq.maple {
-            lines.push(highlight_anumbers_line(code, references, selected, theme));
+        if is_selected {
+            lines.extend(snippet_status.take());
         }
-        lines.push(Line::from(""));
-    }
-
-    if !seq.mathematica.is_empty() {
-        lines.push(Line::from(Span::styled(
-            "Mathematica:",
-            theme.success().add_modifier(Modifier::BOLD),
-        )));
-        for code in &seq.mathematica {
-            lines.push(highlight_anumbers_line(code, references, selected, theme));
+        for code in snippet.code.lines() {
+            lines.push(highlight_anumbers_line(code, references, focus, theme));
         }
-        lines.push(Line::from(""));
-    }
-
-    if !seq.program.is_empty() {
-        lines.push(Line::from(Span::styled(
-            "Other Programs:",
-            theme.success().add_modifier(Modifier::BOLD),
-        )));
-        for code in &seq.program {
-            lines.push(highlight_anumbers_line(code, references, selected, theme));
+        if i + 1 < snippets.len() {
+            lines.push(Line::from(""));
         }
     }
 
@@ -330,9 +456,83 @@ pub fn render_code_content(
     lines
 }
 
+/// Status line for the selected program's local verification, if it has one
+fn code_verification_line(
+    app: &App,
+    seq: &crate::api::Sequence,
+    theme: &crate::ui::Theme,
+) -> Option<Line<'static>> {
+    use fluent::FluentArgs;
+
+    let verification = app.code_verification.as_ref()?;
+    if verification.number != seq.number || verification.snippet_index != app.code_snippet_index {
+        return None;
+    }
+
+    let (text, style) = match &verification.status {
+        VerifyStatus::Running => (
+            format!("{} {}", app.get_spinner_char(), app.i18n.t("verify-running")),
+            theme.highlight(),
+        ),
+        VerifyStatus::Failed(message) => {
+            let mut args = FluentArgs::new();
+            args.set("message", message.clone());
+            (app.i18n.t_with_args("verify-failed", Some(&args)), theme.danger())
+        }
+        VerifyStatus::Finished(report) => {
+            let mut args = FluentArgs::new();
+            args.set("interpreter", report.interpreter.label());
+            args.set("seconds", format!("{:.2}", report.elapsed.as_secs_f64()));
+            let (mut text, style) = match &report.outcome {
+                VerifyOutcome::Match { checked } if report.timed_out => {
+                    args.set("count", *checked);
+                    (app.i18n.t_with_args("verify-match-timed-out", Some(&args)), theme.warning())
+                }
+                VerifyOutcome::Match { checked } => {
+                    args.set("count", *checked);
+                    (app.i18n.t_with_args("verify-match", Some(&args)), theme.success())
+                }
+                VerifyOutcome::Partial { checked, expected } => {
+                    args.set("count", *checked);
+                    args.set("expected", *expected);
+                    (app.i18n.t_with_args("verify-partial", Some(&args)), theme.warning())
+                }
+                VerifyOutcome::Mismatch {
+                    index,
+                    expected,
+                    actual,
+                    matched,
+                } => {
+                    args.set("index", *index);
+                    args.set("expected", expected.clone());
+                    args.set("actual", actual.clone());
+                    args.set("count", *matched);
+                    (app.i18n.t_with_args("verify-mismatch", Some(&args)), theme.danger())
+                }
+                VerifyOutcome::NoOutput => {
+                    let mut text = app.i18n.t_with_args("verify-no-output", Some(&args));
+                    if let Some(error) = &report.error {
+                        text.push_str(&format!(": {}", error));
+                    }
+                    (text, theme.warning())
+                }
+            };
+            if report.timed_out {
+                text.push_str(&format!(" {}", app.i18n.t("verify-timed-out")));
+            }
+            (text, style)
+        }
+    };
+
+    Some(Line::from(Span::styled(
+        format!("  {}", text),
+        style.add_modifier(Modifier::BOLD),
+    )))
+}
+
 pub fn render_references_content(
     seq: &crate::api::Sequence,
-    selected: Option<usize>,
+    focus: ReferenceFocus,
     references: &mut Vec<String>,
     theme: &crate::ui::Theme,
 ) -> Vec<Line<'static>> {
@@ -341,7 +541,7 @@ pub fn render_references_content(
     if !seq.reference.is_empty() {
         for reference in &seq.reference {
             lines.push(highlight_anumbers_line(
-                reference, references, selected, theme,
+                reference, references, focus, theme,
             ));
             lines.push(Line::from(""));
         }
@@ -350,7 +550,7 @@ pub fn render_references_content(
     if !seq.link.is_empty() {
         lines.push(Line::from(Span::styled("Links:", theme.accent_bold())));
         for link in &seq.link {
-            lines.push(highlight_anumbers_line(link, references, selected, theme));
+            lines.push(highlight_anumbers_line(link, references, focus, theme));
         }
     }
 
@@ -366,7 +566,7 @@ pub fn render_references_content(
 
 pub fn render_crossrefs_content(
     seq: &crate::api::Sequence,
-    selected: Option<usize>,
+    focus: ReferenceFocus,
     references: &mut Vec<String>,
     theme: &crate::ui::Theme,
 ) -> Vec<Line<'static>> {
@@ -379,7 +579,7 @@ pub fn render_crossrefs_content(
         )]));
     } else {
         for xref in &seq.xref {
-            lines.push(highlight_anumbers_line(xref, references, selected, theme));
+            lines.push(highlight_anumbers_line(xref, references, focus, theme));
             lines.push(Line::from(""));
         }
     }
@@ -389,7 +589,7 @@ pub fn render_crossrefs_content(
 
 pub fn render_metadata_content(
     seq: &crate::api::Sequence,
-    selected: Option<usize>,
+    focus: ReferenceFocus,
     references: &mut Vec<String>,
     theme: &crate::ui::Theme,
 ) -> Vec<Line<'static>> {
@@ -408,7 +608,7 @@ pub fn render_metadata_content(
         Span::styled(seq.offset.clone(), theme.text()),
     ]));
 
-    let keyword_line = highlight_anumbers_line(&seq.keyword, references, selected, theme);
+    let keyword_line = highlight_anumbers_line(&seq.keyword, references, focus, theme);
     let mut keyword_spans = vec![Span::styled("Keywords: ", theme.highlight())];
     keyword_spans.extend(keyword_line.spans);
     lines.push(Line::from(keyword_spans));
@@ -440,24 +640,74 @@ pub fn render_metadata_content(
 fn render_help(f: &mut Frame, area: ratatui::layout::Rect, app: &crate::app::App) {
     let theme = app.theme();
 
+    let keys = app.keybindings();
+    let back = Span::styled(
+        keys.hint(KeyContext::Detail, &[Action::Back]),
+        theme.danger().add_modifier(Modifier::BOLD),
+    );
+
     // Show export-specific help when on Export tab
     let help_text = if app.detail_tab == 7 {
-        vec![Line::from(app.i18n.t("export-help").to_string())]
+        let quick: Vec<Action> = (1..=9).map(Action::Pick).collect();
+        vec![Line::from(vec![
+            Span::styled(
+                keys.hint(KeyContext::Export, &[Action::Up, Action::Down]),
+                theme.accent_bold(),
+            ),
+            Span::raw(format!(" {} | ", app.i18n.t("export-help-select"))),
+            Span::styled(keys.hint(KeyContext::Export, &quick), theme.accent_bold()),
+            Span::raw(format!(" {} | ", app.i18n.t("export-help-quick"))),
+            Span::styled(keys.hint(KeyContext::Export, &[Action::Confirm]), theme.accent_bold()),
+            Span::raw(format!(" {} | ", app.i18n.t("export-help-clipboard"))),
+            Span::styled(keys.hint(KeyContext::Export, &[Action::Save]), theme.accent_bold()),
+            Span::raw(format!(" {} | ", app.i18n.t("export-help-save"))),
+            back,
+            Span::raw(format!(" {}", app.i18n.t("bulk-export-help-cancel"))),
+        ])]
+    } else if app.detail_tab == 2 {
+        vec![Line::from(vec![
+            Span::styled(
+                keys.hint(KeyContext::Code, &[Action::PreviousSnippet, Action::NextSnippet]),
+                theme.accent_bold(),
+            ),
+            Span::raw(format!(" {} | ", app.i18n.t("code-help-select"))),
+            Span::styled(keys.hint(KeyContext::Code, &[Action::Copy]), theme.accent_bold()),
+            Span::raw(format!(" {} | ", app.i18n.t("code-help-copy"))),
+            Span::styled(keys.hint(KeyContext::Code, &[Action::Save]), theme.accent_bold()),
+            Span::raw(format!(" {} | ", app.i18n.t("code-help-save"))),
+            Span::styled(keys.hint(KeyContext::Code, &[Action::Verify]), theme.accent_bold()),
+            Span::raw(format!(" {} | ", app.i18n.t("code-help-verify"))),
+            Span::styled(
+                keys.hint(KeyContext::Detail, &[Action::Up, Action::Down]),
+                theme.accent_bold(),
+            ),
+            Span::raw(format!(" {} | ", app.i18n.t("detail-help-scroll"))),
+            Span::styled(keys.hint(KeyContext::Detail, &[Action::NextTab]), theme.accent_bold()),
+            Span::raw(format!(" {} | ", app.i18n.t("detail-help-switch-tab"))),
+            back,
+            Span::raw(format!(" {}", app.i18n.t("common-back"))),
+        ])]
     } else {
         vec![Line::from(vec![
-            Span::styled("Tab", theme.accent_bold()),
+            Span::styled(keys.hint(KeyContext::Detail, &[Action::NextTab]), theme.accent_bold()),
             Span::raw(format!(" {} | ", app.i18n.t("detail-help-switch-tab"))),
-            Span::styled("↑↓", theme.accent_bold()),
+            Span::styled(
+                keys.hint(KeyContext::Detail, &[Action::Up, Action::Down]),
+                theme.accent_bold(),
+            ),
             Span::raw(format!(" {} | ", app.i18n.t("detail-help-scroll"))),
-            Span::styled("g", theme.accent_bold()),
+            Span::styled(keys.hint(KeyContext::Detail, &[Action::OpenGraph]), theme.accent_bold()),
             Span::raw(format!(" {} | ", app.i18n.t("detail-help-graph"))),
-            Span::styled("e", theme.accent_bold()),
+            Span::styled(keys.hint(KeyContext::Detail, &[Action::ExportTab]), theme.accent_bold()),
             Span::raw(format!(" {} | ", app.i18n.t("detail-help-export"))),
-            Span::styled("o", theme.accent_bold()),
+            Span::styled(
+                keys.hint(KeyContext::Detail, &[Action::OpenBrowser]),
+                theme.accent_bold(),
+            ),
             Span::raw(format!(" {} | ", app.i18n.t("detail-help-browser"))),
-            Span::styled("Ctrl+H", theme.accent_bold()),
+            Span::styled(keys.hint(KeyContext::Global, &[Action::Help]), theme.accent_bold()),
             Span::raw(format!(" {} | ", app.i18n.t("detail-help-more"))),
-            Span::styled("Esc", theme.danger().add_modifier(Modifier::BOLD)),
+            back,
             Span::raw(format!(" {}", app.i18n.t("common-back"))),
         ])]
     };
@@ -495,52 +745,23 @@ pub fn render_help_modal(f: &mut Frame, app: &App) {
 }
 
 fn build_detail_help_lines(app: &App, theme: &crate::ui::theme::Theme) -> Vec<Line<'static>> {
-    vec![
-        section_header(&app.i18n.t("help-detail"), theme),
-        Line::from(format!(
-            "  Tab / Shift+Tab - {}",
-            app.i18n.t("detail-help-next-link")
-        )),
-        Line::from(format!(
-            "  Ctrl+Tab - {}",
-            app.i18n.t("detail-help-switch-tab")
-        )),
-        Line::from(format!(
-            "  Enter / Ctrl+Click - {}",
-            app.i18n.t("detail-help-follow-link")
-        )),
-        Line::from(format!(
-            "  ↑/↓ or k/j - {}",
-            app.i18n.t("detail-help-scroll")
-        )),
-        Line::from(format!(
-            "  PgUp/PgDn - {}",
-            app.i18n.t("detail-help-scroll-fast")
-        )),
-        Line::from(format!("  g - {}", app.i18n.t("detail-help-graph"))),
-        Line::from(format!("  e - {}", app.i18n.t("detail-help-export"))),
-        Line::from(format!("  o - {}", app.i18n.t("detail-help-browser"))),
-        Line::from(format!("  b - {}", app.i18n.t("detail-help-bookmark"))),
-        Line::from(format!("  f - {}", app.i18n.t("detail-help-bfile"))),
-        Line::from(format!("  Esc - {}", app.i18n.t("common-back"))),
-        Line::from(""),
+    [
+        KeyContext::Detail,
+        KeyContext::Overview,
+        KeyContext::Code,
+        KeyContext::Export,
+        KeyContext::Graph,
     ]
-}
-
-fn section_header(label: &str, theme: &crate::ui::theme::Theme) -> Line<'static> {
-    Line::from(vec![Span::styled(
-        label.to_string(),
-        Style::default()
-            .fg(theme.accent_color())
-            .add_modifier(Modifier::BOLD),
-    )])
+    .into_iter()
+    .flat_map(|context| crate::ui::help::context_lines(app, context, theme))
+    .collect()
 }
 
 /// Render the Graph tab (embedded graph view)
 fn render_graph_tab(
     f: &mut Frame,
     area: Rect,
-    app: &App,
+    app: &mut App,
     seq: &crate::api::Sequence,
     theme: &crate::ui::Theme,
 ) {
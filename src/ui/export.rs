@@ -1,4 +1,5 @@
-use crate::app::{App, ExportFormat};
+use crate::app::{App, BulkLayout, ExportFormat};
+use crate::utils::latex;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
@@ -6,6 +7,10 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, Paragraph},
     Frame,
 };
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
 fn render_format_selection(f: &mut Frame, area: Rect, app: &App, theme: &crate::ui::theme::Theme) {
     let formats = [
@@ -29,6 +34,22 @@ fn render_format_selection(f: &mut Frame, area: Rect, app: &App, theme: &crate::
             ExportFormat::BFile,
             format!("{} - {}", app.i18n.t("export-bfile"), app.i18n.t("export-bfile-desc")),
         ),
+        (
+            ExportFormat::Latex,
+            format!("{} - {}", app.i18n.t("export-latex"), app.i18n.t("export-latex-desc")),
+        ),
+        (
+            ExportFormat::BibTex,
+            format!("{} - {}", app.i18n.t("export-bibtex"), app.i18n.t("export-bibtex-desc")),
+        ),
+        (
+            ExportFormat::Notebook,
+            format!("{} - {}", app.i18n.t("export-notebook"), app.i18n.t("export-notebook-desc")),
+        ),
+        (
+            ExportFormat::PythonScript,
+            format!("{} - {}", app.i18n.t("export-python"), app.i18n.t("export-python-desc")),
+        ),
     ];
 
     let items: Vec<ListItem> = formats
@@ -89,6 +110,10 @@ fn generate_export_preview(
         ExportFormat::Txt => generate_txt_preview(seq),
         ExportFormat::Markdown => generate_markdown_preview(seq),
         ExportFormat::BFile => generate_bfile_preview(seq),
+        ExportFormat::Latex => generate_latex_preview(seq),
+        ExportFormat::BibTex => generate_bibtex_preview(seq),
+        ExportFormat::Notebook => generate_notebook_preview(seq),
+        ExportFormat::PythonScript => generate_python_preview(seq),
     }
 }
 
@@ -174,6 +199,57 @@ fn generate_bfile_preview(seq: &crate::api::Sequence) -> Vec<Line<'static>> {
     lines
 }
 
+fn generate_latex_preview(seq: &crate::api::Sequence) -> Vec<Line<'static>> {
+    let values: Vec<&str> = seq.data.split(',').map(str::trim).take(10).collect();
+
+    let mut lines = vec![
+        Line::from(format!("\\section*{{{}: {}}}", seq.a_number(), latex::escape_latex(&seq.name))),
+        Line::from(""),
+        Line::from(format!("${}, \\ldots$", values.join(", "))),
+        Line::from(""),
+    ];
+
+    if let Some(formula) = seq.formula.first() {
+        lines.push(Line::from("\\begin{itemize}"));
+        lines.push(Line::from(format!("  \\item {}", latex::formula_to_latex(formula))));
+        lines.push(Line::from("\\end{itemize}"));
+        lines.push(Line::from(""));
+    }
+
+    lines.push(Line::from("..."));
+    lines
+}
+
+fn generate_bibtex_preview(seq: &crate::api::Sequence) -> Vec<Line<'static>> {
+    let entry = bibtex_entry(seq, chrono::Local::now().date_naive());
+    entry.lines().map(|line| Line::from(line.to_string())).collect()
+}
+
+fn generate_notebook_preview(seq: &crate::api::Sequence) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    for cell in notebook_cells(seq, None) {
+        let (kind, source) = match &cell {
+            NotebookCell::Markdown(source) => ("markdown", source),
+            NotebookCell::Code(source) => ("code", source),
+        };
+        lines.push(Line::from(format!("[{}]", kind)));
+        for line in source.lines().take(3) {
+            lines.push(Line::from(format!("  {}", line)));
+        }
+    }
+    lines
+}
+
+fn generate_python_preview(seq: &crate::api::Sequence) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line<'static>> = export_to_python_script(seq, None)
+        .lines()
+        .take(16)
+        .map(|line| Line::from(line.to_string()))
+        .collect();
+    lines.push(Line::from("..."));
+    lines
+}
+
 /// Generate full export content for a sequence
 pub fn export_sequence(
     seq: &crate::api::Sequence,
@@ -186,6 +262,106 @@ pub fn export_sequence(
         ExportFormat::Txt => export_to_txt(seq),
         ExportFormat::Markdown => export_to_markdown(seq),
         ExportFormat::BFile => export_to_bfile(seq, bfile_data),
+        ExportFormat::Latex => export_to_latex(seq),
+        ExportFormat::BibTex => export_to_bibtex(seq),
+        ExportFormat::Notebook => export_to_notebook(seq, bfile_data),
+        ExportFormat::PythonScript => export_to_python_script(seq, bfile_data),
+    }
+}
+
+/// Export several sequences as one document titled `title`.
+///
+/// Returns `None` for formats that only make sense one sequence per file
+/// (b-files, notebooks and scripts).
+pub fn export_combined(
+    seqs: &[crate::api::Sequence],
+    format: &ExportFormat,
+    title: &str,
+) -> Option<String> {
+    match format {
+        ExportFormat::Json => Some(
+            serde_json::to_string_pretty(seqs)
+                .unwrap_or_else(|_| "Error serializing to JSON".to_string()),
+        ),
+        ExportFormat::Csv => {
+            let mut output = String::from("A-number,Index,Value\n");
+            for seq in seqs {
+                for line in export_to_csv(seq).lines().skip(1) {
+                    output.push_str(line);
+                    output.push('\n');
+                }
+            }
+            Some(output)
+        }
+        ExportFormat::Txt => Some(
+            seqs.iter()
+                .map(export_to_txt)
+                .collect::<Vec<_>>()
+                .join(&format!("\n{}\n\n", "-".repeat(72))),
+        ),
+        ExportFormat::Markdown => {
+            let mut output = format!("# {}\n\n", title);
+            for seq in seqs {
+                output.push_str(&format!("- [{}]({}): {}\n", seq.a_number(), seq.url(), seq.name));
+            }
+            for seq in seqs {
+                output.push_str("\n---\n\n");
+                output.push_str(&export_to_markdown(seq));
+            }
+            Some(output)
+        }
+        ExportFormat::Latex => Some(
+            seqs.iter()
+                .map(export_to_latex)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        ),
+        ExportFormat::BibTex => Some(
+            seqs.iter()
+                .map(export_to_bibtex)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        ),
+        ExportFormat::BFile | ExportFormat::Notebook | ExportFormat::PythonScript => None,
+    }
+}
+
+/// Write a bulk export below `dir` and return the files written.
+///
+/// The combined layout writes a single `{name}.{ext}` document; the per-sequence
+/// layout writes `{name}/A######.{ext}` files.
+pub fn write_bulk_export(
+    seqs: &[crate::api::Sequence],
+    format: &ExportFormat,
+    layout: BulkLayout,
+    dir: &Path,
+    name: &str,
+) -> anyhow::Result<Vec<PathBuf>> {
+    match layout {
+        BulkLayout::Combined => {
+            let title = format!("OEIS {}", name.replace('-', " "));
+            let content = export_combined(seqs, format, &title).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "{} cannot be combined into one file; export one file per sequence instead",
+                    format.as_str()
+                )
+            })?;
+            fs::create_dir_all(dir)?;
+            let path = dir.join(format!("{}.{}", name, format.extension()));
+            fs::write(&path, content)?;
+            Ok(vec![path])
+        }
+        BulkLayout::PerSequence => {
+            let dir = dir.join(name);
+            fs::create_dir_all(&dir)?;
+            let mut written = Vec::with_capacity(seqs.len());
+            for seq in seqs {
+                let path = dir.join(format!("{}.{}", seq.a_number(), format.extension()));
+                fs::write(&path, export_sequence(seq, format, None))?;
+                written.push(path);
+            }
+            Ok(written)
+        }
     }
 }
 
@@ -397,6 +573,274 @@ fn export_to_bfile(
     output
 }
 
+fn export_to_latex(seq: &crate::api::Sequence) -> String {
+    let mut output = String::new();
+
+    output.push_str(&format!("% {}: {}\n", seq.a_number(), seq.name));
+    output.push_str("% Requires \\usepackage{amsmath} and \\usepackage{hyperref} (or url)\n\n");
+
+    output.push_str(&format!(
+        "\\section*{{{}: {}}}\n\n",
+        seq.a_number(),
+        latex::escape_latex(&seq.name)
+    ));
+
+    let values: Vec<&str> = seq
+        .data
+        .split(',')
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .collect();
+    output.push_str("\\paragraph{Terms}\n");
+    output.push_str(&format!("${}, \\ldots$\n\n", values.join(",\\allowbreak ")));
+
+    output.push_str(&format!(
+        "\\noindent Offset: {}. Keywords: \\texttt{{{}}}.\n\n",
+        latex::escape_latex(&seq.offset),
+        latex::escape_latex(&seq.keyword)
+    ));
+
+    if !seq.formula.is_empty() {
+        output.push_str("\\paragraph{Formulas}\n");
+        output.push_str("\\begin{itemize}\n");
+        for formula in &seq.formula {
+            output.push_str(&format!("  \\item {}\n", latex::formula_to_latex(formula)));
+        }
+        output.push_str("\\end{itemize}\n\n");
+    }
+
+    if !seq.author.is_empty() {
+        output.push_str(&format!(
+            "\\noindent Author: {}.\n\n",
+            latex::escape_latex(&seq.author)
+        ));
+    }
+
+    output.push_str(&format!("\\noindent Source: \\url{{{}}}\n", seq.url()));
+
+    output
+}
+
+fn export_to_bibtex(seq: &crate::api::Sequence) -> String {
+    bibtex_entry(seq, chrono::Local::now().date_naive())
+}
+
+/// Build a BibTeX `@misc` entry citing the sequence, accessed on `accessed`.
+fn bibtex_entry(seq: &crate::api::Sequence, accessed: chrono::NaiveDate) -> String {
+    let a_number = seq.a_number();
+    // `created` is an ISO timestamp; fall back to the access year when it is missing
+    let year = seq
+        .created
+        .get(..4)
+        .filter(|y| y.chars().all(|c| c.is_ascii_digit()))
+        .map(str::to_string)
+        .unwrap_or_else(|| accessed.format("%Y").to_string());
+    let date = accessed.format("%Y-%m-%d");
+
+    let mut output = String::new();
+    output.push_str(&format!("@misc{{oeis:{},\n", a_number));
+    output.push_str("  author       = {{OEIS Foundation Inc.}},\n");
+    output.push_str(&format!(
+        "  title        = {{Entry {{{}}} in {{The On-Line Encyclopedia of Integer Sequences}}}},\n",
+        a_number
+    ));
+    output.push_str(&format!("  year         = {{{}}},\n", year));
+    output.push_str(&format!("  url          = {{{}}},\n", seq.url()));
+    output.push_str(&format!("  urldate      = {{{}}},\n", date));
+    output.push_str(&format!(
+        "  note         = {{{}. Accessed {}}}\n",
+        latex::escape_latex(&seq.name),
+        date
+    ));
+    output.push_str("}\n");
+    output
+}
+
+/// A notebook cell, shared by the `.ipynb` and percent-format `.py` exports
+enum NotebookCell {
+    Markdown(String),
+    Code(String),
+}
+
+/// Cells for the notebook hand-off: metadata, terms, the entry's Python programs and a plot
+fn notebook_cells(
+    seq: &crate::api::Sequence,
+    bfile_data: Option<&Vec<crate::api::models::BFileEntry>>,
+) -> Vec<NotebookCell> {
+    let mut cells = Vec::new();
+
+    let mut intro = format!("# {}: {}\n\n", seq.a_number(), seq.name);
+    intro.push_str(&format!("Source: [{}]({})\n\n", seq.a_number(), seq.url()));
+    intro.push_str(&format!("- **Offset**: {}\n", seq.offset));
+    intro.push_str(&format!("- **Keywords**: {}\n", seq.keyword));
+    if !seq.author.is_empty() {
+        intro.push_str(&format!("- **Author**: {}\n", seq.author));
+    }
+    cells.push(NotebookCell::Markdown(intro.trim_end().to_string()));
+
+    if !seq.formula.is_empty() {
+        let mut formulas = String::from("## Formulas\n\n");
+        for formula in &seq.formula {
+            formulas.push_str(&format!("- {}\n", formula));
+        }
+        cells.push(NotebookCell::Markdown(formulas.trim_end().to_string()));
+    }
+
+    // Terms: the full b-file when it has been fetched, otherwise the entry's data
+    let (start, values, source) = match bfile_data {
+        Some(entries) if !entries.is_empty() => (
+            entries[0].index,
+            entries.iter().map(|e| e.value.clone()).collect::<Vec<_>>(),
+            "b-file",
+        ),
+        _ => (
+            seq.parse_offset().0 as i64,
+            seq.parse_data(),
+            "entry data",
+        ),
+    };
+    // Prefixed names, so the entry's programs below can use `n` or `terms` themselves
+    let mut terms = format!("# {} terms from the {}\n", values.len(), source);
+    terms.push_str(&format!("oeis_a_number = \"{}\"\n", seq.a_number()));
+    terms.push_str(&format!("oeis_offset = {}\n", start));
+    terms.push_str("oeis_terms = [\n");
+    for chunk in values.chunks(10) {
+        terms.push_str(&format!("    {},\n", chunk.join(", ")));
+    }
+    terms.push(']');
+    cells.push(NotebookCell::Code(terms));
+
+    for snippet in seq
+        .code_snippets()
+        .into_iter()
+        .filter(|s| s.matches_language("python"))
+    {
+        cells.push(NotebookCell::Markdown(format!(
+            "## Program ({}) from the OEIS entry",
+            snippet.language
+        )));
+        cells.push(NotebookCell::Code(snippet.code));
+    }
+
+    cells.push(NotebookCell::Markdown("## Plot".to_string()));
+    cells.push(NotebookCell::Code(format!(
+        "import matplotlib.pyplot as plt\n\n\
+         oeis_n = range(oeis_offset, oeis_offset + len(oeis_terms))\n\
+         fig, ax = plt.subplots(figsize=(10, 5))\n\
+         ax.plot(oeis_n, oeis_terms, marker=\".\", linestyle=\"-\")\n\
+         ax.set_xlabel(\"n\")\n\
+         ax.set_ylabel(\"a(n)\")\n\
+         ax.set_title({})\n\
+         if max((abs(t) for t in oeis_terms), default=0) > 10**6:\n    \
+             ax.set_yscale(\"symlog\")\n\
+         plt.show()",
+        python_string(&format!("{}: {}", seq.a_number(), seq.name))
+    )));
+
+    cells
+}
+
+/// `text` as a double-quoted Python string literal
+fn python_string(text: &str) -> String {
+    let mut literal = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '\\' => literal.push_str("\\\\"),
+            '"' => literal.push_str("\\\""),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            // Control characters all fit in four hex digits
+            c if c.is_control() => literal.push_str(&format!("\\u{:04x}", c as u32)),
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+/// Split cell source into notebook JSON lines, each keeping its trailing newline
+fn notebook_source(source: &str) -> Vec<String> {
+    let mut lines: Vec<String> = source.split('\n').map(|l| format!("{}\n", l)).collect();
+    if let Some(last) = lines.last_mut() {
+        last.pop();
+    }
+    lines
+}
+
+fn export_to_notebook(
+    seq: &crate::api::Sequence,
+    bfile_data: Option<&Vec<crate::api::models::BFileEntry>>,
+) -> String {
+    let cells: Vec<serde_json::Value> = notebook_cells(seq, bfile_data)
+        .iter()
+        .map(|cell| match cell {
+            NotebookCell::Markdown(source) => serde_json::json!({
+                "cell_type": "markdown",
+                "metadata": {},
+                "source": notebook_source(source),
+            }),
+            NotebookCell::Code(source) => serde_json::json!({
+                "cell_type": "code",
+                "execution_count": null,
+                "metadata": {},
+                "outputs": [],
+                "source": notebook_source(source),
+            }),
+        })
+        .collect();
+
+    let notebook = serde_json::json!({
+        "cells": cells,
+        "metadata": {
+            "kernelspec": {
+                "display_name": "Python 3",
+                "language": "python",
+                "name": "python3",
+            },
+            "language_info": { "name": "python" },
+        },
+        "nbformat": 4,
+        "nbformat_minor": 4,
+    });
+
+    serde_json::to_string_pretty(&notebook)
+        .unwrap_or_else(|_| "Error serializing notebook".to_string())
+}
+
+/// Percent-format script (`# %%` cells), readable by Jupytext, VS Code and Spyder
+fn export_to_python_script(
+    seq: &crate::api::Sequence,
+    bfile_data: Option<&Vec<crate::api::models::BFileEntry>>,
+) -> String {
+    let mut output = String::new();
+
+    for (i, cell) in notebook_cells(seq, bfile_data).iter().enumerate() {
+        if i > 0 {
+            output.push('\n');
+        }
+        match cell {
+            NotebookCell::Markdown(source) => {
+                output.push_str("# %% [markdown]\n");
+                for line in source.lines() {
+                    if line.is_empty() {
+                        output.push_str("#\n");
+                    } else {
+                        output.push_str(&format!("# {}\n", line));
+                    }
+                }
+            }
+            NotebookCell::Code(source) => {
+                output.push_str("# %%\n");
+                output.push_str(source);
+                output.push('\n');
+            }
+        }
+    }
+
+    output
+}
+
 /// Render export embedded in detail view tab (no title/help bars)
 pub fn render_embedded(
     f: &mut Frame,
@@ -409,7 +853,7 @@ pub fn render_embedded(
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(10), // Format selection
+            Constraint::Length(11), // Format selection
             Constraint::Min(5),     // Preview
         ])
         .split(area);
@@ -417,3 +861,169 @@ pub fn render_embedded(
     render_format_selection(f, chunks[0], app, theme);
     render_preview(f, chunks[1], app, theme);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequence(number: i32, name: &str, data: &str) -> crate::api::Sequence {
+        serde_json::from_value(serde_json::json!({
+            "number": number,
+            "data": data,
+            "name": name,
+            "offset": "1,2",
+            "keyword": "nonn,easy",
+            "author": "N. J. A. Sloane",
+            "created": "1991-04-30T03:00:00-04:00",
+            "time": "2024-01-01T00:00:00-05:00",
+            "revision": 1,
+            "references": 0,
+            "formula": ["a(n) = n."],
+            "program": ["(PARI) a(n) = n", "(Python)", "def a(n):", "    return n"]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_python_string() {
+        assert_eq!(python_string("Fibonacci numbers"), r#""Fibonacci numbers""#);
+        assert_eq!(python_string(r#"Say "n" \ 2"#), r#""Say \"n\" \\ 2""#);
+        assert_eq!(python_string("a\nb\tc\u{7}"), r#""a\nb\tc\u0007""#);
+        // Printable characters stay as they are, combining marks included
+        assert_eq!(python_string("Erdo\u{30b}s: π"), "\"Erdo\u{30b}s: π\"");
+    }
+
+    #[test]
+    fn test_notebook_cells() {
+        let seq = sequence(27, "The \"natural\" numbers \\ Erdo\u{30b}s", "1,2,3");
+        let title = "ax.set_title(\"A000027: The \\\"natural\\\" numbers \\\\ Erdo\u{30b}s\")";
+
+        let notebook: serde_json::Value =
+            serde_json::from_str(&export_sequence(&seq, &ExportFormat::Notebook, None)).unwrap();
+        assert_eq!(notebook["nbformat"], 4);
+        let cells: Vec<(String, String)> = notebook["cells"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|cell| {
+                let source: Vec<&str> = cell["source"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|line| line.as_str().unwrap())
+                    .collect();
+                (cell["cell_type"].as_str().unwrap().to_string(), source.concat())
+            })
+            .collect();
+        let first_lines: Vec<(&str, &str)> = cells
+            .iter()
+            .map(|(kind, source)| (kind.as_str(), source.lines().next().unwrap()))
+            .collect();
+        assert_eq!(
+            first_lines,
+            [
+                ("markdown", "# A000027: The \"natural\" numbers \\ Erdo\u{30b}s"),
+                ("markdown", "## Formulas"),
+                ("code", "# 3 terms from the entry data"),
+                ("markdown", "## Program (Python) from the OEIS entry"),
+                ("code", "def a(n):"),
+                ("markdown", "## Plot"),
+                ("code", "import matplotlib.pyplot as plt"),
+            ]
+        );
+        assert!(cells[2].1.ends_with("oeis_offset = 1\noeis_terms = [\n    1, 2, 3,\n]"));
+        assert!(cells[6].1.lines().any(|line| line == title));
+
+        let script = export_sequence(&seq, &ExportFormat::PythonScript, None);
+        let markers: Vec<&str> = script.lines().filter(|line| line.starts_with("# %%")).collect();
+        assert_eq!(
+            markers,
+            [
+                "# %% [markdown]",
+                "# %% [markdown]",
+                "# %%",
+                "# %% [markdown]",
+                "# %%",
+                "# %% [markdown]",
+                "# %%",
+            ]
+        );
+        assert!(script.contains("# %%\ndef a(n):\n    return n\n"));
+        assert!(script.lines().any(|line| line == title));
+    }
+
+    #[test]
+    fn test_export_combined() {
+        let seqs = [sequence(45, "Fibonacci numbers", "0,1,1"), sequence(40, "The primes", "2,3")];
+
+        let json: serde_json::Value =
+            serde_json::from_str(&export_combined(&seqs, &ExportFormat::Json, "OEIS bookmarks").unwrap()).unwrap();
+        let numbers: Vec<i64> = json.as_array().unwrap().iter().map(|seq| seq["number"].as_i64().unwrap()).collect();
+        assert_eq!(numbers, [45, 40]);
+
+        assert_eq!(
+            export_combined(&seqs, &ExportFormat::Csv, "OEIS bookmarks").unwrap(),
+            "A-number,Index,Value\nA000045,0,0\nA000045,1,1\nA000045,2,1\nA000040,0,2\nA000040,1,3\n"
+        );
+
+        let markdown = export_combined(&seqs, &ExportFormat::Markdown, "OEIS bookmarks").unwrap();
+        assert!(markdown.starts_with(
+            "# OEIS bookmarks\n\n\
+             - [A000045](https://oeis.org/A000045): Fibonacci numbers\n\
+             - [A000040](https://oeis.org/A000040): The primes\n\n---\n\n"
+        ));
+        // Each entry follows a rule, in the order given
+        let fibonacci = markdown.find("\n---\n\n# A000045: Fibonacci numbers\n").unwrap();
+        let primes = markdown.find("\n---\n\n# A000040: The primes\n").unwrap();
+        assert!(fibonacci < primes);
+
+        assert!(export_combined(&seqs, &ExportFormat::Notebook, "OEIS bookmarks").is_none());
+    }
+
+    #[test]
+    fn test_write_bulk_export() {
+        let seqs = [sequence(45, "Fibonacci numbers", "0,1,1"), sequence(40, "The primes", "2,3")];
+        let dir = std::env::temp_dir().join(format!("oeis-bulk-{}", std::process::id()));
+
+        let written = write_bulk_export(&seqs, &ExportFormat::Csv, BulkLayout::Combined, &dir, "oeis-bookmarks").unwrap();
+        assert_eq!(written, [dir.join("oeis-bookmarks.csv")]);
+        assert_eq!(fs::read_to_string(&written[0]).unwrap().lines().count(), 6);
+
+        let written = write_bulk_export(&seqs, &ExportFormat::Markdown, BulkLayout::Combined, &dir, "oeis-bookmarks").unwrap();
+        assert!(fs::read_to_string(&written[0]).unwrap().starts_with("# OEIS oeis bookmarks\n"));
+
+        let written = write_bulk_export(&seqs, &ExportFormat::Json, BulkLayout::PerSequence, &dir, "oeis-bookmarks").unwrap();
+        let files = dir.join("oeis-bookmarks");
+        assert_eq!(written, [files.join("A000045.json"), files.join("A000040.json")]);
+        for (path, seq) in written.iter().zip(&seqs) {
+            assert_eq!(fs::read_to_string(path).unwrap(), export_to_json(seq));
+        }
+
+        let written = write_bulk_export(&seqs, &ExportFormat::Notebook, BulkLayout::PerSequence, &dir, "notebooks").unwrap();
+        assert_eq!(written.len(), 2);
+        assert!(written.iter().all(|path| path.extension().unwrap() == "ipynb"));
+
+        assert!(write_bulk_export(&seqs, &ExportFormat::BFile, BulkLayout::Combined, &dir, "oeis-bfiles").is_err());
+        assert!(!dir.join("oeis-bfiles.txt").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_notebook_program_reusing_names() {
+        // Entry programs often loop over `n` at the top level, between the terms and the plot
+        let mut seq = sequence(27, "The natural numbers", "1,2,3");
+        seq.program = vec![
+            "(Python)".to_string(),
+            "for n in range(1, 4):".to_string(),
+            "    terms = n".to_string(),
+            "    print(n)".to_string(),
+        ];
+        let script = export_sequence(&seq, &ExportFormat::PythonScript, None);
+        let cells: Vec<&str> = script.split("# %%").collect();
+        let plot = cells.last().unwrap();
+        assert!(cells[cells.len() - 3].contains("for n in range(1, 4):"));
+        assert!(plot.contains("oeis_n = range(oeis_offset, oeis_offset + len(oeis_terms))\n"));
+        assert!(plot.contains("ax.plot(oeis_n, oeis_terms, "));
+        assert!(!plot.contains("(n, ") && !plot.contains(" in terms"));
+    }
+}
@@ -1,10 +1,13 @@
 use crate::app::{App, GraphType};
+use crate::plot::{self, Series};
+use crate::utils::keybindings::{Action, KeyContext};
+use fluent::FluentArgs;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::Modifier,
     text::{Line, Span},
     widgets::{
-        canvas::{Canvas, Points},
+        canvas::{Canvas, Line as CanvasLine, Points},
         Block, Borders, Paragraph,
     },
     Frame,
@@ -24,8 +27,9 @@ pub fn render(f: &mut Frame, app: &mut App) {
 
     render_title(f, chunks[0], app, &theme);
 
-    if let Some(ref seq) = app.current_sequence {
-        render_graph(f, chunks[1], app, seq, &theme);
+    app.graph_area = None;
+    if let Some(seq) = app.current_sequence.clone() {
+        render_graph(f, chunks[1], app, &seq, &theme);
     } else {
         let text = Paragraph::new("No sequence loaded").alignment(Alignment::Center);
         f.render_widget(text, chunks[1]);
@@ -56,296 +60,288 @@ fn render_title(f: &mut Frame, area: Rect, app: &App, theme: &crate::ui::Theme)
     f.render_widget(paragraph, area);
 }
 
-fn render_graph(
+/// The chart of the current graph type, in a bordered block
+pub fn render_graph(
     f: &mut Frame,
     area: Rect,
-    app: &App,
+    app: &mut App,
     seq: &crate::api::Sequence,
     theme: &crate::ui::Theme,
 ) {
-    // Parse sequence data into points
-    let data_points = parse_sequence_data(seq);
+    let graph_type = app.graph_type.clone();
+    let series = Series::new(seq, app.bfile_data.as_deref(), &graph_type, app.graph_modulus);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(theme.accent())
+        .title(Span::styled(
+            format!(" {} ", graph_type_name(app, &graph_type)),
+            theme.accent_bold(),
+        ));
+
+    let log = graph_type == GraphType::LogScatter;
+    let mut points = series.points.clone();
+    let (x_min, x_max, visible) = if graph_type.is_indexed() {
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let full = match (points.first(), points.last()) {
+            (Some(first), Some(last)) => (first.0, last.0),
+            _ => (0.0, 0.0),
+        };
+        let (x_min, x_max) = app.graph_viewport.visible(full);
+        let visible: Vec<(f64, f64)> = points
+            .into_iter()
+            .filter(|(x, y)| *x >= x_min && *x <= x_max && (!log || *y > 0.0))
+            .map(|(x, y)| if log { (x, y.log10()) } else { (x, y) })
+            .collect();
+        (x_min, x_max, visible)
+    } else {
+        let x_min = points.iter().map(|(x, _)| *x).fold(f64::INFINITY, f64::min);
+        let x_max = points.iter().map(|(x, _)| *x).fold(f64::NEG_INFINITY, f64::max);
+        (x_min, x_max + plot::bin_width(&points), points)
+    };
 
-    if data_points.is_empty() {
-        let text = Paragraph::new("No numeric data to plot").alignment(Alignment::Center);
+    if visible.is_empty() {
+        let key = if log { "graph-no-positive" } else { "graph-no-data" };
+        let text = Paragraph::new(app.i18n.t(key))
+            .alignment(Alignment::Center)
+            .block(block);
         f.render_widget(text, area);
         return;
     }
 
-    // Use Ratatui's canvas for simple visualization
-    render_simple_graph(f, area, app, &data_points, theme);
-}
-
-fn parse_sequence_data(seq: &crate::api::Sequence) -> Vec<(f64, f64)> {
-    let (start_index, _) = seq.parse_offset();
-
-    seq.data
-        .split(',')
-        .enumerate()
-        .filter_map(|(i, val)| {
-            let trimmed = val.trim();
-            // Try to parse as integer or float
-            if let Ok(num) = trimmed.parse::<i64>() {
-                Some(((start_index + i as i32) as f64, num as f64))
-            } else if let Ok(num) = trimmed.parse::<f64>() {
-                Some(((start_index + i as i32) as f64, num))
-            } else {
-                None
-            }
-        })
-        .take(100) // Limit to 100 points for rendering
-        .collect()
-}
-
-fn render_simple_graph(
-    f: &mut Frame,
-    area: Rect,
-    app: &App,
-    data: &[(f64, f64)],
-    theme: &crate::ui::Theme,
-) {
-    if data.is_empty() {
+    if graph_type == GraphType::UlamSpiral {
+        render_ulam(f, area, app, block, &visible, theme);
         return;
     }
-
-    // Find bounds
-    let x_min = data.iter().map(|(x, _)| *x).fold(f64::INFINITY, f64::min);
-    let x_max = data
-        .iter()
-        .map(|(x, _)| *x)
-        .fold(f64::NEG_INFINITY, f64::max);
-    let y_min = data.iter().map(|(_, y)| *y).fold(f64::INFINITY, f64::min);
-    let y_max = data
-        .iter()
-        .map(|(_, y)| *y)
-        .fold(f64::NEG_INFINITY, f64::max);
-
-    // Add padding to bounds
-    let x_padding = (x_max - x_min) * 0.1;
-    let y_padding = (y_max - y_min) * 0.1;
-    let x_min = x_min - x_padding;
-    let x_max = x_max + x_padding;
-    let y_min = y_min - y_padding;
-    let y_max = y_max + y_padding;
-
-    // Handle edge cases
-    let (x_min, x_max) = if x_max == x_min {
-        (x_min - 1.0, x_max + 1.0)
-    } else {
-        (x_min, x_max)
+    let inner = block.inner(area);
+
+    let y_min = visible.iter().map(|(_, y)| *y).fold(f64::INFINITY, f64::min);
+    let y_max = visible.iter().map(|(_, y)| *y).fold(f64::NEG_INFINITY, f64::max);
+    let (y_min, y_max) = match graph_type {
+        GraphType::ModK => (0.0, app.graph_modulus.saturating_sub(1).max(1) as f64),
+        // Pins and bars grow from zero
+        GraphType::PinPlot | GraphType::Histogram => (y_min.min(0.0), y_max.max(0.0)),
+        _ => (y_min, y_max),
     };
-    let (y_min, y_max) = if y_max == y_min {
-        (y_min - 1.0, y_max + 1.0)
+    let (y_min, y_max) = if y_max > y_min { (y_min, y_max) } else { (y_min - 1.0, y_max + 1.0) };
+    let (x_lo, x_hi) = if x_max > x_min { (x_min, x_max) } else { (x_min - 1.0, x_max + 1.0) };
+
+    // y labels down the left edge, x labels along the bottom
+    let y_label = |y: f64| plot::format_tick(if log { 10f64.powf(y) } else { y });
+    let y_labels = [y_label(y_max), y_label((y_min + y_max) / 2.0), y_label(y_min)];
+    let gutter = y_labels.iter().map(|l| l.chars().count()).max().unwrap_or(0) as u16 + 1;
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(gutter), Constraint::Min(1)])
+        .split(rows[0]);
+    let plot_area = columns[1];
+    app.graph_area = Some(plot_area);
+
+    // Two braille dots per cell horizontally, so more points than that only overdraw
+    let data = if graph_type.is_indexed() {
+        plot::decimate(&visible, plot_area.width as usize * 2)
     } else {
-        (y_min, y_max)
+        visible
     };
 
-    match app.graph_type {
-        GraphType::Line | GraphType::Scatter => {
-            render_standard_plot(
-                f,
-                area,
-                data,
-                x_min,
-                x_max,
-                y_min,
-                y_max,
-                &app.graph_type,
-                theme,
-            );
-        }
-        GraphType::LogScatter => {
-            render_log_plot(f, area, data, x_min, x_max, theme);
-        }
-        GraphType::PinPlot => {
-            render_pin_plot(f, area, data, x_min, x_max, y_min, y_max, theme);
-        }
+    // n is whole, so show the visible n-range in whole terms
+    let x_tick = |x: f64| {
+        plot::format_tick(if graph_type.is_indexed() { x.round() } else { x })
+    };
+    let (x_name, y_name) = plot::axis_labels(&graph_type, app.graph_modulus);
+    let summary = format!(
+        " {} {}–{} · {} {}–{} · {} ",
+        x_name,
+        x_tick(x_min),
+        x_tick(x_max),
+        y_name,
+        y_labels[2],
+        y_labels[0],
+        source_summary(app, &series)
+    );
+    let block = block.title_bottom(Line::from(Span::styled(summary, theme.muted())).right_aligned());
+    f.render_widget(block, area);
+
+    let label_area = columns[0];
+    for (label, row) in y_labels.iter().zip([
+        label_area.y,
+        label_area.y + label_area.height.saturating_sub(1) / 2,
+        label_area.y + label_area.height.saturating_sub(1),
+    ]) {
+        let rect = Rect::new(label_area.x, row, gutter.saturating_sub(1), 1);
+        f.render_widget(
+            Paragraph::new(label.as_str()).alignment(Alignment::Right).style(theme.muted()),
+            rect,
+        );
     }
-}
 
-fn render_standard_plot(
-    f: &mut Frame,
-    area: Rect,
-    data: &[(f64, f64)],
-    x_min: f64,
-    x_max: f64,
-    y_min: f64,
-    y_max: f64,
-    graph_type: &GraphType,
-    theme: &crate::ui::Theme,
-) {
+    let x_axis = Rect::new(plot_area.x, rows[1].y, plot_area.width, 1);
+    for (value, alignment) in [
+        (x_min, Alignment::Left),
+        ((x_min + x_max) / 2.0, Alignment::Center),
+        (x_max, Alignment::Right),
+    ] {
+        f.render_widget(
+            Paragraph::new(x_tick(value)).alignment(alignment).style(theme.muted()),
+            x_axis,
+        );
+    }
+
+    let baseline = 0f64.clamp(y_min, y_max);
+    let bin_width = plot::bin_width(&data);
+    // Horizontal size of one braille dot, for filling histogram bars
+    let dot = (x_hi - x_lo) / (plot_area.width.max(1) as f64 * 2.0);
     let canvas = Canvas::default()
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(theme.accent())
-                .title(Span::styled(
-                    format!(
-                        " {} ",
-                        match graph_type {
-                            GraphType::Line => "Line Chart",
-                            GraphType::Scatter => "Scatter Plot",
-                            _ => "Plot",
-                        }
-                    ),
-                    theme.accent_bold(),
-                )),
-        )
-        .x_bounds([x_min, x_max])
+        .x_bounds([x_lo, x_hi])
         .y_bounds([y_min, y_max])
         .marker(ratatui::symbols::Marker::Braille)
-        .paint(|ctx| {
-            // Draw points
-            for (x, y) in data {
+        .paint(|ctx| match graph_type {
+            GraphType::Line | GraphType::Differences | GraphType::Ratio => {
+                for window in data.windows(2) {
+                    ctx.draw(&CanvasLine::new(
+                        window[0].0,
+                        window[0].1,
+                        window[1].0,
+                        window[1].1,
+                        theme.success_color(),
+                    ));
+                }
                 ctx.draw(&Points {
-                    coords: &[(*x, *y)],
+                    coords: &data,
                     color: theme.accent_color(),
                 });
             }
-
-            // For line chart, connect points
-            if matches!(graph_type, GraphType::Line) {
-                for window in data.windows(2) {
-                    let (x1, y1) = window[0];
-                    let (x2, y2) = window[1];
-
-                    // Draw line between points by drawing multiple points
-                    let steps = 20;
-                    for i in 0..=steps {
-                        let t = i as f64 / steps as f64;
-                        let x = x1 + (x2 - x1) * t;
-                        let y = y1 + (y2 - y1) * t;
-                        ctx.draw(&Points {
-                            coords: &[(x, y)],
-                            color: theme.success_color(),
-                        });
+            GraphType::Scatter | GraphType::LogScatter | GraphType::ModK => {
+                ctx.draw(&Points {
+                    coords: &data,
+                    color: theme.accent_color(),
+                });
+            }
+            GraphType::PinPlot => {
+                // Pins rise from zero (or the lowest visible value) to each term
+                for (x, y) in &data {
+                    ctx.draw(&CanvasLine::new(*x, baseline, *x, *y, theme.highlight_color()));
+                }
+                ctx.draw(&Points {
+                    coords: &data,
+                    color: theme.danger_color(),
+                });
+            }
+            GraphType::Histogram => {
+                // Fill each bar with vertical strokes, leaving a gap before the next one
+                for (start, count) in data.iter().filter(|(_, count)| *count > 0.0) {
+                    let mut x = *start;
+                    while x < start + bin_width * 0.9 {
+                        ctx.draw(&CanvasLine::new(x, 0.0, x, *count, theme.accent_color()));
+                        x += dot;
                     }
                 }
             }
+            GraphType::UlamSpiral => {}
         });
 
-    f.render_widget(canvas, area);
+    f.render_widget(canvas, plot_area);
 }
 
-fn render_log_plot(
+/// Sequence members lit up on the Ulam spiral, kept square on screen
+fn render_ulam(
     f: &mut Frame,
     area: Rect,
-    data: &[(f64, f64)],
-    x_min: f64,
-    x_max: f64,
+    app: &mut App,
+    block: Block,
+    cells: &[(f64, f64)],
     theme: &crate::ui::Theme,
 ) {
-    // Filter positive values for log plot
-    let log_data: Vec<(f64, f64)> = data
+    let inner = block.inner(area);
+    // A braille dot is about as wide as it is tall (2 × 4 dots in a cell twice as tall as wide),
+    // so give each integer one dot and show the middle of larger spirals
+    let dots_x = inner.width.max(1) as f64 * 2.0;
+    let dots_y = inner.height.max(1) as f64 * 4.0;
+    let half = plot::ulam_half_width(cells).min(((dots_x.min(dots_y) - 1.0) / 2.0).floor().max(1.0));
+    let side = 2.0 * half + 1.0;
+    let shown: Vec<(f64, f64)> = cells
         .iter()
-        .filter(|(_, y)| *y > 0.0)
-        .map(|(x, y)| (*x, y.ln()))
+        .copied()
+        .filter(|(x, y)| x.abs() <= half && y.abs() <= half)
         .collect();
 
-    if log_data.is_empty() {
-        let text = Paragraph::new("No positive values to plot on logarithmic scale")
-            .alignment(Alignment::Center);
-        f.render_widget(text, area);
-        return;
-    }
+    let mut args = FluentArgs::new();
+    args.set("members", shown.len());
+    args.set("limit", (side * side) as u64);
+    let summary = format!(" {} ", app.i18n.t_with_args("graph-ulam-summary", Some(&args)));
+    let block = block.title_bottom(Line::from(Span::styled(summary, theme.muted())).right_aligned());
+    f.render_widget(block, area);
+    app.graph_area = Some(inner);
 
-    let y_min = log_data
-        .iter()
-        .map(|(_, y)| *y)
-        .fold(f64::INFINITY, f64::min);
-    let y_max = log_data
-        .iter()
-        .map(|(_, y)| *y)
-        .fold(f64::NEG_INFINITY, f64::max);
-    let y_padding = (y_max - y_min) * 0.1;
+    let unit = (side / dots_x).max(side / dots_y);
+    let (x_half, y_half) = (dots_x * unit / 2.0, dots_y * unit / 2.0);
 
     let canvas = Canvas::default()
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(theme.accent())
-                .title(Span::styled(
-                    " Logarithmic Scatter Plot ",
-                    theme.accent_bold(),
-                )),
-        )
-        .x_bounds([x_min, x_max])
-        .y_bounds([y_min - y_padding, y_max + y_padding])
+        .x_bounds([-x_half, x_half])
+        .y_bounds([-y_half, y_half])
         .marker(ratatui::symbols::Marker::Braille)
         .paint(|ctx| {
-            for (x, y) in &log_data {
-                ctx.draw(&Points {
-                    coords: &[(*x, *y)],
-                    color: theme.accent_color(),
-                });
-            }
+            ctx.draw(&Points {
+                coords: &shown,
+                color: theme.accent_color(),
+            });
+            ctx.draw(&Points {
+                coords: &[(0.0, 0.0)],
+                color: theme.danger_color(),
+            });
         });
-
-    f.render_widget(canvas, area);
+    f.render_widget(canvas, inner);
 }
 
-fn render_pin_plot(
-    f: &mut Frame,
-    area: Rect,
-    data: &[(f64, f64)],
-    x_min: f64,
-    x_max: f64,
-    y_min: f64,
-    y_max: f64,
-    theme: &crate::ui::Theme,
-) {
-    let canvas = Canvas::default()
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(theme.accent())
-                .title(Span::styled(" Pin Plot ", theme.accent_bold())),
-        )
-        .x_bounds([x_min, x_max])
-        .y_bounds([y_min, y_max])
-        .marker(ratatui::symbols::Marker::Braille)
-        .paint(|ctx| {
-            // Draw vertical lines (pins) from x-axis to each point
-            for (x, y) in data {
-                let steps = 20;
-                for i in 0..=steps {
-                    let t = i as f64 / steps as f64;
-                    let pin_y = y_min + (*y - y_min) * t;
-                    ctx.draw(&Points {
-                        coords: &[(*x, pin_y)],
-                        color: theme.highlight_color(),
-                    });
-                }
-
-                // Draw point at top
-                ctx.draw(&Points {
-                    coords: &[(*x, *y)],
-                    color: theme.danger_color(),
-                });
-            }
-        });
+/// Localized name of a graph type (the residue plot includes its k)
+fn graph_type_name(app: &App, graph_type: &GraphType) -> String {
+    let mut args = FluentArgs::new();
+    args.set("k", app.graph_modulus);
+    app.i18n.t_with_args(graph_type.label_key(), Some(&args))
+}
 
-    f.render_widget(canvas, area);
+/// "N terms" or "N b-file terms"
+fn source_summary(app: &App, series: &Series) -> String {
+    let mut args = FluentArgs::new();
+    args.set("count", series.terms);
+    let key = if series.from_bfile { "graph-points-bfile" } else { "graph-points-terms" };
+    app.i18n.t_with_args(key, Some(&args))
 }
 
 fn render_help(f: &mut Frame, area: Rect, app: &App, theme: &crate::ui::Theme) {
     let help_text = vec![
-        Line::from(vec![
-            Span::styled("1", theme.accent_bold()),
-            Span::raw(format!(" {} | ", app.i18n.t("graph-help-line"))),
-            Span::styled("2", theme.accent_bold()),
-            Span::raw(format!(" {} | ", app.i18n.t("graph-help-scatter"))),
-            Span::styled("3", theme.accent_bold()),
-            Span::raw(format!(" {} | ", app.i18n.t("graph-help-log"))),
-            Span::styled("4", theme.accent_bold()),
-            Span::raw(format!(" {}", app.i18n.t("graph-help-pin"))),
-        ]),
-        Line::from(""),
+        Line::from(
+            type_help_spans(app, theme)
+                .into_iter()
+                .chain([
+                    Span::styled(
+                        app.keybindings().hint(KeyContext::Graph, &[Action::Save]),
+                        theme.accent_bold(),
+                    ),
+                    Span::raw(format!(" {}", app.i18n.t("graph-help-save"))),
+                ])
+                .collect::<Vec<_>>(),
+        ),
+        zoom_help_line(app, theme),
         Line::from(vec![
             Span::raw(format!("{}: ", app.i18n.t("graph-current"))),
-            Span::styled(format!("{:?}", app.graph_type), theme.highlight_bold()),
+            Span::styled(
+                format!(
+                    "{} ({}/{})",
+                    graph_type_name(app, &app.graph_type),
+                    app.graph_type.index() + 1,
+                    GraphType::ALL.len()
+                ),
+                theme.highlight_bold(),
+            ),
             Span::raw(" | "),
-            Span::styled("Esc", theme.danger().add_modifier(Modifier::BOLD)),
+            Span::styled(
+                app.keybindings().hint(KeyContext::Graph, &[Action::Back]),
+                theme.danger().add_modifier(Modifier::BOLD),
+            ),
             Span::raw(format!(" {}", app.i18n.t("graph-help-back"))),
         ]),
     ];
@@ -362,11 +358,55 @@ fn render_help(f: &mut Frame, area: Rect, app: &App, theme: &crate::ui::Theme) {
     f.render_widget(help, area);
 }
 
+/// Graph type keys, shared by both help bars; k only matters for the residue plot
+fn type_help_spans(app: &App, theme: &crate::ui::Theme) -> Vec<Span<'static>> {
+    let keys = app.keybindings();
+    let pick: Vec<Action> = (1..=9).map(Action::Pick).collect();
+    let mut spans = vec![
+        Span::styled(
+            keys.hint(KeyContext::Graph, &[Action::NextGraphType, Action::PreviousGraphType]),
+            theme.accent_bold(),
+        ),
+        Span::raw(format!(" {} | ", app.i18n.t("graph-help-next-type"))),
+        Span::styled(keys.hint(KeyContext::Graph, &pick), theme.accent_bold()),
+        Span::raw(format!(" {} | ", app.i18n.t("graph-help-choose-type"))),
+    ];
+    if app.graph_type == GraphType::ModK {
+        spans.push(Span::styled(
+            keys.hint(KeyContext::Graph, &[Action::ModulusDown, Action::ModulusUp]),
+            theme.accent_bold(),
+        ));
+        spans.push(Span::raw(format!(" {} | ", app.i18n.t("graph-help-modulus"))));
+    }
+    spans
+}
+
+/// Zoom and pan keys, shared by both help bars
+fn zoom_help_line(app: &App, theme: &crate::ui::Theme) -> Line<'static> {
+    let keys = app.keybindings();
+    Line::from(vec![
+        Span::styled(
+            keys.hint(KeyContext::Graph, &[Action::ZoomIn, Action::ZoomOut]),
+            theme.accent_bold(),
+        ),
+        Span::raw(format!(" {} | ", app.i18n.t("graph-help-zoom"))),
+        Span::styled(
+            keys.hint(KeyContext::Graph, &[Action::Left, Action::Right]),
+            theme.accent_bold(),
+        ),
+        Span::raw(format!(" {} | ", app.i18n.t("graph-help-pan"))),
+        Span::styled(keys.hint(KeyContext::Graph, &[Action::ResetView]), theme.accent_bold()),
+        Span::raw(format!(" {} | ", app.i18n.t("graph-help-reset"))),
+        Span::styled(keys.hint(KeyContext::Graph, &[Action::Listen]), theme.accent_bold()),
+        Span::raw(format!(" {}", app.i18n.t("graph-help-listen"))),
+    ])
+}
+
 /// Render graph embedded in detail view tab (with help bar)
 pub fn render_embedded(
     f: &mut Frame,
     area: Rect,
-    app: &App,
+    app: &mut App,
     seq: &crate::api::Sequence,
     theme: &crate::ui::Theme,
 ) {
@@ -375,7 +415,7 @@ pub fn render_embedded(
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Min(10),   // Graph
-            Constraint::Length(3), // Help
+            Constraint::Length(4), // Help
         ])
         .split(area);
 
@@ -383,18 +423,26 @@ pub fn render_embedded(
     render_graph(f, chunks[0], app, seq, theme);
 
     // Render help bar
-    let help_text = vec![Line::from(vec![
-        Span::styled("1", theme.accent_bold()),
-        Span::raw(format!(" {} | ", app.i18n.t("graph-help-line"))),
-        Span::styled("2", theme.accent_bold()),
-        Span::raw(format!(" {} | ", app.i18n.t("graph-help-scatter"))),
-        Span::styled("3", theme.accent_bold()),
-        Span::raw(format!(" {} | ", app.i18n.t("graph-help-log"))),
-        Span::styled("4", theme.accent_bold()),
-        Span::raw(format!(" {} | ", app.i18n.t("graph-help-pin"))),
-        Span::styled("g", theme.accent_bold()),
-        Span::raw(" Full-screen"),
-    ])];
+    let help_text = vec![
+        Line::from(
+            type_help_spans(app, theme)
+                .into_iter()
+                .chain([
+                    Span::styled(
+                        app.keybindings().hint(KeyContext::Graph, &[Action::Save]),
+                        theme.accent_bold(),
+                    ),
+                    Span::raw(format!(" {} | ", app.i18n.t("graph-help-save"))),
+                    Span::styled(
+                        app.keybindings().hint(KeyContext::Detail, &[Action::OpenGraph]),
+                        theme.accent_bold(),
+                    ),
+                    Span::raw(" Full-screen"),
+                ])
+                .collect::<Vec<_>>(),
+        ),
+        zoom_help_line(app, theme),
+    ];
 
     let help = Paragraph::new(help_text)
         .alignment(Alignment::Center)
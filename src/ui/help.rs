@@ -1,4 +1,5 @@
 use crate::app::App;
+use crate::utils::keybindings::KeyContext;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
@@ -27,84 +28,54 @@ pub fn render_modal(f: &mut Frame, app: &App) {
 }
 
 fn build_help_lines(app: &App, theme: &crate::ui::theme::Theme) -> Vec<Line<'static>> {
-    vec![
-        section_header(&app.i18n.t("help-global"), theme),
-        Line::from(format!(
-            "  Ctrl+Q / Ctrl+C - {}",
-            app.i18n.t("help-global-quit")
-        )),
-        Line::from(format!("  Ctrl+H - {}", app.i18n.t("help-global-help"))),
-        Line::from("  Ctrl+A - Show about"),
-        Line::from(format!("  Esc - {}", app.i18n.t("help-global-back"))),
-        Line::from(""),
-        section_header(&app.i18n.t("help-search"), theme),
-        Line::from(format!("  i / / - {}", app.i18n.t("help-search-input"))),
-        Line::from(format!("  Enter - {}", app.i18n.t("help-search-view"))),
-        Line::from(format!(
-            "  ↑/↓ or k/j - {}",
-            app.i18n.t("help-search-navigate")
-        )),
-        Line::from(format!("  ←/→ or h/l - {}", app.i18n.t("help-search-page"))),
-        Line::from(format!("  p - {}", app.i18n.t("help-search-preview"))),
-        Line::from(format!(
-            "  Tab / 1-6 - {}",
-            app.i18n.t("help-search-preview-tabs")
-        )),
-        Line::from(format!("  r - {}", app.i18n.t("help-search-random"))),
-        Line::from(format!("  w - {}", app.i18n.t("help-search-webcam"))),
-        Line::from(format!(
-            "  Click - {}",
-            app.i18n.t("help-search-mouse-select")
-        )),
-        Line::from(format!(
-            "  Click x2 - {}",
-            app.i18n.t("help-search-mouse-open")
-        )),
-        Line::from(format!(
-            "  Scroll - {}",
-            app.i18n.t("help-search-mouse-scroll")
-        )),
-        Line::from(""),
-        section_header(&app.i18n.t("help-detail"), theme),
-        Line::from(format!(
-            "  Tab / Shift+Tab - {}",
-            app.i18n.t("help-detail-tabs")
-        )),
-        Line::from(format!(
-            "  ←/→ or h/l - {}",
-            app.i18n.t("help-detail-links")
-        )),
-        Line::from(format!(
-            "  Enter / Ctrl+Click - {}",
-            app.i18n.t("help-detail-open-link")
-        )),
-        Line::from(format!(
-            "  ↑/↓ or k/j - {}",
-            app.i18n.t("help-detail-scroll")
-        )),
-        Line::from(format!(
-            "  PgUp/PgDn - {}",
-            app.i18n.t("help-detail-scroll-fast")
-        )),
-        Line::from(format!("  g - {}", app.i18n.t("help-detail-graph"))),
-        Line::from(format!("  e - {}", app.i18n.t("help-detail-export"))),
-        Line::from(format!("  o - {}", app.i18n.t("help-detail-browser"))),
-        Line::from(""),
-        section_header(&app.i18n.t("help-graph"), theme),
-        Line::from(format!("  1/2/3/4 - {}", app.i18n.t("help-graph-types"))),
-        Line::from(""),
-        section_header(&app.i18n.t("help-webcam"), theme),
-        Line::from(format!(
-            "  Space/Enter - {}",
-            app.i18n.t("help-webcam-next")
-        )),
-        Line::from(format!("  ←/→ - {}", app.i18n.t("help-webcam-category"))),
-        Line::from(format!("  ↑/↓ - {}", app.i18n.t("help-webcam-interval"))),
-        Line::from(format!("  0-5 - {}", app.i18n.t("help-webcam-quick"))),
-        Line::from(format!("  d - {}", app.i18n.t("help-webcam-detail"))),
-        Line::from(""),
-        Line::from(format!("Esc - {}", app.i18n.t("help-global-back"))),
-    ]
+    let bindings = app.keybindings();
+    let mut lines = Vec::new();
+
+    // Problems with settings.json come first so they get noticed
+    if !bindings.warnings().is_empty() {
+        lines.push(Line::from(Span::styled(
+            app.i18n.t("help-keybinding-warnings").to_string(),
+            theme.warning().add_modifier(Modifier::BOLD),
+        )));
+        for warning in bindings.warnings() {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", app.describe_keybinding_warning(warning)),
+                theme.warning(),
+            )));
+        }
+        lines.push(Line::from(""));
+    }
+
+    for context in KeyContext::ALL {
+        lines.extend(context_lines(app, context, theme));
+        // The mouse is not remappable, so its help stays fixed
+        if context == KeyContext::Search {
+            lines.pop();
+            for key in [
+                "help-search-mouse-select",
+                "help-search-mouse-open",
+                "help-search-mouse-scroll",
+            ] {
+                lines.push(Line::from(format!("  {}", app.i18n.t(key))));
+            }
+            lines.push(Line::from(""));
+        }
+    }
+    lines
+}
+
+/// A section header and one line per bound action of `context`, from the live bindings
+pub fn context_lines(
+    app: &App,
+    context: KeyContext,
+    theme: &crate::ui::theme::Theme,
+) -> Vec<Line<'static>> {
+    let mut lines = vec![section_header(&app.i18n.t(context.label_key()), theme)];
+    for (keys, help) in app.keybindings().help_entries(context) {
+        lines.push(Line::from(format!("  {} - {}", keys, app.i18n.t(help))));
+    }
+    lines.push(Line::from(""));
+    lines
 }
 
 fn section_header(label: &str, theme: &crate::ui::theme::Theme) -> Line<'static> {
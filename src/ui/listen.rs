@@ -0,0 +1,167 @@
+use crate::app::{App, ListenDialog, ListenField};
+use crate::audio::{self, Sonification};
+use fluent::FluentArgs;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn render_modal(f: &mut Frame, app: &App) {
+    let Some(dialog) = app.listen_dialog.as_ref() else {
+        return;
+    };
+
+    let area = centered_rect(60, 14, f.area());
+    f.render_widget(Clear, area);
+
+    let paragraph = Paragraph::new(build_dialog_lines(app, dialog))
+        .wrap(Wrap { trim: false })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(app.theme().selected_border())
+                .title(format!(" {} ", app.i18n.t("listen-title")))
+                .title_alignment(Alignment::Center),
+        );
+
+    f.render_widget(paragraph, area);
+}
+
+fn build_dialog_lines(app: &App, dialog: &ListenDialog) -> Vec<Line<'static>> {
+    let theme = app.theme();
+    let settings = dialog.settings;
+
+    let mut tempo = FluentArgs::new();
+    tempo.set("tempo", settings.tempo);
+    let mut range = FluentArgs::new();
+    range.set("count", settings.range);
+
+    let mut lines = vec![
+        Line::from(""),
+        choice_line(
+            app,
+            dialog,
+            ListenField::Instrument,
+            "listen-instrument",
+            app.i18n.t(settings.instrument.label_key()).to_string(),
+        ),
+        choice_line(
+            app,
+            dialog,
+            ListenField::Tempo,
+            "listen-tempo",
+            app.i18n.t_with_args("listen-tempo-value", Some(&tempo)).to_string(),
+        ),
+        choice_line(
+            app,
+            dialog,
+            ListenField::Range,
+            "listen-range",
+            app.i18n.t_with_args("listen-range-value", Some(&range)).to_string(),
+        ),
+        choice_line(
+            app,
+            dialog,
+            ListenField::LowestNote,
+            "listen-lowest",
+            format!(
+                "{} ({})",
+                audio::note_name(settings.lowest_note),
+                settings.lowest_note
+            ),
+        ),
+        Line::from(""),
+    ];
+
+    // What the file will hold, with the current settings
+    if let Some(ref seq) = app.current_sequence {
+        let sound = Sonification::new(seq, app.bfile_data.as_deref(), settings);
+        let seconds = sound.duration_seconds().round() as u64;
+        let highest = settings.lowest_note as u32 + settings.range - 1;
+        let mut args = FluentArgs::new();
+        args.set("count", sound.notes.len());
+        args.set("duration", format!("{}:{:02}", seconds / 60, seconds % 60));
+        args.set(
+            "pitches",
+            format!(
+                "{}–{}",
+                audio::note_name(settings.lowest_note),
+                audio::note_name(highest.min(127) as u8)
+            ),
+        );
+        let key = if sound.from_bfile {
+            "listen-summary-bfile"
+        } else {
+            "listen-summary"
+        };
+        lines.push(Line::from(vec![
+            Span::styled("  → ", theme.accent()),
+            Span::styled(app.i18n.t_with_args(key, Some(&args)).to_string(), theme.text()),
+        ]));
+    }
+    lines.push(Line::from(Span::styled(
+        format!("    {}", app.i18n.t("listen-formats")),
+        theme.muted(),
+    )));
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("  ↑↓", theme.accent_bold()),
+        Span::styled(format!(" {} | ", app.i18n.t("bulk-export-help-field")), theme.muted()),
+        Span::styled("←→", theme.accent_bold()),
+        Span::styled(format!(" {} | ", app.i18n.t("bulk-export-help-change")), theme.muted()),
+        Span::styled("Enter", theme.accent_bold()),
+        Span::styled(format!(" {} | ", app.i18n.t("listen-help-save")), theme.muted()),
+        Span::styled("Esc", theme.accent_bold()),
+        Span::styled(format!(" {}", app.i18n.t("bulk-export-help-cancel")), theme.muted()),
+    ]));
+
+    lines
+}
+
+fn field_label(app: &App, dialog: &ListenDialog, field: ListenField, key: &str) -> Span<'static> {
+    let theme = app.theme();
+    let focused = dialog.field == field;
+    let marker = if focused { "▶ " } else { "  " };
+    let style = if focused { theme.highlight_bold() } else { theme.accent_bold() };
+    Span::styled(format!("{}{:<14}", marker, format!("{}:", app.i18n.t(key))), style)
+}
+
+fn choice_line(
+    app: &App,
+    dialog: &ListenDialog,
+    field: ListenField,
+    key: &str,
+    value: String,
+) -> Line<'static> {
+    let theme = app.theme();
+    let arrows = if dialog.field == field { theme.accent() } else { theme.muted() };
+    Line::from(vec![
+        field_label(app, dialog, field, key),
+        Span::styled("◀ ", arrows),
+        Span::styled(value, theme.text()),
+        Span::styled(" ▶", arrows),
+    ])
+}
+
+fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
+    let horizontal = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(area);
+
+    let height = height.min(area.height);
+    let top = area.height.saturating_sub(height) / 2;
+    Rect {
+        x: horizontal[1].x,
+        y: area.y + top,
+        width: horizontal[1].width,
+        height,
+    }
+}
@@ -1,11 +1,18 @@
 pub mod about;
+pub mod announce;
 pub mod animation;
+pub mod bulk_export;
 pub mod detail;
 pub mod export;
 pub mod graph;
 pub mod help;
+pub mod listen;
+pub mod palette;
+pub mod pending_keys;
+pub mod save_as;
 pub mod search;
 pub mod settings;
+pub mod table;
 pub mod theme;
 pub mod webcam;
 
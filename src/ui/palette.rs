@@ -0,0 +1,104 @@
+use crate::app::App;
+use crate::palette::CommandPalette;
+use fluent::FluentArgs;
+use ratatui::{
+    layout::{Alignment, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Rows of commands shown at once
+const VISIBLE_ROWS: u16 = 14;
+
+pub fn render_modal(f: &mut Frame, app: &App) {
+    let Some(palette) = app.command_palette.as_ref() else {
+        return;
+    };
+    let theme = app.theme();
+
+    let area = f.area();
+    let width = (area.width * 3 / 5).max(40).min(area.width);
+    let height = (VISIBLE_ROWS + 4).min(area.height);
+    // Near the top, like the palettes of most editors
+    let rect = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 4,
+        width,
+        height,
+    };
+    f.render_widget(Clear, rect);
+
+    let inner_width = width.saturating_sub(2) as usize;
+    let mut lines = vec![query_line(app, palette), Line::from("")];
+
+    let rows = rows(app, palette);
+    if rows.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("  {}", app.i18n.t("palette-no-matches")),
+            theme.muted(),
+        )));
+    }
+    let visible = height.saturating_sub(4) as usize;
+    let first = palette.selected().saturating_sub(visible.saturating_sub(1));
+    for (index, (label, keys)) in rows.into_iter().enumerate().skip(first).take(visible) {
+        let selected = index == palette.selected();
+        let (label_style, keys_style) = if selected {
+            (theme.highlight_bg(), theme.highlight_bg())
+        } else {
+            (theme.text(), theme.muted())
+        };
+        let label = Span::styled(format!(" {} ", label), label_style);
+        let keys = Span::styled(format!(" {} ", keys), keys_style);
+        // Keys flush right, the gap styled like the label so the selected row is one bar
+        let gap = inner_width.saturating_sub(label.width() + keys.width());
+        lines.push(Line::from(vec![
+            label,
+            Span::styled(" ".repeat(gap), label_style),
+            keys,
+        ]));
+    }
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.selected_border())
+            .title(format!(" {} ", app.i18n.t("palette-title")))
+            .title_alignment(Alignment::Center),
+    );
+    f.render_widget(paragraph, rect);
+}
+
+fn query_line(app: &App, palette: &CommandPalette) -> Line<'static> {
+    let theme = app.theme();
+    let mut spans = vec![Span::styled(" : ", theme.accent_bold())];
+    if palette.query().is_empty() {
+        spans.push(Span::styled(
+            app.i18n.t("palette-placeholder"),
+            theme.placeholder(),
+        ));
+    } else {
+        spans.push(Span::styled(palette.query().to_string(), theme.text()));
+        spans.push(Span::styled("▏", theme.accent()));
+    }
+    Line::from(spans)
+}
+
+/// Label and keys of every row, the jump to an A-number first
+pub fn rows(app: &App, palette: &CommandPalette) -> Vec<(String, String)> {
+    let mut rows = Vec::new();
+    if let Some(number) = palette.sequence_number() {
+        let mut args = FluentArgs::new();
+        args.set("number", format!("A{:06}", number));
+        rows.push((
+            app.i18n.t_with_args("palette-jump", Some(&args)),
+            String::new(),
+        ));
+    }
+    rows.extend(
+        palette
+            .matches()
+            .map(|entry| (entry.label.clone(), entry.keys.clone())),
+    );
+    rows
+}
@@ -0,0 +1,65 @@
+use crate::app::App;
+use fluent::FluentArgs;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Small box in the bottom-right corner while a key sequence waits for its next key:
+/// the keys so far, the keys that can follow and the time left
+pub fn render(f: &mut Frame, app: &App) {
+    let pending = app.pending_keys();
+    if pending.is_empty() {
+        return;
+    }
+    let theme = app.theme();
+
+    let typed = pending
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" ");
+    let next = app
+        .pending_next_keys()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut args = FluentArgs::new();
+    args.set("keys", next);
+
+    let line = Line::from(vec![
+        Span::styled(format!(" {} …", typed), theme.accent_bold()),
+        Span::styled(
+            format!("  {}", app.i18n.t_with_args("keys-pending-next", Some(&args))),
+            theme.text(),
+        ),
+        Span::styled(
+            format!("  {:.1}s ", app.pending_key_time_left().as_secs_f32()),
+            theme.muted(),
+        ),
+    ]);
+
+    let area = f.area();
+    let width = (line.width() as u16 + 2).min(area.width);
+    let height = 3.min(area.height);
+    let rect = Rect {
+        x: area.x + area.width - width,
+        y: area.y + area.height - height,
+        width,
+        height,
+    };
+
+    f.render_widget(Clear, rect);
+    f.render_widget(
+        Paragraph::new(line).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme.selected_border())
+                .title(format!(" {} ", app.i18n.t("keys-pending-title"))),
+        ),
+        rect,
+    );
+}
@@ -0,0 +1,141 @@
+use crate::app::{App, SaveAsPrompt};
+use fluent::FluentArgs;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Completion candidates listed under the path before collapsing into "+N more"
+const MAX_VISIBLE_COMPLETIONS: usize = 8;
+
+pub fn render_modal(f: &mut Frame, app: &App) {
+    let Some(prompt) = app.save_as.as_ref() else {
+        return;
+    };
+
+    let visible = prompt.completions.len().min(MAX_VISIBLE_COMPLETIONS) as u16;
+    let extra = if prompt.completions.len() > MAX_VISIBLE_COMPLETIONS { 1 } else { 0 };
+    let height = 7 + if visible > 0 { visible + extra + 1 } else { 0 };
+    let area = centered_rect(70, height, f.area());
+    f.render_widget(Clear, area);
+
+    let paragraph = Paragraph::new(build_prompt_lines(app, prompt))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(app.theme().selected_border())
+                .title(format!(" {} ", app.i18n.t("save-as-title")))
+                .title_alignment(Alignment::Center),
+        );
+    f.render_widget(paragraph, area);
+
+    if let Some(path) = prompt.confirm_overwrite.as_ref() {
+        render_overwrite_confirm(f, app, &path.display().to_string());
+    }
+}
+
+fn build_prompt_lines(app: &App, prompt: &SaveAsPrompt) -> Vec<Line<'static>> {
+    let theme = app.theme();
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(format!("  {}: ", app.i18n.t("save-as-path")), theme.accent_bold()),
+            Span::styled(prompt.input.clone(), theme.text()),
+            Span::styled("█", theme.accent()),
+        ]),
+        Line::from(""),
+    ];
+
+    if !prompt.completions.is_empty() {
+        for (i, candidate) in prompt
+            .completions
+            .iter()
+            .enumerate()
+            .take(MAX_VISIBLE_COMPLETIONS)
+        {
+            let style = if prompt.completion_index == Some(i) {
+                theme.highlight_bold()
+            } else {
+                theme.muted()
+            };
+            lines.push(Line::from(Span::styled(format!("    {}", candidate), style)));
+        }
+        if prompt.completions.len() > MAX_VISIBLE_COMPLETIONS {
+            let mut args = FluentArgs::new();
+            args.set("count", prompt.completions.len() - MAX_VISIBLE_COMPLETIONS);
+            lines.push(Line::from(Span::styled(
+                format!("    {}", app.i18n.t_with_args("save-as-more", Some(&args))),
+                theme.muted(),
+            )));
+        }
+        lines.push(Line::from(""));
+    }
+
+    lines.push(Line::from(vec![
+        Span::styled("  Tab", theme.accent_bold()),
+        Span::styled(format!(" {} | ", app.i18n.t("save-as-help-complete")), theme.muted()),
+        Span::styled("Enter", theme.accent_bold()),
+        Span::styled(format!(" {} | ", app.i18n.t("save-as-help-save")), theme.muted()),
+        Span::styled("Esc", theme.accent_bold()),
+        Span::styled(format!(" {}", app.i18n.t("save-as-help-cancel")), theme.muted()),
+    ]));
+
+    lines
+}
+
+fn render_overwrite_confirm(f: &mut Frame, app: &App, path: &str) {
+    let theme = app.theme();
+    let area = centered_rect(56, 8, f.area());
+    f.render_widget(Clear, area);
+
+    let mut args = FluentArgs::new();
+    args.set("path", path.to_string());
+    let lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            app.i18n.t_with_args("save-as-overwrite-question", Some(&args)),
+            theme.text(),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("y", theme.danger()),
+            Span::styled(format!(" {} | ", app.i18n.t("save-as-overwrite-yes")), theme.muted()),
+            Span::styled("n", theme.accent_bold()),
+            Span::styled(format!(" {}", app.i18n.t("save-as-overwrite-no")), theme.muted()),
+        ]),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme.warning())
+                .title(format!(" {} ", app.i18n.t("save-as-overwrite-title")))
+                .title_alignment(Alignment::Center),
+        );
+    f.render_widget(paragraph, area);
+}
+
+fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
+    let horizontal = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(area);
+
+    let height = height.min(area.height);
+    let top = area.height.saturating_sub(height) / 2;
+    Rect {
+        x: horizontal[1].x,
+        y: area.y + top,
+        width: horizontal[1].width,
+        height,
+    }
+}
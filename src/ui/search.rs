@@ -1,5 +1,9 @@
 use crate::app::{App, InputMode, SearchFocus, PLACEHOLDER_EXAMPLES};
-use crate::utils::{highlight_sequence_line, highlight_terms_line};
+use crate::utils::{
+    highlight_sequence_line, highlight_terms_line,
+    keybindings::{Action, KeyContext},
+    ReferenceFocus,
+};
 use fluent::FluentArgs;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -145,6 +149,7 @@ fn render_welcome_modal(f: &mut Frame, app: &mut App) {
             Constraint::Length(3), // Search input
             Constraint::Length(2), // Action text
             Constraint::Length(6), // Body
+            Constraint::Length(1), // Sequence of the day
             Constraint::Min(1),    // Bottom spacing
         ])
         .split(inner);
@@ -183,6 +188,30 @@ fn render_welcome_modal(f: &mut Frame, app: &mut App) {
         .style(theme.muted())
         .wrap(Wrap { trim: true });
     f.render_widget(body, layout[5]);
+
+    app.daily_area = None;
+    if let Some(ref seq) = app.daily_sequence {
+        let label = format!("★ {}: ", app.i18n.t("welcome-daily"));
+        let hint = format!("  Tab {}", app.i18n.t("welcome-daily-open"));
+        let fixed = label.chars().count() + seq.a_number().len() + 1 + hint.chars().count();
+        let room = (layout[6].width as usize).saturating_sub(fixed + 2);
+        let name = if seq.name.chars().count() > room {
+            let kept: String = seq.name.chars().take(room.saturating_sub(1)).collect();
+            format!("{}…", kept)
+        } else {
+            seq.name.clone()
+        };
+
+        let daily = Paragraph::new(Line::from(vec![
+            Span::styled(label, theme.highlight()),
+            Span::styled(seq.a_number(), theme.accent_bold()),
+            Span::styled(format!(" {}", name), theme.text()),
+            Span::styled(hint, theme.muted()),
+        ]))
+        .alignment(Alignment::Center);
+        f.render_widget(daily, layout[6]);
+        app.daily_area = Some(layout[6]);
+    }
 }
 
 pub fn welcome_modal_area(area: Rect) -> Rect {
@@ -469,28 +498,43 @@ fn render_results(f: &mut Frame, area: Rect, app: &mut App) {
 
 fn render_help(f: &mut Frame, area: Rect, app: &App) {
     let theme = app.theme();
+    let keys = app.keybindings();
     let help_text = vec![Line::from(vec![
-        Span::styled("i", theme.accent_bold()),
+        Span::styled(keys.hint(KeyContext::Search, &[Action::FocusInput]), theme.accent_bold()),
         Span::raw(format!(" {} | ", app.i18n.t("search-help-search"))),
-        Span::styled("↑↓", theme.accent_bold()),
+        Span::styled(
+            keys.hint(KeyContext::Search, &[Action::Up, Action::Down]),
+            theme.accent_bold(),
+        ),
         Span::raw(format!(" {} | ", app.i18n.t("search-help-navigate"))),
-        Span::styled("←→", theme.accent_bold()),
+        Span::styled(
+            keys.hint(KeyContext::Search, &[Action::PreviousPage, Action::NextPage]),
+            theme.accent_bold(),
+        ),
         Span::raw(format!(" {} | ", app.i18n.t("search-help-page"))),
-        Span::styled("Enter", theme.accent_bold()),
+        Span::styled(keys.hint(KeyContext::Search, &[Action::Confirm]), theme.accent_bold()),
         Span::raw(format!(" {} | ", app.i18n.t("search-help-view"))),
-        Span::styled("p", theme.accent_bold()),
+        Span::styled(keys.hint(KeyContext::Search, &[Action::TogglePreview]), theme.accent_bold()),
         Span::raw(format!(" {} | ", app.i18n.t("search-help-preview"))),
-        Span::styled("b", theme.accent_bold()),
+        Span::styled(
+            keys.hint(KeyContext::Search, &[Action::ToggleBookmarks]),
+            theme.accent_bold(),
+        ),
         Span::raw(format!(" {} | ", app.i18n.t("search-help-bookmarks"))),
-        Span::styled("r", theme.accent_bold()),
+        Span::styled(keys.hint(KeyContext::Search, &[Action::Random]), theme.accent_bold()),
         Span::raw(format!(" {} | ", app.i18n.t("search-help-random"))),
-        Span::styled("w", theme.accent_bold()),
+        Span::styled(keys.hint(KeyContext::Search, &[Action::OpenWebcam]), theme.accent_bold()),
         Span::raw(format!(" {} | ", app.i18n.t("search-help-webcam"))),
-        Span::styled("s", theme.accent_bold()),
+        Span::styled(keys.hint(KeyContext::Search, &[Action::BulkExport]), theme.accent_bold()),
+        Span::raw(format!(" {} | ", app.i18n.t("search-help-export"))),
+        Span::styled(keys.hint(KeyContext::Search, &[Action::OpenSettings]), theme.accent_bold()),
         Span::raw(format!(" {} | ", app.i18n.t("search-help-settings"))),
-        Span::styled("Ctrl+H", theme.accent_bold()),
+        Span::styled(keys.hint(KeyContext::Global, &[Action::Help]), theme.accent_bold()),
         Span::raw(format!(" {} | ", app.i18n.t("search-help-help"))),
-        Span::styled("q", theme.danger().add_modifier(Modifier::BOLD)),
+        Span::styled(
+            keys.hint(KeyContext::Search, &[Action::Quit]),
+            theme.danger().add_modifier(Modifier::BOLD),
+        ),
         Span::raw(format!(" {}", app.i18n.t("search-help-quit"))),
     ])];
 
@@ -592,14 +636,14 @@ fn render_preview_tabs(f: &mut Frame, area: Rect, app: &mut App) {
 
 fn render_preview_content(f: &mut Frame, area: Rect, app: &App, seq: &crate::api::Sequence) {
     // Reuse the detail screen's rendering logic
-    let selected = None;
+    let selected = ReferenceFocus::default();
     let mut references = Vec::new();
     let theme = app.theme();
 
     let content = match app.preview_tab {
         0 => crate::ui::detail::render_overview_content(app, seq, selected, &mut references, theme),
         1 => crate::ui::detail::render_formulas_content(seq, selected, &mut references, theme),
-        2 => crate::ui::detail::render_code_content(seq, selected, &mut references, theme),
+        2 => crate::ui::detail::render_code_content(seq, selected, None, None, &mut references, theme),
         3 => crate::ui::detail::render_references_content(seq, selected, &mut references, theme),
         4 => crate::ui::detail::render_crossrefs_content(seq, selected, &mut references, theme),
         5 => crate::ui::detail::render_metadata_content(seq, selected, &mut references, theme),
@@ -1,6 +1,7 @@
 use crate::app::{App, SettingsFocus};
 use crate::i18n::Language;
 use crate::ui::animation::WelcomeAnimationMode;
+use crate::utils::keybindings::{Action, KeyContext};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::Modifier,
@@ -276,16 +277,23 @@ fn render_animation_settings(f: &mut Frame, area: Rect, app: &App) {
 
 fn render_help(f: &mut Frame, area: Rect, app: &App) {
     let theme = app.theme();
+    let keys = app.keybindings();
     let help_text = vec![Line::from(vec![
-        Span::styled("Tab", theme.accent_bold()),
+        Span::styled(keys.hint(KeyContext::Settings, &[Action::NextPanel]), theme.accent_bold()),
         Span::raw(format!(" {} | ", app.i18n.t("settings-help-switch"))),
-        Span::styled("↑↓", theme.accent_bold()),
+        Span::styled(
+            keys.hint(KeyContext::Settings, &[Action::Up, Action::Down]),
+            theme.accent_bold(),
+        ),
         Span::raw(format!(" {} | ", app.i18n.t("settings-help-navigate"))),
-        Span::styled("Enter", theme.accent_bold()),
+        Span::styled(keys.hint(KeyContext::Settings, &[Action::Confirm]), theme.accent_bold()),
         Span::raw(format!(" {} | ", app.i18n.t("settings-help-apply"))),
-        Span::styled("Ctrl+T", theme.accent_bold()),
+        Span::styled(keys.hint(KeyContext::Global, &[Action::CycleTheme]), theme.accent_bold()),
         Span::raw(format!(" {} | ", app.i18n.t("settings-help-cycle-theme"))),
-        Span::styled("Esc", theme.danger().add_modifier(Modifier::BOLD)),
+        Span::styled(
+            keys.hint(KeyContext::Settings, &[Action::Back]),
+            theme.danger().add_modifier(Modifier::BOLD),
+        ),
         Span::raw(format!(" {}", app.i18n.t("settings-help-back"))),
     ])];
 
@@ -0,0 +1,345 @@
+use crate::app::App;
+use crate::table::{NumberTable, TableShape};
+use crate::utils::keybindings::{Action, KeyContext};
+use fluent::FluentArgs;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+/// Cells wider than this are cut with an ellipsis (the footer shows the full value)
+const MAX_CELL_WIDTH: usize = 14;
+/// The row-sum column gets a little more room
+const MAX_SUM_WIDTH: usize = 20;
+
+pub fn render(f: &mut Frame, app: &mut App) {
+    let theme = app.theme().clone();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3), // Title
+            Constraint::Min(6),    // Table
+            Constraint::Length(4), // Cell and help
+        ])
+        .split(f.area());
+
+    render_title(f, chunks[0], app, &theme);
+
+    let table = app.current_table();
+    match table {
+        Some(ref table) if !table.rows.is_empty() => {
+            render_table(f, chunks[1], app, table, &theme);
+        }
+        _ => render_unavailable(f, chunks[1], app, &theme),
+    }
+
+    render_help(f, chunks[2], app, table.as_ref(), &theme);
+}
+
+fn render_title(f: &mut Frame, area: Rect, app: &App, theme: &crate::ui::Theme) {
+    let title = if let Some(ref seq) = app.current_sequence {
+        format!("{}: {} - {}", seq.a_number(), seq.name, app.i18n.t("table-title"))
+    } else {
+        app.i18n.t("table-title").to_string()
+    };
+
+    let paragraph = Paragraph::new(Line::from(Span::styled(title, theme.accent_bold())))
+        .alignment(Alignment::Center)
+        .style(theme.text())
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme.accent())
+                .title(Span::styled(
+                    format!(" {} ", app.i18n.t("table-title")),
+                    theme.accent_bold(),
+                )),
+        );
+
+    f.render_widget(paragraph, area);
+}
+
+/// Explain why there is no table, and how to get one anyway
+fn render_unavailable(f: &mut Frame, area: Rect, app: &App, theme: &crate::ui::Theme) {
+    let key = match app.current_sequence {
+        Some(ref seq) if seq.has_keyword("tabl") || seq.has_keyword("tabf") => {
+            "table-unknown-shape"
+        }
+        Some(_) => "table-not-available",
+        None => "graph-no-data",
+    };
+
+    let paragraph = Paragraph::new(vec![
+        Line::from(""),
+        Line::from(Span::styled(app.i18n.t(key).to_string(), theme.muted())),
+    ])
+    .alignment(Alignment::Center)
+    .wrap(Wrap { trim: true })
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.accent()),
+    );
+    f.render_widget(paragraph, area);
+}
+
+fn render_table(
+    f: &mut Frame,
+    area: Rect,
+    app: &mut App,
+    table: &NumberTable,
+    theme: &crate::ui::Theme,
+) {
+    let mut args = FluentArgs::new();
+    args.set("rows", table.rows.len());
+    args.set("terms", table.len());
+    let source_key = if table.from_bfile {
+        "table-source-bfile"
+    } else {
+        "table-source-data"
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(theme.accent())
+        .title(Span::styled(
+            format!(" {} ", app.i18n.t(table.shape.label_key())),
+            theme.accent_bold(),
+        ))
+        .title_bottom(
+            Line::from(Span::styled(
+                format!(" {} ", app.i18n.t_with_args(source_key, Some(&args))),
+                theme.muted(),
+            ))
+            .right_aligned(),
+        );
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    // Keep the cursor on a real cell even after the b-file replaced the data
+    let row = app.table_cursor.0.min(table.rows.len() - 1);
+    let col = app
+        .table_cursor
+        .1
+        .min(table.rows[row].len().saturating_sub(1));
+    app.table_cursor = (row, col);
+
+    let last_row = table.first_row + table.rows.len() as i64 - 1;
+    let label_width = last_row
+        .to_string()
+        .len()
+        .max(table.first_row.to_string().len())
+        .max(3);
+    let show_sums = !table.shape.is_array();
+    let sum_width = if show_sums {
+        table
+            .sums
+            .iter()
+            .flatten()
+            .map(|sum| sum.len())
+            .max()
+            .unwrap_or(1)
+            .clamp(1, MAX_SUM_WIDTH)
+    } else {
+        0
+    };
+
+    // Vertical scrolling: one line per row under the header
+    let visible_rows = (inner.height as usize).saturating_sub(1).max(1);
+    let mut row_offset = app.table_offset.0.min(row);
+    if row >= row_offset + visible_rows {
+        row_offset = row + 1 - visible_rows;
+    }
+    let shown_rows = row_offset..(row_offset + visible_rows).min(table.rows.len());
+
+    // Horizontal scrolling: as many whole columns as fit next to the labels and sums
+    let column_width = |c: usize| -> usize {
+        let header = (table.first_col + c as i64).to_string().len();
+        table.rows[shown_rows.clone()]
+            .iter()
+            .filter_map(|r| r.get(c))
+            .map(|v| v.len().min(MAX_CELL_WIDTH))
+            .max()
+            .unwrap_or(0)
+            .max(header)
+    };
+    let reserved = label_width + 1 + if show_sums { sum_width + 3 } else { 0 };
+    let available = (inner.width as usize).saturating_sub(reserved);
+    let width = table.width();
+    let fitting = |offset: usize| -> Vec<usize> {
+        let mut used = 0;
+        let mut columns = Vec::new();
+        for c in offset..width {
+            let w = column_width(c);
+            if used + w > available && !columns.is_empty() {
+                break;
+            }
+            used += w + 1;
+            columns.push(c);
+        }
+        columns
+    };
+    let mut col_offset = app.table_offset.1.min(col);
+    let mut columns = fitting(col_offset);
+    while columns.last().is_some_and(|last| *last < col) {
+        col_offset += 1;
+        columns = fitting(col_offset);
+    }
+    app.table_offset = (row_offset, col_offset);
+
+    let right = |text: String, style| Cell::from(Line::from(Span::styled(text, style)).right_aligned());
+    let mut header = vec![right("n\\k".to_string(), theme.muted())];
+    header.extend(
+        columns
+            .iter()
+            .map(|c| right((table.first_col + *c as i64).to_string(), theme.highlight_bold())),
+    );
+    if show_sums {
+        header.push(right("Σ".to_string(), theme.highlight_bold()));
+    }
+
+    let rows: Vec<Row> = shown_rows
+        .clone()
+        .map(|r| {
+            let label_style = if r == row {
+                theme.accent_bold()
+            } else {
+                theme.muted()
+            };
+            let mut cells = vec![right((table.first_row + r as i64).to_string(), label_style)];
+            cells.extend(columns.iter().map(|c| {
+                let value = table.get(r, *c).map(|v| truncate(v, MAX_CELL_WIDTH));
+                let style = if r == row && *c == col {
+                    theme.highlight_bg()
+                } else {
+                    theme.text()
+                };
+                right(value.unwrap_or_default(), style)
+            }));
+            if show_sums {
+                let sum = match table.sums.get(r) {
+                    Some(Some(sum)) => truncate(sum, MAX_SUM_WIDTH),
+                    _ => "…".to_string(),
+                };
+                cells.push(right(sum, theme.success()));
+            }
+            Row::new(cells)
+        })
+        .collect();
+
+    let mut widths = vec![Constraint::Length(label_width as u16)];
+    widths.extend(columns.iter().map(|c| Constraint::Length(column_width(*c) as u16)));
+    if show_sums {
+        widths.push(Constraint::Length(sum_width as u16 + 2));
+    }
+
+    let widget = Table::new(rows, widths)
+        .header(Row::new(header))
+        .column_spacing(1)
+        .style(theme.text());
+    f.render_widget(widget, inner);
+}
+
+/// Leading digits of `value` with an ellipsis when it is longer than `max` characters
+fn truncate(value: &str, max: usize) -> String {
+    if value.chars().count() <= max {
+        value.to_string()
+    } else {
+        let kept: String = value.chars().take(max.saturating_sub(1)).collect();
+        format!("{}…", kept)
+    }
+}
+
+fn render_help(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    table: Option<&NumberTable>,
+    theme: &crate::ui::Theme,
+) {
+    let mut lines = Vec::new();
+    if let Some(table) = table.filter(|t| !t.rows.is_empty()) {
+        lines.push(cell_line(app, table, theme));
+    } else {
+        lines.push(Line::from(""));
+    }
+    let keys = app.keybindings();
+    lines.push(Line::from(vec![
+        Span::styled(
+            keys.hint(
+                KeyContext::Table,
+                &[Action::Up, Action::Down, Action::Left, Action::Right],
+            ),
+            theme.accent_bold(),
+        ),
+        Span::raw(format!(" {} | ", app.i18n.t("table-help-move"))),
+        Span::styled(keys.hint(KeyContext::Table, &[Action::CycleShape]), theme.accent_bold()),
+        Span::raw(format!(" {} | ", app.i18n.t("table-help-shape"))),
+        Span::styled(keys.hint(KeyContext::Table, &[Action::Save]), theme.accent_bold()),
+        Span::raw(format!(" {} | ", app.i18n.t("table-help-save"))),
+        Span::styled(
+            keys.hint(KeyContext::Table, &[Action::Back]),
+            theme.danger().add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(format!(" {}", app.i18n.t("graph-help-back"))),
+    ]));
+
+    let help = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .style(theme.text())
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme.accent()),
+        );
+    f.render_widget(help, area);
+}
+
+/// T(n,k) of the selected cell in full, with its row (or antidiagonal) sum
+fn cell_line(app: &App, table: &NumberTable, theme: &crate::ui::Theme) -> Line<'static> {
+    let (row, col) = app.table_cursor;
+    let n = table.first_row + row as i64;
+    let k = table.first_col + col as i64;
+    let value = table.get(row, col).unwrap_or("");
+
+    let mut spans = vec![
+        Span::styled(format!("T({}, {}) = ", n, k), theme.muted()),
+        Span::styled(value.to_string(), theme.highlight_bold()),
+    ];
+
+    let mut args = FluentArgs::new();
+    let (sum_key, sum) = if table.shape.is_array() {
+        args.set("index", n + k);
+        ("table-antidiagonal-sum", table.sums.get(row + col))
+    } else {
+        args.set("index", n);
+        ("table-row-sum", table.sums.get(row))
+    };
+    spans.push(Span::raw(" | "));
+    spans.push(Span::styled(
+        format!("{}: ", app.i18n.t_with_args(sum_key, Some(&args))),
+        theme.muted(),
+    ));
+    spans.push(match sum {
+        Some(Some(sum)) => Span::styled(sum.clone(), theme.success()),
+        _ => Span::styled(app.i18n.t("table-sum-incomplete").to_string(), theme.muted()),
+    });
+
+    // Shape picked with `s` for an entry whose own layout could not be read
+    let guessed = app
+        .current_sequence
+        .as_ref()
+        .is_some_and(|seq| app.table_shape.is_some() && TableShape::detect(seq).is_none());
+    if guessed {
+        spans.push(Span::raw(" | "));
+        spans.push(Span::styled(
+            app.i18n.t("table-shape-chosen").to_string(),
+            theme.warning(),
+        ));
+    }
+
+    Line::from(spans)
+}
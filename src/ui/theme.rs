@@ -1,32 +1,54 @@
 use ratatui::style::{Color, Modifier, Style};
 
+/// Name of the theme the accessibility mode switches to
+pub const HIGH_CONTRAST: &str = "High Contrast";
+
 #[derive(Debug, Clone)]
 pub struct Theme {
-    #[allow(dead_code)]
-    pub name: &'static str,
-    palette: Palette,
+    pub name: String,
+    pub palette: Palette,
 }
 
-#[derive(Debug, Clone)]
-struct Palette {
-    accent: Color,
-    #[allow(dead_code)]
-    accent_dim: Color,
-    highlight: Color,
-    highlight_bg: Color,
-    text: Color,
-    muted: Color,
-    success: Color,
-    #[allow(dead_code)]
-    warning: Color,
-    danger: Color,
-    background: Color,
+/// Colours of a theme; theme files (see `utils::themes`) set them by these field names
+#[derive(Debug, Clone, PartialEq)]
+pub struct Palette {
+    pub accent: Color,
+    pub accent_dim: Color,
+    pub highlight: Color,
+    pub highlight_bg: Color,
+    pub text: Color,
+    pub muted: Color,
+    pub success: Color,
+    pub warning: Color,
+    pub danger: Color,
+    pub background: Color,
 }
 
 impl Theme {
+    /// The bundled themes, in the order they are listed and cycled through
+    pub fn builtin() -> Vec<Self> {
+        vec![
+            Self::dark(),
+            Self::light(),
+            Self::dracula(),
+            Self::nord(),
+            Self::gruvbox(),
+            Self::solarized_dark(),
+            Self::solarized_light(),
+            Self::monokai(),
+            Self::catppuccin_mocha(),
+            Self::one_dark(),
+            Self::night_owl(),
+            Self::phosphor_night(),
+            Self::punchcard_light(),
+            Self::terminal_trove(),
+            Self::high_contrast(),
+        ]
+    }
+
     pub fn dark() -> Self {
         Self {
-            name: "Dark",
+            name: "Dark".to_string(),
             palette: Palette {
                 accent: Color::Cyan,
                 accent_dim: Color::Rgb(120, 200, 220),
@@ -44,7 +66,7 @@ impl Theme {
 
     pub fn light() -> Self {
         Self {
-            name: "Light",
+            name: "Light".to_string(),
             palette: Palette {
                 accent: Color::Blue,
                 accent_dim: Color::Rgb(40, 100, 180),
@@ -62,7 +84,7 @@ impl Theme {
 
     pub fn dracula() -> Self {
         Self {
-            name: "Dracula",
+            name: "Dracula".to_string(),
             palette: Palette {
                 accent: Color::Rgb(189, 147, 249),
                 accent_dim: Color::Rgb(98, 114, 164),
@@ -80,7 +102,7 @@ impl Theme {
 
     pub fn nord() -> Self {
         Self {
-            name: "Nord",
+            name: "Nord".to_string(),
             palette: Palette {
                 accent: Color::Rgb(143, 188, 187),
                 accent_dim: Color::Rgb(94, 129, 172),
@@ -98,7 +120,7 @@ impl Theme {
 
     pub fn gruvbox() -> Self {
         Self {
-            name: "Gruvbox",
+            name: "Gruvbox".to_string(),
             palette: Palette {
                 accent: Color::Rgb(215, 153, 33),
                 accent_dim: Color::Rgb(204, 136, 34),
@@ -116,7 +138,7 @@ impl Theme {
 
     pub fn solarized_dark() -> Self {
         Self {
-            name: "Solarized Dark",
+            name: "Solarized Dark".to_string(),
             palette: Palette {
                 accent: Color::Rgb(38, 139, 210),
                 accent_dim: Color::Rgb(7, 54, 66),
@@ -134,7 +156,7 @@ impl Theme {
 
     pub fn solarized_light() -> Self {
         Self {
-            name: "Solarized Light",
+            name: "Solarized Light".to_string(),
             palette: Palette {
                 accent: Color::Rgb(38, 139, 210),
                 accent_dim: Color::Rgb(131, 148, 150),
@@ -152,7 +174,7 @@ impl Theme {
 
     pub fn monokai() -> Self {
         Self {
-            name: "Monokai",
+            name: "Monokai".to_string(),
             palette: Palette {
                 accent: Color::Rgb(249, 38, 114),
                 accent_dim: Color::Rgb(102, 217, 239),
@@ -170,7 +192,7 @@ impl Theme {
 
     pub fn catppuccin_mocha() -> Self {
         Self {
-            name: "Catppuccin Mocha",
+            name: "Catppuccin Mocha".to_string(),
             palette: Palette {
                 accent: Color::Rgb(137, 180, 250),
                 accent_dim: Color::Rgb(108, 112, 134),
@@ -188,7 +210,7 @@ impl Theme {
 
     pub fn one_dark() -> Self {
         Self {
-            name: "One Dark",
+            name: "One Dark".to_string(),
             palette: Palette {
                 accent: Color::Rgb(97, 175, 239),
                 accent_dim: Color::Rgb(92, 99, 112),
@@ -206,7 +228,7 @@ impl Theme {
 
     pub fn night_owl() -> Self {
         Self {
-            name: "Night Owl",
+            name: "Night Owl".to_string(),
             palette: Palette {
                 accent: Color::Rgb(120, 220, 232),
                 accent_dim: Color::Rgb(89, 158, 173),
@@ -224,7 +246,7 @@ impl Theme {
 
     pub fn phosphor_night() -> Self {
         Self {
-            name: "Phosphor Night",
+            name: "Phosphor Night".to_string(),
             palette: Palette {
                 // Authentic P3 phosphor CRT - black and green
                 accent: Color::Rgb(51, 255, 51), // #33FF33 - authentic P3 phosphor green
@@ -243,7 +265,7 @@ impl Theme {
 
     pub fn punchcard_light() -> Self {
         Self {
-            name: "Punchcard Light",
+            name: "Punchcard Light".to_string(),
             palette: Palette {
                 accent: Color::Rgb(15, 98, 254), // #0F62FE - IBM Blue 60
                 accent_dim: Color::Rgb(78, 70, 180), // #4E46B4 - IBM Purple 60
@@ -261,7 +283,7 @@ impl Theme {
 
     pub fn terminal_trove() -> Self {
         Self {
-            name: "Terminal Trove",
+            name: "Terminal Trove".to_string(),
             palette: Palette {
                 accent: Color::Rgb(185, 255, 172), // #B9FFAC - Terminal Trove brand green
                 accent_dim: Color::Rgb(119, 255, 96), // #77FF60 - hover state
@@ -277,9 +299,28 @@ impl Theme {
         }
     }
 
-    #[allow(dead_code)]
+    /// Used by the accessibility mode: basic colours only, white on black, and a solid
+    /// blue bar for the selection
+    pub fn high_contrast() -> Self {
+        Self {
+            name: HIGH_CONTRAST.to_string(),
+            palette: Palette {
+                accent: Color::LightCyan,
+                accent_dim: Color::Cyan,
+                highlight: Color::LightYellow,
+                highlight_bg: Color::Blue,
+                text: Color::White,
+                muted: Color::Gray,
+                success: Color::LightGreen,
+                warning: Color::LightYellow,
+                danger: Color::LightRed,
+                background: Color::Black,
+            },
+        }
+    }
+
     pub fn name(&self) -> &str {
-        self.name
+        &self.name
     }
 
     pub fn accent(&self) -> Style {
@@ -1,13 +1,21 @@
+use crate::api::client::SequenceCategory;
 use crate::app::{App, WebcamFocus, WebcamInterval};
+use crate::utils::keybindings::{Action, KeyContext};
+use fluent::FluentArgs;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::Modifier,
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph, Wrap},
     Frame,
 };
 
 pub fn render(f: &mut Frame, app: &mut App) {
+    if app.webcam_fullscreen {
+        render_fullscreen(f, app);
+        return;
+    }
+
     let theme = app.theme().clone();
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -64,24 +72,15 @@ fn render_controls(f: &mut Frame, area: Rect, app: &App, theme: &crate::ui::Them
 }
 
 fn render_categories(f: &mut Frame, area: Rect, app: &App, theme: &crate::ui::Theme) {
-    let categories = [
-        (
-            app.i18n.t("webcam-category-all"),
-            app.i18n.t("webcam-category-all-desc"),
-        ),
-        (
-            app.i18n.t("webcam-category-best"),
-            app.i18n.t("webcam-category-best-desc"),
-        ),
-        (
-            app.i18n.t("webcam-category-needing"),
-            app.i18n.t("webcam-category-needing-desc"),
-        ),
-        (
-            app.i18n.t("webcam-category-recent"),
-            app.i18n.t("webcam-category-recent-desc"),
-        ),
-    ];
+    let categories: Vec<(String, String)> = SequenceCategory::ALL
+        .iter()
+        .map(|category| {
+            (
+                app.i18n.t(category.label_key()),
+                app.i18n.t(category.description_key()),
+            )
+        })
+        .collect();
 
     let items: Vec<ListItem> = categories
         .iter()
@@ -273,6 +272,11 @@ fn render_sequence(f: &mut Frame, area: Rect, app: &App, theme: &crate::ui::Them
             lines.push(Line::from(""));
         }
 
+        // The last attempt failed; the previous sequence stays on screen
+        if let Some(line) = error_line(app, theme) {
+            lines.push(line);
+        }
+
         // Refresh status
         if let Some(interval) = app.webcam_interval {
             if let Some(last_update) = app.webcam_last_update {
@@ -314,12 +318,16 @@ fn render_sequence(f: &mut Frame, area: Rect, app: &App, theme: &crate::ui::Them
 
         f.render_widget(paragraph, area);
     } else {
-        let text = vec![
+        let mut text = vec![
             Line::from(""),
             Line::from(Span::styled(app.i18n.t("webcam-no-sequence"), theme.muted())),
             Line::from(""),
             Line::from(app.i18n.t("webcam-load-first")),
         ];
+        if let Some(line) = error_line(app, theme) {
+            text.push(Line::from(""));
+            text.push(line);
+        }
 
         let border_style = if matches!(app.webcam_focus, WebcamFocus::Sequence) {
             theme.selected_border()
@@ -340,23 +348,47 @@ fn render_sequence(f: &mut Frame, area: Rect, app: &App, theme: &crate::ui::Them
 
 fn render_help(f: &mut Frame, area: Rect, app: &App) {
     let theme = app.theme();
+    let keys = app.keybindings();
+    let pick: Vec<Action> = (0..=5).map(Action::Pick).collect();
     let help_text = vec![Line::from(vec![
-        Span::styled("Space/Enter", theme.accent_bold()),
+        Span::styled(
+            keys.keys(KeyContext::Webcam, Action::NextSequence)
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("/"),
+            theme.accent_bold(),
+        ),
         Span::raw(format!(" {} | ", app.i18n.t("webcam-help-next"))),
-        Span::styled("←→", theme.accent_bold()),
+        Span::styled(
+            keys.hint(KeyContext::Webcam, &[Action::PreviousPanel, Action::NextPanel]),
+            theme.accent_bold(),
+        ),
         Span::raw(format!(" {} | ", app.i18n.t("webcam-help-category"))),
-        Span::styled("↑↓", theme.accent_bold()),
+        Span::styled(
+            keys.hint(KeyContext::Webcam, &[Action::Up, Action::Down]),
+            theme.accent_bold(),
+        ),
         Span::raw(format!(" {} | ", app.i18n.t("webcam-help-interval"))),
-        Span::styled("0-5", theme.accent_bold()),
+        Span::styled(keys.hint(KeyContext::Webcam, &pick), theme.accent_bold()),
         Span::raw(format!(" {} | ", app.i18n.t("webcam-help-quick"))),
-        Span::styled("d", theme.accent_bold()),
+        Span::styled(keys.hint(KeyContext::Webcam, &[Action::OpenDetail]), theme.accent_bold()),
         Span::raw(format!(" {} | ", app.i18n.t("webcam-help-detail"))),
-        Span::styled("Esc", theme.danger().add_modifier(Modifier::BOLD)),
+        Span::styled(
+            keys.hint(KeyContext::Webcam, &[Action::ToggleFullscreen]),
+            theme.accent_bold(),
+        ),
+        Span::raw(format!(" {} | ", app.i18n.t("webcam-help-fullscreen"))),
+        Span::styled(
+            keys.hint(KeyContext::Webcam, &[Action::Back]),
+            theme.danger().add_modifier(Modifier::BOLD),
+        ),
         Span::raw(format!(" {}", app.i18n.t("webcam-help-back"))),
     ])];
 
     let help = Paragraph::new(help_text)
         .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
         .style(theme.text())
         .block(
             Block::default()
@@ -366,3 +398,106 @@ fn render_help(f: &mut Frame, area: Rect, app: &App) {
 
     f.render_widget(help, area);
 }
+
+/// The last fetch error, with the time left before the next attempt
+fn error_line(app: &App, theme: &crate::ui::Theme) -> Option<Line<'static>> {
+    let error = app.error_message.as_ref()?;
+    let mut text = error.clone();
+    if let Some(retry_at) = app.webcam_retry_at {
+        let mut args = FluentArgs::new();
+        args.set(
+            "seconds",
+            retry_at
+                .saturating_duration_since(std::time::Instant::now())
+                .as_secs()
+                + 1,
+        );
+        text.push_str(&format!(
+            " · {}",
+            app.i18n.t_with_args("webcam-retry-in", Some(&args))
+        ));
+    }
+    Some(Line::from(Span::styled(text, theme.warning())))
+}
+
+/// Just the sequence and a chart, for displays nobody sits at
+fn render_fullscreen(f: &mut Frame, app: &mut App) {
+    let theme = app.theme().clone();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3), // A-number and name
+            Constraint::Min(8),    // Terms and chart
+            Constraint::Length(1), // Status
+        ])
+        .split(f.area());
+
+    let Some(seq) = app.current_sequence.clone() else {
+        let mut lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                format!("{} {}", app.get_spinner_char(), app.i18n.t("webcam-loading")),
+                theme.muted(),
+            )),
+        ];
+        if let Some(line) = error_line(app, &theme) {
+            lines.push(Line::from(""));
+            lines.push(line);
+        }
+        let paragraph = Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        f.render_widget(paragraph, chunks[1]);
+        return;
+    };
+
+    let header = Paragraph::new(Line::from(vec![
+        Span::styled(seq.a_number(), theme.accent_bold()),
+        Span::raw("  "),
+        Span::styled(seq.name.clone(), theme.text().add_modifier(Modifier::BOLD)),
+    ]))
+    .alignment(Alignment::Center)
+    .wrap(Wrap { trim: true });
+    f.render_widget(header, chunks[0]);
+
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(chunks[1]);
+
+    let mut lines = vec![Line::from(Span::styled(
+        seq.data.replace(',', ", "),
+        theme.text(),
+    ))];
+    for comment in seq.comment.iter().take(3) {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(comment.clone(), theme.muted())));
+    }
+    let text = Paragraph::new(lines)
+        .wrap(Wrap { trim: true })
+        .block(Block::default().padding(Padding::horizontal(1)));
+    f.render_widget(text, body[0]);
+
+    crate::ui::graph::render_graph(f, body[1], app, &seq, &theme);
+
+    // Category and countdown, or why the sequence has not changed
+    let status = match error_line(app, &theme) {
+        Some(line) => line,
+        None => {
+            let mut parts = vec![app.i18n.t(app.webcam_category().label_key())];
+            let remaining = app
+                .webcam_interval
+                .and_then(|interval| interval.as_duration())
+                .zip(app.webcam_last_update)
+                .map(|(interval, last_update)| interval.saturating_sub(last_update.elapsed()));
+            if let Some(remaining) = remaining {
+                let mut args = FluentArgs::new();
+                args.set("seconds", remaining.as_secs());
+                parts.push(app.i18n.t_with_args("webcam-refresh-in", Some(&args)));
+            }
+            Line::from(Span::styled(parts.join(" · "), theme.muted()))
+        }
+    };
+    f.render_widget(Paragraph::new(status).alignment(Alignment::Center), chunks[2]);
+}
@@ -0,0 +1,379 @@
+//! What the terminal can show: how many colours (from `NO_COLOR`, `COLORTERM` and `TERM`)
+//! and whether its background is light or dark (asked with OSC 11).
+//!
+//! Themes keep whatever colours suit them; each frame is brought down to what the terminal
+//! supports just before it is drawn, so `#rrggbb` colours become the nearest of the 256 or
+//! 16 terminal colours, and under `NO_COLOR` there are no colours at all.
+
+use ratatui::{
+    buffer::Buffer,
+    style::{Color, Modifier},
+};
+use std::{env, time::Duration};
+
+/// Values of `TERM_PROGRAM` for terminals that do 24-bit colour without saying so in `COLORTERM`
+const TRUECOLOR_PROGRAMS: &[&str] = &["iTerm.app", "WezTerm", "vscode", "ghostty"];
+/// Parts of `TERM` that mean 24-bit colour
+const TRUECOLOR_TERMS: &[&str] = &["direct", "truecolor", "kitty", "alacritty", "foot", "wezterm"];
+
+/// The 16 ANSI colours in index order, with the usual VGA values used to find the nearest one
+const ANSI16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (170, 0, 0)),
+    (Color::Green, (0, 170, 0)),
+    (Color::Yellow, (170, 85, 0)),
+    (Color::Blue, (0, 0, 170)),
+    (Color::Magenta, (170, 0, 170)),
+    (Color::Cyan, (0, 170, 170)),
+    (Color::Gray, (170, 170, 170)),
+    (Color::DarkGray, (85, 85, 85)),
+    (Color::LightRed, (255, 85, 85)),
+    (Color::LightGreen, (85, 255, 85)),
+    (Color::LightYellow, (255, 255, 85)),
+    (Color::LightBlue, (85, 85, 255)),
+    (Color::LightMagenta, (255, 85, 255)),
+    (Color::LightCyan, (85, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+/// Channel values of the 6x6x6 colour cube at indices 16-231
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// How many colours the terminal can show
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSupport {
+    /// `NO_COLOR` is set or the terminal is `dumb`
+    None,
+    Ansi16,
+    Ansi256,
+    TrueColor,
+}
+
+impl ColorSupport {
+    pub fn detect() -> Self {
+        Self::from_env(|name| env::var(name).ok())
+    }
+
+    /// Worked out from environment variables looked up with `var`
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Self {
+        let var = |name: &str| var(name).filter(|value| !value.is_empty());
+        // https://no-color.org: any non-empty value
+        if var("NO_COLOR").is_some() {
+            return Self::None;
+        }
+        if var("COLORTERM").is_some_and(|value| {
+            value.eq_ignore_ascii_case("truecolor") || value.eq_ignore_ascii_case("24bit")
+        }) {
+            return Self::TrueColor;
+        }
+        if var("WT_SESSION").is_some()
+            || var("TERM_PROGRAM").is_some_and(|program| TRUECOLOR_PROGRAMS.contains(&program.as_str()))
+        {
+            return Self::TrueColor;
+        }
+
+        let term = var("TERM").unwrap_or_default().to_ascii_lowercase();
+        if term == "dumb" {
+            Self::None
+        } else if TRUECOLOR_TERMS.iter().any(|part| term.contains(part)) {
+            Self::TrueColor
+        } else if term.contains("256") {
+            Self::Ansi256
+        } else {
+            Self::Ansi16
+        }
+    }
+
+    /// `color`, or the nearest colour the terminal can show
+    pub fn convert(self, color: Color) -> Color {
+        match (self, color) {
+            (_, Color::Reset) | (Self::TrueColor, _) => color,
+            (Self::None, _) => Color::Reset,
+            (Self::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(rgb_to_256(r, g, b)),
+            (Self::Ansi256, _) => color,
+            (Self::Ansi16, Color::Rgb(r, g, b)) => rgb_to_16(r, g, b),
+            (Self::Ansi16, Color::Indexed(index)) => match ANSI16.get(index as usize) {
+                Some(&(named, _)) => named,
+                None => {
+                    let (r, g, b) = indexed_rgb(index);
+                    rgb_to_16(r, g, b)
+                }
+            },
+            (Self::Ansi16, _) => color,
+        }
+    }
+
+    /// Bring a drawn frame down to the colours the terminal can show. Without colours,
+    /// cells set apart by their background (selections, highlighted rows) are shown
+    /// reversed instead; `background` is the theme's own background, which is not.
+    pub fn apply(self, buffer: &mut Buffer, background: Color) {
+        if self == Self::TrueColor {
+            return;
+        }
+        for cell in buffer.content.iter_mut() {
+            if self == Self::None && cell.bg != Color::Reset && cell.bg != background {
+                cell.modifier.insert(Modifier::REVERSED);
+            }
+            cell.fg = self.convert(cell.fg);
+            cell.bg = self.convert(cell.bg);
+        }
+    }
+}
+
+/// Nearest entry of the colour cube or the grey ramp
+fn rgb_to_256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |value: u8| {
+        CUBE_LEVELS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, level)| (i32::from(**level) - i32::from(value)).abs())
+            .map(|(index, _)| index as u8)
+            .unwrap_or(0)
+    };
+    let (lr, lg, lb) = (level(r), level(g), level(b));
+    let cube = 16 + 36 * lr + 6 * lg + lb;
+
+    // Grey ramp 232-255 runs from 8 to 238 in steps of 10
+    let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let grey = 232 + step;
+
+    let target = (r, g, b);
+    if distance(indexed_rgb(grey), target) < distance(indexed_rgb(cube), target) {
+        grey
+    } else {
+        cube
+    }
+}
+
+fn rgb_to_16(r: u8, g: u8, b: u8) -> Color {
+    ANSI16
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb, (r, g, b)))
+        .map(|&(color, _)| color)
+        .unwrap_or(Color::Reset)
+}
+
+/// The colour at a 256-colour index
+fn indexed_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI16[index as usize].1,
+        16..=231 => {
+            let index = index - 16;
+            (
+                CUBE_LEVELS[(index / 36) as usize],
+                CUBE_LEVELS[(index / 6 % 6) as usize],
+                CUBE_LEVELS[(index % 6) as usize],
+            )
+        }
+        _ => {
+            let value = 8 + 10 * (index - 232);
+            (value, value, value)
+        }
+    }
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let channel = |x: u8, y: u8| (i32::from(x) - i32::from(y)).unsigned_abs().pow(2);
+    channel(a.0, b.0) + channel(a.1, b.1) + channel(a.2, b.2)
+}
+
+/// Whether the terminal background is light or dark
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Background {
+    Light,
+    Dark,
+}
+
+impl Background {
+    /// From the answer to OSC 11, `ESC ] 11 ; rgb:RRRR/GGGG/BBBB` (one to four hex digits
+    /// per channel) ended by BEL or ST
+    pub fn from_osc11(reply: &[u8]) -> Option<Self> {
+        let reply = String::from_utf8_lossy(reply);
+        let start = reply.find("]11;rgb:")? + "]11;rgb:".len();
+        let color = &reply[start..];
+        let end = color.find(['\x07', '\x1b']).unwrap_or(color.len());
+
+        let mut channels = color[..end].split('/').map(|hex| {
+            if hex.is_empty() || hex.len() > 4 {
+                return None;
+            }
+            let value = u32::from_str_radix(hex, 16).ok()?;
+            let max = (1u32 << (4 * hex.len())) - 1;
+            Some(f64::from(value) / f64::from(max))
+        });
+        let (r, g, b) = (channels.next()??, channels.next()??, channels.next()??);
+        if channels.next().is_some() {
+            return None;
+        }
+        Some(Self::from_luminance(0.2126 * r + 0.7152 * g + 0.0722 * b))
+    }
+
+    /// From `COLORFGBG` (`15;0` is white on black), which rxvt, Konsole and some others set
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        let value = var("COLORFGBG")?;
+        let background: u8 = value.rsplit(';').next()?.trim().parse().ok()?;
+        Some(match background {
+            7 | 9..=15 => Self::Light,
+            _ => Self::Dark,
+        })
+    }
+
+    fn from_luminance(luminance: f64) -> Self {
+        if luminance > 0.5 {
+            Self::Light
+        } else {
+            Self::Dark
+        }
+    }
+}
+
+/// Ask the terminal for its background colour, falling back to `COLORFGBG`. Raw mode has
+/// to be on, so that the answer is neither echoed nor held back until Enter.
+pub fn detect_background(timeout: Duration) -> Option<Background> {
+    query_background(timeout).or_else(|| Background::from_env(|name| env::var(name).ok()))
+}
+
+#[cfg(unix)]
+fn query_background(timeout: Duration) -> Option<Background> {
+    use std::io::{self, IsTerminal, Write};
+
+    if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        return None;
+    }
+    let mut stdout = io::stdout();
+    // Every terminal answers the device attributes request (`ESC [ c`), so its answer
+    // marks the end of the reply even when OSC 11 goes unanswered
+    stdout.write_all(b"\x1b]11;?\x1b\\\x1b[c").ok()?;
+    stdout.flush().ok()?;
+    Background::from_osc11(&read_reply(timeout))
+}
+
+#[cfg(not(unix))]
+fn query_background(_timeout: Duration) -> Option<Background> {
+    None
+}
+
+/// Read stdin until the device attributes answer (`ESC [ ? … c`) arrives or time is up,
+/// without leaving anything half-read for the key event reader
+#[cfg(unix)]
+fn read_reply(timeout: Duration) -> Vec<u8> {
+    use std::{io, os::fd::AsRawFd, time::Instant};
+
+    let fd = io::stdin().as_raw_fd();
+    let deadline = Instant::now() + timeout;
+    let mut reply = Vec::new();
+    while !attributes_answered(&reply) {
+        let Some(left) = deadline.checked_duration_since(Instant::now()) else {
+            break;
+        };
+        let mut pollfd = libc::pollfd {
+            fd,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        // SAFETY: `pollfd` is a single valid entry and the count says so
+        let ready = unsafe { libc::poll(&mut pollfd, 1, left.as_millis() as libc::c_int) };
+        if ready <= 0 {
+            break;
+        }
+        let mut buf = [0u8; 256];
+        // SAFETY: reads at most `buf.len()` bytes into `buf`
+        let read = unsafe { libc::read(fd, buf.as_mut_ptr().cast(), buf.len()) };
+        if read <= 0 {
+            break;
+        }
+        reply.extend_from_slice(&buf[..read as usize]);
+    }
+    reply
+}
+
+#[cfg(unix)]
+fn attributes_answered(reply: &[u8]) -> bool {
+    reply
+        .windows(3)
+        .rposition(|window| window == b"\x1b[?")
+        .is_some_and(|start| reply[start..].contains(&b'c'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn support(vars: &[(&str, &str)]) -> ColorSupport {
+        let vars: HashMap<&str, &str> = vars.iter().copied().collect();
+        ColorSupport::from_env(|name| vars.get(name).map(|value| value.to_string()))
+    }
+
+    #[test]
+    fn test_detect_support() {
+        assert_eq!(support(&[("TERM", "xterm-256color")]), ColorSupport::Ansi256);
+        assert_eq!(
+            support(&[("TERM", "xterm-256color"), ("COLORTERM", "truecolor")]),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(support(&[("TERM", "xterm-kitty")]), ColorSupport::TrueColor);
+        assert_eq!(
+            support(&[("TERM", "xterm-256color"), ("TERM_PROGRAM", "iTerm.app")]),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(support(&[("TERM", "xterm")]), ColorSupport::Ansi16);
+        assert_eq!(support(&[("TERM", "linux")]), ColorSupport::Ansi16);
+        assert_eq!(support(&[("TERM", "dumb")]), ColorSupport::None);
+        // NO_COLOR wins over everything, but only when it is not empty
+        assert_eq!(
+            support(&[("NO_COLOR", "1"), ("COLORTERM", "truecolor")]),
+            ColorSupport::None
+        );
+        assert_eq!(support(&[("NO_COLOR", ""), ("TERM", "xterm-256color")]), ColorSupport::Ansi256);
+    }
+
+    #[test]
+    fn test_convert() {
+        let rgb = Color::Rgb(60, 60, 60);
+        assert_eq!(ColorSupport::TrueColor.convert(rgb), rgb);
+        assert_eq!(ColorSupport::Ansi256.convert(rgb), Color::Indexed(237));
+        assert_eq!(ColorSupport::Ansi256.convert(Color::Rgb(255, 0, 0)), Color::Indexed(196));
+        assert_eq!(ColorSupport::Ansi256.convert(Color::Cyan), Color::Cyan);
+        assert_eq!(ColorSupport::Ansi16.convert(rgb), Color::DarkGray);
+        assert_eq!(ColorSupport::Ansi16.convert(Color::Rgb(240, 220, 150)), Color::LightYellow);
+        assert_eq!(ColorSupport::Ansi16.convert(Color::Indexed(9)), Color::LightRed);
+        assert_eq!(ColorSupport::Ansi16.convert(Color::Indexed(196)), Color::Red);
+        assert_eq!(ColorSupport::None.convert(Color::Cyan), Color::Reset);
+    }
+
+    #[test]
+    fn test_apply_without_colors() {
+        let mut buffer = Buffer::empty(ratatui::layout::Rect::new(0, 0, 2, 1));
+        buffer.content[0].bg = Color::Black;
+        buffer.content[1].bg = Color::Rgb(60, 60, 60);
+        buffer.content[1].fg = Color::Yellow;
+        ColorSupport::None.apply(&mut buffer, Color::Black);
+
+        assert_eq!(buffer.content[0].bg, Color::Reset);
+        assert!(!buffer.content[0].modifier.contains(Modifier::REVERSED));
+        // The highlighted cell stays visible
+        assert_eq!(buffer.content[1].fg, Color::Reset);
+        assert!(buffer.content[1].modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn test_background() {
+        assert_eq!(
+            Background::from_osc11(b"\x1b]11;rgb:ffff/ffff/dddd\x1b\\\x1b[?62;22c"),
+            Some(Background::Light)
+        );
+        assert_eq!(
+            Background::from_osc11(b"\x1b]11;rgb:1e/1e/2e\x07"),
+            Some(Background::Dark)
+        );
+        // Only the device attributes answer: OSC 11 is not supported
+        assert_eq!(Background::from_osc11(b"\x1b[?1;2c"), None);
+        assert_eq!(Background::from_osc11(b"\x1b]11;rgb:zz/00/00\x07"), None);
+
+        let env = |value: &'static str| move |_: &str| Some(value.to_string());
+        assert_eq!(Background::from_env(env("15;0")), Some(Background::Dark));
+        assert_eq!(Background::from_env(env("0;default;15")), Some(Background::Light));
+        assert_eq!(Background::from_env(env("default")), None);
+    }
+}
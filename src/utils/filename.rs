@@ -0,0 +1,99 @@
+//! Export filename templates such as `{anumber}-{slug}.{ext}`.
+//!
+//! Supported placeholders: `{anumber}` (A000045), `{number}` (45), `{slug}`
+//! (a short lowercase form of the sequence name) and `{ext}`. Unknown
+//! placeholders are left untouched.
+
+use crate::api::Sequence;
+
+/// Template used when the user has not configured one (matches the historic `A000045.json`).
+pub const DEFAULT_TEMPLATE: &str = "{anumber}.{ext}";
+
+/// Longest slug produced for `{slug}`, in bytes
+const MAX_SLUG_LEN: usize = 40;
+
+/// Expand a filename template for `seq`; an empty template falls back to [`DEFAULT_TEMPLATE`].
+pub fn render_template(template: &str, seq: &Sequence, ext: &str) -> String {
+    let template = if template.trim().is_empty() {
+        DEFAULT_TEMPLATE
+    } else {
+        template.trim()
+    };
+
+    template
+        .replace("{anumber}", &seq.a_number())
+        .replace("{number}", &seq.number.to_string())
+        .replace("{slug}", &slugify(&seq.name))
+        .replace("{ext}", ext)
+}
+
+/// Lowercase ASCII slug of `name`, cut at a word boundary (e.g. "fibonacci-numbers").
+pub fn slugify(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    if slug.len() > MAX_SLUG_LEN {
+        let cut = slug[..=MAX_SLUG_LEN].rfind('-').unwrap_or(MAX_SLUG_LEN);
+        slug.truncate(cut);
+    }
+
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() {
+        "sequence".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Sequence {
+        serde_json::from_value(serde_json::json!({
+            "number": 45,
+            "data": "0,1,1,2,3,5,8",
+            "name": "Fibonacci numbers: F(n) = F(n-1) + F(n-2) with F(0) = 0 and F(1) = 1.",
+            "offset": "0,4",
+            "keyword": "core,nonn,nice,easy",
+            "author": "N. J. A. Sloane",
+            "created": "1991-04-30T03:00:00-04:00",
+            "time": "2024-01-01T00:00:00-05:00",
+            "revision": 1,
+            "references": 0
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Fibonacci numbers"), "fibonacci-numbers");
+        assert_eq!(slugify("  The primes.  "), "the-primes");
+        assert_eq!(slugify("Number of groups of order n."), "number-of-groups-of-order-n");
+        assert_eq!(slugify("a(n) = 2^n"), "a-n-2-n");
+        assert_eq!(slugify("∑"), "sequence");
+
+        let long = slugify("Fibonacci numbers: F(n) = F(n-1) + F(n-2) with F(0) = 0 and F(1) = 1.");
+        assert!(long.len() <= MAX_SLUG_LEN);
+        assert!(long.starts_with("fibonacci-numbers-f-n"));
+        assert!(!long.ends_with('-'));
+    }
+
+    #[test]
+    fn test_render_template() {
+        let seq = sample();
+        assert_eq!(render_template("", &seq, "json"), "A000045.json");
+        assert_eq!(
+            render_template("{anumber}-{slug}.{ext}", &seq, "md"),
+            format!("A000045-{}.md", slugify(&seq.name))
+        );
+        assert_eq!(render_template("oeis_{number}.{ext}", &seq, "csv"), "oeis_45.csv");
+        assert_eq!(render_template("{anumber}-{other}.{ext}", &seq, "txt"), "A000045-{other}.txt");
+    }
+}
@@ -1,7 +1,22 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::Duration;
+
+/// How long a started key sequence (`g g`, `ctrl+x ctrl+s`) waits for its next key
+const DEFAULT_SEQUENCE_TIMEOUT_MS: u64 = 1000;
+
+/// Bundled presets, in the order `toggle_keybindings_preset` cycles through them
+pub const PRESETS: [&str; 4] = ["default", "vim", "emacs", "helix"];
 
 /// User-editable keybinding configuration (stored as strings in settings.json).
+///
+/// The global actions keep their own fields; every other screen has a table from action
+/// names to keys, e.g. `"detail": { "open_graph": ["G"], "notebook": [] }`. An empty list
+/// unbinds the action, and space-separated keys (`"g g"`, `"ctrl+x ctrl+s"`) are pressed
+/// one after the other.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct KeyBindingsConfig {
     pub quit: Option<Vec<String>>,
@@ -10,110 +25,1060 @@ pub struct KeyBindingsConfig {
     pub cycle_theme: Option<Vec<String>>,
     pub toggle_keybindings_preset: Option<Vec<String>>,
     pub preset: Option<String>,
+    /// How long to wait for the next key of a sequence, in milliseconds
+    pub sequence_timeout_ms: Option<u64>,
+    /// Per-screen tables, keyed by [`KeyContext::name`]
+    #[serde(flatten)]
+    pub screens: BTreeMap<String, Value>,
+}
+
+/// Where a binding applies. The welcome window falls back to `Search`, and the
+/// Overview, Code, Export and Graph tabs of the detail view fall back to `Detail`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KeyContext {
+    Global,
+    Search,
+    Welcome,
+    Detail,
+    Overview,
+    Code,
+    Export,
+    Graph,
+    Table,
+    Webcam,
+    Settings,
+    /// Help and about windows
+    Modal,
 }
 
+impl KeyContext {
+    pub const ALL: [Self; 12] = [
+        Self::Global,
+        Self::Search,
+        Self::Welcome,
+        Self::Detail,
+        Self::Overview,
+        Self::Code,
+        Self::Export,
+        Self::Graph,
+        Self::Table,
+        Self::Webcam,
+        Self::Settings,
+        Self::Modal,
+    ];
+
+    /// Section name in settings.json
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Global => "global",
+            Self::Search => "search",
+            Self::Welcome => "welcome",
+            Self::Detail => "detail",
+            Self::Overview => "overview",
+            Self::Code => "code",
+            Self::Export => "export",
+            Self::Graph => "graph",
+            Self::Table => "table",
+            Self::Webcam => "webcam",
+            Self::Settings => "settings",
+            Self::Modal => "modal",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|context| context.name() == name)
+    }
+
+    /// Section header in the help window
+    pub fn label_key(self) -> &'static str {
+        match self {
+            Self::Global => "help-global",
+            Self::Search => "help-search",
+            Self::Welcome => "help-welcome",
+            Self::Detail => "help-detail",
+            Self::Overview => "help-overview",
+            Self::Code => "help-code",
+            Self::Export => "help-export",
+            Self::Graph => "help-graph",
+            Self::Table => "help-table",
+            Self::Webcam => "help-webcam",
+            Self::Settings => "help-settings",
+            Self::Modal => "help-modal",
+        }
+    }
+}
+
+/// Everything a key can do. Navigation actions are shared between screens and mean
+/// whatever moving up, confirming or going back means there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    // Global
+    Quit,
+    Help,
+    About,
+    CycleTheme,
+    TogglePreset,
+    CommandPalette,
+    // Shared
+    Back,
+    Confirm,
+    Up,
+    Down,
+    Left,
+    Right,
+    PageUp,
+    PageDown,
+    Top,
+    Bottom,
+    LineStart,
+    LineEnd,
+    NextPanel,
+    PreviousPanel,
+    Save,
+    Copy,
+    /// Numbered choice: preview tab, export format, graph type, interval or language
+    Pick(u8),
+    // Search
+    FocusInput,
+    FocusHistory,
+    PreviousPage,
+    NextPage,
+    Random,
+    TogglePreview,
+    ToggleBookmarks,
+    PreviewUp,
+    PreviewDown,
+    OpenWebcam,
+    OpenSettings,
+    BulkExport,
+    OpenDaily,
+    // Detail
+    DetailHelp,
+    NextTab,
+    PreviousTab,
+    OpenGraph,
+    ExportTab,
+    OpenBrowser,
+    ToggleBookmark,
+    FetchBFile,
+    OpenTable,
+    Notebook,
+    MorePlaces,
+    FewerPlaces,
+    NextSnippet,
+    PreviousSnippet,
+    Verify,
+    // Graph
+    NextGraphType,
+    PreviousGraphType,
+    ModulusUp,
+    ModulusDown,
+    ZoomIn,
+    ZoomOut,
+    ResetView,
+    Listen,
+    // Table
+    CycleShape,
+    // Webcam
+    NextSequence,
+    OpenDetail,
+    ToggleFullscreen,
+}
+
+/// Names used in settings.json (`Pick(n)` is `pick_n`)
+const ACTION_NAMES: &[(Action, &str)] = &[
+    (Action::Quit, "quit"),
+    (Action::Help, "help"),
+    (Action::About, "about"),
+    (Action::CycleTheme, "cycle_theme"),
+    (Action::TogglePreset, "toggle_keybindings_preset"),
+    (Action::CommandPalette, "command_palette"),
+    (Action::Back, "back"),
+    (Action::Confirm, "confirm"),
+    (Action::Up, "up"),
+    (Action::Down, "down"),
+    (Action::Left, "left"),
+    (Action::Right, "right"),
+    (Action::PageUp, "page_up"),
+    (Action::PageDown, "page_down"),
+    (Action::Top, "top"),
+    (Action::Bottom, "bottom"),
+    (Action::LineStart, "line_start"),
+    (Action::LineEnd, "line_end"),
+    (Action::NextPanel, "next_panel"),
+    (Action::PreviousPanel, "previous_panel"),
+    (Action::Save, "save"),
+    (Action::Copy, "copy"),
+    (Action::FocusInput, "focus_input"),
+    (Action::FocusHistory, "focus_history"),
+    (Action::PreviousPage, "previous_page"),
+    (Action::NextPage, "next_page"),
+    (Action::Random, "random"),
+    (Action::TogglePreview, "toggle_preview"),
+    (Action::ToggleBookmarks, "toggle_bookmarks"),
+    (Action::PreviewUp, "preview_up"),
+    (Action::PreviewDown, "preview_down"),
+    (Action::OpenWebcam, "open_webcam"),
+    (Action::OpenSettings, "open_settings"),
+    (Action::BulkExport, "bulk_export"),
+    (Action::OpenDaily, "open_daily"),
+    (Action::DetailHelp, "detail_help"),
+    (Action::NextTab, "next_tab"),
+    (Action::PreviousTab, "previous_tab"),
+    (Action::OpenGraph, "open_graph"),
+    (Action::ExportTab, "export_tab"),
+    (Action::OpenBrowser, "open_browser"),
+    (Action::ToggleBookmark, "toggle_bookmark"),
+    (Action::FetchBFile, "fetch_bfile"),
+    (Action::OpenTable, "open_table"),
+    (Action::Notebook, "notebook"),
+    (Action::MorePlaces, "more_places"),
+    (Action::FewerPlaces, "fewer_places"),
+    (Action::NextSnippet, "next_snippet"),
+    (Action::PreviousSnippet, "previous_snippet"),
+    (Action::Verify, "verify"),
+    (Action::NextGraphType, "next_graph_type"),
+    (Action::PreviousGraphType, "previous_graph_type"),
+    (Action::ModulusUp, "modulus_up"),
+    (Action::ModulusDown, "modulus_down"),
+    (Action::ZoomIn, "zoom_in"),
+    (Action::ZoomOut, "zoom_out"),
+    (Action::ResetView, "reset_view"),
+    (Action::Listen, "listen"),
+    (Action::CycleShape, "cycle_shape"),
+    (Action::NextSequence, "next_sequence"),
+    (Action::OpenDetail, "open_detail"),
+    (Action::ToggleFullscreen, "toggle_fullscreen"),
+];
+
+impl Action {
+    /// Name used in settings.json
+    pub fn name(self) -> String {
+        if let Action::Pick(n) = self {
+            return format!("pick_{}", n);
+        }
+        ACTION_NAMES
+            .iter()
+            .find(|(action, _)| *action == self)
+            .map(|(_, name)| name.to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        if let Some(n) = name.strip_prefix("pick_") {
+            return n.parse().ok().map(Action::Pick);
+        }
+        ACTION_NAMES
+            .iter()
+            .find(|(_, n)| *n == name)
+            .map(|(action, _)| *action)
+    }
+
+    /// Label (i18n key) in the command palette; moving around and numbered choices are
+    /// left out, as they only make sense as keys
+    pub fn label_key(self) -> Option<&'static str> {
+        use Action::*;
+        Some(match self {
+            Quit => "action-quit",
+            Help => "action-help",
+            About => "action-about",
+            CycleTheme => "action-cycle-theme",
+            TogglePreset => "action-toggle-preset",
+            Back => "action-back",
+            NextPanel => "action-next-panel",
+            PreviousPanel => "action-previous-panel",
+            Save => "action-save",
+            Copy => "action-copy",
+            FocusInput => "action-focus-input",
+            FocusHistory => "action-focus-history",
+            PreviousPage => "action-previous-page",
+            NextPage => "action-next-page",
+            Random => "action-random",
+            TogglePreview => "action-toggle-preview",
+            ToggleBookmarks => "action-toggle-bookmarks",
+            OpenWebcam => "action-open-webcam",
+            OpenSettings => "action-open-settings",
+            BulkExport => "action-bulk-export",
+            OpenDaily => "action-open-daily",
+            DetailHelp => "action-detail-help",
+            NextTab => "action-next-tab",
+            PreviousTab => "action-previous-tab",
+            OpenGraph => "action-open-graph",
+            ExportTab => "action-export-tab",
+            OpenBrowser => "action-open-browser",
+            ToggleBookmark => "action-toggle-bookmark",
+            FetchBFile => "action-fetch-bfile",
+            OpenTable => "action-open-table",
+            Notebook => "action-notebook",
+            MorePlaces => "action-more-places",
+            FewerPlaces => "action-fewer-places",
+            NextSnippet => "action-next-snippet",
+            PreviousSnippet => "action-previous-snippet",
+            Verify => "action-verify",
+            NextGraphType => "action-next-graph-type",
+            PreviousGraphType => "action-previous-graph-type",
+            ModulusUp => "action-modulus-up",
+            ModulusDown => "action-modulus-down",
+            ZoomIn => "action-zoom-in",
+            ZoomOut => "action-zoom-out",
+            ResetView => "action-reset-view",
+            Listen => "action-listen",
+            CycleShape => "action-cycle-shape",
+            NextSequence => "action-next-sequence",
+            OpenDetail => "action-open-detail",
+            ToggleFullscreen => "action-toggle-fullscreen",
+            CommandPalette | Confirm | Up | Down | Left | Right | PageUp | PageDown | Top
+            | Bottom | LineStart | LineEnd | PreviewUp | PreviewDown | Pick(_) => return None,
+        })
+    }
+}
+
+/// Keys bound to one action on one screen
+#[derive(Debug, Clone)]
+pub struct Binding {
+    pub action: Action,
+    pub keys: Vec<KeySequence>,
+    /// Help text (i18n key) shown next to the keys
+    pub help: &'static str,
+    /// Set from settings.json rather than the preset
+    custom: bool,
+}
+
+/// Something in settings.json that could not be used as written
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeyBindingWarning {
+    /// One key bound to two actions; a global binding wins over a screen's
+    Conflict {
+        key: KeySequence,
+        first: (KeyContext, Action),
+        second: (KeyContext, Action),
+    },
+    UnknownContext(String),
+    /// A section that is not an object of action names to keys
+    BadSection(KeyContext),
+    /// Unknown action, or one that does nothing on that screen
+    UnknownAction(KeyContext, String),
+    BadKey {
+        context: KeyContext,
+        action: Action,
+        key: String,
+    },
+}
+
+impl KeyBindingWarning {
+    /// i18n key of the message
+    pub fn message_key(&self) -> &'static str {
+        match self {
+            Self::Conflict { .. } => "keybindings-conflict",
+            Self::UnknownContext(_) => "keybindings-unknown-context",
+            Self::BadSection(_) => "keybindings-bad-section",
+            Self::UnknownAction(..) => "keybindings-unknown-action",
+            Self::BadKey { .. } => "keybindings-bad-key",
+        }
+    }
+
+    /// Arguments of the message
+    pub fn args(&self) -> Vec<(&'static str, String)> {
+        let qualified = |(context, action): &(KeyContext, Action)| {
+            format!("{}.{}", context.name(), action.name())
+        };
+        match self {
+            Self::Conflict { key, first, second } => vec![
+                ("key", key.to_string()),
+                ("first", qualified(first)),
+                ("second", qualified(second)),
+            ],
+            Self::UnknownContext(context) => vec![("context", context.clone())],
+            Self::BadSection(context) => vec![("context", context.name().to_string())],
+            Self::UnknownAction(context, action) => vec![
+                ("context", context.name().to_string()),
+                ("action", action.clone()),
+            ],
+            Self::BadKey {
+                context,
+                action,
+                key,
+            } => vec![
+                ("context", context.name().to_string()),
+                ("action", action.name()),
+                ("key", key.clone()),
+            ],
+        }
+    }
+}
+
+/// Resolved bindings for every screen
 #[derive(Debug, Clone)]
 pub struct KeyBindings {
-    pub quit: Vec<KeyChord>,
-    pub help: Vec<KeyChord>,
-    pub about: Vec<KeyChord>,
-    pub cycle_theme: Vec<KeyChord>,
-    pub toggle_keybindings_preset: Vec<KeyChord>,
+    tables: BTreeMap<KeyContext, Vec<Binding>>,
+    warnings: Vec<KeyBindingWarning>,
+    sequence_timeout: Duration,
 }
 
 impl KeyBindings {
     pub fn from_config(config: Option<&KeyBindingsConfig>, preset: Option<&str>) -> Self {
-        let default = Self::preset_or_default(preset);
+        let mut bindings = Self::preset_or_default(preset);
+        let Some(config) = config else {
+            return bindings;
+        };
+        if let Some(ms) = config.sequence_timeout_ms {
+            bindings.sequence_timeout = Duration::from_millis(ms);
+        }
 
-        let resolve = |custom: Option<&Vec<String>>, fallback: &[KeyChord]| -> Vec<KeyChord> {
-            if let Some(list) = custom {
-                let parsed = list
-                    .iter()
-                    .filter_map(|s| KeyChord::parse(s))
-                    .collect::<Vec<_>>();
-                if !parsed.is_empty() {
-                    return parsed;
-                }
+        for (name, section) in &config.screens {
+            let Some(context) = KeyContext::from_name(name) else {
+                bindings
+                    .warnings
+                    .push(KeyBindingWarning::UnknownContext(name.clone()));
+                continue;
+            };
+            let Some(entries) = section.as_object() else {
+                bindings.warnings.push(KeyBindingWarning::BadSection(context));
+                continue;
+            };
+            for (action_name, keys) in entries {
+                let action = Action::from_name(action_name)
+                    .filter(|action| bindings.binding(context, *action).is_some());
+                let Some(action) = action else {
+                    bindings
+                        .warnings
+                        .push(KeyBindingWarning::UnknownAction(context, action_name.clone()));
+                    continue;
+                };
+                let keys = match keys {
+                    Value::String(key) => vec![key.clone()],
+                    Value::Array(items) => items
+                        .iter()
+                        .map(|item| match item {
+                            Value::String(key) => key.clone(),
+                            other => other.to_string(),
+                        })
+                        .collect(),
+                    other => vec![other.to_string()],
+                };
+                bindings.rebind(context, action, &keys);
             }
-            fallback.to_vec()
-        };
+        }
 
-        let fallback_config = KeyBindingsConfig::default();
-        let config = config.unwrap_or(&fallback_config);
+        // The older top-level fields; an empty list keeps the preset's keys
+        let legacy = [
+            (Action::Quit, &config.quit),
+            (Action::Help, &config.help),
+            (Action::About, &config.about),
+            (Action::CycleTheme, &config.cycle_theme),
+            (Action::TogglePreset, &config.toggle_keybindings_preset),
+        ];
+        for (action, keys) in legacy {
+            if let Some(keys) = keys.as_ref().filter(|keys| !keys.is_empty()) {
+                bindings.rebind(KeyContext::Global, action, keys);
+            }
+        }
+
+        let conflicts = bindings.conflicts();
+        bindings.warnings.extend(conflicts);
+        bindings
+    }
 
+    pub fn preset_or_default(preset: Option<&str>) -> Self {
+        match preset {
+            Some("vim") => Self::vim(),
+            Some("emacs") => Self::emacs(),
+            Some("helix") => Self::helix(),
+            _ => Self::default(),
+        }
+    }
+
+    pub fn vim() -> Self {
+        let mut bindings = Self::with_global(vec![
+            bind(Action::Quit, &["q", "ctrl+c"], "help-global-quit"),
+            bind(Action::Help, &["?"], "help-global-help"),
+            bind(Action::About, &["A"], "help-global-about"),
+            bind(Action::CycleTheme, &["ctrl+t"], "help-global-theme"),
+            bind(Action::TogglePreset, &["ctrl+v"], "help-global-preset"),
+            bind(Action::CommandPalette, &[":", "ctrl+p"], "help-global-palette"),
+        ]);
+        bindings.add_keys(&[
+            (Action::Top, &["g g"]),
+            (Action::Bottom, &["G"]),
+            (Action::NextTab, &["g t"]),
+            (Action::PreviousTab, &["g T"]),
+        ]);
+        bindings
+    }
+
+    /// Ctrl-x prefixes for the global commands, and Emacs motion keys on every screen
+    pub fn emacs() -> Self {
+        let mut bindings = Self::with_global(vec![
+            bind(Action::Quit, &["ctrl+x ctrl+c", "ctrl+c"], "help-global-quit"),
+            bind(Action::Help, &["ctrl+h", "f1"], "help-global-help"),
+            bind(Action::About, &["ctrl+x ctrl+a"], "help-global-about"),
+            bind(Action::CycleTheme, &["ctrl+x t"], "help-global-theme"),
+            bind(Action::TogglePreset, &["ctrl+x ctrl+v"], "help-global-preset"),
+            bind(Action::CommandPalette, &["alt+x"], "help-global-palette"),
+        ]);
+        bindings.add_keys(&[
+            (Action::Up, &["ctrl+p"]),
+            (Action::Down, &["ctrl+n"]),
+            (Action::Left, &["ctrl+b"]),
+            (Action::Right, &["ctrl+f"]),
+            (Action::PageUp, &["alt+v"]),
+            (Action::PageDown, &["ctrl+v"]),
+            (Action::Top, &["alt+<"]),
+            (Action::Bottom, &["alt+>"]),
+            (Action::LineStart, &["ctrl+a"]),
+            (Action::LineEnd, &["ctrl+e"]),
+            (Action::Back, &["ctrl+g"]),
+            (Action::Save, &["ctrl+x ctrl+s"]),
+            (Action::Copy, &["alt+w"]),
+            // Emacs' other-window: the next tab on the detail screen, the next panel on
+            // the search, webcam and settings screens (no screen has both actions)
+            (Action::NextTab, &["ctrl+x o"]),
+            (Action::NextPanel, &["ctrl+x o"]),
+            (Action::FocusInput, &["ctrl+s"]),
+        ]);
+        bindings
+    }
+
+    /// A space leader for the global commands, and helix's goto mode (`g`) on every screen
+    pub fn helix() -> Self {
+        let mut bindings = Self::with_global(vec![
+            bind(Action::Quit, &["space q", "ctrl+c"], "help-global-quit"),
+            bind(Action::Help, &["space ?"], "help-global-help"),
+            bind(Action::About, &["space a"], "help-global-about"),
+            bind(Action::CycleTheme, &["space t"], "help-global-theme"),
+            bind(Action::TogglePreset, &["ctrl+v"], "help-global-preset"),
+            bind(Action::CommandPalette, &[":"], "help-global-palette"),
+        ]);
+        bindings.add_keys(&[
+            (Action::Top, &["g g"]),
+            (Action::Bottom, &["g e"]),
+            (Action::LineStart, &["g h"]),
+            (Action::LineEnd, &["g l"]),
+            (Action::NextTab, &["g n"]),
+            (Action::PreviousTab, &["g p"]),
+            (Action::Save, &["space w"]),
+            (Action::Copy, &["space y"]),
+        ]);
+        bindings
+    }
+
+    /// The given global bindings with the screen tables shared by all presets
+    fn with_global(global: Vec<Binding>) -> Self {
+        let mut tables = screen_tables();
+        tables.insert(KeyContext::Global, global);
         Self {
-            quit: resolve(config.quit.as_ref(), &default.quit),
-            help: resolve(config.help.as_ref(), &default.help),
-            about: resolve(config.about.as_ref(), &default.about),
-            cycle_theme: resolve(config.cycle_theme.as_ref(), &default.cycle_theme),
-            toggle_keybindings_preset: resolve(
-                config.toggle_keybindings_preset.as_ref(),
-                &default.toggle_keybindings_preset,
-            ),
+            tables,
+            warnings: Vec::new(),
+            sequence_timeout: Duration::from_millis(DEFAULT_SEQUENCE_TIMEOUT_MS),
+        }
+    }
+
+    /// Extra keys for actions on every screen that has them, after the built-in ones
+    fn add_keys(&mut self, extra: &[(Action, &[&str])]) {
+        for (action, keys) in extra {
+            let keys: Vec<KeySequence> = keys
+                .iter()
+                .map(|key| KeySequence::parse(key).expect("built-in key should parse"))
+                .collect();
+            for (context, table) in &mut self.tables {
+                if *context == KeyContext::Global {
+                    continue;
+                }
+                for binding in table.iter_mut().filter(|binding| binding.action == *action) {
+                    binding.keys.extend(keys.iter().cloned());
+                }
+            }
         }
     }
 
-    pub fn is_quit(&self, key: &KeyEvent) -> bool {
-        self.quit.iter().any(|c| c.matches(key))
+    /// The action the keys pressed so far trigger in `context`
+    pub fn action(&self, context: KeyContext, keys: &[KeyChord]) -> Option<Action> {
+        self.bindings(context)
+            .iter()
+            .find(|binding| binding.keys.iter().any(|sequence| sequence.matches(keys)))
+            .map(|binding| binding.action)
+    }
+
+    /// The first of `contexts` that binds `keys`, with its action
+    pub fn resolve(
+        &self,
+        contexts: &[KeyContext],
+        keys: &[KeyChord],
+    ) -> Option<(KeyContext, Action)> {
+        contexts
+            .iter()
+            .find_map(|context| self.action(*context, keys).map(|action| (*context, action)))
     }
 
-    pub fn is_help(&self, key: &KeyEvent) -> bool {
-        self.help.iter().any(|c| c.matches(key))
+    /// Keys that would carry on a sequence started with `keys` in any of `contexts`;
+    /// empty when no binding starts that way
+    pub fn next_keys(&self, contexts: &[KeyContext], keys: &[KeyChord]) -> Vec<KeyChord> {
+        let mut next: Vec<KeyChord> = Vec::new();
+        for context in contexts {
+            for binding in self.bindings(*context) {
+                for sequence in &binding.keys {
+                    if let Some(chord) = sequence.next_after(keys) {
+                        if !next.contains(chord) {
+                            next.push(chord.clone());
+                        }
+                    }
+                }
+            }
+        }
+        next
     }
 
-    pub fn is_about(&self, key: &KeyEvent) -> bool {
-        self.about.iter().any(|c| c.matches(key))
+    /// How long a started sequence waits for its next key
+    pub fn sequence_timeout(&self) -> Duration {
+        self.sequence_timeout
     }
 
-    pub fn is_cycle_theme(&self, key: &KeyEvent) -> bool {
-        self.cycle_theme.iter().any(|c| c.matches(key))
+    pub fn bindings(&self, context: KeyContext) -> &[Binding] {
+        self.tables.get(&context).map_or(&[], Vec::as_slice)
     }
 
-    pub fn is_toggle_keybindings_preset(&self, key: &KeyEvent) -> bool {
-        self.toggle_keybindings_preset
+    fn binding(&self, context: KeyContext, action: Action) -> Option<&Binding> {
+        self.bindings(context)
             .iter()
-            .any(|c| c.matches(key))
+            .find(|binding| binding.action == action)
     }
 
-    pub fn preset_or_default(preset: Option<&str>) -> Self {
-        match preset {
-            Some("vim") => Self::vim(),
-            _ => Self::default(),
+    /// Keys bound to `action` in `context`
+    pub fn keys(&self, context: KeyContext, action: Action) -> &[KeySequence] {
+        self.binding(context, action)
+            .map_or(&[], |binding| binding.keys.as_slice())
+    }
+
+    /// Short label for footers: the first key of each action, e.g. `↑/↓` or `1-9`
+    pub fn hint(&self, context: KeyContext, actions: &[Action]) -> String {
+        let keys: Vec<String> = actions
+            .iter()
+            .filter_map(|action| self.keys(context, *action).first())
+            .map(|sequence| sequence.to_string())
+            .collect();
+        digit_range(&keys).unwrap_or_else(|| keys.join("/"))
+    }
+
+    /// Lines of the help window for `context`: the keys of every bound action, with
+    /// actions sharing a help text on one line (`↑ k / ↓ j`, or `Home, g g` once a
+    /// sequence is among them). Keys a global binding takes first are left out.
+    pub fn help_entries(&self, context: KeyContext) -> Vec<(String, &'static str)> {
+        let global = self.bindings(KeyContext::Global);
+        let shadowed = |binding: &Binding, key: &KeySequence| {
+            context != KeyContext::Global
+                && global
+                    .iter()
+                    .any(|global| global.action != binding.action && global.keys.contains(key))
+        };
+
+        let mut groups: Vec<(&'static str, Vec<Vec<&KeySequence>>)> = Vec::new();
+        for binding in self.bindings(context) {
+            let keys: Vec<&KeySequence> = binding
+                .keys
+                .iter()
+                .filter(|key| !shadowed(binding, key))
+                .collect();
+            if keys.is_empty() {
+                continue;
+            }
+            match groups.iter_mut().find(|(help, _)| *help == binding.help) {
+                Some((_, group)) => group.push(keys),
+                None => groups.push((binding.help, vec![keys])),
+            }
         }
+
+        groups
+            .into_iter()
+            .map(|(help, group)| {
+                let labels: Vec<Vec<String>> = group
+                    .iter()
+                    .map(|keys| keys.iter().map(|key| key.to_string()).collect())
+                    .collect();
+                let separator = if group
+                    .iter()
+                    .any(|keys| keys.iter().any(|sequence| sequence.len() > 1))
+                {
+                    ", "
+                } else {
+                    " "
+                };
+                let singles: Vec<String> = labels
+                    .iter()
+                    .filter(|keys| keys.len() == 1)
+                    .map(|keys| keys[0].clone())
+                    .collect();
+                let keys = match digit_range(&singles) {
+                    Some(range) if singles.len() == labels.len() => range,
+                    _ => labels
+                        .iter()
+                        .map(|keys| keys.join(separator))
+                        .collect::<Vec<_>>()
+                        .join(" / "),
+                };
+                (keys, help)
+            })
+            .collect()
     }
 
-    pub fn vim() -> Self {
-        Self {
-            quit: vec![
-                KeyChord::new(KeyCode::Char('q'), KeyModifiers::NONE),
-                KeyChord::new(KeyCode::Char('c'), KeyModifiers::CONTROL),
-            ],
-            help: vec![KeyChord::new(KeyCode::Char('?'), KeyModifiers::NONE)],
-            about: vec![KeyChord::new(KeyCode::Char('a'), KeyModifiers::NONE)],
-            cycle_theme: vec![KeyChord::new(KeyCode::Char('t'), KeyModifiers::NONE)],
-            toggle_keybindings_preset: vec![KeyChord::new(
-                KeyCode::Char('v'),
-                KeyModifiers::CONTROL,
-            )],
+    /// Problems found while reading settings.json
+    pub fn warnings(&self) -> &[KeyBindingWarning] {
+        &self.warnings
+    }
+
+    /// Replace the keys of `action`; keys that cannot be read are reported and skipped,
+    /// and if none of them could be read the preset's keys stay
+    fn rebind(&mut self, context: KeyContext, action: Action, keys: &[String]) {
+        let mut parsed = Vec::new();
+        for key in keys {
+            match KeySequence::parse(key) {
+                Some(sequence) => parsed.push(sequence),
+                None => self.warnings.push(KeyBindingWarning::BadKey {
+                    context,
+                    action,
+                    key: key.clone(),
+                }),
+            }
+        }
+        if parsed.is_empty() && !keys.is_empty() {
+            return;
         }
+        if let Some(binding) = self
+            .tables
+            .get_mut(&context)
+            .and_then(|table| table.iter_mut().find(|binding| binding.action == action))
+        {
+            binding.keys = parsed;
+            binding.custom = true;
+        }
+    }
+
+    /// Keys bound twice on one screen, or on a screen and globally (where the global
+    /// binding wins), that involve settings.json
+    fn conflicts(&self) -> Vec<KeyBindingWarning> {
+        let custom = |(context, action): (KeyContext, Action)| {
+            self.binding(context, action).is_some_and(|binding| binding.custom)
+        };
+        self.clashes()
+            .into_iter()
+            .filter(|clash| match clash {
+                KeyBindingWarning::Conflict { first, second, .. } => custom(*first) || custom(*second),
+                _ => false,
+            })
+            .collect()
+    }
+
+    /// Every key bound twice on one screen, or on a screen and globally. The bundled
+    /// tables only shadow on purpose (vim's `q` quits everywhere).
+    fn clashes(&self) -> Vec<KeyBindingWarning> {
+        let global = self.bindings(KeyContext::Global);
+        let mut conflicts = Vec::new();
+
+        for context in KeyContext::ALL {
+            let table = self.bindings(context);
+            for (i, first) in table.iter().enumerate() {
+                for second in &table[i + 1..] {
+                    report_clash(&mut conflicts, context, first, context, second);
+                }
+                if context != KeyContext::Global {
+                    for global_binding in global {
+                        report_clash(
+                            &mut conflicts,
+                            KeyContext::Global,
+                            global_binding,
+                            context,
+                            first,
+                        );
+                    }
+                }
+            }
+        }
+        conflicts
     }
 }
 
 impl Default for KeyBindings {
     fn default() -> Self {
-        Self {
-            quit: vec![
-                KeyChord::new(KeyCode::Char('q'), KeyModifiers::CONTROL),
-                KeyChord::new(KeyCode::Char('c'), KeyModifiers::CONTROL),
-            ],
-            help: vec![KeyChord::new(KeyCode::Char('h'), KeyModifiers::CONTROL)],
-            about: vec![KeyChord::new(KeyCode::Char('a'), KeyModifiers::CONTROL)],
-            cycle_theme: vec![KeyChord::new(KeyCode::Char('t'), KeyModifiers::CONTROL)],
-            toggle_keybindings_preset: vec![KeyChord::new(
-                KeyCode::Char('v'),
-                KeyModifiers::CONTROL,
-            )],
+        Self::with_global(vec![
+            bind(Action::Quit, &["ctrl+q", "ctrl+c"], "help-global-quit"),
+            bind(Action::Help, &["ctrl+h"], "help-global-help"),
+            bind(Action::About, &["ctrl+a"], "help-global-about"),
+            bind(Action::CycleTheme, &["ctrl+t"], "help-global-theme"),
+            bind(Action::TogglePreset, &["ctrl+v"], "help-global-preset"),
+            bind(Action::CommandPalette, &[":", "ctrl+p"], "help-global-palette"),
+        ])
+    }
+}
+
+fn report_clash(
+    conflicts: &mut Vec<KeyBindingWarning>,
+    first_context: KeyContext,
+    first: &Binding,
+    second_context: KeyContext,
+    second: &Binding,
+) {
+    if first.action == second.action {
+        return;
+    }
+    if let Some(key) = first.keys.iter().find(|key| second.keys.contains(key)) {
+        conflicts.push(KeyBindingWarning::Conflict {
+            key: key.clone(),
+            first: (first_context, first.action),
+            second: (second_context, second.action),
+        });
+    }
+}
+
+/// `1-6` for consecutive single digits, so number rows stay short
+fn digit_range(keys: &[String]) -> Option<String> {
+    if keys.len() < 3 {
+        return None;
+    }
+    let digits: Vec<u32> = keys
+        .iter()
+        .map(|key| match key.as_bytes() {
+            [digit @ b'0'..=b'9'] => Some((digit - b'0') as u32),
+            _ => None,
+        })
+        .collect::<Option<_>>()?;
+    let consecutive = digits.windows(2).all(|pair| pair[1] == pair[0] + 1);
+    consecutive.then(|| format!("{}-{}", digits[0], digits[digits.len() - 1]))
+}
+
+/// A built-in binding; the key strings use the settings.json syntax
+fn bind(action: Action, keys: &[&str], help: &'static str) -> Binding {
+    Binding {
+        action,
+        keys: keys
+            .iter()
+            .map(|key| KeySequence::parse(key).expect("built-in key should parse"))
+            .collect(),
+        help,
+        custom: false,
+    }
+}
+
+/// `Pick(n)` on the digit keys `range`
+fn picks(range: std::ops::RangeInclusive<u8>, help: &'static str) -> Vec<Binding> {
+    range
+        .map(|n| bind(Action::Pick(n), &[n.to_string().as_str()], help))
+        .collect()
+}
+
+/// Bindings of every screen, the same in all presets
+fn screen_tables() -> BTreeMap<KeyContext, Vec<Binding>> {
+    use Action::*;
+
+    let mut tables = BTreeMap::new();
+
+    let mut search = vec![
+        bind(FocusInput, &["i", "/"], "help-search-input"),
+        bind(Confirm, &["enter"], "help-search-view"),
+        bind(Up, &["up", "k"], "help-search-navigate"),
+        bind(Down, &["down", "j"], "help-search-navigate"),
+        bind(PreviousPage, &["left", "h"], "help-search-page"),
+        bind(NextPage, &["right", "l"], "help-search-page"),
+        bind(FocusHistory, &["ctrl+r"], "help-search-history"),
+        bind(NextPanel, &["tab"], "help-search-focus"),
+        bind(TogglePreview, &["p"], "help-search-preview"),
+    ];
+    search.extend(picks(1..=6, "help-search-preview-tabs"));
+    search.extend([
+        bind(PreviewUp, &["ctrl+pageup", "ctrl+u"], "help-search-preview-scroll"),
+        bind(PreviewDown, &["ctrl+pagedown", "ctrl+d"], "help-search-preview-scroll"),
+        bind(ToggleBookmarks, &["b"], "help-search-bookmarks"),
+        bind(Random, &["r"], "help-search-random"),
+        bind(OpenWebcam, &["w"], "help-search-webcam"),
+        bind(BulkExport, &["e"], "help-search-export"),
+        bind(OpenSettings, &["s"], "help-search-settings"),
+        bind(Quit, &["q", "esc"], "help-global-quit"),
+    ]);
+    tables.insert(KeyContext::Search, search);
+
+    tables.insert(
+        KeyContext::Welcome,
+        vec![
+            bind(Confirm, &["enter"], "help-welcome-search"),
+            bind(Back, &["esc"], "help-welcome-close"),
+            bind(OpenDaily, &["tab"], "help-welcome-daily"),
+            bind(OpenWebcam, &["w"], "help-search-webcam"),
+        ],
+    );
+
+    tables.insert(
+        KeyContext::Detail,
+        vec![
+            bind(NextTab, &["tab"], "help-detail-tabs"),
+            bind(PreviousTab, &["backtab"], "help-detail-tabs"),
+            bind(Left, &["left", "h"], "help-detail-links"),
+            bind(Right, &["right", "l"], "help-detail-links"),
+            bind(Confirm, &["enter"], "help-detail-open-link"),
+            bind(Up, &["up", "k"], "help-detail-scroll"),
+            bind(Down, &["down", "j"], "help-detail-scroll"),
+            bind(PageUp, &["pageup"], "help-detail-scroll-fast"),
+            bind(PageDown, &["pagedown"], "help-detail-scroll-fast"),
+            bind(OpenGraph, &["g"], "help-detail-graph"),
+            bind(ExportTab, &["e"], "help-detail-export"),
+            bind(OpenBrowser, &["o"], "help-detail-browser"),
+            bind(ToggleBookmark, &["b"], "help-detail-bookmark"),
+            bind(FetchBFile, &["f"], "help-detail-bfile"),
+            bind(OpenTable, &["a"], "help-detail-table"),
+            bind(Notebook, &["n"], "help-detail-notebook"),
+            bind(DetailHelp, &["ctrl+?"], "help-detail-help"),
+            bind(Back, &["esc", "q", "backspace"], "help-global-back"),
+        ],
+    );
+
+    tables.insert(
+        KeyContext::Overview,
+        vec![
+            bind(MorePlaces, &["+", "="], "help-overview-places"),
+            bind(FewerPlaces, &["-"], "help-overview-places"),
+            bind(Copy, &["y"], "help-overview-copy"),
+        ],
+    );
+
+    tables.insert(
+        KeyContext::Code,
+        vec![
+            bind(PreviousSnippet, &["["], "help-code-select"),
+            bind(NextSnippet, &["]"], "help-code-select"),
+            bind(Copy, &["y"], "help-code-copy"),
+            bind(Verify, &["v"], "help-code-verify"),
+            bind(Save, &["ctrl+s"], "help-code-save"),
+        ],
+    );
+
+    let mut export = vec![
+        bind(Up, &["up", "k"], "help-export-select"),
+        bind(Down, &["down", "j"], "help-export-select"),
+    ];
+    export.extend(picks(1..=9, "help-export-quick"));
+    export.extend([
+        bind(Confirm, &["enter"], "help-export-clipboard"),
+        bind(Save, &["ctrl+s"], "help-export-file"),
+    ]);
+    tables.insert(KeyContext::Export, export);
+
+    let mut graph = picks(1..=9, "help-graph-types");
+    graph.extend([
+        bind(NextGraphType, &["t"], "help-graph-cycle"),
+        bind(PreviousGraphType, &["T"], "help-graph-cycle"),
+        bind(ModulusDown, &["["], "help-graph-modulus"),
+        bind(ModulusUp, &["]"], "help-graph-modulus"),
+        bind(ZoomIn, &["+", "="], "help-graph-zoom"),
+        bind(ZoomOut, &["-"], "help-graph-zoom"),
+        bind(Left, &["left", "h"], "help-graph-pan"),
+        bind(Right, &["right", "l"], "help-graph-pan"),
+        bind(ResetView, &["0"], "help-graph-reset"),
+        bind(Save, &["ctrl+s"], "help-graph-save"),
+        bind(Listen, &["m"], "help-graph-listen"),
+        bind(Back, &["esc", "q", "backspace"], "help-global-back"),
+    ]);
+    tables.insert(KeyContext::Graph, graph);
+
+    tables.insert(
+        KeyContext::Table,
+        vec![
+            bind(Up, &["up", "k"], "help-table-move"),
+            bind(Down, &["down", "j"], "help-table-move"),
+            bind(Left, &["left", "h"], "help-table-move"),
+            bind(Right, &["right", "l"], "help-table-move"),
+            bind(PageUp, &["pageup"], "help-table-rows"),
+            bind(PageDown, &["pagedown"], "help-table-rows"),
+            bind(Top, &["g"], "help-table-rows"),
+            bind(Bottom, &["G"], "help-table-rows"),
+            bind(LineStart, &["home"], "help-table-row-ends"),
+            bind(LineEnd, &["end"], "help-table-row-ends"),
+            bind(CycleShape, &["s"], "help-table-shape"),
+            bind(Save, &["ctrl+s"], "help-table-save"),
+            bind(Back, &["esc", "q", "backspace"], "help-global-back"),
+        ],
+    );
+
+    let mut webcam = vec![
+        bind(NextSequence, &["space", "enter"], "help-webcam-next"),
+        bind(PreviousPanel, &["left", "h", "backtab"], "help-webcam-panel"),
+        bind(NextPanel, &["right", "l", "tab"], "help-webcam-panel"),
+        bind(Up, &["up", "k"], "help-webcam-select"),
+        bind(Down, &["down", "j"], "help-webcam-select"),
+    ];
+    webcam.extend(picks(0..=5, "help-webcam-quick"));
+    webcam.extend([
+        bind(OpenDetail, &["d"], "help-webcam-detail"),
+        bind(ToggleFullscreen, &["f"], "help-webcam-fullscreen"),
+        bind(Back, &["esc", "q", "backspace"], "help-global-back"),
+    ]);
+    tables.insert(KeyContext::Webcam, webcam);
+
+    let mut settings = vec![
+        bind(NextPanel, &["tab", "right", "l"], "help-settings-panel"),
+        bind(PreviousPanel, &["backtab", "left", "h"], "help-settings-panel"),
+        bind(Up, &["up", "k"], "help-settings-select"),
+        bind(Down, &["down", "j"], "help-settings-select"),
+        bind(Confirm, &["enter"], "help-settings-apply"),
+    ];
+    settings.extend(picks(1..=9, "help-settings-language"));
+    settings.push(bind(Back, &["esc", "q", "backspace"], "help-global-back"));
+    tables.insert(KeyContext::Settings, settings);
+
+    tables.insert(
+        KeyContext::Modal,
+        vec![
+            bind(Up, &["up", "k"], "help-detail-scroll"),
+            bind(Down, &["down", "j"], "help-detail-scroll"),
+            bind(PageUp, &["pageup"], "help-detail-scroll-fast"),
+            bind(PageDown, &["pagedown"], "help-detail-scroll-fast"),
+            bind(Top, &["home"], "help-modal-ends"),
+            bind(Bottom, &["end"], "help-modal-ends"),
+            bind(Back, &["esc", "q"], "help-global-back"),
+        ],
+    );
+
+    tables
+}
+
+/// Keys pressed one after the other, like `g g` or `ctrl+x ctrl+s`; most are a single key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeySequence(Vec<KeyChord>);
+
+impl KeySequence {
+    /// Read space-separated keys, each in the [`KeyChord::parse`] syntax
+    pub fn parse(input: &str) -> Option<Self> {
+        let chords = input
+            .split_whitespace()
+            .map(KeyChord::parse)
+            .collect::<Option<Vec<_>>>()?;
+        (!chords.is_empty()).then_some(Self(chords))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether `keys` is the whole sequence
+    pub fn matches(&self, keys: &[KeyChord]) -> bool {
+        self.0 == keys
+    }
+
+    /// The key that follows when `keys` is a proper start of the sequence
+    pub fn next_after(&self, keys: &[KeyChord]) -> Option<&KeyChord> {
+        if self.0.starts_with(keys) {
+            self.0.get(keys.len())
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for KeySequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, chord) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", chord)?;
         }
+        Ok(())
     }
 }
 
@@ -125,60 +1090,342 @@ pub struct KeyChord {
 
 impl KeyChord {
     pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let (code, modifiers) = normalize(code, modifiers);
         Self { code, modifiers }
     }
 
-    pub fn matches(&self, key: &KeyEvent) -> bool {
-        key.code == self.code && key.modifiers.contains(self.modifiers)
-    }
+    /// Read a key such as `ctrl+s`, `G`, `shift+tab`, `pagedown` or `ctrl++`
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        let (prefix, key) = if input == "+" {
+            ("", "+")
+        } else if let Some(prefix) = input.strip_suffix("++") {
+            (prefix, "+")
+        } else {
+            input.rsplit_once('+').unwrap_or(("", input))
+        };
 
-    fn parse(input: &str) -> Option<Self> {
         let mut modifiers = KeyModifiers::empty();
-        let mut code_str: Option<String> = None;
-
-        for raw in input.split('+') {
-            let part = raw.trim().to_lowercase();
-            match part.as_str() {
-                "ctrl" | "control" => {
-                    modifiers.insert(KeyModifiers::CONTROL);
-                }
-                "alt" | "meta" => {
-                    modifiers.insert(KeyModifiers::ALT);
-                }
-                "shift" => {
-                    modifiers.insert(KeyModifiers::SHIFT);
-                }
+        for part in prefix.split('+') {
+            match part.trim().to_lowercase().as_str() {
+                "ctrl" | "control" => modifiers.insert(KeyModifiers::CONTROL),
+                "alt" | "meta" => modifiers.insert(KeyModifiers::ALT),
+                "shift" => modifiers.insert(KeyModifiers::SHIFT),
                 "" => {}
-                other => {
-                    code_str = Some(other.to_string());
-                }
+                _ => return None,
             }
         }
 
-        let code = parse_key_code(code_str.as_deref().unwrap_or_default())?;
+        let key = key.trim();
+        let code = match key.chars().collect::<Vec<_>>().as_slice() {
+            [c] if modifiers.contains(KeyModifiers::SHIFT) => {
+                KeyCode::Char(c.to_ascii_uppercase())
+            }
+            [c] => KeyCode::Char(*c),
+            _ => parse_key_code(&key.to_lowercase())?,
+        };
+        let code = match code {
+            KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+            code => code,
+        };
         Some(Self::new(code, modifiers))
     }
 }
 
+impl From<&KeyEvent> for KeyChord {
+    fn from(key: &KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "Shift+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            // Ctrl+S rather than Ctrl+s, as terminals and menus write it
+            KeyCode::Char(c) if !self.modifiers.is_empty() => write!(f, "{}", c.to_ascii_uppercase()),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::Enter => write!(f, "Enter"),
+            KeyCode::Esc => write!(f, "Esc"),
+            KeyCode::Tab => write!(f, "Tab"),
+            KeyCode::BackTab => write!(f, "Shift+Tab"),
+            KeyCode::Backspace => write!(f, "Backspace"),
+            KeyCode::Delete => write!(f, "Del"),
+            KeyCode::Insert => write!(f, "Ins"),
+            KeyCode::Up => write!(f, "↑"),
+            KeyCode::Down => write!(f, "↓"),
+            KeyCode::Left => write!(f, "←"),
+            KeyCode::Right => write!(f, "→"),
+            KeyCode::Home => write!(f, "Home"),
+            KeyCode::End => write!(f, "End"),
+            KeyCode::PageUp => write!(f, "PgUp"),
+            KeyCode::PageDown => write!(f, "PgDn"),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            other => write!(f, "{:?}", other),
+        }
+    }
+}
+
+/// Shift is part of a character (`G`, `?`) and of BackTab, so it is not compared
+fn normalize(code: KeyCode, modifiers: KeyModifiers) -> (KeyCode, KeyModifiers) {
+    let mut modifiers =
+        modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT);
+    if matches!(code, KeyCode::Char(_) | KeyCode::BackTab) {
+        modifiers.remove(KeyModifiers::SHIFT);
+    }
+    (code, modifiers)
+}
+
 fn parse_key_code(s: &str) -> Option<KeyCode> {
     match s {
-        "enter" => Some(KeyCode::Enter),
+        "enter" | "return" => Some(KeyCode::Enter),
         "esc" | "escape" => Some(KeyCode::Esc),
         "tab" => Some(KeyCode::Tab),
-        "backtab" | "shift+tab" => Some(KeyCode::BackTab),
+        "backtab" => Some(KeyCode::BackTab),
+        "space" => Some(KeyCode::Char(' ')),
+        "plus" => Some(KeyCode::Char('+')),
+        "backspace" => Some(KeyCode::Backspace),
+        "delete" | "del" => Some(KeyCode::Delete),
+        "insert" | "ins" => Some(KeyCode::Insert),
         "up" => Some(KeyCode::Up),
         "down" => Some(KeyCode::Down),
         "left" => Some(KeyCode::Left),
         "right" => Some(KeyCode::Right),
         "home" => Some(KeyCode::Home),
         "end" => Some(KeyCode::End),
-        "pageup" | "page_up" => Some(KeyCode::PageUp),
-        "pagedown" | "page_down" => Some(KeyCode::PageDown),
-        _ => {
-            if s.len() == 1 {
-                return s.chars().next().map(KeyCode::Char);
+        "pageup" | "page_up" | "pgup" => Some(KeyCode::PageUp),
+        "pagedown" | "page_down" | "pgdn" => Some(KeyCode::PageDown),
+        _ => s
+            .strip_prefix('f')
+            .and_then(|n| n.parse().ok())
+            .filter(|n| (1..=12).contains(n))
+            .map(KeyCode::F),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(code: KeyCode, modifiers: KeyModifiers) -> Vec<KeyChord> {
+        vec![KeyChord::from(&KeyEvent::new(code, modifiers))]
+    }
+
+    /// The chords of a settings.json key sequence
+    fn keys(input: &str) -> Vec<KeyChord> {
+        KeySequence::parse(input).unwrap().0
+    }
+
+    fn config(json: Value) -> KeyBindingsConfig {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_parse_keys() {
+        let parse = |s| KeyChord::parse(s).unwrap();
+        assert_eq!(parse("ctrl+s"), KeyChord::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+        assert_eq!(parse("G"), KeyChord::new(KeyCode::Char('G'), KeyModifiers::NONE));
+        assert_eq!(parse("shift+g"), parse("G"));
+        assert_eq!(parse("shift+tab"), parse("backtab"));
+        assert_eq!(parse("+"), KeyChord::new(KeyCode::Char('+'), KeyModifiers::NONE));
+        assert_eq!(parse("ctrl++"), KeyChord::new(KeyCode::Char('+'), KeyModifiers::CONTROL));
+        assert_eq!(parse("Ctrl+PageDown").to_string(), "Ctrl+PgDn");
+        assert_eq!(parse("space").to_string(), "Space");
+        assert_eq!(KeyChord::parse("hyper+x"), None);
+        assert_eq!(KeyChord::parse("f13"), None);
+
+        // Terminals report Shift with upper-case letters; it is part of the character
+        assert_eq!(press(KeyCode::Char('G'), KeyModifiers::SHIFT), [parse("G")]);
+        assert_ne!(press(KeyCode::Char('g'), KeyModifiers::CONTROL), [parse("g")]);
+
+        assert_eq!(keys("ctrl+x  ctrl+s"), [parse("ctrl+x"), parse("ctrl+s")]);
+        assert_eq!(KeySequence::parse("g g").unwrap().to_string(), "g g");
+        assert_eq!(KeySequence::parse("g nope"), None);
+        assert_eq!(KeySequence::parse(" "), None);
+    }
+
+    #[test]
+    fn test_action_names() {
+        for (action, name) in ACTION_NAMES {
+            assert_eq!(Action::from_name(name), Some(*action));
+            assert_eq!(action.name(), *name);
+        }
+        assert_eq!(Action::from_name("pick_3"), Some(Action::Pick(3)));
+        assert_eq!(Action::Pick(3).name(), "pick_3");
+    }
+
+    #[test]
+    fn test_presets_resolve() {
+        let default = KeyBindings::default();
+        let vim = KeyBindings::vim();
+        let q = press(KeyCode::Char('q'), KeyModifiers::NONE);
+        assert_eq!(default.action(KeyContext::Global, &q), None);
+        assert_eq!(vim.action(KeyContext::Global, &q), Some(Action::Quit));
+        assert_eq!(default.action(KeyContext::Detail, &q), Some(Action::Back));
+        assert_eq!(
+            default.resolve(&[KeyContext::Export, KeyContext::Detail], &press(KeyCode::Down, KeyModifiers::NONE)),
+            Some((KeyContext::Export, Action::Down))
+        );
+        // Built-in tables don't warn, even where vim's global keys shadow a screen's
+        assert!(default.warnings().is_empty());
+        assert!(KeyBindings::from_config(Some(&KeyBindingsConfig::default()), Some("vim"))
+            .warnings()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_presets_do_not_clash() {
+        for preset in PRESETS {
+            let bindings = KeyBindings::preset_or_default(Some(preset));
+            for clash in bindings.clashes() {
+                // Only vim's `q`, which quits instead of going back
+                let KeyBindingWarning::Conflict { key, first, second } = &clash else {
+                    unreachable!()
+                };
+                assert!(
+                    preset == "vim"
+                        && key.to_string() == "q"
+                        && *first == (KeyContext::Global, Action::Quit)
+                        && second.1 == Action::Back,
+                    "{preset}: {clash:?}"
+                );
             }
-            None
         }
+
+        let vim = KeyBindings::vim();
+        assert_eq!(vim.action(KeyContext::Detail, &keys("a")), Some(Action::OpenTable));
+        assert_eq!(vim.action(KeyContext::Graph, &keys("t")), Some(Action::NextGraphType));
+        assert_eq!(
+            KeyBindings::default().resolve(&[KeyContext::Global, KeyContext::Search], &keys("ctrl+r")),
+            Some((KeyContext::Search, Action::FocusHistory))
+        );
+    }
+
+    #[test]
+    fn test_sequences() {
+        let vim = KeyBindings::vim();
+        let table = [KeyContext::Global, KeyContext::Table];
+        // `g` is Top on its own and the start of `g g`, so it waits for the next key
+        assert_eq!(vim.next_keys(&table, &keys("g")), keys("g"));
+        assert_eq!(vim.resolve(&table, &keys("g")), Some((KeyContext::Table, Action::Top)));
+        assert_eq!(vim.resolve(&table, &keys("g g")), Some((KeyContext::Table, Action::Top)));
+        assert_eq!(vim.resolve(&table, &keys("g x")), None);
+        assert!(vim.next_keys(&table, &keys("g g")).is_empty());
+        assert_eq!(vim.action(KeyContext::Detail, &keys("g t")), Some(Action::NextTab));
+
+        let emacs = KeyBindings::preset_or_default(Some("emacs"));
+        assert_eq!(emacs.action(KeyContext::Global, &keys("ctrl+x ctrl+c")), Some(Action::Quit));
+        assert_eq!(emacs.action(KeyContext::Code, &keys("ctrl+x ctrl+s")), Some(Action::Save));
+        assert_eq!(emacs.action(KeyContext::Code, &keys("ctrl+s")), Some(Action::Save));
+        assert_eq!(emacs.action(KeyContext::Detail, &keys("ctrl+n")), Some(Action::Down));
+        assert_eq!(emacs.action(KeyContext::Detail, &keys("ctrl+x o")), Some(Action::NextTab));
+        for context in [KeyContext::Search, KeyContext::Webcam, KeyContext::Settings] {
+            assert_eq!(emacs.action(context, &keys("ctrl+x o")), Some(Action::NextPanel));
+        }
+        assert!(emacs.warnings().is_empty());
+
+        let helix = KeyBindings::preset_or_default(Some("helix"));
+        assert_eq!(helix.action(KeyContext::Global, &keys("space q")), Some(Action::Quit));
+        assert_eq!(helix.action(KeyContext::Table, &keys("g e")), Some(Action::Bottom));
+        assert_eq!(helix.hint(KeyContext::Global, &[Action::Help]), "Space ?");
+
+        let config = config(serde_json::json!({
+            "sequence_timeout_ms": 400,
+            "graph": { "save": ["ctrl+x ctrl+s"] }
+        }));
+        let custom = KeyBindings::from_config(Some(&config), None);
+        assert_eq!(custom.sequence_timeout(), Duration::from_millis(400));
+        assert_eq!(custom.action(KeyContext::Graph, &keys("ctrl+x ctrl+s")), Some(Action::Save));
+        assert_eq!(custom.action(KeyContext::Graph, &keys("ctrl+s")), None);
+        assert!(custom
+            .help_entries(KeyContext::Graph)
+            .contains(&("Ctrl+X Ctrl+S".to_string(), "help-graph-save")));
+        assert!(vim
+            .help_entries(KeyContext::Modal)
+            .contains(&("Home, g g / End, G".to_string(), "help-modal-ends")));
+    }
+
+    #[test]
+    fn test_custom_bindings() {
+        let config = config(serde_json::json!({
+            "quit": ["ctrl+x"],
+            "detail": { "open_graph": ["G"], "notebook": [], "fly": ["z"] },
+            "graph": { "listen": ["ctrl+nope"] },
+            "nowhere": {}
+        }));
+        let bindings = KeyBindings::from_config(Some(&config), None);
+
+        let g = press(KeyCode::Char('G'), KeyModifiers::SHIFT);
+        assert_eq!(bindings.action(KeyContext::Detail, &g), Some(Action::OpenGraph));
+        assert_eq!(
+            bindings.action(KeyContext::Detail, &press(KeyCode::Char('g'), KeyModifiers::NONE)),
+            None
+        );
+        assert!(bindings.keys(KeyContext::Detail, Action::Notebook).is_empty());
+        assert_eq!(bindings.keys(KeyContext::Graph, Action::Listen).len(), 1);
+        assert_eq!(bindings.hint(KeyContext::Global, &[Action::Quit]), "Ctrl+X");
+
+        let warnings = bindings.warnings();
+        assert!(warnings.contains(&KeyBindingWarning::UnknownContext("nowhere".to_string())));
+        assert!(warnings.contains(&KeyBindingWarning::UnknownAction(
+            KeyContext::Detail,
+            "fly".to_string()
+        )));
+        assert!(warnings.contains(&KeyBindingWarning::BadKey {
+            context: KeyContext::Graph,
+            action: Action::Listen,
+            key: "ctrl+nope".to_string(),
+        }));
+    }
+
+    #[test]
+    fn test_conflicts() {
+        let config = config(serde_json::json!({
+            "help": ["g"],
+            "table": { "save": ["s"] }
+        }));
+        let warnings = KeyBindings::from_config(Some(&config), None).warnings().to_vec();
+        let s = KeySequence::parse("s").unwrap();
+        assert!(warnings.contains(&KeyBindingWarning::Conflict {
+            key: s,
+            first: (KeyContext::Table, Action::CycleShape),
+            second: (KeyContext::Table, Action::Save),
+        }));
+        assert!(warnings.contains(&KeyBindingWarning::Conflict {
+            key: KeySequence::parse("g").unwrap(),
+            first: (KeyContext::Global, Action::Help),
+            second: (KeyContext::Detail, Action::OpenGraph),
+        }));
+    }
+
+    #[test]
+    fn test_help_entries() {
+        let bindings = KeyBindings::default();
+        let search = bindings.help_entries(KeyContext::Search);
+        assert!(search.contains(&("↑ k / ↓ j".to_string(), "help-search-navigate")));
+        assert!(search.contains(&("1-6".to_string(), "help-search-preview-tabs")));
+        assert_eq!(bindings.hint(KeyContext::Webcam, &[Action::Pick(0), Action::Pick(1), Action::Pick(5)]), "0/1/5");
+        assert_eq!(bindings.hint(KeyContext::Graph, &[Action::NextGraphType, Action::PreviousGraphType]), "t/T");
+
+        // A key the global table takes first is not listed for the screen
+        let vim = KeyBindings::vim();
+        assert!(vim
+            .help_entries(KeyContext::Detail)
+            .contains(&("Esc Backspace".to_string(), "help-global-back")));
+        let config = config(serde_json::json!({ "help": ["b"] }));
+        let custom = KeyBindings::from_config(Some(&config), None);
+        assert!(!custom
+            .help_entries(KeyContext::Detail)
+            .iter()
+            .any(|(_, help)| *help == "help-detail-bookmark"));
     }
 }
@@ -0,0 +1,350 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Trailing OEIS attribution such as ` - _N. J. A. Sloane_, Jan 01 2000`.
+static ATTRIBUTION_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\s+-\s+_([^_]+)_(.*)$").expect("valid regex"));
+
+/// Leading formula label such as `G.f.:` or `E.g.f.:`.
+static LABEL_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^([A-Za-z][A-Za-z.\- ]{0,24}):\s+").expect("valid regex"));
+
+/// Words that are part of OEIS ASCII math notation rather than prose.
+const MATH_WORDS: &[&str] = &[
+    "sum", "product", "binomial", "sqrt", "floor", "ceiling", "log", "exp", "sin", "cos", "tan",
+    "gcd", "lcm", "max", "min", "mod", "abs", "phi", "pi", "zeta", "infinity", "sigma", "tau",
+    "mu", "omega", "lambda", "gamma",
+];
+
+/// Escape characters that carry special meaning in LaTeX text mode.
+pub fn escape_latex(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => out.push_str(r"\textbackslash{}"),
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '~' => out.push_str(r"\textasciitilde{}"),
+            '^' => out.push_str(r"\textasciicircum{}"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Convert an OEIS formula line into LaTeX, typesetting the mathematical parts in
+/// math mode and keeping prose (and the author attribution) as escaped text.
+pub fn formula_to_latex(formula: &str) -> String {
+    let mut body = formula.trim();
+    let mut attribution = None;
+
+    if let Some(caps) = ATTRIBUTION_PATTERN.captures(body) {
+        let whole = caps.get(0).expect("match");
+        attribution = Some(format!(
+            "{}{}",
+            caps[1].trim(),
+            caps.get(2).map(|m| m.as_str()).unwrap_or_default()
+        ));
+        body = &body[..whole.start()];
+    }
+
+    let mut out = String::new();
+    if let Some(caps) = LABEL_PATTERN.captures(body) {
+        let whole = caps.get(0).expect("match");
+        out.push_str(&format!(r"\textit{{{}:}} ", escape_latex(&caps[1])));
+        body = &body[whole.end()..];
+    }
+
+    out.push_str(&mixed_to_latex(body));
+
+    if let Some(attribution) = attribution {
+        out.push_str(&format!(" --- {}", escape_latex(attribution.trim())));
+    }
+
+    out
+}
+
+/// Split `text` into runs of math tokens and prose words, wrapping the math runs in `$...$`.
+fn mixed_to_latex(text: &str) -> String {
+    let mut out = String::new();
+    let mut math_run: Vec<&str> = Vec::new();
+
+    let flush = |run: &mut Vec<&str>, out: &mut String| {
+        if run.is_empty() {
+            return;
+        }
+        let joined = run.join(" ");
+        let trimmed = joined.trim_end_matches(['.', ',', ';']);
+        let trailing = &joined[trimmed.len()..];
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        if trimmed.is_empty() {
+            out.push_str(trailing);
+        } else {
+            out.push_str(&format!("${}${}", ascii_math_to_latex(trimmed), trailing));
+        }
+        run.clear();
+    };
+
+    for token in text.split_whitespace() {
+        if is_prose_word(token) {
+            flush(&mut math_run, &mut out);
+            if !out.is_empty() {
+                out.push(' ');
+            }
+            out.push_str(&escape_latex(token));
+        } else {
+            math_run.push(token);
+        }
+    }
+    flush(&mut math_run, &mut out);
+
+    out
+}
+
+fn is_prose_word(token: &str) -> bool {
+    let word = token.trim_end_matches(['.', ',', ';', ':']);
+    word.len() >= 3
+        && word.chars().all(|c| c.is_ascii_alphabetic() || c == '\'')
+        && !MATH_WORDS.contains(&word.to_ascii_lowercase().as_str())
+}
+
+/// Convert OEIS ASCII math notation (e.g. `Sum_{k=0..n} binomial(n,k)*x^(k+1)`) to LaTeX.
+pub fn ascii_math_to_latex(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut out = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c.is_ascii_alphabetic() {
+            let start = i;
+            while i < chars.len() && chars[i].is_ascii_alphanumeric() {
+                i += 1;
+            }
+            let ident: String = chars[start..i].iter().collect();
+            let lower = ident.to_ascii_lowercase();
+
+            if (lower == "sum" || lower == "product") && chars.get(i) == Some(&'_') {
+                let operator = if lower == "sum" { r"\sum" } else { r"\prod" };
+                if chars.get(i + 1) == Some(&'{') {
+                    if let Some(end) = matching_close(&chars, i + 1, '{', '}') {
+                        let range: String = chars[i + 2..end].iter().collect();
+                        out.push_str(&big_operator(operator, &range));
+                        i = end + 1;
+                        continue;
+                    }
+                }
+                out.push_str(operator);
+                continue;
+            }
+
+            if chars.get(i) == Some(&'(') {
+                if let Some(end) = matching_close(&chars, i, '(', ')') {
+                    let inner: String = chars[i + 1..end].iter().collect();
+                    out.push_str(&function_call(&ident, &inner));
+                    i = end + 1;
+                    continue;
+                }
+            }
+
+            out.push_str(&identifier(&ident));
+            continue;
+        }
+
+        match c {
+            '^' => {
+                i += 1;
+                if chars.get(i) == Some(&'(') {
+                    if let Some(end) = matching_close(&chars, i, '(', ')') {
+                        let inner: String = chars[i + 1..end].iter().collect();
+                        out.push_str(&format!("^{{{}}}", ascii_math_to_latex(&inner)));
+                        i = end + 1;
+                        continue;
+                    }
+                }
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '.') {
+                    i += 1;
+                }
+                let exponent: String = chars[start..i].iter().collect();
+                out.push_str(&format!("^{{{}}}", exponent.trim_end_matches('.')));
+                if exponent.ends_with('.') {
+                    out.push('.');
+                }
+            }
+            '*' => {
+                out.push_str(r" \cdot ");
+                i += 1;
+            }
+            '<' if chars.get(i + 1) == Some(&'=') => {
+                out.push_str(r" \le ");
+                i += 2;
+            }
+            '>' if chars.get(i + 1) == Some(&'=') => {
+                out.push_str(r" \ge ");
+                i += 2;
+            }
+            '<' if chars.get(i + 1) == Some(&'>') => {
+                out.push_str(r" \ne ");
+                i += 2;
+            }
+            '!' if chars.get(i + 1) == Some(&'=') => {
+                out.push_str(r" \ne ");
+                i += 2;
+            }
+            '-' if chars.get(i + 1) == Some(&'>') => {
+                out.push_str(r" \to ");
+                i += 2;
+            }
+            '.' if chars.get(i + 1) == Some(&'.') => {
+                out.push_str(r", \ldots, ");
+                i += 2;
+            }
+            '~' => {
+                out.push_str(r" \sim ");
+                i += 1;
+            }
+            '{' | '}' | '%' | '#' | '&' | '$' => {
+                out.push('\\');
+                out.push(c);
+                i += 1;
+            }
+            '\\' => {
+                out.push_str(r"\backslash ");
+                i += 1;
+            }
+            _ => {
+                out.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    out
+}
+
+fn big_operator(operator: &str, range: &str) -> String {
+    match range.split_once("..") {
+        Some((lower, upper)) => format!(
+            "{}_{{{}}}^{{{}}}",
+            operator,
+            ascii_math_to_latex(lower.trim()),
+            ascii_math_to_latex(upper.trim())
+        ),
+        None => format!(
+            "{}_{{{}}}",
+            operator,
+            ascii_math_to_latex(range.trim()).replace('|', r"\mid ")
+        ),
+    }
+}
+
+fn function_call(name: &str, inner: &str) -> String {
+    let args = split_top_level(inner);
+    match name.to_ascii_lowercase().as_str() {
+        "binomial" if args.len() == 2 => format!(
+            r"\binom{{{}}}{{{}}}",
+            ascii_math_to_latex(args[0].trim()),
+            ascii_math_to_latex(args[1].trim())
+        ),
+        "sqrt" => format!(r"\sqrt{{{}}}", ascii_math_to_latex(inner)),
+        "floor" => format!(r"\lfloor {} \rfloor", ascii_math_to_latex(inner)),
+        "ceiling" => format!(r"\lceil {} \rceil", ascii_math_to_latex(inner)),
+        "abs" => format!(r"\lvert {} \rvert", ascii_math_to_latex(inner)),
+        _ => format!("{}({})", identifier(name), ascii_math_to_latex(inner)),
+    }
+}
+
+fn identifier(name: &str) -> String {
+    match name {
+        "log" | "exp" | "sin" | "cos" | "tan" | "gcd" | "max" | "min" => format!(r"\{name}"),
+        "lcm" => r"\operatorname{lcm}".to_string(),
+        "mod" => r" \bmod ".to_string(),
+        "phi" | "pi" | "zeta" | "sigma" | "tau" | "mu" | "omega" | "lambda" | "gamma" => {
+            format!(r"\{name}")
+        }
+        "Pi" => r"\pi".to_string(),
+        "infinity" => r"\infty".to_string(),
+        _ if name.len() > 1 && name.chars().all(|c| c.is_ascii_alphabetic()) => {
+            format!(r"\mathrm{{{name}}}")
+        }
+        _ => name.to_string(),
+    }
+}
+
+/// Find the index of the bracket closing the one opened at `open_index`.
+fn matching_close(chars: &[char], open_index: usize, open: char, close: char) -> Option<usize> {
+    let mut depth = 0usize;
+    for (offset, c) in chars[open_index..].iter().enumerate() {
+        if *c == open {
+            depth += 1;
+        } else if *c == close {
+            depth -= 1;
+            if depth == 0 {
+                return Some(open_index + offset);
+            }
+        }
+    }
+    None
+}
+
+/// Split function arguments on commas that are not nested inside brackets.
+fn split_top_level(inner: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&inner[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&inner[start..]);
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_latex() {
+        assert_eq!(escape_latex("50% of a_n & {x}"), r"50\% of a\_n \& \{x\}");
+    }
+
+    #[test]
+    fn test_ascii_math_conversion() {
+        assert_eq!(ascii_math_to_latex("x^(n+1)"), "x^{n+1}");
+        assert_eq!(ascii_math_to_latex("2^n*3"), r"2^{n} \cdot 3");
+        assert_eq!(
+            ascii_math_to_latex("Sum_{k=0..n} binomial(n,k)"),
+            r"\sum_{k=0}^{n} \binom{n}{k}"
+        );
+        assert_eq!(
+            ascii_math_to_latex("round(phi^n/sqrt(5))"),
+            r"\mathrm{round}(\phi^{n}/\sqrt{5})"
+        );
+    }
+
+    #[test]
+    fn test_formula_to_latex() {
+        assert_eq!(
+            formula_to_latex("G.f.: x/(1-x-x^2). - _Simon Plouffe_, Feb 1993"),
+            r"\textit{G.f.:} $x/(1-x-x^{2})$. --- Simon Plouffe, Feb 1993"
+        );
+        assert_eq!(
+            formula_to_latex("a(n) = a(n-1) + a(n-2) with a(0) = 0, a(1) = 1."),
+            "$a(n) = a(n-1) + a(n-2)$ with $a(0) = 0, a(1) = 1$."
+        );
+    }
+}
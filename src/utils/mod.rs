@@ -1,7 +1,12 @@
+pub mod color;
+pub mod filename;
 pub mod keybindings;
+pub mod latex;
 pub mod paths;
 pub mod text;
+pub mod themes;
 
 pub use text::{
     highlight_anumbers_line, highlight_sequence_line, highlight_terms_line, parse_search_terms,
+    ReferenceFocus,
 };
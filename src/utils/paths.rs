@@ -38,3 +38,76 @@ fn preferred_base() -> PathBuf {
 pub fn config_file(name: &str) -> Result<PathBuf> {
     Ok(ensure_config_dir()?.join(name))
 }
+
+/// Expand a leading `~` to the home directory; an empty input means the current directory.
+pub fn expand_home(input: &str) -> PathBuf {
+    let input = input.trim();
+    if input.is_empty() {
+        return PathBuf::from(".");
+    }
+
+    if input == "~" || input.starts_with("~/") {
+        if let Some(home) = dirs::home_dir() {
+            return home.join(input.trim_start_matches('~').trim_start_matches('/'));
+        }
+    }
+
+    PathBuf::from(input)
+}
+
+/// Filesystem completions for a partially typed path, keeping the user's own spelling of
+/// the directory part (so `~/Doc` completes to `~/Documents/`). Directories end in `/`.
+pub fn complete_path(input: &str) -> Vec<String> {
+    if input == "~" {
+        return vec!["~/".to_string()];
+    }
+
+    let (dir_part, prefix) = match input.rfind('/') {
+        Some(i) => (&input[..=i], &input[i + 1..]),
+        None => ("", input),
+    };
+    let dir = if dir_part.is_empty() {
+        PathBuf::from(".")
+    } else {
+        expand_home(dir_part)
+    };
+
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return Vec::new();
+    };
+
+    let mut matches: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            // Hidden entries only when explicitly asked for
+            if !name.starts_with(prefix) || (name.starts_with('.') && !prefix.starts_with('.')) {
+                return None;
+            }
+            let suffix = if entry.path().is_dir() { "/" } else { "" };
+            Some(format!("{}{}{}", dir_part, name, suffix))
+        })
+        .collect();
+    matches.sort();
+    matches
+}
+
+/// Longest common prefix shared by all `candidates`.
+pub fn common_prefix(candidates: &[String]) -> String {
+    let Some(first) = candidates.first() else {
+        return String::new();
+    };
+
+    let mut len = first.len();
+    for candidate in &candidates[1..] {
+        len = first
+            .char_indices()
+            .zip(candidate.chars())
+            .take_while(|((_, a), b)| a == b)
+            .map(|((i, a), _)| i + a.len_utf8())
+            .last()
+            .unwrap_or(0)
+            .min(len);
+    }
+    first[..len].to_string()
+}
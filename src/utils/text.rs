@@ -10,13 +10,22 @@ use crate::ui::Theme;
 /// Regex that matches canonical OEIS sequence identifiers (A-number format).
 static ANUMBER_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"A\d{6}").expect("valid regex"));
 
+/// Which A-number reference in a detail pane has focus
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReferenceFocus {
+    /// Index among the references, in the order they appear
+    pub selected: Option<usize>,
+    /// Mark the selected reference with `▶` as well as colour (accessibility mode)
+    pub markers: bool,
+}
+
 /// Highlight OEIS A-number references within `text`, registering each discovered
 /// identifier in `references`. The currently selected reference is highlighted
 /// with an accent background to indicate focus.
 pub fn highlight_anumbers_line(
     text: &str,
     references: &mut Vec<String>,
-    selected: Option<usize>,
+    focus: ReferenceFocus,
     theme: &Theme,
 ) -> Line<'static> {
     let mut spans = Vec::new();
@@ -34,11 +43,15 @@ pub fn highlight_anumbers_line(
         let matched = text[mat.start()..mat.end()].to_string();
         references.push(matched.clone());
 
-        let style = if Some(index) == selected {
-            Style::default()
+        let style = if Some(index) == focus.selected {
+            let style = Style::default()
                 .fg(theme.text_color())
                 .bg(theme.highlight_bg_color())
-                .add_modifier(Modifier::BOLD)
+                .add_modifier(Modifier::BOLD);
+            if focus.markers {
+                spans.push(Span::styled("▶", style));
+            }
+            style
         } else {
             Style::default()
                 .fg(theme.accent_color())
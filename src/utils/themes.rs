@@ -0,0 +1,258 @@
+//! User themes from `~/.config/oeis-tui/themes/*.toml`.
+//!
+//! A theme file sets the palette slots by name; slots it leaves out come from the theme
+//! named in `inherits`, so a small tweak of a built-in theme is a few lines:
+//!
+//! ```toml
+//! name = "Dark, amber"
+//! inherits = "Dark"
+//! accent = "#ffb000"
+//! highlight = "light-yellow"
+//! ```
+//!
+//! Colours are names (`cyan`, `light-red`, `dark-gray`), `#rrggbb` or a 256-colour
+//! index. A theme with the name of a built-in one takes its place in the list.
+
+use anyhow::{anyhow, bail, Context, Result};
+use ratatui::style::Color;
+use serde::Deserialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::SystemTime,
+};
+
+use crate::ui::theme::{Palette, Theme};
+
+/// Directory inside the config directory that theme files are read from
+pub const THEMES_DIR: &str = "themes";
+
+/// Contents of one theme file
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeFile {
+    /// Shown in the settings screen and stored in settings.json (defaults to the file name)
+    name: Option<String>,
+    /// Theme the unset slots are taken from
+    inherits: Option<String>,
+    accent: Option<String>,
+    accent_dim: Option<String>,
+    highlight: Option<String>,
+    highlight_bg: Option<String>,
+    text: Option<String>,
+    muted: Option<String>,
+    success: Option<String>,
+    warning: Option<String>,
+    danger: Option<String>,
+    background: Option<String>,
+}
+
+/// A theme file that could not be loaded, and why
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeFileError {
+    pub file: String,
+    pub message: String,
+}
+
+/// Read a theme from TOML. `inherits` is looked up in `themes`, ignoring case.
+pub fn parse_theme(source: &str, default_name: &str, themes: &[Theme]) -> Result<Theme> {
+    let file: ThemeFile = toml::from_str(source)?;
+    let base = match &file.inherits {
+        Some(name) => Some(
+            &find_theme(themes, name)
+                .ok_or_else(|| anyhow!("`inherits`: there is no theme called \"{}\"", name))?
+                .palette,
+        ),
+        None => None,
+    };
+
+    let slot = |key: &str, value: &Option<String>, inherited: Option<Color>| -> Result<Color> {
+        match value {
+            Some(value) => parse_color(value).with_context(|| format!("`{}`", key)),
+            None => inherited.ok_or_else(|| {
+                anyhow!("`{}` is missing (set it, or take it from another theme with `inherits`)", key)
+            }),
+        }
+    };
+    let palette = Palette {
+        accent: slot("accent", &file.accent, base.map(|p| p.accent))?,
+        accent_dim: slot("accent_dim", &file.accent_dim, base.map(|p| p.accent_dim))?,
+        highlight: slot("highlight", &file.highlight, base.map(|p| p.highlight))?,
+        highlight_bg: slot("highlight_bg", &file.highlight_bg, base.map(|p| p.highlight_bg))?,
+        text: slot("text", &file.text, base.map(|p| p.text))?,
+        muted: slot("muted", &file.muted, base.map(|p| p.muted))?,
+        success: slot("success", &file.success, base.map(|p| p.success))?,
+        warning: slot("warning", &file.warning, base.map(|p| p.warning))?,
+        danger: slot("danger", &file.danger, base.map(|p| p.danger))?,
+        background: slot("background", &file.background, base.map(|p| p.background))?,
+    };
+
+    let name = file
+        .name
+        .map(|name| name.trim().to_string())
+        .unwrap_or_else(|| default_name.to_string());
+    if name.is_empty() {
+        bail!("`name` is empty");
+    }
+    Ok(Theme { name, palette })
+}
+
+/// A colour name, `#rrggbb` or a 256-colour index
+pub fn parse_color(value: &str) -> Result<Color> {
+    let value = value.trim();
+    match Color::from_str(value) {
+        Ok(color) => Ok(color),
+        Err(_) if value.starts_with('#') => {
+            bail!("\"{}\" is not a colour; hex colours are written #rrggbb", value)
+        }
+        Err(_) => bail!(
+            "\"{}\" is not a colour; use a name like \"cyan\" or \"light-red\", #rrggbb, or 0-255",
+            value
+        ),
+    }
+}
+
+/// The theme called `name`, ignoring case
+fn find_theme<'a>(themes: &'a [Theme], name: &str) -> Option<&'a Theme> {
+    themes
+        .iter()
+        .find(|theme| theme.name.eq_ignore_ascii_case(name.trim()))
+}
+
+/// The built-in themes followed by the ones in `dir` (in file name order), with a
+/// message for each file that could not be used. Files may inherit from built-in
+/// themes and from files that sort before them.
+pub fn load_themes(dir: &Path, builtin: Vec<Theme>) -> (Vec<Theme>, Vec<ThemeFileError>) {
+    let mut themes = builtin;
+    let mut errors = Vec::new();
+
+    for (path, _) in theme_files(dir) {
+        let file = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let stem = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let theme = fs::read_to_string(&path)
+            .map_err(anyhow::Error::from)
+            .and_then(|source| parse_theme(&source, &stem, &themes));
+        match theme {
+            Ok(theme) => match themes.iter().position(|t| t.name.eq_ignore_ascii_case(&theme.name)) {
+                Some(index) => themes[index] = theme,
+                None => themes.push(theme),
+            },
+            Err(err) => errors.push(ThemeFileError {
+                file,
+                message: format!("{:#}", err),
+            }),
+        }
+    }
+    (themes, errors)
+}
+
+/// `*.toml` files in `dir` with their modification times, sorted by path; comparing two
+/// of these tells whether a theme file was added, removed or changed
+pub fn theme_files(dir: &Path) -> Vec<(PathBuf, Option<SystemTime>)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<(PathBuf, Option<SystemTime>)> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml") && path.is_file())
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|meta| meta.modified()).ok();
+            (path, modified)
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FULL: &str = r##"
+        name = "Amber"
+        accent = "#ffb000"
+        accent_dim = "yellow"
+        highlight = "light-yellow"
+        highlight_bg = "236"
+        text = "white"
+        muted = "dark-gray"
+        success = "green"
+        warning = "#ffdd00"
+        danger = "red"
+        background = "black"
+    "##;
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(parse_color("#ffb000").unwrap(), Color::Rgb(255, 176, 0));
+        assert_eq!(parse_color(" Cyan ").unwrap(), Color::Cyan);
+        assert_eq!(parse_color("light-red").unwrap(), Color::LightRed);
+        assert_eq!(parse_color("236").unwrap(), Color::Indexed(236));
+
+        let err = parse_color("#fff").unwrap_err().to_string();
+        assert!(err.contains("#rrggbb"), "{}", err);
+        let err = parse_color("bluish").unwrap_err().to_string();
+        assert!(err.contains("\"bluish\" is not a colour"), "{}", err);
+    }
+
+    #[test]
+    fn test_parse_theme() {
+        let theme = parse_theme(FULL, "amber", &[]).unwrap();
+        assert_eq!(theme.name, "Amber");
+        assert_eq!(theme.palette.accent, Color::Rgb(255, 176, 0));
+        assert_eq!(theme.palette.highlight_bg, Color::Indexed(236));
+
+        // Unset slots come from the inherited theme; the name from the file
+        let builtin = Theme::builtin();
+        let theme = parse_theme("inherits = \"dark\"\naccent = \"magenta\"", "tweak", &builtin).unwrap();
+        assert_eq!(theme.name, "tweak");
+        assert_eq!(theme.palette.accent, Color::Magenta);
+        assert_eq!(theme.palette.text, Theme::dark().palette.text);
+    }
+
+    #[test]
+    fn test_theme_errors() {
+        let message = |source: &str| format!("{:#}", parse_theme(source, "bad", &Theme::builtin()).unwrap_err());
+
+        let err = message(&FULL.replace("\"#ffdd00\"", "\"#ffdd0\""));
+        assert!(err.starts_with("`warning`: \"#ffdd0\" is not a colour"), "{}", err);
+        let err = message("accent = \"cyan\"");
+        assert!(err.contains("`accent_dim` is missing"), "{}", err);
+        let err = message("inherits = \"Nope\"");
+        assert!(err.contains("no theme called \"Nope\""), "{}", err);
+        // A misspelt slot is reported rather than ignored
+        let err = message("inherits = \"Dark\"\nacent = \"cyan\"");
+        assert!(err.contains("unknown field `acent`"), "{}", err);
+    }
+
+    #[test]
+    fn test_load_themes() {
+        let dir = std::env::temp_dir().join(format!("oeis-tui-themes-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a-amber.toml"), FULL).unwrap();
+        fs::write(dir.join("b-dark.toml"), "name = \"Dark\"\ninherits = \"Amber\"").unwrap();
+        fs::write(dir.join("c-broken.toml"), "accent = ").unwrap();
+        fs::write(dir.join("notes.txt"), "not a theme").unwrap();
+
+        let builtin = Theme::builtin();
+        let count = builtin.len();
+        let (themes, errors) = load_themes(&dir, builtin);
+        fs::remove_dir_all(&dir).unwrap();
+
+        // "Dark" is replaced in place, "Amber" is added at the end
+        assert_eq!(themes.len(), count + 1);
+        assert_eq!(themes[0].name, "Dark");
+        assert_eq!(themes[0].palette.accent, Color::Rgb(255, 176, 0));
+        assert_eq!(themes[count].name, "Amber");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].file, "c-broken.toml");
+    }
+}
@@ -0,0 +1,474 @@
+//! Local verification of the PARI/GP and Python programs attached to OEIS entries.
+//!
+//! Programs run in a scratch directory with a minimal environment, no stdin, a capped
+//! amount of captured output and a hard timeout. This keeps a runaway program from
+//! hanging the app, but it is not a security boundary: only verify programs you trust.
+
+use crate::api::{models::CodeSnippet, Sequence};
+use crate::i18n::Localized;
+use anyhow::{anyhow, Context, Result};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+use tokio::io::AsyncReadExt;
+use tokio::process::Command;
+
+/// Default wall-clock limit for a single verification run
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum number of terms requested from `a(n)`-style programs
+const MAX_TERMS: usize = 30;
+
+/// Stop capturing output after this many bytes (the rest is read and dropped)
+const MAX_OUTPUT_BYTES: usize = 1 << 20;
+
+static INTEGER_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"-?\d+").expect("valid regex"));
+
+/// gp echoes results of top-level expressions as `%1 = ...`
+static PARI_HISTORY_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?m)^%\d+\s*=\s*").expect("valid regex"));
+
+static PARI_TERM_FUNCTION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?m)(^|[;\s{])(a|A\d{6})\(n\)\s*=").expect("valid regex"));
+
+static PYTHON_TERM_FUNCTION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?m)^def\s+(a|A\d{6})\s*\(").expect("valid regex"));
+
+/// Interpreters we know how to drive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpreter {
+    Pari,
+    Python,
+}
+
+impl Interpreter {
+    /// Interpreter able to run `snippet`, if the language is supported
+    pub fn for_snippet(snippet: &CodeSnippet) -> Option<Self> {
+        match snippet.language_key().as_str() {
+            "pari" => Some(Self::Pari),
+            "python" => Some(Self::Python),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Pari => "PARI/GP",
+            Self::Python => "Python",
+        }
+    }
+
+    fn candidates(&self) -> &'static [&'static str] {
+        match self {
+            Self::Pari => &["gp"],
+            Self::Python => &["python3", "python"],
+        }
+    }
+
+    fn script_name(&self) -> &'static str {
+        match self {
+            Self::Pari => "snippet.gp",
+            Self::Python => "snippet.py",
+        }
+    }
+
+    fn args(&self, script: &Path) -> Vec<String> {
+        let script = script.to_string_lossy().to_string();
+        match self {
+            // Quiet, skip gprc, and never fall into the interactive prompt
+            Self::Pari => vec!["-q".into(), "-f".into(), "-D".into(), "readline=0".into(), script],
+            // Isolated mode ignores PYTHON* variables and the user site directory
+            Self::Python => vec!["-I".into(), script],
+        }
+    }
+
+    /// Locate the interpreter on `PATH`
+    pub fn locate(&self) -> Option<PathBuf> {
+        let path = std::env::var_os("PATH")?;
+        self.candidates().iter().find_map(|name| {
+            std::env::split_paths(&path).find_map(|dir| {
+                let candidate = dir.join(name);
+                if candidate.is_file() {
+                    return Some(candidate);
+                }
+                let exe = dir.join(format!("{}.exe", name));
+                exe.is_file().then_some(exe)
+            })
+        })
+    }
+}
+
+/// How the produced terms compare with the entry's data
+#[derive(Debug, Clone, PartialEq)]
+pub enum VerifyOutcome {
+    /// The program printed all `checked` terms asked for and they agree with the entry
+    Match { checked: usize },
+    /// The `checked` terms printed agree, but the program stopped short of the `expected`
+    /// ones asked for
+    Partial { checked: usize, expected: usize },
+    /// Term `a(index)` differs; `matched` terms agreed before it
+    Mismatch {
+        index: i64,
+        expected: String,
+        actual: String,
+        matched: usize,
+    },
+    /// The program printed no integers
+    NoOutput,
+}
+
+/// Result of running one program
+#[derive(Debug, Clone)]
+pub struct VerifyReport {
+    pub interpreter: Interpreter,
+    pub outcome: VerifyOutcome,
+    /// The program hit the time limit (output up to that point was still compared, but
+    /// the run does not pass)
+    pub timed_out: bool,
+    pub elapsed: Duration,
+    /// Last line of stderr (usually the actual error) when a program fails to run
+    pub error: Option<String>,
+}
+
+impl VerifyReport {
+    pub fn passed(&self) -> bool {
+        matches!(self.outcome, VerifyOutcome::Match { .. }) && !self.timed_out
+    }
+}
+
+/// Build the script to run: the snippet itself plus, when it defines `a(n)` (or
+/// `A000045(n)`), a driver loop printing the first terms one per line.
+pub fn build_script(
+    interpreter: Interpreter,
+    snippet: &CodeSnippet,
+    seq: &Sequence,
+    terms: usize,
+) -> String {
+    let (offset, _) = seq.parse_offset();
+    let last = offset as i64 + terms as i64 - 1;
+    let mut script = snippet.code.clone();
+    script.push('\n');
+
+    match interpreter {
+        Interpreter::Pari => {
+            if let Some(caps) = PARI_TERM_FUNCTION.captures(&snippet.code) {
+                script.push_str(&format!(
+                    "for(n={},{},print({}(n)))\n",
+                    offset, last, &caps[2]
+                ));
+            }
+            script.push_str("quit\n");
+        }
+        Interpreter::Python => {
+            if let Some(caps) = PYTHON_TERM_FUNCTION.captures(&snippet.code) {
+                script.push_str(&format!(
+                    "\nfor _n in range({}, {}):\n    print({}(_n))\n",
+                    offset,
+                    last + 1,
+                    &caps[1]
+                ));
+            }
+        }
+    }
+
+    script
+}
+
+/// Pull the integers a program printed, in order
+pub fn extract_terms(interpreter: Interpreter, output: &str) -> Vec<String> {
+    let cleaned = match interpreter {
+        Interpreter::Pari => PARI_HISTORY_PATTERN.replace_all(output, "").to_string(),
+        Interpreter::Python => output.to_string(),
+    };
+    INTEGER_PATTERN
+        .find_iter(&cleaned)
+        .map(|m| m.as_str().to_string())
+        .collect()
+}
+
+/// Compare produced terms with the entry's terms, starting at index `offset`; only output
+/// covering every expected term is a match
+pub fn compare_terms(expected: &[String], produced: &[String], offset: i64) -> VerifyOutcome {
+    if produced.is_empty() {
+        return VerifyOutcome::NoOutput;
+    }
+
+    let mut matched = 0;
+    for (i, (want, got)) in expected.iter().zip(produced).enumerate() {
+        if want.trim() != got.trim() {
+            return VerifyOutcome::Mismatch {
+                index: offset + i as i64,
+                expected: want.trim().to_string(),
+                actual: got.trim().to_string(),
+                matched,
+            };
+        }
+        matched += 1;
+    }
+
+    if matched < expected.len() {
+        return VerifyOutcome::Partial {
+            checked: matched,
+            expected: expected.len(),
+        };
+    }
+    VerifyOutcome::Match { checked: matched }
+}
+
+/// Why a program could not be run
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum VerifyError {
+    #[error("Verification supports PARI/GP and Python, not {0}")]
+    Unsupported(String),
+    #[error("{0} interpreter not found on PATH")]
+    MissingInterpreter(&'static str),
+}
+
+impl Localized for VerifyError {
+    fn message_key(&self) -> &'static str {
+        match self {
+            Self::Unsupported(_) => "verify-unsupported",
+            Self::MissingInterpreter(_) => "verify-missing-interpreter",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match self {
+            Self::Unsupported(language) => vec![("language", language.clone())],
+            Self::MissingInterpreter(interpreter) => vec![("interpreter", interpreter.to_string())],
+        }
+    }
+}
+
+/// Run `snippet` locally and compare its output with `seq.data`
+pub async fn verify_snippet(
+    snippet: &CodeSnippet,
+    seq: &Sequence,
+    timeout: Duration,
+) -> Result<VerifyReport> {
+    let interpreter = Interpreter::for_snippet(snippet)
+        .ok_or_else(|| VerifyError::Unsupported(snippet.language.clone()))?;
+    let program = interpreter
+        .locate()
+        .ok_or(VerifyError::MissingInterpreter(interpreter.label()))?;
+
+    let expected = seq.parse_data();
+    let terms = expected.len().min(MAX_TERMS);
+    let script = build_script(interpreter, snippet, seq, terms);
+
+    let workdir = scratch_dir()?;
+    let result = run_in(&workdir, interpreter, &program, &script, timeout).await;
+    let _ = std::fs::remove_dir_all(&workdir);
+    let (stdout, stderr, timed_out, elapsed) = result?;
+
+    let produced = extract_terms(interpreter, &stdout);
+    let (offset, _) = seq.parse_offset();
+    let outcome = compare_terms(&expected[..terms], &produced, offset as i64);
+
+    Ok(VerifyReport {
+        interpreter,
+        outcome,
+        timed_out,
+        elapsed,
+        error: stderr
+            .lines()
+            .rev()
+            .map(str::trim)
+            .find(|l| !l.is_empty())
+            .map(str::to_string),
+    })
+}
+
+fn scratch_dir() -> Result<PathBuf> {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    let dir = std::env::temp_dir().join(format!("oeis-verify-{}-{}", std::process::id(), nanos));
+    std::fs::create_dir_all(&dir).context("Failed to create scratch directory")?;
+    Ok(dir)
+}
+
+async fn run_in(
+    workdir: &Path,
+    interpreter: Interpreter,
+    program: &Path,
+    script: &str,
+    timeout: Duration,
+) -> Result<(String, String, bool, Duration)> {
+    let script_path = workdir.join(interpreter.script_name());
+    std::fs::write(&script_path, script).context("Failed to write script")?;
+
+    let mut command = Command::new(program);
+    command
+        .args(interpreter.args(&script_path))
+        .current_dir(workdir)
+        .env_clear()
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    // Keep only what interpreters (and version-manager shims) need to start
+    for key in ["PATH", "HOME", "LANG", "SYSTEMROOT", "PYENV_ROOT", "PYENV_VERSION"] {
+        if let Some(value) = std::env::var_os(key) {
+            command.env(key, value);
+        }
+    }
+    command.env("PYTHONDONTWRITEBYTECODE", "1");
+
+    let started = Instant::now();
+    let mut child = command
+        .spawn()
+        .with_context(|| format!("Failed to start {}", program.display()))?;
+
+    let stdout = child.stdout.take().ok_or_else(|| anyhow!("stdout not captured"))?;
+    let stderr = child.stderr.take().ok_or_else(|| anyhow!("stderr not captured"))?;
+    let stdout_task = tokio::spawn(read_capped(stdout));
+    let stderr_task = tokio::spawn(read_capped(stderr));
+
+    let timed_out = match tokio::time::timeout(timeout, child.wait()).await {
+        Ok(status) => {
+            status.context("Failed to wait for interpreter")?;
+            false
+        }
+        Err(_) => {
+            let _ = child.kill().await;
+            true
+        }
+    };
+    let elapsed = started.elapsed();
+
+    let stdout = stdout_task.await.unwrap_or_default();
+    let stderr = stderr_task.await.unwrap_or_default();
+    Ok((stdout, stderr, timed_out, elapsed))
+}
+
+/// Read `reader` to the end, keeping the first `MAX_OUTPUT_BYTES`. The pipe is drained
+/// past the cap so a program printing more does not block on a full pipe.
+async fn read_capped<R: tokio::io::AsyncRead + Unpin>(mut reader: R) -> String {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 8192];
+    loop {
+        match reader.read(&mut chunk).await {
+            Ok(0) | Err(_) => break,
+            Ok(read) => {
+                let kept = read.min(MAX_OUTPUT_BYTES - buffer.len());
+                buffer.extend_from_slice(&chunk[..kept]);
+            }
+        }
+    }
+    String::from_utf8_lossy(&buffer).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci() -> Sequence {
+        serde_json::from_value(serde_json::json!({
+            "number": 45,
+            "data": "0,1,1,2,3,5,8,13",
+            "name": "Fibonacci numbers",
+            "offset": "0,4",
+            "keyword": "nonn,core",
+            "author": "",
+            "created": "",
+            "time": "",
+            "references": 0,
+            "revision": 0
+        }))
+        .expect("valid sequence")
+    }
+
+    fn snippet(language: &str, code: &str) -> CodeSnippet {
+        CodeSnippet {
+            language: language.to_string(),
+            code: code.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_build_script_adds_driver() {
+        let seq = fibonacci();
+        let pari = build_script(Interpreter::Pari, &snippet("PARI", "a(n)=fibonacci(n)"), &seq, 8);
+        assert!(pari.ends_with("for(n=0,7,print(a(n)))\nquit\n"));
+
+        let python = build_script(
+            Interpreter::Python,
+            &snippet("Python", "def A000045(n):\n    return n"),
+            &seq,
+            8,
+        );
+        assert!(python.ends_with("for _n in range(0, 8):\n    print(A000045(_n))\n"));
+
+        let listing = build_script(Interpreter::Python, &snippet("Python", "print([0, 1, 1])"), &seq, 8);
+        assert_eq!(listing, "print([0, 1, 1])\n");
+    }
+
+    #[test]
+    fn test_extract_and_compare_terms() {
+        let produced = extract_terms(Interpreter::Pari, "%1 = [0, 1, 1, 2, 3]\n");
+        assert_eq!(produced, vec!["0", "1", "1", "2", "3"]);
+
+        let expected: Vec<String> = fibonacci().parse_data();
+        assert_eq!(
+            compare_terms(&expected[..5], &produced, 0),
+            VerifyOutcome::Match { checked: 5 }
+        );
+        assert_eq!(
+            compare_terms(&expected, &produced, 0),
+            VerifyOutcome::Partial {
+                checked: 5,
+                expected: 8
+            }
+        );
+        assert_eq!(
+            compare_terms(&expected, &expected, 0),
+            VerifyOutcome::Match { checked: 8 }
+        );
+
+        let wrong: Vec<String> = vec!["0".into(), "1".into(), "2".into()];
+        assert_eq!(
+            compare_terms(&expected, &wrong, 0),
+            VerifyOutcome::Mismatch {
+                index: 2,
+                expected: "1".into(),
+                actual: "2".into(),
+                matched: 2
+            }
+        );
+        assert_eq!(compare_terms(&expected, &[], 0), VerifyOutcome::NoOutput);
+    }
+
+    #[test]
+    fn test_timed_out_run_does_not_pass() {
+        let mut report = VerifyReport {
+            interpreter: Interpreter::Python,
+            outcome: VerifyOutcome::Match { checked: 8 },
+            timed_out: false,
+            elapsed: Duration::from_millis(40),
+            error: None,
+        };
+        assert!(report.passed());
+        report.timed_out = true;
+        assert!(!report.passed());
+        report.timed_out = false;
+        report.outcome = VerifyOutcome::Partial {
+            checked: 1,
+            expected: 8,
+        };
+        assert!(!report.passed());
+    }
+
+    #[tokio::test]
+    async fn test_read_capped_drains_past_the_cap() {
+        let output = "1\n".repeat(MAX_OUTPUT_BYTES);
+        let mut reader = std::io::Cursor::new(output.as_bytes());
+        let captured = read_capped(&mut reader).await;
+        assert_eq!(captured.len(), MAX_OUTPUT_BYTES);
+        assert!(captured.starts_with("1\n1\n"));
+        assert_eq!(reader.position() as usize, output.len());
+    }
+}